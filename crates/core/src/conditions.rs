@@ -4,55 +4,57 @@ use super::print_items::*;
 use super::*;
 
 pub fn indent_if_start_of_line(items: PrintItems) -> Condition {
+    let arena = items.arena();
     let rc_path = items.into_rc_path();
     if_true_or(
         "indentIfStartOfLine",
         |context| Some(condition_resolvers::is_start_of_line(&context)),
-        parser_helpers::with_indent(rc_path.clone().into()),
-        rc_path.into(),
+        parser_helpers::with_indent(arena.items(rc_path)),
+        arena.items(rc_path),
     )
 }
 
 pub fn with_indent_if_start_of_line_indented(items: PrintItems) -> Condition {
+    let arena = items.arena();
     let rc_path = items.into_rc_path();
     if_true_or(
         "withIndentIfStartOfLineIndented",
         |context| Some(condition_resolvers::is_start_of_line_indented(context)),
-        parser_helpers::with_indent(rc_path.clone().into()),
-        rc_path.into(),
+        parser_helpers::with_indent(arena.items(rc_path)),
+        arena.items(rc_path),
     )
 }
 
-pub struct NewLineIfHangingSpaceOtherwiseOptions {
+pub struct NewLineIfHangingSpaceOtherwiseOptions<'a> {
     pub start_info: Info,
     pub end_info: Option<Info>,
-    pub space_char: Option<PrintItems>,
+    pub space_char: Option<PrintItems<'a>>,
 }
 
-pub fn new_line_if_hanging_space_otherwise(opts: NewLineIfHangingSpaceOtherwiseOptions) -> Condition {
-    let space_char = opts.space_char.unwrap_or(" ".into());
+pub fn new_line_if_hanging_space_otherwise<'a>(arena: &'a PrintItemsArena<'a>, opts: NewLineIfHangingSpaceOtherwiseOptions<'a>) -> Condition<'a> {
+    let space_char = opts.space_char.unwrap_or_else(|| arena.items(" "));
     let start_info = opts.start_info;
     let end_info = opts.end_info;
 
     if_true_or(
         "newLineIfHangingSpaceOtherwise",
         move |context| condition_resolvers::is_hanging(context, &start_info, &end_info),
-        Signal::NewLine.into(),
+        arena.items(Signal::NewLine),
         space_char,
     )
 }
 
-pub fn new_line_if_hanging(start_info: Info, end_info: Option<Info>) -> Condition {
+pub fn new_line_if_hanging<'a>(arena: &'a PrintItemsArena<'a>, start_info: Info, end_info: Option<Info>) -> Condition<'a> {
     if_true(
         "newlineIfHanging",
         move |context| condition_resolvers::is_hanging(context, &start_info, &end_info),
-        Signal::NewLine.into(),
+        arena.items(Signal::NewLine),
     )
 }
 
 /// This condition can be used to force the printer to jump back to the point
 /// this condition exists at once the provided info is resolved.
-pub fn force_reevaluation_once_resolved(info: Info) -> Condition {
+pub fn force_reevaluation_once_resolved<'a>(info: Info) -> Condition<'a> {
     Condition::new("forceReevaluationOnceInfoResolved", ConditionProperties {
         condition: Rc::new(Box::new(move |context| {
             let resolved_info = context.get_resolved_info(&info);
@@ -63,7 +65,7 @@ pub fn force_reevaluation_once_resolved(info: Info) -> Condition {
     })
 }
 
-pub fn new_line_if_multiple_lines_space_or_new_line_otherwise(start_info: Info, end_info: Option<Info>) -> Condition {
+pub fn new_line_if_multiple_lines_space_or_new_line_otherwise<'a>(arena: &'a PrintItemsArena<'a>, start_info: Info, end_info: Option<Info>) -> Condition<'a> {
     if_true_or(
         "newLineIfMultipleLinesSpaceOrNewLineOtherwise",
         move |context| {
@@ -78,28 +80,29 @@ pub fn new_line_if_multiple_lines_space_or_new_line_otherwise(start_info: Info,
 
             return Some(end_info.line_number > start_info.line_number);
         },
-        Signal::NewLine.into(),
-        Signal::SpaceOrNewLine.into(),
+        arena.items(Signal::NewLine),
+        arena.items(Signal::SpaceOrNewLine),
     )
 }
 
-pub fn single_indent_if_start_of_line() -> Condition {
+pub fn single_indent_if_start_of_line<'a>(arena: &'a PrintItemsArena<'a>) -> Condition<'a> {
     if_true(
         "singleIndentIfStartOfLine",
         |context| Some(condition_resolvers::is_start_of_line(context)),
-        Signal::SingleIndent.into(),
+        arena.items(Signal::SingleIndent),
     )
 }
 
 /// Prints the provided items when the current relative column number is above
 /// the specified width.
 pub fn if_above_width(width: u8, items: PrintItems) -> Condition {
-    if_above_width_or(width, items, PrintItems::new())
+    let arena = items.arena();
+    if_above_width_or(width, items, PrintItems::new(arena))
 }
 
 /// Prints the provided true_items when the current relative column number is above
 /// the specified width or prints the false_items otherwise.
-pub fn if_above_width_or(width: u8, true_items: PrintItems, false_items: PrintItems) -> Condition {
+pub fn if_above_width_or<'a>(width: u8, true_items: PrintItems<'a>, false_items: PrintItems<'a>) -> Condition<'a> {
     Condition::new("ifAboveWidth", ConditionProperties {
         condition: Rc::new(Box::new(move |context| {
             let writer_info = &context.writer_info;
@@ -111,11 +114,11 @@ pub fn if_above_width_or(width: u8, true_items: PrintItems, false_items: PrintIt
     })
 }
 
-pub fn if_true(
+pub fn if_true<'a>(
     name: &'static str,
     resolver: impl Fn(&mut ConditionResolverContext) -> Option<bool> + 'static,
-    true_path: PrintItems
-) -> Condition {
+    true_path: PrintItems<'a>
+) -> Condition<'a> {
     Condition::new(name, ConditionProperties {
         true_path: Some(true_path),
         false_path: None,
@@ -123,12 +126,12 @@ pub fn if_true(
     })
 }
 
-pub fn if_true_or(
+pub fn if_true_or<'a>(
     name: &'static str,
     resolver: impl Fn(&mut ConditionResolverContext) -> Option<bool> + 'static,
-    true_path: PrintItems,
-    false_path: PrintItems
-) -> Condition {
+    true_path: PrintItems<'a>,
+    false_path: PrintItems<'a>
+) -> Condition<'a> {
     Condition::new(name, ConditionProperties {
         true_path: Some(true_path),
         false_path: Some(false_path),
@@ -136,11 +139,11 @@ pub fn if_true_or(
     })
 }
 
-pub fn if_false(
+pub fn if_false<'a>(
     name: &'static str,
     resolver: impl Fn(&mut ConditionResolverContext) -> Option<bool> + 'static,
-    false_path: PrintItems
-) -> Condition {
+    false_path: PrintItems<'a>
+) -> Condition<'a> {
     Condition::new(name, ConditionProperties {
         true_path: None,
         false_path: Some(false_path),
@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+use serde::Serialize;
+use super::{ConfigOption, ResolveConfigurationResult};
+use super::super::format_error::FormatError;
+
+/// The text a comment starts with to override the configuration of a file
+/// (ex. `// dprint-config: lineWidth=160, quoteStyle=alwaysSingle`).
+pub const CONFIG_DIRECTIVE_PREFIX: &str = "dprint-config:";
+
+/// Gets the key value pairs of a `dprint-config:` directive found in the comments at the start
+/// of a file with JS-like comments.
+///
+/// Returns an error when a value of the directive is not in the form `key=value`.
+pub fn get_js_like_config_directive(file_text: &str) -> Result<Option<HashMap<String, String>>, String> {
+    let mut text = file_text.trim_start_matches('\u{FEFF}');
+    if text.starts_with("#!") {
+        text = &text[text.find('\n').unwrap_or(text.len())..];
+    }
+
+    loop {
+        text = text.trim_start();
+        let comment_text = if text.starts_with("//") {
+            let end = text.find('\n').unwrap_or(text.len());
+            let comment_text = &text[2..end];
+            text = &text[end..];
+            comment_text
+        } else if text.starts_with("/*") {
+            match text[2..].find("*/") {
+                Some(index) => {
+                    let comment_text = &text[2..index + 2];
+                    text = &text[index + 4..];
+                    comment_text
+                },
+                None => return Ok(None),
+            }
+        } else {
+            return Ok(None);
+        };
+
+        if let Some(directive_text) = comment_text.trim().strip_prefix(CONFIG_DIRECTIVE_PREFIX) {
+            return parse_config_directive(directive_text).map(Some);
+        }
+    }
+}
+
+/// Resolves the configuration of a file that has a `dprint-config:` directive by resolving the
+/// plugin's configuration with the directive's values applied.
+///
+/// Returns `Ok(None)` when the file has no directive and a config error when the directive's values have diagnostics.
+pub fn resolve_config_directive<T>(
+    file_text: &str,
+    config: &HashMap<String, String>,
+    options: &[ConfigOption],
+    resolve_config: impl FnOnce(HashMap<String, String>) -> ResolveConfigurationResult<T>,
+) -> Result<Option<T>, FormatError> where T : Clone + Serialize {
+    let directive = match get_js_like_config_directive(file_text) {
+        Ok(Some(directive)) => directive,
+        Ok(None) => return Ok(None),
+        Err(err) => return Err(get_directive_error(&err)),
+    };
+
+    let directive_keys = directive.keys().cloned().collect::<Vec<_>>();
+    let mut file_config = config.clone();
+    for (key, value) in directive.into_iter() {
+        // remove the option's value even when specified by a deprecated alias so the directive's value is used
+        if let Some(option) = options.iter().find(|option| option.key == key || option.deprecated_aliases.contains(&key.as_str())) {
+            file_config.remove(option.key);
+            for alias in option.deprecated_aliases.iter() {
+                file_config.remove(*alias);
+            }
+        }
+        file_config.insert(key, value);
+    }
+
+    // the diagnostics of the plugin's configuration are reported when the plugin is initialized
+    let result = resolve_config(file_config);
+    let diagnostics = result.diagnostics.iter().filter(|diagnostic| directive_keys.contains(&diagnostic.property_name)).collect::<Vec<_>>();
+    if diagnostics.is_empty() {
+        Ok(Some(result.config))
+    } else {
+        let messages = diagnostics.iter().map(|diagnostic| diagnostic.message.as_str()).collect::<Vec<_>>();
+        Err(get_directive_error(&messages.join(" ")))
+    }
+}
+
+fn parse_config_directive(text: &str) -> Result<HashMap<String, String>, String> {
+    let mut config = HashMap::new();
+    for pair in text.split(',').map(|pair| pair.trim()).filter(|pair| !pair.is_empty()) {
+        let index = pair.find('=').filter(|index| *index > 0);
+        match index {
+            Some(index) => config.insert(String::from(pair[..index].trim()), String::from(pair[index + 1..].trim())),
+            None => return Err(format!("Expected a value in the form 'key=value', but found '{}'.", pair)),
+        };
+    }
+    Ok(config)
+}
+
+fn get_directive_error(message: &str) -> FormatError {
+    FormatError::config(format!("Error in the {} directive. {}", CONFIG_DIRECTIVE_PREFIX.trim_end_matches(':'), message))
+}
@@ -0,0 +1,72 @@
+/// Detects whether the file text is indented with tabs based on whether more of its
+/// indented lines start with a tab than with a space.
+///
+/// Returns `None` when no lines are indented.
+pub fn detect_use_tabs(file_text: &str) -> Option<bool> {
+    let mut tab_count = 0;
+    let mut space_count = 0;
+
+    for line in get_indented_lines(file_text) {
+        if line.starts_with('\t') {
+            tab_count += 1;
+        } else {
+            space_count += 1;
+        }
+    }
+
+    if tab_count == 0 && space_count == 0 {
+        None
+    } else {
+        Some(tab_count > space_count)
+    }
+}
+
+/// Detects the indent width of the file text from the most common increase in the number of
+/// leading spaces between lines, preferring the smaller width on a tie.
+///
+/// Returns `None` when no lines are indented with spaces.
+pub fn detect_indent_width(file_text: &str) -> Option<u8> {
+    const MAX_INDENT_WIDTH: usize = 8;
+    let mut counts = [0; MAX_INDENT_WIDTH + 1];
+    let mut last_indent = 0;
+
+    for line in file_text.lines() {
+        let trimmed_line = line.trim_start();
+        if trimmed_line.is_empty() || is_block_comment_line(trimmed_line) {
+            continue;
+        }
+        let leading_whitespace = &line[..line.len() - trimmed_line.len()];
+        if leading_whitespace.contains('\t') {
+            continue;
+        }
+
+        let indent = leading_whitespace.len();
+        if indent > last_indent && indent - last_indent <= MAX_INDENT_WIDTH {
+            counts[indent - last_indent] += 1;
+        }
+        last_indent = indent;
+    }
+
+    let mut best_width = None;
+    let mut best_count = 0;
+    for (width, count) in counts.iter().enumerate() {
+        if *count > best_count {
+            best_width = Some(width as u8);
+            best_count = *count;
+        }
+    }
+    best_width
+}
+
+fn get_indented_lines(file_text: &str) -> impl Iterator<Item = &str> {
+    file_text.lines().filter(|line| {
+        let trimmed_line = line.trim_start();
+        trimmed_line.len() < line.len() && !trimmed_line.is_empty() && !is_block_comment_line(trimmed_line)
+    })
+}
+
+/// Gets if the line is the continuation of a block comment (ex. ` * text`), which is
+/// commonly offset by a single space.
+fn is_block_comment_line(trimmed_line: &str) -> bool {
+    trimmed_line.starts_with('*')
+}
@@ -1,6 +1,14 @@
 use std::collections::HashMap;
 use serde::{Serialize, Deserialize};
 
+mod directive;
+mod indentation;
+mod options;
+
+pub use directive::*;
+pub use indentation::*;
+pub use options::*;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ParseConfigurationError(pub String);
 
@@ -10,24 +18,32 @@ impl std::fmt::Display for ParseConfigurationError {
     }
 }
 
+/// Implements `FromStr` and `Display` for a configuration enum along with a `VALUES`
+/// constant of its text values that can be used in a `ConfigOptionKind::Enum`.
+#[macro_export]
 macro_rules! generate_str_to_from {
     ($enum_name:ident, $([$member_name:ident, $string_value:expr]),* ) => {
+        impl $enum_name {
+            /// The text values of this configuration value.
+            pub const VALUES: &'static [&'static str] = &[$($string_value),*];
+        }
+
         impl std::str::FromStr for $enum_name {
-            type Err = ParseConfigurationError;
+            type Err = $crate::configuration::ParseConfigurationError;
 
             fn from_str(s: &str) -> Result<Self, Self::Err> {
                 match s {
                     $($string_value => Ok($enum_name::$member_name)),*,
-                    _ => Err(ParseConfigurationError(String::from(s))),
+                    _ => Err($crate::configuration::ParseConfigurationError(String::from(s))),
                 }
             }
         }
 
-        impl std::string::ToString for $enum_name {
-            fn to_string(&self) -> String {
-                match self {
-                    $($enum_name::$member_name => String::from($string_value)),*,
-                }
+        impl std::fmt::Display for $enum_name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(match self {
+                    $($enum_name::$member_name => $string_value),*,
+                })
             }
         }
     };
@@ -57,6 +73,71 @@ generate_str_to_from![
     [System, "system"]
 ];
 
+/// A configuration value that may be `"auto"` to detect it from the file text (ex. `indentWidth`).
+#[derive(Clone, PartialEq, Copy, Debug)]
+pub enum AutoValue<T> {
+    /// Detect the value from the file text.
+    Auto,
+    Value(T),
+}
+
+impl<T> AutoValue<T> {
+    /// Gets the value when it's not `Auto`.
+    pub fn value(self) -> Option<T> {
+        match self {
+            AutoValue::Auto => None,
+            AutoValue::Value(value) => Some(value),
+        }
+    }
+}
+
+impl<T> std::str::FromStr for AutoValue<T> where T : std::str::FromStr {
+    type Err = T::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "auto" {
+            Ok(AutoValue::Auto)
+        } else {
+            s.parse::<T>().map(AutoValue::Value)
+        }
+    }
+}
+
+impl<T> std::fmt::Display for AutoValue<T> where T : std::fmt::Display {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AutoValue::Auto => write!(f, "auto"),
+            AutoValue::Value(value) => value.fmt(f),
+        }
+    }
+}
+
+impl<T> Serialize for AutoValue<T> where T : Serialize {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S : serde::Serializer {
+        match self {
+            AutoValue::Auto => serializer.serialize_str("auto"),
+            AutoValue::Value(value) => value.serialize(serializer),
+        }
+    }
+}
+
+impl<'de, T> Deserialize<'de> for AutoValue<T> where T : Deserialize<'de> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D : serde::Deserializer<'de> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum RawAutoValue<T> {
+            Text(String),
+            Value(T),
+        }
+
+        match RawAutoValue::<T>::deserialize(deserializer)? {
+            RawAutoValue::Text(text) if text == "auto" => Ok(AutoValue::Auto),
+            RawAutoValue::Text(text) => Err(serde::de::Error::custom(format!("Found invalid value '{}'.", text))),
+            RawAutoValue::Value(value) => Ok(AutoValue::Value(value)),
+        }
+    }
+}
+
 /// Represents a problem within the configuration.
 #[derive(Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -71,9 +152,22 @@ pub struct ConfigurationDiagnostic {
 #[serde(rename_all = "camelCase")]
 pub struct GlobalConfiguration {
     pub line_width: Option<u32>,
-    pub use_tabs: Option<bool>,
-    pub indent_width: Option<u8>,
+    /// Whether to use tabs or `Auto` to detect it from the file's indentation.
+    ///
+    /// Plugins that don't support detecting it use the default instead of `Auto`.
+    pub use_tabs: Option<AutoValue<bool>>,
+    /// The indent width or `Auto` to detect it from the file's indentation.
+    ///
+    /// Plugins that don't support detecting it use the default instead of `Auto`.
+    pub indent_width: Option<AutoValue<u8>>,
     pub new_line_kind: Option<NewLineKind>,
+    /// Whether to measure text by its display width (ex. CJK characters and emoji take up two columns)
+    /// instead of its character count.
+    pub unicode_width: Option<bool>,
+    /// The maximum number of times the printer may evaluate a single condition (see `PrintBudget`).
+    pub max_condition_evaluations: Option<u32>,
+    /// The maximum number of times the printer may visit each print node on average (see `PrintBudget`).
+    pub max_visits_per_node: Option<u32>,
 }
 
 pub const DEFAULT_GLOBAL_CONFIGURATION: DefaultGlobalConfiguration = DefaultGlobalConfiguration {
@@ -81,6 +175,9 @@ pub const DEFAULT_GLOBAL_CONFIGURATION: DefaultGlobalConfiguration = DefaultGlob
     indent_width: 4,
     use_tabs: false,
     new_line_kind: NewLineKind::LineFeed,
+    unicode_width: false,
+    max_condition_evaluations: 10_000,
+    max_visits_per_node: 1_000,
 };
 
 pub struct DefaultGlobalConfiguration {
@@ -88,6 +185,9 @@ pub struct DefaultGlobalConfiguration {
     pub use_tabs: bool,
     pub indent_width: u8,
     pub new_line_kind: NewLineKind,
+    pub unicode_width: bool,
+    pub max_condition_evaluations: u32,
+    pub max_visits_per_node: u32,
 }
 
 #[derive(Clone, Serialize)]
@@ -111,6 +211,9 @@ pub fn resolve_global_config(config: HashMap<String, String>) -> ResolveConfigur
         use_tabs: get_nullable_value(&mut config, "useTabs", &mut diagnostics),
         indent_width: get_nullable_value(&mut config, "indentWidth", &mut diagnostics),
         new_line_kind: get_nullable_value(&mut config, "newLineKind", &mut diagnostics),
+        unicode_width: get_nullable_value(&mut config, "unicodeWidth", &mut diagnostics),
+        max_condition_evaluations: get_nullable_value(&mut config, "maxConditionEvaluations", &mut diagnostics),
+        max_visits_per_node: get_nullable_value(&mut config, "maxVisitsPerNode", &mut diagnostics),
     };
 
     for (key, _) in config.iter() {
@@ -0,0 +1,442 @@
+use std::collections::HashMap;
+use super::{AutoValue, ConfigurationDiagnostic, GlobalConfiguration, NewLineKind, DEFAULT_GLOBAL_CONFIGURATION, get_nullable_value};
+use super::super::utils::string_utils::get_json_string;
+
+/// The kind of value a configuration option accepts.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ConfigOptionKind {
+    Bool,
+    Number,
+    String,
+    /// One of the provided text values (ex. `BracePosition::VALUES`).
+    Enum(&'static [&'static str]),
+    /// `"auto"` to detect the value from the file text or a value of the provided kind.
+    Auto(&'static ConfigOptionKind),
+}
+
+/// Where a configuration option gets its value from when it's not specified.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ConfigOptionDefault {
+    /// The provided text value.
+    Value(&'static str),
+    /// The resolved value of another option (ex. `"bracePosition"` for `"ifStatement.bracePosition"`).
+    Option(&'static str),
+    /// The value of the option with the same key in the global configuration, falling back to the dprint default.
+    ///
+    /// When the global option accepts `"auto"` and this option doesn't, a global `"auto"` also falls back to the dprint default.
+    Global,
+    /// Like `Global`, but falling back to the provided text value instead of the dprint default
+    /// (ex. `"2"` for the indent width of a language conventionally indented with two spaces).
+    GlobalOr(&'static str),
+}
+
+/// Declaration of a plugin's configuration option.
+///
+/// Resolving the configuration, the unknown property diagnostics, the resolved configuration
+/// output and the documentation are all derived from these declarations.
+#[derive(Clone, Copy, Debug)]
+pub struct ConfigOption {
+    /// The key used in the configuration file.
+    pub key: &'static str,
+    pub kind: ConfigOptionKind,
+    pub default: ConfigOptionDefault,
+    pub description: &'static str,
+    /// Previous keys of the option that are still accepted.
+    pub deprecated_aliases: &'static [&'static str],
+}
+
+impl ConfigOption {
+    pub const fn new(key: &'static str, kind: ConfigOptionKind, default: ConfigOptionDefault, description: &'static str) -> ConfigOption {
+        ConfigOption {
+            key,
+            kind,
+            default,
+            description,
+            deprecated_aliases: &[],
+        }
+    }
+
+    /// Sets the previous keys of the option that are still accepted.
+    pub const fn with_deprecated_aliases(self, deprecated_aliases: &'static [&'static str]) -> ConfigOption {
+        ConfigOption {
+            deprecated_aliases,
+            ..self
+        }
+    }
+}
+
+/// Resolves the values of the declared configuration options from a collection of key value pairs.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashMap;
+/// use dprint_core::configuration::*;
+///
+/// const OPTIONS: &[ConfigOption] = &[
+///     ConfigOption::new("lineWidth", ConfigOptionKind::Number, ConfigOptionDefault::Global, "The width of a line."),
+///     ConfigOption::new("semiColons", ConfigOptionKind::Bool, ConfigOptionDefault::Value("true"), "Whether to use semi-colons."),
+/// ];
+///
+/// let global_config = resolve_global_config(HashMap::new()).config;
+/// let mut resolver = ConfigOptionResolver::new(OPTIONS, HashMap::new(), &global_config);
+/// let line_width: u32 = resolver.get("lineWidth");
+/// let semi_colons: bool = resolver.get("semiColons");
+/// let resolved_options = resolver.finish();
+///
+/// // check resolved_options.diagnostics here
+/// ```
+pub struct ConfigOptionResolver<'a> {
+    options: &'a [ConfigOption],
+    config: HashMap<String, String>,
+    global_config: &'a GlobalConfiguration,
+    resolved_values: HashMap<&'static str, String>,
+    diagnostics: Vec<ConfigurationDiagnostic>,
+}
+
+impl<'a> ConfigOptionResolver<'a> {
+    pub fn new(options: &'a [ConfigOption], config: HashMap<String, String>, global_config: &'a GlobalConfiguration) -> ConfigOptionResolver<'a> {
+        let mut config = config;
+        let mut diagnostics = Vec::new();
+
+        for option in options.iter() {
+            for alias in option.deprecated_aliases.iter() {
+                if let Some(value) = config.remove(*alias) {
+                    diagnostics.push(ConfigurationDiagnostic {
+                        property_name: String::from(*alias),
+                        message: format!("The '{}' property is deprecated. Use '{}' instead.", alias, option.key),
+                    });
+                    if !config.contains_key(option.key) {
+                        config.insert(String::from(option.key), value);
+                    }
+                }
+            }
+        }
+
+        ConfigOptionResolver {
+            options,
+            config,
+            global_config,
+            resolved_values: HashMap::new(),
+            diagnostics,
+        }
+    }
+
+    /// Sets the values of any options that weren't specified in the configuration (ex. from a preset).
+    pub fn fill_unset_values(&mut self, values: &HashMap<String, String>) {
+        for (key, value) in values.iter() {
+            if !self.config.contains_key(key) {
+                self.config.insert(key.clone(), value.clone());
+            }
+        }
+    }
+
+    /// Gets the resolved value of the declared option.
+    ///
+    /// Adds a diagnostic and uses the option's default when the specified value is invalid.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the option isn't declared, its declaration is invalid (see `get_config_option_declaration_errors`)
+    /// or its default can't be parsed as `T`. These are mistakes in the plugin rather than the configuration,
+    /// so a plugin should check them in a test with `dprint_development::ensure_config_options_resolve`.
+    pub fn get<T>(&mut self, key: &'static str) -> T where T : std::str::FromStr + ToString, <T as std::str::FromStr>::Err : std::fmt::Display {
+        if let Some(value) = self.resolved_values.get(key) {
+            return parse_resolved_value(key, value);
+        }
+
+        let option = get_option(self.options, key);
+        self.validate_enum_value(option);
+        let value = match get_nullable_value::<T>(&mut self.config, key, &mut self.diagnostics) {
+            Some(value) => value,
+            None => match option.default {
+                ConfigOptionDefault::Value(value) => parse_resolved_value(key, value),
+                ConfigOptionDefault::Option(other_key) => self.get(other_key),
+                ConfigOptionDefault::Global => {
+                    let value = get_global_value(self.global_config, option).unwrap_or_else(|| get_default_global_value(key));
+                    parse_resolved_value(key, &value)
+                },
+                ConfigOptionDefault::GlobalOr(default_value) => {
+                    let value = get_global_value(self.global_config, option).unwrap_or_else(|| String::from(default_value));
+                    parse_resolved_value(key, &value)
+                },
+            },
+        };
+
+        self.resolved_values.insert(option.key, value.to_string());
+        value
+    }
+
+    /// Adds a diagnostic and removes the specified value when it's not one of the option's allowed values.
+    fn validate_enum_value(&mut self, option: &ConfigOption) {
+        let values = match option.kind {
+            ConfigOptionKind::Enum(values) => values,
+            ConfigOptionKind::Auto(ConfigOptionKind::Enum(values)) => *values,
+            _ => return,
+        };
+        let is_valid = match self.config.get(option.key) {
+            Some(value) => values.contains(&value.as_str()) || (value == "auto" && matches!(option.kind, ConfigOptionKind::Auto(_))),
+            None => true,
+        };
+        if !is_valid {
+            let value = self.config.remove(option.key).unwrap();
+            self.diagnostics.push(ConfigurationDiagnostic {
+                property_name: String::from(option.key),
+                message: format!(
+                    "Error parsing configuration value for '{}'. Message: Found invalid value '{}'. Expected one of: {}",
+                    option.key,
+                    value,
+                    values.join(", "),
+                ),
+            });
+        }
+    }
+
+    /// Resolves any options that weren't retrieved and adds a diagnostic for each unknown property.
+    pub fn finish(mut self) -> ResolvedConfigOptions {
+        let options = self.options;
+        for option in options.iter() {
+            self.get::<String>(option.key);
+        }
+
+        for (key, _) in self.config.iter() {
+            self.diagnostics.push(ConfigurationDiagnostic {
+                property_name: String::from(key),
+                message: format!("Unknown property in configuration: {}", key),
+            });
+        }
+
+        let mut resolved_values = self.resolved_values;
+        ResolvedConfigOptions {
+            values: options.iter().map(|option| ResolvedConfigOption {
+                key: option.key,
+                kind: option.kind,
+                value: resolved_values.remove(option.key).unwrap(),
+            }).collect(),
+            diagnostics: self.diagnostics,
+        }
+    }
+}
+
+/// The result of resolving the declared configuration options.
+#[derive(Clone)]
+pub struct ResolvedConfigOptions {
+    /// The resolved values in the order the options were declared.
+    pub values: Vec<ResolvedConfigOption>,
+    /// The configuration diagnostics.
+    pub diagnostics: Vec<ConfigurationDiagnostic>,
+}
+
+#[derive(Clone)]
+pub struct ResolvedConfigOption {
+    pub key: &'static str,
+    pub kind: ConfigOptionKind,
+    /// The text of the resolved value.
+    pub value: String,
+}
+
+impl ResolvedConfigOptions {
+    /// Gets the resolved values as a pretty printed JSON object.
+    pub fn to_json_string(&self) -> String {
+        let mut text = String::from("{");
+        for (i, resolved_option) in self.values.iter().enumerate() {
+            if i > 0 {
+                text.push(',');
+            }
+            text.push_str("\n  ");
+            text.push_str(&get_json_string(resolved_option.key));
+            text.push_str(": ");
+            text.push_str(&get_json_value(resolved_option.kind, &resolved_option.value));
+        }
+        if !self.values.is_empty() {
+            text.push('\n');
+        }
+        text.push('}');
+        text
+    }
+}
+
+/// Gets markdown documentation of the provided configuration options.
+pub fn get_config_options_documentation(options: &[ConfigOption]) -> String {
+    let mut text = String::new();
+    for option in options.iter() {
+        if !text.is_empty() {
+            text.push('\n');
+        }
+        text.push_str(&format!("## {}\n\n{}\n\n", option.key, option.description));
+        text.push_str(&format!("* Type: {}\n", get_kind_documentation(option.kind)));
+        text.push_str(&format!("* Default: {}\n", match option.default {
+            ConfigOptionDefault::Value(value) => format!("`{}`", get_json_value(option.kind, value)),
+            ConfigOptionDefault::Option(other_key) => format!("The value of `{}`", other_key),
+            ConfigOptionDefault::Global => format!("The global `{}` or `{}`", option.key, get_default_global_value(option.key)),
+            ConfigOptionDefault::GlobalOr(default_value) => format!("The global `{}` or `{}`", option.key, default_value),
+        }));
+        if !option.deprecated_aliases.is_empty() {
+            text.push_str(&format!("* Deprecated aliases: {}\n", option.deprecated_aliases.iter().map(|alias| format!("`{}`", alias)).collect::<Vec<_>>().join(", ")));
+        }
+    }
+    text
+}
+
+/// Gets the errors in the declarations of the configuration options.
+///
+/// The declarations are valid when the keys are unique, each default value is a value of the option's
+/// kind, each option default refers to another declared option of the same kind without a cycle and
+/// each global default is for an option of the global configuration with the same kind (or the same
+/// kind without `"auto"`).
+pub fn get_config_option_declaration_errors(options: &[ConfigOption]) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    for (i, option) in options.iter().enumerate() {
+        let keys = options[..i].iter().flat_map(|other| std::iter::once(&other.key).chain(other.deprecated_aliases.iter()));
+        for key in keys {
+            if *key == option.key || option.deprecated_aliases.contains(key) {
+                errors.push(format!("The '{}' configuration option is declared more than once.", key));
+            }
+        }
+
+        match option.default {
+            ConfigOptionDefault::Value(value) => {
+                if !is_kind_value(option.kind, value) {
+                    errors.push(format!("The default value '{}' of the '{}' configuration option is not a value of its kind.", value, option.key));
+                }
+            },
+            ConfigOptionDefault::Option(other_key) => {
+                match options.iter().find(|other| other.key == other_key) {
+                    Some(other) if other.kind != option.kind => {
+                        errors.push(format!("The '{}' configuration option defaults to the '{}' option of a different kind.", option.key, other_key));
+                    },
+                    Some(_) => {
+                        if has_default_option_cycle(options, option) {
+                            errors.push(format!("The '{}' configuration option defaults to itself.", option.key));
+                        }
+                    },
+                    None => errors.push(format!("The '{}' configuration option defaults to the undeclared '{}' option.", option.key, other_key)),
+                }
+            },
+            ConfigOptionDefault::Global | ConfigOptionDefault::GlobalOr(_) => {
+                match get_global_kind(option.key) {
+                    Some(kind) if kind != option.kind && !matches!(kind, ConfigOptionKind::Auto(inner_kind) if *inner_kind == option.kind) => {
+                        errors.push(format!("The '{}' configuration option defaults to the global option of a different kind.", option.key));
+                    },
+                    Some(_) => {},
+                    None => errors.push(format!("The '{}' configuration option defaults to a global option that doesn't exist.", option.key)),
+                }
+                if let ConfigOptionDefault::GlobalOr(value) = option.default {
+                    if !is_kind_value(option.kind, value) {
+                        errors.push(format!("The default value '{}' of the '{}' configuration option is not a value of its kind.", value, option.key));
+                    }
+                }
+            },
+        }
+    }
+
+    errors
+}
+
+fn is_kind_value(kind: ConfigOptionKind, value: &str) -> bool {
+    match kind {
+        ConfigOptionKind::Bool => value == "true" || value == "false",
+        ConfigOptionKind::Number => value.parse::<u64>().is_ok(),
+        ConfigOptionKind::String => true,
+        ConfigOptionKind::Enum(values) => values.contains(&value),
+        ConfigOptionKind::Auto(kind) => value == "auto" || is_kind_value(*kind, value),
+    }
+}
+
+fn has_default_option_cycle(options: &[ConfigOption], option: &ConfigOption) -> bool {
+    let mut current = option;
+    // a chain without a cycle can't be longer than the number of options
+    for _ in 0..options.len() {
+        current = match current.default {
+            ConfigOptionDefault::Option(other_key) => match options.iter().find(|other| other.key == other_key) {
+                Some(other) => other,
+                None => return false,
+            },
+            _ => return false,
+        };
+        if current.key == option.key {
+            return true;
+        }
+    }
+    false
+}
+
+fn get_kind_documentation(kind: ConfigOptionKind) -> String {
+    match kind {
+        ConfigOptionKind::Bool => String::from("`true` | `false`"),
+        ConfigOptionKind::Number => String::from("number"),
+        ConfigOptionKind::String => String::from("string"),
+        ConfigOptionKind::Enum(values) => values.iter().map(|value| format!("`\"{}\"`", value)).collect::<Vec<_>>().join(" | "),
+        ConfigOptionKind::Auto(kind) => format!("`\"auto\"` | {}", get_kind_documentation(*kind)),
+    }
+}
+
+fn get_json_value(kind: ConfigOptionKind, value: &str) -> String {
+    match kind {
+        ConfigOptionKind::Bool | ConfigOptionKind::Number => String::from(value),
+        ConfigOptionKind::String | ConfigOptionKind::Enum(_) => get_json_string(value),
+        ConfigOptionKind::Auto(_) if value == "auto" => get_json_string(value),
+        ConfigOptionKind::Auto(kind) => get_json_value(*kind, value),
+    }
+}
+
+fn get_option<'a>(options: &'a [ConfigOption], key: &str) -> &'a ConfigOption {
+    match options.iter().find(|option| option.key == key) {
+        Some(option) => option,
+        None => panic!("The '{}' configuration option was not declared.", key),
+    }
+}
+
+fn get_global_kind(key: &str) -> Option<ConfigOptionKind> {
+    match key {
+        "lineWidth" | "maxConditionEvaluations" | "maxVisitsPerNode" => Some(ConfigOptionKind::Number),
+        "useTabs" => Some(ConfigOptionKind::Auto(&ConfigOptionKind::Bool)),
+        "indentWidth" => Some(ConfigOptionKind::Auto(&ConfigOptionKind::Number)),
+        "newLineKind" => Some(ConfigOptionKind::Enum(NewLineKind::VALUES)),
+        "unicodeWidth" => Some(ConfigOptionKind::Bool),
+        _ => None,
+    }
+}
+
+/// Gets the text of the global configuration value of the option when it's specified.
+fn get_global_value(global_config: &GlobalConfiguration, option: &ConfigOption) -> Option<String> {
+    let allows_auto = matches!(option.kind, ConfigOptionKind::Auto(_));
+    match option.key {
+        "lineWidth" => global_config.line_width.map(|value| value.to_string()),
+        "useTabs" => get_auto_value_text(global_config.use_tabs, allows_auto),
+        "indentWidth" => get_auto_value_text(global_config.indent_width, allows_auto),
+        "newLineKind" => global_config.new_line_kind.map(|value| value.to_string()),
+        "unicodeWidth" => global_config.unicode_width.map(|value| value.to_string()),
+        "maxConditionEvaluations" => global_config.max_condition_evaluations.map(|value| value.to_string()),
+        "maxVisitsPerNode" => global_config.max_visits_per_node.map(|value| value.to_string()),
+        _ => panic!("The '{}' configuration option does not have a global configuration value.", option.key),
+    }
+}
+
+fn get_auto_value_text<T>(value: Option<AutoValue<T>>, allows_auto: bool) -> Option<String> where T : std::fmt::Display {
+    match value {
+        Some(AutoValue::Auto) if !allows_auto => None,
+        value => value.map(|value| value.to_string()),
+    }
+}
+
+fn get_default_global_value(key: &str) -> String {
+    match key {
+        "lineWidth" => DEFAULT_GLOBAL_CONFIGURATION.line_width.to_string(),
+        "useTabs" => DEFAULT_GLOBAL_CONFIGURATION.use_tabs.to_string(),
+        "indentWidth" => DEFAULT_GLOBAL_CONFIGURATION.indent_width.to_string(),
+        "newLineKind" => DEFAULT_GLOBAL_CONFIGURATION.new_line_kind.to_string(),
+        "unicodeWidth" => DEFAULT_GLOBAL_CONFIGURATION.unicode_width.to_string(),
+        "maxConditionEvaluations" => DEFAULT_GLOBAL_CONFIGURATION.max_condition_evaluations.to_string(),
+        "maxVisitsPerNode" => DEFAULT_GLOBAL_CONFIGURATION.max_visits_per_node.to_string(),
+        _ => panic!("The '{}' configuration option does not have a global configuration value.", key),
+    }
+}
+
+fn parse_resolved_value<T>(key: &str, value: &str) -> T where T : std::str::FromStr, <T as std::str::FromStr>::Err : std::fmt::Display {
+    match value.parse::<T>() {
+        Ok(value) => value,
+        Err(err) => panic!("The resolved value of '{}' could not be parsed. Message: {}", key, err),
+    }
+}
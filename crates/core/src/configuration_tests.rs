@@ -10,6 +10,9 @@ fn get_default_config_when_empty() {
     assert_eq!(config.indent_width, None);
     assert_eq!(config.new_line_kind.is_none(), true);
     assert_eq!(config.use_tabs, None);
+    assert_eq!(config.unicode_width, None);
+    assert_eq!(config.max_condition_evaluations, None);
+    assert_eq!(config.max_visits_per_node, None);
 }
 
 #[test]
@@ -19,13 +22,34 @@ fn get_values_when_filled() {
     global_config.insert(String::from("indentWidth"), String::from("8"));
     global_config.insert(String::from("newLineKind"), String::from("crlf"));
     global_config.insert(String::from("useTabs"), String::from("true"));
+    global_config.insert(String::from("unicodeWidth"), String::from("true"));
+    global_config.insert(String::from("maxConditionEvaluations"), String::from("500"));
+    global_config.insert(String::from("maxVisitsPerNode"), String::from("50"));
     let config_result = resolve_global_config(global_config);
     let config = config_result.config;
     assert_eq!(config_result.diagnostics.len(), 0);
     assert_eq!(config.line_width, Some(80));
-    assert_eq!(config.indent_width, Some(8));
+    assert_eq!(config.indent_width, Some(AutoValue::Value(8)));
     assert_eq!(config.new_line_kind == Some(NewLineKind::CarriageReturnLineFeed), true);
-    assert_eq!(config.use_tabs, Some(true));
+    assert_eq!(config.use_tabs, Some(AutoValue::Value(true)));
+    assert_eq!(config.unicode_width, Some(true));
+    assert_eq!(config.max_condition_evaluations, Some(500));
+    assert_eq!(config.max_visits_per_node, Some(50));
+}
+
+#[test]
+fn get_auto_values() {
+    let config_result = resolve_global_config(get_config_map(vec![("indentWidth", "auto"), ("useTabs", "auto")]));
+    assert_eq!(config_result.diagnostics.len(), 0);
+    assert_eq!(config_result.config.indent_width, Some(AutoValue::Auto));
+    assert_eq!(config_result.config.use_tabs, Some(AutoValue::Auto));
+}
+
+#[test]
+fn get_diagnostic_for_invalid_auto_value() {
+    let diagnostics = resolve_global_config(get_config_map(vec![("indentWidth", "automatic")])).diagnostics;
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].message, "Error parsing configuration value for 'indentWidth'. Message: invalid digit found in string");
 }
 
 #[test]
@@ -57,3 +81,274 @@ fn get_diagnostic_for_excess_property() {
     assert_eq!(diagnostics[0].message, "Unknown property in configuration: something");
     assert_eq!(diagnostics[0].property_name, "something");
 }
+
+const TEST_OPTIONS: &[ConfigOption] = &[
+    ConfigOption::new("lineWidth", ConfigOptionKind::Number, ConfigOptionDefault::Global, "The width of a line."),
+    ConfigOption::new("newLineKind", ConfigOptionKind::Enum(NewLineKind::VALUES), ConfigOptionDefault::Global, "The kind of newline."),
+    ConfigOption::new("quoteStyle", ConfigOptionKind::String, ConfigOptionDefault::Value("double"), "The \"quote\" style."),
+    ConfigOption::new("ifStatement.quoteStyle", ConfigOptionKind::String, ConfigOptionDefault::Option("quoteStyle"), "The quote style of if statements."),
+    ConfigOption::new("semiColons", ConfigOptionKind::Bool, ConfigOptionDefault::Value("true"), "Whether to use semi-colons.")
+        .with_deprecated_aliases(&["semicolons"]),
+];
+
+#[test]
+fn resolve_config_options_to_defaults_when_empty() {
+    let global_config = get_global_config(vec![("lineWidth", "80")]);
+    let mut resolver = ConfigOptionResolver::new(TEST_OPTIONS, HashMap::new(), &global_config);
+    assert_eq!(resolver.get::<u32>("lineWidth"), 80);
+    assert!(resolver.get::<NewLineKind>("newLineKind") == NewLineKind::LineFeed);
+    assert_eq!(resolver.get::<String>("ifStatement.quoteStyle"), "double");
+    assert!(resolver.get::<bool>("semiColons"));
+    assert_eq!(resolver.finish().diagnostics.len(), 0);
+}
+
+#[test]
+fn resolve_config_options_from_values_and_other_options() {
+    let global_config = get_global_config(vec![]);
+    let config = get_config_map(vec![("quoteStyle", "single"), ("semicolons", "false"), ("lineWidth", "90")]);
+    let mut resolver = ConfigOptionResolver::new(TEST_OPTIONS, config, &global_config);
+    assert_eq!(resolver.get::<String>("ifStatement.quoteStyle"), "single");
+    assert_eq!(resolver.get::<String>("quoteStyle"), "single");
+    assert!(!resolver.get::<bool>("semiColons"));
+    assert_eq!(resolver.get::<u32>("lineWidth"), 90);
+    let diagnostics = resolver.finish().diagnostics;
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].property_name, "semicolons");
+    assert_eq!(diagnostics[0].message, "The 'semicolons' property is deprecated. Use 'semiColons' instead.");
+}
+
+#[test]
+fn resolve_config_options_prefers_key_over_deprecated_alias() {
+    let global_config = get_global_config(vec![]);
+    let config = get_config_map(vec![("semicolons", "false"), ("semiColons", "true")]);
+    let mut resolver = ConfigOptionResolver::new(TEST_OPTIONS, config, &global_config);
+    assert!(resolver.get::<bool>("semiColons"));
+    assert_eq!(resolver.finish().diagnostics.len(), 1);
+}
+
+#[test]
+fn resolve_config_options_fills_unset_values() {
+    let global_config = get_global_config(vec![]);
+    let config = get_config_map(vec![("quoteStyle", "single")]);
+    let mut resolver = ConfigOptionResolver::new(TEST_OPTIONS, config, &global_config);
+    resolver.fill_unset_values(&get_config_map(vec![("quoteStyle", "preset"), ("lineWidth", "100")]));
+    assert_eq!(resolver.get::<String>("quoteStyle"), "single");
+    assert_eq!(resolver.get::<u32>("lineWidth"), 100);
+}
+
+#[test]
+fn resolve_config_options_gets_diagnostics() {
+    let global_config = get_global_config(vec![]);
+    let config = get_config_map(vec![("newLineKind", "something"), ("other", "value")]);
+    let mut resolver = ConfigOptionResolver::new(TEST_OPTIONS, config, &global_config);
+    assert!(resolver.get::<NewLineKind>("newLineKind") == NewLineKind::LineFeed);
+    let diagnostics = resolver.finish().diagnostics;
+    assert_eq!(diagnostics.len(), 2);
+    assert_eq!(diagnostics[0].message, "Error parsing configuration value for 'newLineKind'. Message: Found invalid value 'something'. Expected one of: auto, lf, crlf, system");
+    assert_eq!(diagnostics[1].message, "Unknown property in configuration: other");
+}
+
+#[test]
+fn resolve_config_options_validates_enum_values_of_unretrieved_options() {
+    let global_config = get_global_config(vec![]);
+    let config = get_config_map(vec![("newLineKind", "something")]);
+    let resolved_options = ConfigOptionResolver::new(TEST_OPTIONS, config, &global_config).finish();
+    assert_eq!(resolved_options.values[1].value, "lf");
+    assert_eq!(resolved_options.diagnostics.len(), 1);
+    assert_eq!(resolved_options.diagnostics[0].property_name, "newLineKind");
+}
+
+#[test]
+fn resolve_config_options_to_json() {
+    let global_config = get_global_config(vec![]);
+    let config = get_config_map(vec![("quoteStyle", "a\"b")]);
+    let resolved_options = ConfigOptionResolver::new(TEST_OPTIONS, config, &global_config).finish();
+    assert_eq!(resolved_options.to_json_string(), concat!(
+        "{\n",
+        "  \"lineWidth\": 120,\n",
+        "  \"newLineKind\": \"lf\",\n",
+        "  \"quoteStyle\": \"a\\\"b\",\n",
+        "  \"ifStatement.quoteStyle\": \"a\\\"b\",\n",
+        "  \"semiColons\": true\n",
+        "}",
+    ));
+}
+
+#[test]
+fn get_config_options_documentation_for_options() {
+    assert_eq!(get_config_options_documentation(&TEST_OPTIONS[1..]), concat!(
+        "## newLineKind\n\nThe kind of newline.\n\n",
+        "* Type: `\"auto\"` | `\"lf\"` | `\"crlf\"` | `\"system\"`\n",
+        "* Default: The global `newLineKind` or `lf`\n",
+        "\n## quoteStyle\n\nThe \"quote\" style.\n\n",
+        "* Type: string\n",
+        "* Default: `\"double\"`\n",
+        "\n## ifStatement.quoteStyle\n\nThe quote style of if statements.\n\n",
+        "* Type: string\n",
+        "* Default: The value of `quoteStyle`\n",
+        "\n## semiColons\n\nWhether to use semi-colons.\n\n",
+        "* Type: `true` | `false`\n",
+        "* Default: `true`\n",
+        "* Deprecated aliases: `semicolons`\n",
+    ));
+}
+
+const AUTO_TEST_OPTIONS: &[ConfigOption] = &[
+    ConfigOption::new("indentWidth", ConfigOptionKind::Auto(&ConfigOptionKind::Number), ConfigOptionDefault::Global, "The indent width."),
+    ConfigOption::new("useTabs", ConfigOptionKind::Auto(&ConfigOptionKind::Bool), ConfigOptionDefault::Value("auto"), "Whether to use tabs."),
+];
+
+#[test]
+fn get_config_option_declaration_errors_for_valid_options() {
+    assert_eq!(get_config_option_declaration_errors(TEST_OPTIONS), Vec::<String>::new());
+    assert_eq!(get_config_option_declaration_errors(AUTO_TEST_OPTIONS), Vec::<String>::new());
+}
+
+#[test]
+fn get_config_option_declaration_errors_for_invalid_options() {
+    const INVALID_OPTIONS: &[ConfigOption] = &[
+        ConfigOption::new("lineWidth", ConfigOptionKind::Bool, ConfigOptionDefault::Global, ""),
+        ConfigOption::new("other", ConfigOptionKind::Number, ConfigOptionDefault::Global, ""),
+        ConfigOption::new("semiColons", ConfigOptionKind::Bool, ConfigOptionDefault::Value("yes"), ""),
+        ConfigOption::new("quoteStyle", ConfigOptionKind::Enum(&["single", "double"]), ConfigOptionDefault::Option("semiColons"), ""),
+        ConfigOption::new("ifStatement.quoteStyle", ConfigOptionKind::String, ConfigOptionDefault::Option("unknown"), ""),
+        ConfigOption::new("a", ConfigOptionKind::String, ConfigOptionDefault::Option("b"), ""),
+        ConfigOption::new("b", ConfigOptionKind::String, ConfigOptionDefault::Option("a"), "")
+            .with_deprecated_aliases(&["semiColons"]),
+        ConfigOption::new("indentWidth", ConfigOptionKind::Number, ConfigOptionDefault::GlobalOr("two"), ""),
+    ];
+
+    assert_eq!(get_config_option_declaration_errors(INVALID_OPTIONS), vec![
+        "The 'lineWidth' configuration option defaults to the global option of a different kind.",
+        "The 'other' configuration option defaults to a global option that doesn't exist.",
+        "The default value 'yes' of the 'semiColons' configuration option is not a value of its kind.",
+        "The 'quoteStyle' configuration option defaults to the 'semiColons' option of a different kind.",
+        "The 'ifStatement.quoteStyle' configuration option defaults to the undeclared 'unknown' option.",
+        "The 'a' configuration option defaults to itself.",
+        "The 'semiColons' configuration option is declared more than once.",
+        "The 'b' configuration option defaults to itself.",
+        "The default value 'two' of the 'indentWidth' configuration option is not a value of its kind.",
+    ]);
+}
+
+#[test]
+fn resolve_config_options_with_auto_values() {
+    let global_config = get_global_config(vec![("indentWidth", "auto")]);
+    let mut resolver = ConfigOptionResolver::new(AUTO_TEST_OPTIONS, HashMap::new(), &global_config);
+    assert_eq!(resolver.get::<AutoValue<u8>>("indentWidth"), AutoValue::Auto);
+    assert_eq!(resolver.get::<AutoValue<bool>>("useTabs"), AutoValue::Auto);
+    assert_eq!(resolver.finish().to_json_string(), "{\n  \"indentWidth\": \"auto\",\n  \"useTabs\": \"auto\"\n}");
+
+    let config = get_config_map(vec![("useTabs", "true")]);
+    let resolved_options = ConfigOptionResolver::new(AUTO_TEST_OPTIONS, config, &get_global_config(vec![])).finish();
+    assert_eq!(resolved_options.to_json_string(), "{\n  \"indentWidth\": 4,\n  \"useTabs\": true\n}");
+}
+
+const GLOBAL_TEST_OPTIONS: &[ConfigOption] = &[
+    ConfigOption::new("indentWidth", ConfigOptionKind::Number, ConfigOptionDefault::GlobalOr("2"), "The indent width."),
+    ConfigOption::new("useTabs", ConfigOptionKind::Bool, ConfigOptionDefault::Global, "Whether to use tabs."),
+];
+
+#[test]
+fn resolve_config_options_with_global_values_without_auto() {
+    assert_eq!(get_config_option_declaration_errors(GLOBAL_TEST_OPTIONS), Vec::<String>::new());
+
+    let resolved_options = ConfigOptionResolver::new(GLOBAL_TEST_OPTIONS, HashMap::new(), &get_global_config(vec![])).finish();
+    assert_eq!(resolved_options.to_json_string(), "{\n  \"indentWidth\": 2,\n  \"useTabs\": false\n}");
+
+    let global_config = get_global_config(vec![("indentWidth", "auto"), ("useTabs", "auto")]);
+    let resolved_options = ConfigOptionResolver::new(GLOBAL_TEST_OPTIONS, HashMap::new(), &global_config).finish();
+    assert_eq!(resolved_options.to_json_string(), "{\n  \"indentWidth\": 2,\n  \"useTabs\": false\n}");
+
+    let global_config = get_global_config(vec![("indentWidth", "8"), ("useTabs", "true")]);
+    let resolved_options = ConfigOptionResolver::new(GLOBAL_TEST_OPTIONS, HashMap::new(), &global_config).finish();
+    assert_eq!(resolved_options.to_json_string(), "{\n  \"indentWidth\": 8,\n  \"useTabs\": true\n}");
+}
+
+#[test]
+fn get_config_options_documentation_for_global_options() {
+    assert_eq!(get_config_options_documentation(GLOBAL_TEST_OPTIONS), concat!(
+        "## indentWidth\n\nThe indent width.\n\n",
+        "* Type: number\n",
+        "* Default: The global `indentWidth` or `2`\n",
+        "\n## useTabs\n\nWhether to use tabs.\n\n",
+        "* Type: `true` | `false`\n",
+        "* Default: The global `useTabs` or `false`\n",
+    ));
+}
+
+#[test]
+fn get_config_options_documentation_for_auto_options() {
+    assert_eq!(get_config_options_documentation(AUTO_TEST_OPTIONS), concat!(
+        "## indentWidth\n\nThe indent width.\n\n",
+        "* Type: `\"auto\"` | number\n",
+        "* Default: The global `indentWidth` or `4`\n",
+        "\n## useTabs\n\nWhether to use tabs.\n\n",
+        "* Type: `\"auto\"` | `true` | `false`\n",
+        "* Default: `\"auto\"`\n",
+    ));
+}
+
+#[test]
+fn detect_indentation_from_file_text() {
+    assert_eq!(detect_use_tabs("a\n\tb\n\t\tc\n  d\n"), Some(true));
+    assert_eq!(detect_use_tabs("a\n  b\n    c\n\td\n"), Some(false));
+    assert_eq!(detect_use_tabs("a\n/**\n * b\n */\n\tc\n"), Some(true));
+    assert_eq!(detect_use_tabs("a\n\nb\n"), None);
+
+    assert_eq!(detect_indent_width("a {\n  b {\n    c\n  }\n}\n"), Some(2));
+    assert_eq!(detect_indent_width("a {\n    b {\n        c\n    }\n    d(e,\n      f);\n}\n"), Some(4));
+    assert_eq!(detect_indent_width("a {\n   b\n}\nc {\n  d\n}\n"), Some(2));
+    assert_eq!(detect_indent_width("a {\n\tb\n}\n/**\n * c\n */\n"), None);
+}
+
+#[test]
+fn get_config_directive_from_leading_comments() {
+    assert!(get_js_like_config_directive("const a = 5; // dprint-config: lineWidth=80").unwrap().is_none());
+    assert_eq!(get_js_like_config_directive("// dprint-config: lineWidth=80, quoteStyle = alwaysSingle\nconst a;").unwrap(), Some(get_config_map(vec![
+        ("lineWidth", "80"),
+        ("quoteStyle", "alwaysSingle"),
+    ])));
+    assert_eq!(get_js_like_config_directive("#!/usr/bin/env node\n/* Copyright */\n\n/* dprint-config: lineWidth=80 */").unwrap(), Some(get_config_map(vec![
+        ("lineWidth", "80"),
+    ])));
+}
+
+#[test]
+fn get_config_directive_error_for_invalid_value() {
+    let err = get_js_like_config_directive("// dprint-config: lineWidth").err().unwrap();
+    assert_eq!(err, "Expected a value in the form 'key=value', but found 'lineWidth'.");
+}
+
+#[test]
+fn resolve_config_directive_with_plugin_config() {
+    let global_config = get_global_config(vec![]);
+    let config = get_config_map(vec![("quoteStyle", "single"), ("semicolons", "false")]);
+    let resolve = |file_text: &str| resolve_config_directive(file_text, &config, TEST_OPTIONS, |config| {
+        let mut resolver = ConfigOptionResolver::new(TEST_OPTIONS, config, &global_config);
+        let config = (resolver.get::<String>("ifStatement.quoteStyle"), resolver.get::<bool>("semiColons"));
+        ResolveConfigurationResult {
+            config,
+            diagnostics: resolver.finish().diagnostics,
+        }
+    });
+
+    assert_eq!(resolve("const a;").unwrap(), None);
+    assert_eq!(resolve("// dprint-config: quoteStyle=double, semiColons=true").unwrap(), Some((String::from("double"), true)));
+    assert_eq!(
+        resolve("// dprint-config: other=5").err().unwrap().message,
+        "Error in the dprint-config directive. Unknown property in configuration: other",
+    );
+    assert_eq!(
+        resolve("// dprint-config: semicolons=true").err().unwrap().message,
+        "Error in the dprint-config directive. The 'semicolons' property is deprecated. Use 'semiColons' instead.",
+    );
+}
+
+fn get_global_config(values: Vec<(&str, &str)>) -> GlobalConfiguration {
+    resolve_global_config(get_config_map(values)).config
+}
+
+fn get_config_map(values: Vec<(&str, &str)>) -> HashMap<String, String> {
+    values.into_iter().map(|(key, value)| (String::from(key), String::from(value))).collect()
+}
@@ -0,0 +1,125 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+use super::print_error::PrintError;
+use super::utils::diagnostics::{render_diagnostic, DiagnosticLabel, DiagnosticOptions, DiagnosticSpan};
+use super::utils::string_utils::{get_column_number_of_pos, get_line_number_of_pos};
+
+/// The kind of problem that caused a `FormatError`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FormatErrorKind {
+    /// The file text could not be parsed.
+    Syntax,
+    /// The configuration used to format the file is invalid (ex. a bad `dprint-config` directive).
+    Config,
+    /// A failure in the formatter itself (ex. exceeding the print budget or a plugin panic).
+    Internal,
+}
+
+/// An error that occurred formatting a file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FormatError {
+    pub kind: FormatErrorKind,
+    pub message: String,
+    /// The byte range of the file text the error occurred at.
+    pub range: Option<(usize, usize)>,
+    /// The 1-indexed line and column number of the start of the range.
+    pub line_and_column: Option<(u32, u32)>,
+    /// Other ranges of the file text related to the error (ex. an unclosed brace).
+    pub labels: Vec<DiagnosticLabel>,
+    pub file_path: Option<PathBuf>,
+}
+
+impl FormatError {
+    pub fn new(kind: FormatErrorKind, message: String) -> FormatError {
+        FormatError {
+            kind,
+            message,
+            range: None,
+            line_and_column: None,
+            labels: Vec::new(),
+            file_path: None,
+        }
+    }
+
+    /// Creates an error for a problem parsing the file text at the provided byte range.
+    pub fn syntax(message: String, range: (usize, usize), file_text: &str) -> FormatError {
+        FormatError::new(FormatErrorKind::Syntax, message).with_range(range, file_text)
+    }
+
+    pub fn config(message: String) -> FormatError {
+        FormatError::new(FormatErrorKind::Config, message)
+    }
+
+    pub fn internal(message: String) -> FormatError {
+        FormatError::new(FormatErrorKind::Internal, message)
+    }
+
+    /// Sets the byte range of the file text the error occurred at along with its line and column.
+    pub fn with_range(self, range: (usize, usize), file_text: &str) -> FormatError {
+        FormatError {
+            range: Some(range),
+            line_and_column: Some((get_line_number_of_pos(file_text, range.0) as u32, get_column_number_of_pos(file_text, range.0) as u32)),
+            ..self
+        }
+    }
+
+    /// Adds a range of the file text related to the error that's shown with the provided message.
+    pub fn with_label(mut self, range: (usize, usize), message: String) -> FormatError {
+        self.labels.push(DiagnosticLabel { range, message });
+        self
+    }
+
+    /// Moves the ranges by the provided number of bytes. Used when the error occurred
+    /// formatting text that starts at the offset of the file text.
+    pub fn with_offset(self, offset: usize, file_text: &str) -> FormatError {
+        let labels = self.labels.into_iter()
+            .map(|label| DiagnosticLabel { range: (label.range.0 + offset, label.range.1 + offset), ..label })
+            .collect();
+        let err = FormatError { labels, ..self };
+        match err.range {
+            Some((start, end)) => err.with_range((start + offset, end + offset), file_text),
+            None => err,
+        }
+    }
+
+    pub fn with_file_path(self, file_path: &Path) -> FormatError {
+        FormatError {
+            file_path: Some(file_path.to_path_buf()),
+            ..self
+        }
+    }
+
+    /// Gets the message along with a snippet of the file text that highlights the range of the error.
+    pub fn get_diagnostic_text(&self, file_text: &str) -> String {
+        self.get_diagnostic_text_with_options(file_text, &DiagnosticOptions::default())
+    }
+
+    /// Gets the diagnostic text with the labels and using the provided number of context lines and colors.
+    pub fn get_diagnostic_text_with_options(&self, file_text: &str, options: &DiagnosticOptions) -> String {
+        let mut spans = Vec::new();
+        if let Some(range) = self.range {
+            spans.push(DiagnosticSpan { range, label: None, is_primary: true });
+        }
+        for label in self.labels.iter() {
+            spans.push(DiagnosticSpan { range: label.range, label: Some(&label.message), is_primary: false });
+        }
+        render_diagnostic(&self.message, &spans, file_text, options)
+    }
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some((line_number, column_number)) = self.line_and_column {
+            write!(f, "Line {}, column {}: ", line_number, column_number)?;
+        }
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for FormatError {}
+
+impl From<PrintError> for FormatError {
+    fn from(err: PrintError) -> FormatError {
+        FormatError::internal(err.to_string())
+    }
+}
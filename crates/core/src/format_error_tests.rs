@@ -0,0 +1,59 @@
+use super::*;
+
+#[test]
+fn format_error_gets_line_and_column_of_range() {
+    let file_text = "a;\nb c;";
+    let err = FormatError::syntax(String::from("Expected ';'."), (5, 6), file_text);
+    assert_eq!(err.kind, FormatErrorKind::Syntax);
+    assert_eq!(err.line_and_column, Some((2, 3)));
+    assert_eq!(err.to_string(), "Line 2, column 3: Expected ';'.");
+    assert_eq!(
+        err.get_diagnostic_text(file_text),
+        concat!(
+            "Line 2, column 3: Expected ';'.\n",
+            "\n",
+            "  1 | a;\n",
+            "  2 | b c;\n",
+            "    |   ~"
+        )
+    );
+}
+
+#[test]
+fn format_error_gets_diagnostic_text_with_labels() {
+    let file_text = "let a = (5;\nb;";
+    let err = FormatError::syntax(String::from("Expected ')'."), (10, 11), file_text)
+        .with_label((8, 9), String::from("Unclosed parenthesis."));
+    assert_eq!(
+        err.get_diagnostic_text(file_text),
+        concat!(
+            "Line 1, column 11: Expected ')'.\n",
+            "\n",
+            "  1 | let a = (5;\n",
+            "    |         - Unclosed parenthesis.\n",
+            "    |           ~\n",
+            "  2 | b;"
+        )
+    );
+}
+
+#[test]
+fn format_error_with_offset_moves_ranges() {
+    let file_text = "<script>\na b;\n</script>";
+    let err = FormatError::syntax(String::from("Expected ';'."), (2, 3), "a b;")
+        .with_label((0, 1), String::from("Label."))
+        .with_offset(9, file_text);
+    assert_eq!(err.range, Some((11, 12)));
+    assert_eq!(err.line_and_column, Some((2, 3)));
+    assert_eq!(err.labels[0].range, (9, 10));
+}
+
+#[test]
+fn format_error_from_print_error_is_internal() {
+    let err = FormatError::from(PrintError::ConditionEvaluationsExceeded {
+        condition_name: "test",
+        evaluations: 5,
+    });
+    assert_eq!(err.kind, FormatErrorKind::Internal);
+    assert_eq!(err.to_string(), "Printing exceeded its budget after evaluating the condition 'test' 5 times. This is a bug in the formatter.");
+}
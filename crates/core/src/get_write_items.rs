@@ -1,6 +1,8 @@
 use super::printer::*;
-use super::PrintItems;
+use super::print_items::PrintItems;
 use super::WriteItem;
+use super::print_budget::PrintBudget;
+use super::print_error::PrintError;
 
 /// Options for getting the write items.
 pub struct GetWriteItemsOptions {
@@ -8,10 +10,15 @@ pub struct GetWriteItemsOptions {
     pub max_width: u32,
     /// The number of columns to count when indenting or using a tab.
     pub indent_width: u8,
+    /// Whether to measure strings by their display width instead of their character count.
+    pub unicode_width: bool,
+    /// Limits on the amount of work the printer may do before erroring.
+    pub budget: PrintBudget,
 }
 
 /// Gets write items from the print items.
-pub fn get_write_items(print_items: &PrintItems, options: GetWriteItemsOptions) -> impl Iterator<Item = WriteItem> {
-    let printer = Printer::new(print_items.first_node.clone(), options);
-    printer.print()
+///
+/// Errors when the printer exceeds the budget provided in the options.
+pub fn get_write_items<'a>(print_items: &PrintItems<'a>, options: GetWriteItemsOptions) -> Result<impl Iterator<Item = WriteItem<'a>>, PrintError> {
+    Printer::new(print_items, options).print()
 }
@@ -7,6 +7,11 @@ mod writer;
 mod write_items;
 mod print_write_items;
 mod print;
+mod print_budget;
+mod print_error;
+mod format_error;
+mod position_map;
+mod long_lines;
 pub mod condition_resolvers;
 pub mod conditions;
 pub mod parser_helpers;
@@ -16,11 +21,28 @@ pub mod utils;
 
 pub use print_items::*;
 pub use write_items::*;
-pub use print_write_items::{print_write_items, PrintWriteItemsOptions};
+pub use print_write_items::{print_write_items, print_write_items_equals, print_write_items_to, print_write_items_to_io, print_write_items_with_mapping, PrintWriteItemsOptions};
 pub use get_write_items::{get_write_items, GetWriteItemsOptions};
-pub use print::{print, PrintOptions};
+pub use print::{format, format_equals, format_with_long_lines, format_with_mapping, print, print_equals, print_to, print_to_io, print_with_long_lines, print_with_mapping, PrintOptions};
+pub use position_map::{PositionMap, PositionMapping};
+pub use long_lines::{get_long_lines, LongLine};
+pub use print_budget::PrintBudget;
+pub use print_error::PrintError;
+pub use format_error::{FormatError, FormatErrorKind};
 
 #[cfg(test)]
 mod writer_tests;
 #[cfg(test)]
 mod configuration_tests;
+#[cfg(test)]
+mod print_items_tests;
+#[cfg(test)]
+mod position_map_tests;
+#[cfg(test)]
+mod long_lines_tests;
+#[cfg(test)]
+mod printer_tests;
+#[cfg(test)]
+mod format_error_tests;
+#[cfg(test)]
+mod plugins_tests;
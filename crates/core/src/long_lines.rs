@@ -0,0 +1,133 @@
+use super::WriteItem;
+use super::utils::unicode_width::get_display_width;
+
+/// A line of the printed text that's wider than the max width because the printer couldn't
+/// find a way to break it up (ex. a long string literal or URL).
+#[derive(Clone, Debug, PartialEq)]
+pub struct LongLine {
+    /// The 1-indexed line number in the printed text.
+    pub line_number: u32,
+    /// The 1-indexed column in the printed text where the text that went past the max width starts.
+    pub column_number: u32,
+    /// The number of columns the line takes up.
+    pub width: u32,
+    /// The string that went past the max width or `None` when it was whitespace.
+    pub text: Option<String>,
+    /// The byte position of the string in the original text, if known.
+    pub source_position: Option<usize>,
+}
+
+/// Gets the lines of the text that are wider than the max width.
+///
+/// This is for formatters that don't print with print items (ex. markdown), so each word between
+/// the spaces or tabs of a line is treated as a string that couldn't be broken up.
+pub fn get_long_lines(text: &str, max_width: u32, tab_width: u8, unicode_width: bool) -> Vec<LongLine> {
+    let mut recorder = LongLineRecorder::new(max_width, tab_width, unicode_width);
+
+    for (i, line) in text.split('\n').enumerate() {
+        if i > 0 {
+            recorder.finish_line();
+            recorder.line_number += 1;
+        }
+
+        let mut word_start = None;
+        for (index, c) in line.trim_end_matches('\r').char_indices() {
+            if c == ' ' || c == '\t' {
+                if let Some(start) = word_start.take() {
+                    recorder.add_word(&line[start..index]);
+                }
+                recorder.add_width(if c == '\t' { tab_width as u32 } else { 1 }, None, None);
+            } else if word_start.is_none() {
+                word_start = Some(index);
+            }
+        }
+        if let Some(start) = word_start {
+            recorder.add_word(line[start..].trim_end_matches('\r'));
+        }
+    }
+
+    recorder.into_long_lines()
+}
+
+/// Records the lines that exceed the max width while the write items are printed.
+pub(crate) struct LongLineRecorder {
+    max_width: u32,
+    indent_width: u8,
+    unicode_width: bool,
+    line_number: u32,
+    column: u32,
+    current_long_line: Option<LongLine>,
+    long_lines: Vec<LongLine>,
+}
+
+impl LongLineRecorder {
+    pub fn new(max_width: u32, indent_width: u8, unicode_width: bool) -> LongLineRecorder {
+        LongLineRecorder {
+            max_width,
+            indent_width,
+            unicode_width,
+            line_number: 1,
+            column: 0,
+            current_long_line: None,
+            long_lines: Vec::new(),
+        }
+    }
+
+    pub fn record(&mut self, item: &WriteItem) {
+        match item {
+            WriteItem::NewLine => {
+                self.finish_line();
+                self.line_number += 1;
+            },
+            WriteItem::Indent(times) => self.add_width(*times as u32 * self.indent_width as u32, None, None),
+            WriteItem::Tab => self.add_width(self.indent_width as u32, None, None),
+            WriteItem::Space => self.add_width(1, None, None),
+            WriteItem::String(text) => self.add_width(text.get_width(self.unicode_width), Some(text.text), text.source_position.get()),
+        }
+    }
+
+    pub fn into_long_lines(mut self) -> Vec<LongLine> {
+        self.finish_line();
+        self.long_lines
+    }
+
+    fn add_word(&mut self, word: &str) {
+        let width = if self.unicode_width { get_display_width(word) as u32 } else { word.chars().count() as u32 };
+        self.add_width(width, Some(word), None);
+    }
+
+    fn add_width(&mut self, width: u32, text: Option<&str>, source_position: Option<usize>) {
+        let column_number = self.column + 1;
+        self.column += width;
+        if self.column <= self.max_width || width == 0 {
+            return;
+        }
+
+        match &mut self.current_long_line {
+            // prefer reporting the string over trailing whitespace that went past the max width
+            Some(long_line) if long_line.text.is_none() && text.is_some() => {
+                long_line.column_number = column_number;
+                long_line.text = text.map(String::from);
+                long_line.source_position = source_position;
+            },
+            Some(_) => {},
+            None => {
+                self.current_long_line = Some(LongLine {
+                    line_number: self.line_number,
+                    column_number,
+                    width: 0,
+                    text: text.map(String::from),
+                    source_position,
+                });
+            },
+        }
+    }
+
+    fn finish_line(&mut self) {
+        if let Some(mut long_line) = self.current_long_line.take() {
+            long_line.width = self.column;
+            self.long_lines.push(long_line);
+        }
+        self.column = 0;
+    }
+}
@@ -0,0 +1,85 @@
+use super::*;
+
+fn get_print_options(max_width: u32) -> PrintOptions {
+    PrintOptions {
+        indent_width: 2,
+        max_width,
+        use_tabs: false,
+        new_line_text: "\n",
+        unicode_width: true,
+        budget: PrintBudget::default(),
+    }
+}
+
+#[test]
+fn print_with_long_lines_gets_lines_wider_than_max_width() {
+    let (text, long_lines) = format_with_long_lines(|arena| {
+        let mut items = PrintItems::new(arena);
+        items.push_str("a = ");
+        items.push_str("\"long text\";");
+        items.push_signal(Signal::NewLine);
+        items.push_str("b;");
+        items.push_signal(Signal::NewLine);
+        items.push_signal(Signal::StartIndent);
+        items.push_str("c = ");
+        let mut string_items = PrintItems::new(arena);
+        string_items.push_str("\"日本\";");
+        string_items.set_source_position(20);
+        items.extend(string_items);
+        items.push_signal(Signal::FinishIndent);
+        items
+    }, get_print_options(10)).unwrap();
+
+    assert_eq!(text, "a = \"long text\";\nb;\n  c = \"日本\";");
+    assert_eq!(long_lines, vec![
+        LongLine {
+            line_number: 1,
+            column_number: 5,
+            width: 16,
+            text: Some(String::from("\"long text\";")),
+            source_position: None,
+        },
+        LongLine {
+            line_number: 3,
+            column_number: 7,
+            width: 13,
+            text: Some(String::from("\"日本\";")),
+            source_position: Some(20),
+        },
+    ]);
+}
+
+#[test]
+fn print_with_long_lines_gets_no_lines_when_within_max_width() {
+    let (text, long_lines) = format_with_long_lines(|arena| {
+        let mut items = PrintItems::new(arena);
+        items.push_str("a = 5;");
+        items.push_signal(Signal::NewLine);
+        items.push_str("b = 10;");
+        items
+    }, get_print_options(7)).unwrap();
+
+    assert_eq!(text, "a = 5;\nb = 10;");
+    assert_eq!(long_lines, Vec::new());
+}
+
+#[test]
+fn get_long_lines_gets_words_of_text_past_max_width() {
+    let long_lines = get_long_lines("a b\r\n  some long_text\n\tb  日本\nok", 10, 4, true);
+    assert_eq!(long_lines, vec![
+        LongLine {
+            line_number: 2,
+            column_number: 8,
+            width: 16,
+            text: Some(String::from("long_text")),
+            source_position: None,
+        },
+        LongLine {
+            line_number: 3,
+            column_number: 8,
+            width: 11,
+            text: Some(String::from("日本")),
+            source_position: None,
+        },
+    ]);
+}
@@ -0,0 +1,320 @@
+use super::super::print_items::*;
+use super::super::conditions;
+use super::helpers::{parse_raw_string, parse_string};
+use super::super::utils::unicode_width::get_display_width;
+
+/// Options for parsing a JS-like block comment.
+pub struct CommentBlockOptions {
+    /// Re-aligns the leading `*` of each line to the indentation the comment is printed at.
+    pub align_gutters: bool,
+    /// Wraps the prose paragraphs of the comment to this width when set. This also aligns the gutters.
+    pub wrap_width: Option<u32>,
+    /// Measures the words by their display width when wrapping (see `PrintOptions::unicode_width`).
+    pub unicode_width: bool,
+}
+
+/// Parses a JS-like block comment given the text between the `/*` and `*/`.
+///
+/// Comments that don't have a `*` at the start of each line are printed as-is. Code blocks,
+/// `@example` sections, and indented lines are never wrapped.
+pub fn parse_js_like_comment_block<'a>(arena: &'a PrintItemsArena<'a>, text: &str, opts: CommentBlockOptions) -> PrintItems<'a> {
+    let mut items = PrintItems::new(arena);
+    items.push_str("/*");
+
+    match get_gutter_comment(text) {
+        Some(comment) if opts.align_gutters || opts.wrap_width.is_some() => {
+            items.extend(parse_gutter_comment(arena, comment, &opts));
+        },
+        _ => items.extend(parse_raw_string(arena, text)),
+    }
+
+    items.push_str("*/");
+    items
+}
+
+struct GutterComment<'a> {
+    /// Text on the line of the `/*` (ex. the second `*` of `/**`).
+    first_line: &'a str,
+    /// Text after the `*` of each following line.
+    lines: Vec<&'a str>,
+    /// If the `*/` is on the last line of text rather than its own line.
+    closes_on_last_line: bool,
+}
+
+fn get_gutter_comment<'a>(text: &'a str) -> Option<GutterComment<'a>> {
+    let mut raw_lines = text.split('\n').map(|line| line.trim_end_matches('\r')).collect::<Vec<_>>();
+    if raw_lines.len() < 2 {
+        return None;
+    }
+
+    let first_line = raw_lines.remove(0);
+    let closes_on_last_line = !raw_lines.last().unwrap().trim().is_empty();
+    if !closes_on_last_line {
+        raw_lines.pop();
+    }
+
+    let mut lines = Vec::new();
+    for (i, raw_line) in raw_lines.iter().enumerate() {
+        let line = raw_line.trim_start();
+        if !line.starts_with('*') {
+            return None;
+        }
+        let line = &line[1..];
+        // keep the spacing before the `*/` when it's on the same line
+        lines.push(if closes_on_last_line && i == raw_lines.len() - 1 { line } else { line.trim_end() });
+    }
+
+    Some(GutterComment {
+        first_line: first_line.trim_end(),
+        lines,
+        closes_on_last_line,
+    })
+}
+
+fn parse_gutter_comment<'a>(arena: &'a PrintItemsArena<'a>, comment: GutterComment, opts: &CommentBlockOptions) -> PrintItems<'a> {
+    let mut items = PrintItems::new(arena);
+    items.extend(parse_string(arena, comment.first_line));
+
+    let blocks = match opts.wrap_width {
+        Some(_) if !comment.closes_on_last_line => get_blocks(&comment.lines),
+        _ => comment.lines.iter().map(|line| CommentBlock::Verbatim(line)).collect(),
+    };
+
+    for block in blocks {
+        match block {
+            CommentBlock::Verbatim(line) => {
+                items.push_signal(Signal::NewLine);
+                items.push_str(" *");
+                items.extend(parse_string(arena, line));
+            },
+            CommentBlock::Paragraph { words, hanging_indent } => {
+                items.push_signal(Signal::NewLine);
+                items.push_str(" *");
+                items.extend(parse_paragraph(arena, words, hanging_indent, opts.wrap_width.unwrap(), opts.unicode_width));
+            },
+        }
+    }
+
+    if !comment.closes_on_last_line {
+        items.push_signal(Signal::NewLine);
+        items.push_str(" ");
+    }
+
+    items
+}
+
+fn parse_paragraph<'a>(arena: &'a PrintItemsArena<'a>, words: Vec<&str>, hanging_indent: usize, wrap_width: u32, unicode_width: bool) -> PrintItems<'a> {
+    let mut items = PrintItems::new(arena);
+    for (i, word) in words.into_iter().enumerate() {
+        if i == 0 {
+            items.push_str(" ");
+        } else {
+            let word_width = if unicode_width { get_display_width(word) } else { word.chars().count() } as u32;
+            items.push_condition(conditions::if_true_or(
+                "commentWordExceedsWidth",
+                move |context| Some(context.writer_info.column_number + 1 + word_width > wrap_width),
+                {
+                    let mut items = PrintItems::new(arena);
+                    items.push_signal(Signal::NewLine);
+                    items.push_str(&format!(" * {}", " ".repeat(hanging_indent)));
+                    items
+                },
+                arena.items(" "),
+            ));
+        }
+        items.push_str(word);
+    }
+    items
+}
+
+enum CommentBlock<'a> {
+    Verbatim(&'a str),
+    Paragraph {
+        words: Vec<&'a str>,
+        /// Indentation of the wrapped lines (ex. to line up with the text of a list item).
+        hanging_indent: usize,
+    },
+}
+
+fn get_blocks<'a>(lines: &[&'a str]) -> Vec<CommentBlock<'a>> {
+    let mut blocks = Vec::new();
+    let mut in_code_block = false;
+    let mut in_example = false;
+    let mut is_paragraph_open = false;
+
+    for line in lines {
+        let trimmed_line = line.trim();
+        if trimmed_line.starts_with("```") {
+            in_code_block = !in_code_block;
+            is_paragraph_open = false;
+            blocks.push(CommentBlock::Verbatim(line));
+            continue;
+        }
+        if trimmed_line.starts_with('@') {
+            in_example = trimmed_line.starts_with("@example");
+        }
+
+        if in_code_block || in_example || !is_prose_line(line) {
+            is_paragraph_open = false;
+            blocks.push(CommentBlock::Verbatim(line));
+            continue;
+        }
+
+        let list_marker_width = get_list_marker_width(trimmed_line);
+        let starts_paragraph = !is_paragraph_open || trimmed_line.starts_with('@') || list_marker_width.is_some();
+        if !starts_paragraph {
+            if let Some(CommentBlock::Paragraph { words, .. }) = blocks.last_mut() {
+                words.extend(trimmed_line.split_whitespace());
+                continue;
+            }
+        }
+
+        blocks.push(CommentBlock::Paragraph {
+            words: trimmed_line.split_whitespace().collect(),
+            hanging_indent: list_marker_width.unwrap_or(0),
+        });
+        is_paragraph_open = true;
+    }
+
+    blocks
+}
+
+/// Gets if the line is text that could be wrapped. Blank lines and lines indented
+/// like a code block are kept as-is.
+fn is_prose_line(line: &str) -> bool {
+    if !line.starts_with(' ') || line.trim().is_empty() {
+        return false;
+    }
+    let indent_width = line.chars().take_while(|c| *c == ' ').count();
+    indent_width < 5 && !line.contains('\t')
+}
+
+/// Gets the width of the marker of a list item (ex. `- ` or `1. `).
+fn get_list_marker_width(text: &str) -> Option<usize> {
+    if text.starts_with("- ") || text.starts_with("* ") || text.starts_with("+ ") {
+        return Some(2);
+    }
+
+    let digit_count = text.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digit_count > 0 && text[digit_count..].starts_with(". ") {
+        Some(digit_count + 2)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::super::{format, PrintBudget, PrintOptions};
+    use super::super::helpers::with_indent;
+
+    #[test]
+    fn it_should_print_as_is_when_not_normalizing() {
+        assert_eq!(print_indented("*\n     * Testing\n     ", None, false), "  /**\n     * Testing\n     */");
+    }
+
+    #[test]
+    fn it_should_align_gutters_to_the_indentation() {
+        assert_eq!(print_indented("*\n     * Testing\n     *   this\n     ", None, true), "  /**\n   * Testing\n   *   this\n   */");
+        assert_eq!(print_indented("\n* a\n* b ", None, true), "  /*\n   * a\n   * b */");
+    }
+
+    #[test]
+    fn it_should_print_as_is_without_gutters() {
+        assert_eq!(print_indented(" a\n   b ", Some(10), true), "  /* a\n   b */");
+        assert_eq!(print_indented(" single line ", Some(10), true), "  /* single line */");
+    }
+
+    #[test]
+    fn it_should_wrap_paragraphs() {
+        let text = concat!(
+            "*\n",
+            " * Some text that should\n",
+            " * wrap to the width.\n",
+            " *\n",
+            " * - a list item that wraps\n",
+            " * @param a - The param.\n",
+            " ",
+        );
+        assert_eq!(print_indented(text, Some(20), true), concat!(
+            "  /**\n",
+            "   * Some text that\n",
+            "   * should wrap to\n",
+            "   * the width.\n",
+            "   *\n",
+            "   * - a list item\n",
+            "   *   that wraps\n",
+            "   * @param a - The\n",
+            "   * param.\n",
+            "   */",
+        ));
+    }
+
+    #[test]
+    fn it_should_wrap_paragraphs_by_display_width_when_unicode_width() {
+        let text = concat!(
+            "*\n",
+            " * 你好 世界 你好 世界\n",
+            " ",
+        );
+        assert_eq!(print_indented_with_unicode_width(text, Some(16), true, true), concat!(
+            "  /**\n",
+            "   * 你好 世界\n",
+            "   * 你好 世界\n",
+            "   */",
+        ));
+        assert_eq!(print_indented_with_unicode_width(text, Some(16), true, false), concat!(
+            "  /**\n",
+            "   * 你好 世界 你好 世界\n",
+            "   */",
+        ));
+    }
+
+    #[test]
+    fn it_should_not_wrap_code() {
+        let text = concat!(
+            "*\n",
+            " * Text\n",
+            " *     indented code that is long\n",
+            " * ```\n",
+            " * fenced code that is long\n",
+            " * ```\n",
+            " * @example\n",
+            " * example code that is long\n",
+            " ",
+        );
+        assert_eq!(print_indented(text, Some(20), true), concat!(
+            "  /**\n",
+            "   * Text\n",
+            "   *     indented code that is long\n",
+            "   * ```\n",
+            "   * fenced code that is long\n",
+            "   * ```\n",
+            "   * @example\n",
+            "   * example code that is long\n",
+            "   */",
+        ));
+    }
+
+    fn print_indented(text: &str, wrap_width: Option<u32>, align_gutters: bool) -> String {
+        print_indented_with_unicode_width(text, wrap_width, align_gutters, false)
+    }
+
+    fn print_indented_with_unicode_width(text: &str, wrap_width: Option<u32>, align_gutters: bool, unicode_width: bool) -> String {
+        format(|arena| {
+            with_indent(parse_js_like_comment_block(arena, text, CommentBlockOptions {
+                align_gutters,
+                wrap_width,
+                unicode_width,
+            }))
+        }, PrintOptions {
+            indent_width: 2,
+            max_width: 80,
+            use_tabs: false,
+            new_line_text: "\n",
+            unicode_width,
+            budget: PrintBudget::default(),
+        }).unwrap()
+    }
+}
@@ -0,0 +1,116 @@
+use super::super::print_items::*;
+use super::helpers::new_line_group;
+
+/// Packs as many of the values on each line as fit within the line width, then wraps to the next line.
+///
+/// The separator is placed after every value except the last one. Each value is placed in a new
+/// line group so that lines are wrapped between the values before within them.
+pub fn fill<'a>(values: Vec<PrintItems<'a>>, separator: PrintItems<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(separator.arena());
+    let values_count = values.len();
+    let separator = separator.into_rc_path();
+
+    for (i, value) in values.into_iter().enumerate() {
+        if i > 0 {
+            items.push_signal(Signal::SpaceOrNewLine);
+        }
+
+        let mut value = value;
+        if i < values_count - 1 {
+            value.push_optional_path(separator);
+        }
+        items.extend(new_line_group(value));
+    }
+
+    items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::super::{format, PrintBudget, PrintOptions};
+    use super::super::helpers::with_indent;
+    use super::super::parse_separated_values::*;
+
+    #[test]
+    fn it_should_pack_values_on_each_line() {
+        let text = format(|arena| {
+            let values = (1..=12).map(|i| arena.items(i.to_string())).collect();
+            let mut items = PrintItems::new(arena);
+            items.push_str("[");
+            items.push_signal(Signal::NewLine);
+            items.extend(with_indent(fill(values, arena.items(","))));
+            items.push_signal(Signal::NewLine);
+            items.push_str("]");
+            items
+        }, create_options(12)).unwrap();
+
+        assert_eq!(text, "[\n  1, 2, 3,\n  4, 5, 6,\n  7, 8, 9,\n  10, 11, 12\n]");
+    }
+
+    #[test]
+    fn it_should_wrap_between_values_before_within_them() {
+        let text = format(|arena| {
+            let values = vec![
+                arena.items("aaaa"),
+                {
+                    let mut items = PrintItems::new(arena);
+                    items.push_str("b(");
+                    items.push_signal(Signal::PossibleNewLine);
+                    items.push_str("cccc)");
+                    items
+                },
+            ];
+            fill(values, arena.items(","))
+        }, create_options(10)).unwrap();
+
+        assert_eq!(text, "aaaa,\nb(cccc)");
+    }
+
+    #[test]
+    fn it_should_fill_separated_values_when_multi_line() {
+        let print_array = |count: usize| format(|arena| {
+            let mut items = PrintItems::new(arena);
+            items.push_str("[");
+            items.extend(parse_separated_values(|_| {
+                (1..=count).map(|i| {
+                    let mut value = arena.items((i * 100).to_string());
+                    if i < count {
+                        value.push_str(",");
+                    }
+                    ParsedValue::from_items(new_line_group(value))
+                }).collect()
+            }, ParseSeparatedValuesOptions {
+                prefer_hanging: false,
+                force_use_new_lines: false,
+                allow_blank_lines: false,
+                single_line_space_at_start: false,
+                single_line_space_at_end: false,
+                single_line_separator: arena.items(Signal::SpaceOrNewLine),
+                indent_width: 2,
+                multi_line_options: MultiLineOptions {
+                    fill: true,
+                    ..MultiLineOptions::surround_newlines_indented()
+                },
+                force_possible_newline_at_start: false,
+            }).items);
+            items.push_str("]");
+            items
+        }, create_options(20)).unwrap();
+
+        assert_eq!(print_array(3), "[100, 200, 300]");
+        assert_eq!(print_array(7), "[\n  100, 200, 300,\n  400, 500, 600, 700\n]");
+        assert_eq!(print_array(8), "[\n  100, 200, 300,\n  400, 500, 600,\n  700, 800\n]");
+    }
+
+    fn create_options(max_width: u32) -> PrintOptions {
+        PrintOptions {
+            indent_width: 2,
+            max_width,
+            use_tabs: false,
+            new_line_text: "\n",
+            unicode_width: false,
+            budget: PrintBudget::default(),
+        }
+    }
+}
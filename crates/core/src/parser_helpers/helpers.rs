@@ -5,7 +5,7 @@ use super::super::conditions;
 use super::super::condition_resolvers;
 
 pub fn surround_with_new_lines(item: PrintItems) -> PrintItems {
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(item.arena());
     items.push_signal(Signal::NewLine);
     items.extend(item);
     items.push_signal(Signal::NewLine);
@@ -17,7 +17,7 @@ pub fn with_indent(item: PrintItems) -> PrintItems {
 }
 
 pub fn with_queued_indent(item: PrintItems) -> PrintItems {
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(item.arena());
     items.push_signal(Signal::QueueStartIndent);
     items.extend(item);
     items.push_signal(Signal::FinishIndent);
@@ -25,7 +25,7 @@ pub fn with_queued_indent(item: PrintItems) -> PrintItems {
 }
 
 pub fn with_indent_times(item: PrintItems, times: u32) -> PrintItems {
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(item.arena());
     for _ in 0..times { items.push_signal(Signal::StartIndent); }
     items.extend(item);
     for _ in 0..times { items.push_signal(Signal::FinishIndent); }
@@ -33,7 +33,7 @@ pub fn with_indent_times(item: PrintItems, times: u32) -> PrintItems {
 }
 
 pub fn with_no_new_lines(item: PrintItems) -> PrintItems {
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(item.arena());
     items.push_signal(Signal::StartForceNoNewLines);
     items.extend(item);
     items.push_signal(Signal::FinishForceNoNewLines);
@@ -41,7 +41,7 @@ pub fn with_no_new_lines(item: PrintItems) -> PrintItems {
 }
 
 pub fn new_line_group(item: PrintItems) -> PrintItems {
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(item.arena());
     items.push_signal(Signal::StartNewLineGroup);
     items.extend(item);
     items.push_signal(Signal::FinishNewLineGroup);
@@ -49,19 +49,19 @@ pub fn new_line_group(item: PrintItems) -> PrintItems {
 }
 
 /// Parses a string as is and ignores its indent.
-pub fn parse_raw_string(text: &str) -> PrintItems {
+pub fn parse_raw_string<'a>(arena: &'a PrintItemsArena<'a>, text: &str) -> PrintItems<'a> {
     let add_ignore_indent = text.find("\n").is_some();
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(arena);
     if add_ignore_indent { items.push_signal(Signal::StartIgnoringIndent); }
-    items.extend(parse_string(text));
+    items.extend(parse_string(arena, text));
     if add_ignore_indent { items.push_signal(Signal::FinishIgnoringIndent); }
 
     return items;
 }
 
 /// Parses a string to a series of PrintItems.
-pub fn parse_string(text: &str) -> PrintItems {
-    let mut items = PrintItems::new();
+pub fn parse_string<'a>(arena: &'a PrintItemsArena<'a>, text: &str) -> PrintItems<'a> {
+    let mut items = PrintItems::new(arena);
     let mut lines = text.lines().collect::<Vec<&str>>();
 
     // todo: this is kind of hacky...
@@ -75,13 +75,13 @@ pub fn parse_string(text: &str) -> PrintItems {
             items.push_signal(Signal::NewLine);
         }
 
-        items.extend(parse_line(&lines[i]));
+        items.extend(parse_line(arena, &lines[i]));
     }
 
     return items;
 
-    fn parse_line(line: &str) -> PrintItems {
-        let mut items = PrintItems::new();
+    fn parse_line<'a>(arena: &'a PrintItemsArena<'a>, line: &str) -> PrintItems<'a> {
+        let mut items = PrintItems::new(arena);
         let parts = line.split("\t").collect::<Vec<&str>>();
         for i in 0..parts.len() {
             if i > 0 {
@@ -91,7 +91,7 @@ pub fn parse_string(text: &str) -> PrintItems {
                 items.push_str(parts[i]);
             }
         }
-        items.into()
+        items
     }
 }
 
@@ -99,21 +99,22 @@ pub fn parse_string(text: &str) -> PrintItems {
 /// Note: This currently inserts a possible newline at the start, but that might change or be made
 /// conditional in the future.
 pub fn surround_with_newlines_indented_if_multi_line(inner_items: PrintItems, indent_width: u8) -> PrintItems {
-    let mut items = PrintItems::new();
+    let arena = inner_items.arena();
+    let mut items = PrintItems::new(arena);
     let start_info = Info::new("surroundWithNewLinesIndentedIfMultiLineStart");
     let end_info = Info::new("surroundWithNewLineIndentedsIfMultiLineEnd");
     let inner_items = inner_items.into_rc_path();
 
     items.push_info(start_info);
     items.push_condition(Condition::new_with_dependent_infos("newlineIfMultiLine", ConditionProperties {
-        true_path: Some(surround_with_new_lines(with_indent(inner_items.clone().into()))),
+        true_path: Some(surround_with_new_lines(with_indent(arena.items(inner_items)))),
         false_path: Some({
-            let mut items = PrintItems::new();
+            let mut items = PrintItems::new(arena);
             items.push_condition(conditions::if_above_width(
                 indent_width,
-                Signal::PossibleNewLine.into()
+                arena.items(Signal::PossibleNewLine)
             ));
-            items.extend(inner_items.into());
+            items.push_optional_path(inner_items);
             items
         }),
         condition: Rc::new(Box::new(move |context| {
@@ -129,9 +130,9 @@ pub fn surround_with_newlines_indented_if_multi_line(inner_items: PrintItems, in
     items
 }
 
-pub fn parse_js_like_comment_line(text: &str, force_space_after_slashes: bool) -> PrintItems {
-    let mut items = PrintItems::new();
-    items.extend(parse_raw_string(&get_comment_text(text, force_space_after_slashes)));
+pub fn parse_js_like_comment_line<'a>(arena: &'a PrintItemsArena<'a>, text: &str, force_space_after_slashes: bool) -> PrintItems<'a> {
+    let mut items = PrintItems::new(arena);
+    items.extend(parse_raw_string(arena, &get_comment_text(text, force_space_after_slashes)));
     items.push_signal(Signal::ExpectNewLine);
     return with_no_new_lines(items);
 
@@ -1,5 +1,9 @@
+mod comment_block;
+mod fill;
 mod helpers;
 mod parse_separated_values;
 
+pub use comment_block::*;
+pub use fill::*;
 pub use helpers::*;
 pub use parse_separated_values::*;
\ No newline at end of file
@@ -6,13 +6,13 @@ use super::super::conditions::*;
 use super::super::condition_resolvers;
 use super::super::parser_helpers;
 
-pub struct ParseSeparatedValuesOptions {
+pub struct ParseSeparatedValuesOptions<'a> {
     pub prefer_hanging: bool,
     pub force_use_new_lines: bool,
     pub allow_blank_lines: bool,
     pub single_line_space_at_start: bool,
     pub single_line_space_at_end: bool,
-    pub single_line_separator: PrintItems,
+    pub single_line_separator: PrintItems<'a>,
     pub indent_width: u8,
     pub multi_line_options: MultiLineOptions,
     /// Forces a possible newline at the start when there are values.
@@ -32,6 +32,9 @@ pub struct MultiLineOptions {
     pub with_indent: bool,
     pub with_hanging_indent: BoolOrCondition,
     pub maintain_line_breaks: bool,
+    /// Packs as many values on each line as fit instead of placing each value on its own line.
+    /// Each value should be in a new line group so that lines are wrapped between the values.
+    pub fill: bool,
 }
 
 impl MultiLineOptions {
@@ -42,6 +45,7 @@ impl MultiLineOptions {
             with_indent: true,
             with_hanging_indent: BoolOrCondition::Bool(false),
             maintain_line_breaks: false,
+            fill: false,
         }
     }
 
@@ -52,6 +56,7 @@ impl MultiLineOptions {
             with_indent: true,
             with_hanging_indent: BoolOrCondition::Bool(false),
             maintain_line_breaks: false,
+            fill: false,
         }
     }
 
@@ -62,6 +67,7 @@ impl MultiLineOptions {
             with_indent: false,
             with_hanging_indent: BoolOrCondition::Bool(true),
             maintain_line_breaks: false,
+            fill: false,
         }
     }
 
@@ -72,6 +78,7 @@ impl MultiLineOptions {
             with_indent: false,
             with_hanging_indent: BoolOrCondition::Bool(false),
             maintain_line_breaks: false,
+            fill: false,
         }
     }
 
@@ -82,12 +89,13 @@ impl MultiLineOptions {
             with_indent: false,
             with_hanging_indent: BoolOrCondition::Bool(false),
             maintain_line_breaks: true,
+            fill: false,
         }
     }
 }
 
-pub struct ParsedValue {
-    pub items: PrintItems,
+pub struct ParsedValue<'a> {
+    pub items: PrintItems<'a>,
     pub lines_span: Option<LinesSpan>,
     /// Whether this value is allowed to start on the same line as the
     /// previous token and finish on the same line as the next token
@@ -106,9 +114,9 @@ pub struct LinesSpan {
     pub end_line: usize,
 }
 
-impl ParsedValue {
+impl<'a> ParsedValue<'a> {
     /// Use this when you don't care about blank lines.
-    pub fn from_items(items: PrintItems) -> ParsedValue {
+    pub fn from_items(items: PrintItems<'a>) -> ParsedValue<'a> {
         ParsedValue {
             items,
             lines_span: None,
@@ -118,8 +126,8 @@ impl ParsedValue {
     }
 }
 
-pub struct ParseSeparatedValuesResult {
-    pub items: PrintItems,
+pub struct ParseSeparatedValuesResult<'a> {
+    pub items: PrintItems<'a>,
     pub is_multi_line_condition_ref: ConditionReference,
 }
 
@@ -129,10 +137,11 @@ struct ParsedValueData {
     allow_inline_single_line: bool,
 }
 
-pub fn parse_separated_values(
-    parsed_values: impl FnOnce(&ConditionReference) -> Vec<ParsedValue>,
-    opts: ParseSeparatedValuesOptions
-) -> ParseSeparatedValuesResult {
+pub fn parse_separated_values<'a>(
+    parsed_values: impl FnOnce(&ConditionReference) -> Vec<ParsedValue<'a>>,
+    opts: ParseSeparatedValuesOptions<'a>
+) -> ParseSeparatedValuesResult<'a> {
+    let arena = opts.single_line_separator.arena();
     let indent_width = opts.indent_width;
     let start_info = Info::new("startSeparatedValues");
     let end_info = Info::new("endSeparatedValues");
@@ -153,7 +162,7 @@ pub fn parse_separated_values(
     let is_multi_line = is_multi_line_condition_ref.create_resolver();
     let is_multi_line = Rc::new(Box::new(is_multi_line) as Box<ConditionResolver>);
 
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(arena);
     items.push_info(start_info);
     items.push_condition(get_clearer_resolutions_on_start_change_condition(value_datas.clone(), start_info, end_info));
     items.push_condition(is_start_standalone_line);
@@ -174,18 +183,18 @@ pub fn parse_separated_values(
     let parsed_values_items = inner_parse_result.items.into_rc_path();
     items.push_condition(Condition::new("multiLineOrHanging", ConditionProperties {
         condition: is_multi_line,
-        true_path: Some(if_true_or(
+        true_path: Some(arena.items(if_true_or(
             "newLineIndentedIfNotStandalone",
             move |context| Some(!context.get_resolved_condition(&is_start_standalone_line_ref)?),
             {
-                let mut items = PrintItems::new();
+                let mut items = PrintItems::new(arena);
                 if multi_line_options.newline_at_start {
                     items.push_signal(Signal::NewLine);
                 }
                 if multi_line_options.with_indent {
                     items.push_signal(Signal::StartIndent);
                 }
-                items.extend(parsed_values_items.clone().into());
+                items.push_optional_path(parsed_values_items);
                 if multi_line_options.with_indent {
                     items.push_signal(Signal::FinishIndent);
                 }
@@ -194,10 +203,10 @@ pub fn parse_separated_values(
                 }
                 items
             },
-            parsed_values_items.clone().into(),
-        ).into()),
+            arena.items(parsed_values_items),
+        ))),
         false_path: Some({
-            let mut items = PrintItems::new();
+            let mut items = PrintItems::new(arena);
             let has_start_space = opts.single_line_space_at_start;
             if has_start_space {
                 items.push_signal(Signal::SpaceIfNotTrailing);
@@ -207,10 +216,10 @@ pub fn parse_separated_values(
                 // place this after the space so the first item will start on a newline when there is a newline here
                 items.push_condition(if_above_width(
                     if opts.force_possible_newline_at_start { 0 } else { indent_width + if has_start_space { 1 } else { 0 } },
-                    Signal::PossibleNewLine.into()
+                    arena.items(Signal::PossibleNewLine)
                 ));
             }
-            items.extend(parsed_values_items.into());
+            items.push_optional_path(parsed_values_items);
             if opts.single_line_space_at_end { items.push_str(" "); }
             items
         }),
@@ -223,24 +232,26 @@ pub fn parse_separated_values(
         is_multi_line_condition_ref,
     };
 
-    struct InnerParseResult {
-        items: PrintItems,
+    struct InnerParseResult<'a> {
+        items: PrintItems<'a>,
         value_datas: Vec<ParsedValueData>,
     }
 
-    fn inner_parse(
-        parsed_values: Vec<ParsedValue>,
+    fn inner_parse<'a>(
+        parsed_values: Vec<ParsedValue<'a>>,
         is_multi_line: Rc<Box<ConditionResolver>>,
-        single_line_separator: PrintItems,
+        single_line_separator: PrintItems<'a>,
         multi_line_options: &MultiLineOptions,
         allow_blank_lines: bool,
-    ) -> InnerParseResult {
-        let mut items = PrintItems::new();
+    ) -> InnerParseResult<'a> {
+        let arena = single_line_separator.arena();
+        let mut items = PrintItems::new(arena);
         let mut value_datas = Vec::new();
         let values_count = parsed_values.len();
         let single_line_separator = single_line_separator.into_rc_path();
         let mut last_lines_span: Option<LinesSpan> = None;
         let maintain_line_breaks = multi_line_options.maintain_line_breaks;
+        let fill = multi_line_options.fill;
         let mut had_newline = false;
         let first_start_info = Info::new("firstValueStartInfo");
         let mut last_start_info = None;
@@ -258,7 +269,7 @@ pub fn parse_separated_values(
                     items.push_condition(if_false(
                         "isNotStartOfLine",
                         |context| Some(condition_resolvers::is_start_of_line(context)),
-                        Signal::PossibleNewLine.into()
+                        arena.items(Signal::PossibleNewLine)
                     ));
                 }
 
@@ -270,27 +281,30 @@ pub fn parse_separated_values(
                         (last_lines_span.end_line < current_lines_span.start_line, last_lines_span.end_line < current_lines_span.start_line - 1)
                     } else { (false, false) }
                 } else { (false, false) };
-                let use_blank_line = allow_blank_lines && has_blank_line;
+                let use_blank_line = allow_blank_lines && has_blank_line && !fill;
                 let parsed_value = parsed_value.items.into_rc_path();
                 items.push_condition(Condition::new("multiLineOrHangingCondition", ConditionProperties {
                     condition: is_multi_line.clone(),
                     true_path: {
-                        let mut items = PrintItems::new();
+                        let mut items = PrintItems::new(arena);
                         if use_blank_line { items.push_signal(Signal::NewLine); }
-                        if !maintain_line_breaks || has_new_line {
+                        if fill {
+                            // see parser_helpers::fill
+                            items.push_optional_path(single_line_separator);
+                        } else if !maintain_line_breaks || has_new_line {
                             items.push_signal(Signal::NewLine);
                             had_newline = true;
                         } else {
                             let space_or_newline = {
                                 if let Some(last_start_info) = last_start_info {
-                                    if_true_or(
+                                    arena.items(if_true_or(
                                         "newlineIfHanging",
                                         move |context| condition_resolvers::is_hanging(context, &last_start_info, &None),
-                                        Signal::NewLine.into(),
-                                        single_line_separator.clone().into(),
-                                    ).into()
+                                        arena.items(Signal::NewLine),
+                                        arena.items(single_line_separator),
+                                    ))
                                 } else {
-                                    single_line_separator.clone().into()
+                                    arena.items(single_line_separator)
                                 }
                             };
                             if i == values_count - 1 && !had_newline {
@@ -301,7 +315,7 @@ pub fn parse_separated_values(
                                     "forcedNewLineIfNoNewLine",
                                     move |context| condition_resolvers::is_on_different_line(context, &first_start_info),
                                     space_or_newline,
-                                    Signal::NewLine.into(),
+                                    arena.items(Signal::NewLine),
                                 ))
                             } else {
                                 items.extend(space_or_newline);
@@ -312,27 +326,27 @@ pub fn parse_separated_values(
                             BoolOrCondition::Bool(with_hanging_indent) => {
                                 if *with_hanging_indent {
                                     items.push_condition(indent_if_start_of_line({
-                                        let mut items = PrintItems::new();
+                                        let mut items = PrintItems::new(arena);
                                         items.push_info(start_info);
-                                        items.extend(parsed_value.clone().into());
+                                        items.push_optional_path(parsed_value);
                                         items
                                     }));
                                 } else {
                                     items.push_info(start_info);
-                                    items.extend(parsed_value.clone().into());
+                                    items.push_optional_path(parsed_value);
                                 }
                             },
                             BoolOrCondition::Condition(condition) => {
                                 let inner_items = {
-                                    let mut items = PrintItems::new();
+                                    let mut items = PrintItems::new(arena);
                                     items.push_info(start_info);
-                                    items.extend(parsed_value.clone().into());
+                                    items.push_optional_path(parsed_value);
                                     items
                                 }.into_rc_path();
                                 items.push_condition(Condition::new("valueHangingIndent", ConditionProperties {
                                     condition: condition.clone(),
-                                    true_path: Some(parser_helpers::with_indent(inner_items.clone().into())),
-                                    false_path: Some(inner_items.into()),
+                                    true_path: Some(parser_helpers::with_indent(arena.items(inner_items))),
+                                    false_path: Some(arena.items(inner_items)),
                                 }));
                             }
                         }
@@ -340,12 +354,12 @@ pub fn parse_separated_values(
                         Some(items)
                     },
                     false_path: {
-                        let mut items = PrintItems::new();
-                        items.extend(single_line_separator.clone().into()); // ex. Signal::SpaceOrNewLine
+                        let mut items = PrintItems::new(arena);
+                        items.push_optional_path(single_line_separator); // ex. Signal::SpaceOrNewLine
                         items.push_condition(indent_if_start_of_line({
-                            let mut items = PrintItems::new();
+                            let mut items = PrintItems::new(arena);
                             items.push_info(start_info);
-                            items.extend(parsed_value.into());
+                            items.push_optional_path(parsed_value);
                             items
                         }));
                         Some(items)
@@ -364,7 +378,7 @@ pub fn parse_separated_values(
     }
 }
 
-fn get_clearer_resolutions_on_start_change_condition(value_datas: Rc<RefCell<Vec<ParsedValueData>>>, start_info: Info, end_info: Info) -> Condition {
+fn get_clearer_resolutions_on_start_change_condition<'a>(value_datas: Rc<RefCell<Vec<ParsedValueData>>>, start_info: Info, end_info: Info) -> Condition<'a> {
     Condition::new("clearWhenStartInfoChanges", ConditionProperties {
         condition: Rc::new(Box::new(move |condition_context| {
             // when the start info position changes, clear all the infos so they get re-evaluated again
@@ -382,7 +396,7 @@ fn get_clearer_resolutions_on_start_change_condition(value_datas: Rc<RefCell<Vec
     })
 }
 
-fn get_is_start_standalone_line(start_info: Info) -> Condition {
+fn get_is_start_standalone_line<'a>(start_info: Info) -> Condition<'a> {
     Condition::new("isStartStandaloneLine", ConditionProperties {
         condition: Rc::new(Box::new(move |condition_context| {
             let start_info = condition_context.get_resolved_info(&start_info)?;
@@ -393,7 +407,7 @@ fn get_is_start_standalone_line(start_info: Info) -> Condition {
     })
 }
 
-fn get_is_multi_line_for_hanging(value_datas: Rc<RefCell<Vec<ParsedValueData>>>, is_start_standalone_line_ref: ConditionReference, end_info: Info) -> Condition {
+fn get_is_multi_line_for_hanging<'a>(value_datas: Rc<RefCell<Vec<ParsedValueData>>>, is_start_standalone_line_ref: ConditionReference, end_info: Info) -> Condition<'a> {
     Condition::new_with_dependent_infos("isMultiLineForHanging", ConditionProperties {
         condition: Rc::new(Box::new(move |condition_context| {
             let is_start_standalone_line = condition_context.get_resolved_condition(&is_start_standalone_line_ref)?;
@@ -418,7 +432,7 @@ fn get_is_multi_line_for_hanging(value_datas: Rc<RefCell<Vec<ParsedValueData>>>,
     }, vec![end_info])
 }
 
-fn get_is_multi_line_for_multi_line(start_info: Info, value_datas: Rc<RefCell<Vec<ParsedValueData>>>, is_start_standalone_line_ref: ConditionReference, end_info: Info) -> Condition {
+fn get_is_multi_line_for_multi_line<'a>(start_info: Info, value_datas: Rc<RefCell<Vec<ParsedValueData>>>, is_start_standalone_line_ref: ConditionReference, end_info: Info) -> Condition<'a> {
     return Condition::new_with_dependent_infos("isMultiLineForMultiLine", ConditionProperties {
         condition: Rc::new(Box::new(move |condition_context| {
             // todo: This is slightly confusing because it works on the "last" value rather than the current
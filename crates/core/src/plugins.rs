@@ -1,7 +1,11 @@
 use core::slice::{Iter, IterMut};
-use std::path::PathBuf;
+use std::cell::Cell;
+use std::path::{Path, PathBuf};
 use std::collections::HashMap;
+use std::panic::{self, AssertUnwindSafe};
 use super::configuration::{ConfigurationDiagnostic, GlobalConfiguration};
+use super::format_error::FormatError;
+use super::long_lines::LongLine;
 
 /// Plugin that can be implemented for use in the CLI.
 pub trait Plugin : std::marker::Sync {
@@ -20,7 +24,23 @@ pub trait Plugin : std::marker::Sync {
     /// Gets the configuration diagnostics.
     fn get_configuration_diagnostics(&self) -> &Vec<ConfigurationDiagnostic>;
     /// Formats the text in memory based on the file path and file text.
-    fn format_text(&self, file_path: &PathBuf, file_text: &str) -> Result<String, String>;
+    ///
+    /// The host may be used to format code embedded in the file with the other plugins.
+    fn format_text(&self, file_path: &PathBuf, file_text: &str, host: &FormatHost) -> Result<String, FormatError>;
+    /// Gets the print items the file text is formatted with as an indented tree for debugging.
+    fn get_print_ir(&self, _file_path: &PathBuf, _file_text: &str, _host: &FormatHost) -> Result<String, FormatError> {
+        Err(FormatError::internal(format!("The {} plugin does not support outputting its print items.", self.name())))
+    }
+    /// Gets if the file text is already formatted.
+    ///
+    /// Plugins may override this to compare the printed text to the file text without allocating it.
+    fn format_text_equals(&self, file_path: &PathBuf, file_text: &str, host: &FormatHost) -> Result<bool, FormatError> {
+        self.format_text(file_path, file_text, host).map(|formatted_text| formatted_text == file_text)
+    }
+    /// Formats the text also getting the lines of the formatted text that are still wider than the line width.
+    fn format_text_with_long_lines(&self, _file_path: &PathBuf, _file_text: &str, _host: &FormatHost) -> Result<(String, Vec<LongLine>), FormatError> {
+        Err(FormatError::internal(format!("The {} plugin does not support reporting long lines.", self.name())))
+    }
 }
 
 /// A formatter constructed from a collection of plugins.
@@ -47,13 +67,179 @@ impl Formatter {
     /// Formats the file text with one of the plugins.
     ///
     /// Returns the string when a plugin formatted or error. Otherwise None when no plugin was found.
-    pub fn format_text(&self, file_path: &PathBuf, file_text: &str) -> Result<Option<String>, String> {
+    /// A panic in the plugin is returned as an error so that other files may continue being formatted.
+    /// Errors always have the file path set.
+    pub fn format_text(&self, file_path: &PathBuf, file_text: &str) -> Result<Option<String>, FormatError> {
+        for plugin in self.plugins.iter() {
+            if plugin.should_format_file(file_path, file_text) {
+                let host = FormatHost::new(&self.plugins);
+                return run_plugin(plugin.as_ref(), file_path, "formatting", || plugin.format_text(file_path, file_text, &host)).map(Some);
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Gets if the file text is already formatted by the plugin that formats it.
+    ///
+    /// Returns None when no plugin was found. A panic in the plugin is returned as an error like in `format_text`.
+    pub fn format_text_equals(&self, file_path: &PathBuf, file_text: &str) -> Result<Option<bool>, FormatError> {
+        for plugin in self.plugins.iter() {
+            if plugin.should_format_file(file_path, file_text) {
+                let host = FormatHost::new(&self.plugins);
+                return run_plugin(plugin.as_ref(), file_path, "checking", || plugin.format_text_equals(file_path, file_text, &host)).map(Some);
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Gets the print items of the file text from the plugin that formats it.
+    ///
+    /// Returns None when no plugin was found. A panic in the plugin is returned as an error like in `format_text`.
+    pub fn get_print_ir(&self, file_path: &PathBuf, file_text: &str) -> Result<Option<String>, FormatError> {
+        for plugin in self.plugins.iter() {
+            if plugin.should_format_file(file_path, file_text) {
+                let host = FormatHost::new(&self.plugins);
+                return run_plugin(plugin.as_ref(), file_path, "getting the print items of", || plugin.get_print_ir(file_path, file_text, &host)).map(Some);
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Formats the file text with the plugin that formats it also getting the lines that are wider than the line width.
+    ///
+    /// Returns None when no plugin was found. A panic in the plugin is returned as an error like in `format_text`.
+    pub fn format_text_with_long_lines(&self, file_path: &PathBuf, file_text: &str) -> Result<Option<(String, Vec<LongLine>)>, FormatError> {
         for plugin in self.plugins.iter() {
             if plugin.should_format_file(file_path, file_text) {
-                return plugin.format_text(file_path, file_text).map(|x| Some(x));
+                let host = FormatHost::new(&self.plugins);
+                return run_plugin(plugin.as_ref(), file_path, "formatting", || plugin.format_text_with_long_lines(file_path, file_text, &host)).map(Some);
             }
         }
 
         Ok(None)
     }
 }
+
+/// The maximum number of embedded texts that may be nested within each other (ex. a markdown code
+/// block within a markdown code block) before the host refuses to format.
+const MAX_EMBEDDED_DEPTH: usize = 5;
+
+/// Handle to the host given to a plugin when formatting so that it may format code embedded
+/// in the file with the other plugins (ex. a CSS tagged template in a JavaScript file).
+pub struct FormatHost<'a> {
+    plugins: &'a [Box<dyn Plugin>],
+    depth: usize,
+    line_width: Option<u32>,
+}
+
+impl<'a> FormatHost<'a> {
+    fn new(plugins: &'a [Box<dyn Plugin>]) -> FormatHost<'a> {
+        FormatHost {
+            plugins,
+            depth: 0,
+            line_width: None,
+        }
+    }
+
+    /// Gets the line width the plugin should use instead of its configured line width.
+    ///
+    /// This is set when the text being formatted is embedded in another file.
+    pub fn line_width(&self) -> Option<u32> {
+        self.line_width
+    }
+
+    /// Formats a fragment of text with the plugin for the provided file extension or language id (ex. `ts` or `typescript`).
+    ///
+    /// Each line of the formatted text is prefixed with the indent text and the line width is the
+    /// width remaining after that indentation. Returns `Ok(None)` when no plugin formats the language.
+    pub fn format_text(&self, language: &str, text: &str, indent_text: &str, line_width: Option<u32>) -> Result<Option<String>, FormatError> {
+        if self.depth >= MAX_EMBEDDED_DEPTH {
+            return Err(FormatError::internal(format!("Exceeded the maximum depth of {} formatting embedded text.", MAX_EMBEDDED_DEPTH)));
+        }
+
+        let file_path = PathBuf::from(format!("embedded.{}", get_extension_for_language(language)));
+        let host = FormatHost {
+            plugins: self.plugins,
+            depth: self.depth + 1,
+            line_width,
+        };
+        for plugin in self.plugins.iter() {
+            if plugin.should_format_file(&file_path, text) {
+                let formatted_text = plugin.format_text(&file_path, text, &host)?;
+                return Ok(Some(indent_lines(&formatted_text, indent_text)));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+fn get_extension_for_language(language: &str) -> String {
+    let language = language.to_lowercase();
+    match language.as_str() {
+        "typescript" => String::from("ts"),
+        "javascript" => String::from("js"),
+        "jsonc" => String::from("json"),
+        "markdown" => String::from("md"),
+        _ => language,
+    }
+}
+
+fn indent_lines(text: &str, indent_text: &str) -> String {
+    if indent_text.is_empty() {
+        return String::from(text);
+    }
+
+    text.split('\n').map(|line| {
+        if line.trim_end_matches('\r').is_empty() {
+            String::from(line)
+        } else {
+            format!("{}{}", indent_text, line)
+        }
+    }).collect::<Vec<_>>().join("\n")
+}
+
+thread_local! {
+    /// The number of plugin actions running on the current thread (a plugin may run others through the host).
+    static RUNNING_PLUGIN_COUNT: Cell<usize> = Cell::new(0);
+}
+
+/// Gets if a plugin is running on the current thread.
+///
+/// A panic in a plugin is returned as an error, so a panic hook may use this to not also output it.
+pub fn is_running_plugin() -> bool {
+    RUNNING_PLUGIN_COUNT.with(|count| count.get() > 0)
+}
+
+/// Runs the plugin's action returning a panic as an error and setting the file path of any error.
+fn run_plugin<T>(plugin: &dyn Plugin, file_path: &Path, action_text: &str, action: impl FnOnce() -> Result<T, FormatError>) -> Result<T, FormatError> {
+    RUNNING_PLUGIN_COUNT.with(|count| count.set(count.get() + 1));
+    let result = panic::catch_unwind(AssertUnwindSafe(action));
+    RUNNING_PLUGIN_COUNT.with(|count| count.set(count.get() - 1));
+
+    match result {
+        Ok(result) => result.map_err(|err| err.with_file_path(file_path)),
+        Err(payload) => {
+            Err(FormatError::internal(format!(
+                "The {} plugin panicked {} {}: {}",
+                plugin.name(),
+                action_text,
+                file_path.to_string_lossy(),
+                get_panic_message(&payload),
+            )).with_file_path(file_path))
+        },
+    }
+}
+
+fn get_panic_message(payload: &Box<dyn std::any::Any + Send>) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "Unknown panic payload."
+    }
+}
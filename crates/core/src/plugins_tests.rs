@@ -0,0 +1,197 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use super::configuration::{ConfigurationDiagnostic, GlobalConfiguration};
+use super::plugins::*;
+use super::format_error::*;
+use super::long_lines::LongLine;
+
+struct PanickingPlugin {
+    diagnostics: Vec<ConfigurationDiagnostic>,
+}
+
+impl Plugin for PanickingPlugin {
+    fn name(&self) -> &'static str { "panicking" }
+    fn version(&self) -> &'static str { "0.1.0" }
+    fn config_keys(&self) -> Vec<String> { Vec::new() }
+    fn initialize(&mut self, _: HashMap<String, String>, _: &GlobalConfiguration) {}
+    fn should_format_file(&self, _: &PathBuf, _: &str) -> bool { true }
+    fn get_resolved_config(&self) -> String { String::new() }
+    fn get_configuration_diagnostics(&self) -> &Vec<ConfigurationDiagnostic> { &self.diagnostics }
+    fn format_text(&self, _: &PathBuf, file_text: &str, _: &FormatHost) -> Result<String, FormatError> {
+        if file_text == "panic" {
+            panic!("Plugin must be initialized.");
+        } else if file_text == "panic string" {
+            panic!("Unexpected token: {}", file_text);
+        } else if file_text == "is running" {
+            return Ok(is_running_plugin().to_string());
+        }
+        Ok(String::from(file_text))
+    }
+    fn format_text_with_long_lines(&self, file_path: &PathBuf, file_text: &str, host: &FormatHost) -> Result<(String, Vec<LongLine>), FormatError> {
+        self.format_text(file_path, file_text, host).map(|text| (text, Vec::new()))
+    }
+    fn get_print_ir(&self, _: &PathBuf, file_text: &str, _: &FormatHost) -> Result<String, FormatError> {
+        if file_text == "panic" {
+            panic!("Plugin must be initialized.");
+        }
+        Ok(String::from(file_text))
+    }
+}
+
+#[test]
+fn format_text_returns_panics_as_errors() {
+    let formatter = Formatter::new(vec![Box::new(PanickingPlugin { diagnostics: Vec::new() })]);
+    let file_path = PathBuf::from("/file.txt");
+
+    let err = formatter.format_text(&file_path, "panic").err().unwrap();
+    assert_eq!(err.kind, FormatErrorKind::Internal);
+    assert_eq!(err.file_path, Some(file_path.clone()));
+    assert_eq!(err.message, format!("The panicking plugin panicked formatting {}: Plugin must be initialized.", file_path.to_string_lossy()));
+    assert_eq!(
+        formatter.format_text(&file_path, "panic string").err().unwrap().message,
+        format!("The panicking plugin panicked formatting {}: Unexpected token: panic string", file_path.to_string_lossy()),
+    );
+    assert_eq!(formatter.format_text(&file_path, "text"), Ok(Some(String::from("text"))));
+}
+
+#[test]
+fn is_running_plugin_only_while_running_a_plugin() {
+    let formatter = Formatter::new(vec![Box::new(PanickingPlugin { diagnostics: Vec::new() })]);
+    let file_path = PathBuf::from("/file.txt");
+
+    assert_eq!(formatter.format_text(&file_path, "is running"), Ok(Some(String::from("true"))));
+    assert!(formatter.format_text(&file_path, "panic").is_err());
+    assert_eq!(is_running_plugin(), false);
+}
+
+/// Formats `.outer` files by formatting the text after the language id on the first line with the host.
+struct EmbeddingPlugin {
+    diagnostics: Vec<ConfigurationDiagnostic>,
+}
+
+impl Plugin for EmbeddingPlugin {
+    fn name(&self) -> &'static str { "embedding" }
+    fn version(&self) -> &'static str { "0.1.0" }
+    fn config_keys(&self) -> Vec<String> { Vec::new() }
+    fn initialize(&mut self, _: HashMap<String, String>, _: &GlobalConfiguration) {}
+    fn should_format_file(&self, file_path: &PathBuf, _: &str) -> bool {
+        file_path.extension().and_then(|e| e.to_str()) == Some("outer")
+    }
+    fn get_resolved_config(&self) -> String { String::new() }
+    fn get_configuration_diagnostics(&self) -> &Vec<ConfigurationDiagnostic> { &self.diagnostics }
+    fn format_text(&self, _: &PathBuf, file_text: &str, host: &FormatHost) -> Result<String, FormatError> {
+        let index = file_text.find('\n').unwrap();
+        let language = &file_text[..index];
+        let embedded_text = &file_text[index + 1..];
+        match host.format_text(language, embedded_text, "  ", Some(40))? {
+            Some(formatted_text) => Ok(format!("{}\n{}", language, formatted_text)),
+            None => Ok(String::from(file_text)),
+        }
+    }
+}
+
+/// Upper cases `.inner` files and appends the line width it was formatted with.
+struct InnerPlugin {
+    diagnostics: Vec<ConfigurationDiagnostic>,
+}
+
+impl Plugin for InnerPlugin {
+    fn name(&self) -> &'static str { "inner" }
+    fn version(&self) -> &'static str { "0.1.0" }
+    fn config_keys(&self) -> Vec<String> { Vec::new() }
+    fn initialize(&mut self, _: HashMap<String, String>, _: &GlobalConfiguration) {}
+    fn should_format_file(&self, file_path: &PathBuf, _: &str) -> bool {
+        file_path.extension().and_then(|e| e.to_str()) == Some("inner")
+    }
+    fn get_resolved_config(&self) -> String { String::new() }
+    fn get_configuration_diagnostics(&self) -> &Vec<ConfigurationDiagnostic> { &self.diagnostics }
+    fn format_text(&self, _: &PathBuf, file_text: &str, host: &FormatHost) -> Result<String, FormatError> {
+        Ok(format!("{}\n\n{}", file_text.to_uppercase(), host.line_width().unwrap_or(120)))
+    }
+}
+
+fn create_embedding_formatter() -> Formatter {
+    Formatter::new(vec![
+        Box::new(EmbeddingPlugin { diagnostics: Vec::new() }),
+        Box::new(InnerPlugin { diagnostics: Vec::new() }),
+    ])
+}
+
+#[test]
+fn format_text_formats_embedded_text_with_host() {
+    let formatter = create_embedding_formatter();
+    assert_eq!(
+        formatter.format_text(&PathBuf::from("/file.outer"), "inner\ntext"),
+        Ok(Some(String::from("inner\n  TEXT\n\n  40"))),
+    );
+    assert_eq!(formatter.format_text(&PathBuf::from("/file.inner"), "text"), Ok(Some(String::from("TEXT\n\n120"))));
+}
+
+#[test]
+fn format_text_keeps_embedded_text_without_plugin() {
+    let formatter = create_embedding_formatter();
+    assert_eq!(
+        formatter.format_text(&PathBuf::from("/file.outer"), "other\ntext"),
+        Ok(Some(String::from("other\ntext"))),
+    );
+}
+
+#[test]
+fn format_text_errors_on_recursive_embedded_text() {
+    let formatter = create_embedding_formatter();
+    let file_path = PathBuf::from("/file.outer");
+    let err = formatter.format_text(&file_path, "outer\nouter\nouter\nouter\nouter\nouter\ninner\ntext").err().unwrap();
+    assert_eq!(err.kind, FormatErrorKind::Internal);
+    assert_eq!(err.file_path, Some(file_path));
+    assert_eq!(err.message, "Exceeded the maximum depth of 5 formatting embedded text.");
+    assert_eq!(
+        formatter.format_text(&PathBuf::from("/file.outer"), "outer\nouter\nouter\nouter\ninner\ntext"),
+        Ok(Some(String::from("outer\n  outer\n    outer\n      outer\n        inner\n          TEXT\n\n          40"))),
+    );
+}
+
+#[test]
+fn format_text_with_long_lines_errors_when_plugin_does_not_support_it() {
+    let formatter = create_embedding_formatter();
+    let file_path = PathBuf::from("/file.inner");
+    let err = formatter.format_text_with_long_lines(&file_path, "text").err().unwrap();
+    assert_eq!(err.kind, FormatErrorKind::Internal);
+    assert_eq!(err.file_path, Some(file_path));
+    assert_eq!(err.message, "The inner plugin does not support reporting long lines.");
+}
+
+#[test]
+fn format_text_with_long_lines_returns_panics_as_errors() {
+    let formatter = Formatter::new(vec![Box::new(PanickingPlugin { diagnostics: Vec::new() })]);
+    let file_path = PathBuf::from("/file.txt");
+    let err = formatter.format_text_with_long_lines(&file_path, "panic").err().unwrap();
+    assert_eq!(err.kind, FormatErrorKind::Internal);
+    assert_eq!(err.file_path, Some(file_path.clone()));
+    assert_eq!(err.message, format!("The panicking plugin panicked formatting {}: Plugin must be initialized.", file_path.to_string_lossy()));
+    assert_eq!(formatter.format_text_with_long_lines(&file_path, "text"), Ok(Some((String::from("text"), Vec::new()))));
+}
+
+#[test]
+fn get_print_ir_returns_panics_as_errors() {
+    let formatter = Formatter::new(vec![Box::new(PanickingPlugin { diagnostics: Vec::new() })]);
+    let file_path = PathBuf::from("/file.txt");
+    let err = formatter.get_print_ir(&file_path, "panic").err().unwrap();
+    assert_eq!(err.kind, FormatErrorKind::Internal);
+    assert_eq!(err.file_path, Some(file_path.clone()));
+    assert_eq!(err.message, format!("The panicking plugin panicked getting the print items of {}: Plugin must be initialized.", file_path.to_string_lossy()));
+    assert_eq!(formatter.get_print_ir(&file_path, "text"), Ok(Some(String::from("text"))));
+}
+
+#[test]
+fn format_text_equals_gets_if_file_text_is_formatted() {
+    let formatter = create_embedding_formatter();
+    assert_eq!(formatter.format_text_equals(&PathBuf::from("/file.inner"), "text"), Ok(Some(false)));
+    assert_eq!(formatter.format_text_equals(&PathBuf::from("/file.txt"), "text"), Ok(None));
+
+    let formatter = Formatter::new(vec![Box::new(PanickingPlugin { diagnostics: Vec::new() })]);
+    let file_path = PathBuf::from("/file.txt");
+    assert_eq!(formatter.format_text_equals(&file_path, "text"), Ok(Some(true)));
+    let err = formatter.format_text_equals(&file_path, "panic").err().unwrap();
+    assert_eq!(err.file_path, Some(file_path.clone()));
+    assert_eq!(err.message, format!("The panicking plugin panicked checking {}: Plugin must be initialized.", file_path.to_string_lossy()));
+}
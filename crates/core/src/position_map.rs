@@ -0,0 +1,65 @@
+/// A mapping of a string from its position in the original text to its position in the formatted text.
+#[derive(Clone, PartialEq, Debug)]
+pub struct PositionMapping {
+    /// The byte position of the string in the original text.
+    pub old_position: usize,
+    /// The byte position of the string in the formatted text.
+    pub new_position: usize,
+    /// The byte length of the string in the formatted text.
+    pub len: usize,
+}
+
+/// Maps byte positions in the original text to byte positions in the formatted text.
+///
+/// This is useful for keeping the cursor and selections in place after formatting.
+#[derive(Clone, PartialEq, Debug)]
+pub struct PositionMap {
+    /// The mappings sorted by their position in the original text.
+    mappings: Vec<PositionMapping>,
+    new_text_len: usize,
+}
+
+impl PositionMap {
+    /// Creates a position map from the mappings of the strings written to the formatted text.
+    pub fn new(mut mappings: Vec<PositionMapping>, new_text_len: usize) -> PositionMap {
+        mappings.sort_by_key(|mapping| mapping.old_position);
+        mappings.dedup_by_key(|mapping| mapping.old_position);
+        PositionMap {
+            mappings,
+            new_text_len,
+        }
+    }
+
+    /// Creates a position map for text that was not changed.
+    pub fn unchanged(text_len: usize) -> PositionMap {
+        PositionMap::new(vec![PositionMapping {
+            old_position: 0,
+            new_position: 0,
+            len: text_len,
+        }], text_len)
+    }
+
+    /// Gets the mappings sorted by their position in the original text.
+    pub fn mappings(&self) -> &Vec<PositionMapping> {
+        &self.mappings
+    }
+
+    /// Maps a byte position in the original text to a byte position in the formatted text.
+    ///
+    /// Positions within a string map to the same offset within that string in the formatted
+    /// text. Positions between strings (ex. in whitespace that was removed) map to the end of
+    /// the previous string.
+    pub fn map_position(&self, old_position: usize) -> usize {
+        let index = match self.mappings.binary_search_by_key(&old_position, |mapping| mapping.old_position) {
+            Ok(index) => index,
+            Err(0) => return match self.mappings.first() {
+                Some(mapping) => std::cmp::min(old_position, mapping.new_position),
+                None => std::cmp::min(old_position, self.new_text_len),
+            },
+            Err(index) => index - 1,
+        };
+        let mapping = &self.mappings[index];
+        let offset = std::cmp::min(old_position - mapping.old_position, mapping.len);
+        std::cmp::min(mapping.new_position + offset, self.new_text_len)
+    }
+}
@@ -0,0 +1,62 @@
+use super::*;
+
+#[test]
+fn map_position_maps_within_and_between_strings() {
+    // original: "a  =   5;" formatted: "a = 5;"
+    let position_map = PositionMap::new(vec![
+        PositionMapping { old_position: 7, new_position: 4, len: 1 },
+        PositionMapping { old_position: 0, new_position: 0, len: 1 },
+        PositionMapping { old_position: 3, new_position: 2, len: 1 },
+    ], 6);
+
+    assert_eq!(position_map.map_position(0), 0);
+    assert_eq!(position_map.map_position(1), 1);
+    assert_eq!(position_map.map_position(2), 1);
+    assert_eq!(position_map.map_position(3), 2);
+    assert_eq!(position_map.map_position(7), 4);
+    assert_eq!(position_map.map_position(8), 5);
+    assert_eq!(position_map.map_position(100), 5);
+}
+
+#[test]
+fn map_position_maps_before_first_string() {
+    let position_map = PositionMap::new(vec![PositionMapping { old_position: 4, new_position: 0, len: 1 }], 2);
+    assert_eq!(position_map.map_position(2), 0);
+    assert_eq!(PositionMap::new(Vec::new(), 2).map_position(5), 2);
+}
+
+#[test]
+fn print_with_mapping_maps_strings_with_source_positions() {
+    let (text, position_map) = format_with_mapping(|arena| {
+        let mut items = PrintItems::new(arena);
+        items.extend(get_source_items(arena, "a", 2));
+        items.push_str(" + ");
+        items.push_signal(Signal::NewLine);
+        items.push_signal(Signal::StartIndent);
+        items.extend(get_source_items(arena, "b", 8));
+        items.push_signal(Signal::FinishIndent);
+        items
+    }, PrintOptions {
+            indent_width: 2,
+            max_width: 40,
+            use_tabs: false,
+            new_line_text: "\n",
+            unicode_width: false,
+            budget: PrintBudget::default(),
+    }).unwrap();
+
+    assert_eq!(text, "a + \n  b");
+    assert_eq!(position_map.mappings(), &vec![
+        PositionMapping { old_position: 2, new_position: 0, len: 1 },
+        PositionMapping { old_position: 8, new_position: 7, len: 1 },
+    ]);
+
+    fn get_source_items<'a>(arena: &'a PrintItemsArena<'a>, text: &str, source_position: usize) -> PrintItems<'a> {
+        let mut items = PrintItems::new(arena);
+        items.push_signal(Signal::PossibleNewLine);
+        items.push_str("");
+        items.push_str(text);
+        items.set_source_position(source_position);
+        items
+    }
+}
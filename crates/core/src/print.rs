@@ -1,4 +1,7 @@
+use std::fmt;
+use std::io;
 use super::*;
+use super::long_lines::LongLineRecorder;
 
 /// Options for printing the print items.
 pub struct PrintOptions {
@@ -6,22 +9,107 @@ pub struct PrintOptions {
     pub max_width: u32,
     /// The number of columns to count when indenting or using a tab.
     pub indent_width: u8,
+    /// Whether to measure strings by their display width (ex. CJK characters and emoji
+    /// take up two columns) instead of their character count.
+    pub unicode_width: bool,
     /// Whether to use tabs for indenting.
     pub use_tabs: bool,
     /// The newline character to use when doing a new line.
     pub new_line_text: &'static str,
+    /// Limits on the amount of work the printer may do before erroring.
+    pub budget: PrintBudget,
 }
 
-/// Prints out the print items using the provided
-pub fn print(print_items: PrintItems, options: PrintOptions) -> String {
+/// Creates the print items in an arena owned by this call and prints them out using the provided options.
+///
+/// The print items are freed along with the arena once printed.
+pub fn format(get_print_items: impl for<'a> FnOnce(&'a PrintItemsArena<'a>) -> PrintItems<'a>, options: PrintOptions) -> Result<String, PrintError> {
+    let arena = PrintItemsArena::new();
+    print(get_print_items(&arena), options)
+}
+
+/// Prints out the print items using the provided options.
+///
+/// Errors when the printer exceeds the budget provided in the options.
+pub fn print(print_items: PrintItems<'_>, options: PrintOptions) -> Result<String, PrintError> {
+    let (write_items, options) = get_write_items_and_options(print_items, options)?;
+    Ok(print_write_items(write_items, options))
+}
+
+/// Creates the print items in an arena owned by this call and prints them out along with a map of
+/// the positions in the original text to the positions in the formatted text.
+///
+/// The positions are only known for the strings given a source position while creating the print items.
+pub fn format_with_mapping(get_print_items: impl for<'a> FnOnce(&'a PrintItemsArena<'a>) -> PrintItems<'a>, options: PrintOptions) -> Result<(String, PositionMap), PrintError> {
+    let arena = PrintItemsArena::new();
+    print_with_mapping(get_print_items(&arena), options)
+}
+
+/// Prints out the print items along with a map of the positions in the original text to the
+/// positions in the formatted text.
+pub fn print_with_mapping(print_items: PrintItems<'_>, options: PrintOptions) -> Result<(String, PositionMap), PrintError> {
+    let (write_items, options) = get_write_items_and_options(print_items, options)?;
+    Ok(print_write_items_with_mapping(write_items, options))
+}
+
+/// Creates the print items in an arena owned by this call and prints them out along with the lines
+/// that are wider than the max width.
+pub fn format_with_long_lines(get_print_items: impl for<'a> FnOnce(&'a PrintItemsArena<'a>) -> PrintItems<'a>, options: PrintOptions) -> Result<(String, Vec<LongLine>), PrintError> {
+    let arena = PrintItemsArena::new();
+    print_with_long_lines(get_print_items(&arena), options)
+}
+
+/// Prints out the print items along with the lines that are wider than the max width.
+///
+/// Each long line includes the string that went past the max width, which may be used
+/// to find the code the printer couldn't break up.
+pub fn print_with_long_lines(print_items: PrintItems<'_>, options: PrintOptions) -> Result<(String, Vec<LongLine>), PrintError> {
+    let mut recorder = LongLineRecorder::new(options.max_width, options.indent_width, options.unicode_width);
+    let (write_items, options) = get_write_items_and_options(print_items, options)?;
+    let text = print_write_items(write_items.inspect(|item| recorder.record(item)), options);
+    Ok((text, recorder.into_long_lines()))
+}
+
+/// Prints out the print items to a `fmt::Write` sink using the provided options.
+pub fn print_to(print_items: PrintItems<'_>, options: PrintOptions, writer: &mut impl fmt::Write) -> Result<(), PrintError> {
+    let (write_items, options) = get_write_items_and_options(print_items, options)?;
+    print_write_items_to(write_items, options, writer).map_err(PrintError::Fmt)
+}
+
+/// Prints out the print items to an `io::Write` sink using the provided options.
+pub fn print_to_io(print_items: PrintItems<'_>, options: PrintOptions, writer: &mut impl io::Write) -> Result<(), PrintError> {
+    let (write_items, options) = get_write_items_and_options(print_items, options)?;
+    print_write_items_to_io(write_items, options, writer).map_err(PrintError::Io)
+}
+
+/// Creates the print items in an arena owned by this call and gets if printing them out would result
+/// in the provided text.
+pub fn format_equals(get_print_items: impl for<'a> FnOnce(&'a PrintItemsArena<'a>) -> PrintItems<'a>, options: PrintOptions, text: &str) -> Result<bool, PrintError> {
+    let arena = PrintItemsArena::new();
+    print_equals(get_print_items(&arena), options, text)
+}
+
+/// Gets if printing out the print items would result in the provided text.
+///
+/// This doesn't allocate the printed text and stops comparing at the first difference, which is
+/// useful for checking if a file is already formatted. Note that the printer still resolves all
+/// the write items before they're compared.
+pub fn print_equals(print_items: PrintItems<'_>, options: PrintOptions, text: &str) -> Result<bool, PrintError> {
+    let (write_items, options) = get_write_items_and_options(print_items, options)?;
+    Ok(print_write_items_equals(write_items, options, text))
+}
+
+fn get_write_items_and_options(print_items: PrintItems<'_>, options: PrintOptions) -> Result<(impl Iterator<Item = WriteItem<'_>>, PrintWriteItemsOptions), PrintError> {
     let write_items = get_write_items(&print_items, GetWriteItemsOptions {
         indent_width: options.indent_width,
         max_width: options.max_width,
-    });
+        unicode_width: options.unicode_width,
+        budget: options.budget,
+    })?;
 
-    print_write_items(write_items, PrintWriteItemsOptions {
+    Ok((write_items, PrintWriteItemsOptions {
         use_tabs: options.use_tabs,
         new_line_text: options.new_line_text,
         indent_width: options.indent_width,
-    })
+    }))
 }
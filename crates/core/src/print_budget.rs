@@ -0,0 +1,24 @@
+use super::configuration::DEFAULT_GLOBAL_CONFIGURATION;
+
+/// Limits on the amount of work the printer may do before giving up.
+///
+/// The printer jumps back to save points when it looks ahead, so print items that keep
+/// changing the outcome of a condition could otherwise cause it to never finish.
+///
+/// Plugins get these from the `maxConditionEvaluations` and `maxVisitsPerNode` configuration values.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct PrintBudget {
+    /// The maximum number of times a single condition may be evaluated.
+    pub max_condition_evaluations: u32,
+    /// The maximum number of times each print node may be visited on average.
+    pub max_visits_per_node: u32,
+}
+
+impl Default for PrintBudget {
+    fn default() -> PrintBudget {
+        PrintBudget {
+            max_condition_evaluations: DEFAULT_GLOBAL_CONFIGURATION.max_condition_evaluations,
+            max_visits_per_node: DEFAULT_GLOBAL_CONFIGURATION.max_visits_per_node,
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use std::fmt;
+use std::io;
+
+/// An error that occurred while printing the print items.
+#[derive(Debug)]
+pub enum PrintError {
+    /// A condition was evaluated more times than allowed by the print budget.
+    ConditionEvaluationsExceeded {
+        /// The name of the condition that kept being re-evaluated.
+        condition_name: &'static str,
+        evaluations: u32,
+    },
+    /// More print nodes were visited than allowed by the print budget.
+    NodeVisitsExceeded {
+        node_visits: u64,
+        /// The name of the condition that was evaluated the most times, if any.
+        most_evaluated_condition_name: Option<&'static str>,
+    },
+    /// Writing to a `fmt::Write` sink failed.
+    Fmt(fmt::Error),
+    /// Writing to an `io::Write` sink failed.
+    Io(io::Error),
+}
+
+impl fmt::Display for PrintError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PrintError::ConditionEvaluationsExceeded { condition_name, evaluations } => write!(
+                f,
+                "Printing exceeded its budget after evaluating the condition '{}' {} times. This is a bug in the formatter.",
+                condition_name,
+                evaluations,
+            ),
+            PrintError::NodeVisitsExceeded { node_visits, most_evaluated_condition_name } => {
+                write!(f, "Printing exceeded its budget after visiting {} print nodes", node_visits)?;
+                if let Some(condition_name) = most_evaluated_condition_name {
+                    write!(f, " (most re-evaluated condition: '{}')", condition_name)?;
+                }
+                write!(f, ". This is a bug in the formatter.")
+            },
+            PrintError::Fmt(err) => write!(f, "Error writing the printed text: {}", err),
+            PrintError::Io(err) => write!(f, "Error writing the printed text: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for PrintError {}
+
+impl From<PrintError> for String {
+    fn from(err: PrintError) -> String {
+        err.to_string()
+    }
+}
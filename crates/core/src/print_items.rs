@@ -1,52 +1,110 @@
 use super::printer::Printer;
 use std::rc::Rc;
-use std::cell::UnsafeCell;
+use std::cell::{Cell, RefCell};
 use std::sync::atomic::{AtomicUsize, Ordering};
-use std::mem;
+use typed_arena::Arena;
+use super::utils::unicode_width::get_display_width;
 
-/** Print Items */
+// Print Items Arena
 
-pub struct PrintItems {
-    pub(super) first_node: Option<PrintItemPath>,
-    last_node: Option<PrintItemPath>,
+/// Storage for the print items created while formatting a text.
+///
+/// Print items reference the arena they're created in, so they can't outlive it. Create
+/// one for each text being formatted (ex. `format` creates one for the duration of the call)
+/// and create all the print items that get printed together in the same arena.
+pub struct PrintItemsArena<'a> {
+    nodes: Arena<PrintNode<'a>>,
+    strings: Arena<StringContainer<'a>>,
+    texts: Arena<u8>,
+    conditions: Arena<Condition<'a>>,
+    alignments: Arena<Alignment>,
+}
+
+impl<'a> PrintItemsArena<'a> {
+    pub fn new() -> PrintItemsArena<'a> {
+        PrintItemsArena {
+            nodes: Arena::new(),
+            strings: Arena::new(),
+            texts: Arena::new(),
+            conditions: Arena::new(),
+            alignments: Arena::new(),
+        }
+    }
+
+    /// Creates print items in this arena from the provided value (ex. a string or signal).
+    pub fn items(&'a self, value: impl IntoPrintItems<'a>) -> PrintItems<'a> {
+        value.into_print_items(self)
+    }
+
+    /// Gets the number of print nodes created in this arena.
+    pub(super) fn get_node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Gets the number of conditions created in this arena.
+    pub(super) fn get_condition_count(&self) -> usize {
+        self.conditions.len()
+    }
+}
+
+impl<'a> Default for PrintItemsArena<'a> {
+    fn default() -> Self {
+        PrintItemsArena::new()
+    }
+}
+
+// Print Items
+
+pub struct PrintItems<'a> {
+    arena: &'a PrintItemsArena<'a>,
+    pub(super) first_node: Option<PrintItemPath<'a>>,
+    last_node: Option<PrintItemPath<'a>>,
 }
 
-impl PrintItems {
-    pub fn new() -> PrintItems {
+impl<'a> PrintItems<'a> {
+    pub fn new(arena: &'a PrintItemsArena<'a>) -> PrintItems<'a> {
         PrintItems {
+            arena,
             first_node: None,
             last_node: None,
         }
     }
 
-    pub fn into_rc_path(self) -> Option<PrintItemPath> {
+    /// Gets the arena the print items are stored in.
+    #[inline]
+    pub fn arena(&self) -> &'a PrintItemsArena<'a> {
+        self.arena
+    }
+
+    pub fn into_rc_path(self) -> Option<PrintItemPath<'a>> {
         self.first_node
     }
 
-    pub fn push_item(&mut self, item: PrintItem) {
+    pub fn push_item(&mut self, item: PrintItem<'a>) {
         self.push_item_internal(item);
     }
 
-    // seems marginally faster to inline this? probably not worth it
     #[inline]
-    fn push_item_internal(&mut self, item: PrintItem) {
-        let node = Rc::new(PrintNodeCell::new(item));
-        if let Some(first_node) = &self.first_node {
-            let new_last_node = node.get_last_next().unwrap_or(node.clone());
-            self.last_node.as_ref().unwrap_or(first_node).set_next(Some(node));
-            self.last_node = Some(new_last_node);
+    fn push_item_internal(&mut self, item: PrintItem<'a>) {
+        let node = self.arena.nodes.alloc(PrintNode::new(item));
+        if let Some(last_node) = self.last_node {
+            last_node.next.set(Some(node));
         } else {
-            self.last_node = node.get_last_next();
             self.first_node = Some(node);
         }
+        self.last_node = Some(node);
     }
 }
 
-impl PrintItems {
-    pub fn extend(&mut self, items: PrintItems) {
-        if let Some(first_node) = &self.first_node {
-            self.last_node.as_ref().unwrap_or(first_node).set_next(items.first_node.clone());
-            self.last_node = items.last_node.or(items.first_node.or(self.last_node.clone())); // todo: fix this
+impl<'a> PrintItems<'a> {
+    pub fn extend(&mut self, items: PrintItems<'a>) {
+        debug_assert!(std::ptr::eq(self.arena, items.arena), "Print items must be created in the same arena.");
+
+        if let Some(last_node) = self.last_node {
+            if items.first_node.is_some() {
+                last_node.next.set(items.first_node);
+                self.last_node = items.last_node;
+            }
         } else {
             self.first_node = items.first_node;
             self.last_node = items.last_node;
@@ -54,40 +112,88 @@ impl PrintItems {
     }
 
     pub fn push_str(&mut self, item: &str) {
-        self.push_item_internal(PrintItem::String(Rc::from(StringContainer::new(String::from(item)))));
+        let text = self.arena.texts.alloc_str(item);
+        let string = self.arena.strings.alloc(StringContainer::new(text));
+        self.push_item_internal(PrintItem::String(string));
     }
 
-    pub fn push_condition(&mut self, condition: Condition) {
-        self.push_item_internal(PrintItem::Condition(Rc::from(condition)));
+    pub fn push_condition(&mut self, condition: Condition<'a>) {
+        let condition = self.arena.conditions.alloc(Condition {
+            index: self.arena.conditions.len(),
+            ..condition
+        });
+        self.push_item_internal(PrintItem::Condition(condition));
     }
 
     pub fn push_info(&mut self, info: Info) {
-        self.push_item_internal(PrintItem::Info(Rc::from(info)));
+        self.push_item_internal(PrintItem::Info(info));
     }
 
     pub fn push_signal(&mut self, signal: Signal) {
         self.push_item_internal(PrintItem::Signal(signal));
     }
 
-    pub fn push_path(&mut self, path: PrintItemPath) {
+    pub fn push_path(&mut self, path: PrintItemPath<'a>) {
         self.push_item_internal(PrintItem::RcPath(path))
     }
 
-    pub fn push_optional_path(&mut self, path: Option<PrintItemPath>) {
+    pub fn push_optional_path(&mut self, path: Option<PrintItemPath<'a>>) {
         if let Some(path) = path {
             self.push_path(path);
         }
     }
 
+    /// Pads the current column with spaces to the furthest column of all the members
+    /// of the alignment group, making the current location a member of the group.
+    ///
+    /// The alignments of a group must be printed in the order they were pushed. Alignments
+    /// that are never printed (ex. in a condition's unprinted path) are ignored.
+    pub fn push_alignment(&mut self, group: &AlignmentGroup) {
+        let info = Info::new(group.name);
+        group.members.borrow_mut().push(info);
+        self.push_info(info);
+        let alignment = self.arena.alignments.alloc(Alignment {
+            info,
+            members: group.members.clone(),
+        });
+        self.push_item_internal(PrintItem::Alignment(alignment));
+    }
+
     pub fn is_empty(&self) -> bool {
         self.first_node.is_none()
     }
 
-    // todo: clean this up
-    #[cfg(debug_assertions)]
+    /// Sets the position in the original text of the first string in these print items.
+    ///
+    /// This is used to map positions in the original text to the formatted text. Nothing is
+    /// done when the first string already has a position or when a condition or path comes
+    /// before it, since it's not known which string will be printed first in those cases.
+    pub fn set_source_position(&mut self, source_position: usize) {
+        for item in self.iter() {
+            match item {
+                PrintItem::String(text) => {
+                    if text.text.is_empty() {
+                        continue;
+                    }
+                    if text.source_position.get().is_none() {
+                        text.source_position.set(Some(source_position));
+                    }
+                    return;
+                },
+                PrintItem::Info(_) | PrintItem::Signal(_) | PrintItem::Alignment(_) => {},
+                PrintItem::Condition(_) | PrintItem::RcPath(_) => return,
+            }
+        }
+    }
+
+    /// Gets the print items as an indented tree for debugging.
+    ///
+    /// Each line is a string, signal, info, or condition. The true and false paths
+    /// of a condition are nested below it, so paths shared between conditions are repeated.
+    /// Outputting a graph (ex. Graphviz DOT) is not supported.
     pub fn get_as_text(&self) -> String {
-        return if let Some(first_node) = &self.first_node {
-            get_items_as_text(first_node.clone(), String::from(""))
+        return if let Some(first_node) = self.first_node {
+            get_items_as_text(first_node, String::from(""))
         } else {
             String::new()
         };
@@ -97,109 +203,126 @@ impl PrintItems {
             for item in PrintItemsIterator::new(items) {
                 match item {
                     PrintItem::Signal(signal) => text.push_str(&get_line(format!("Signal::{:?}", signal), &indent_text)),
-                    PrintItem::Info(info) => text.push_str(&get_line(format!("Info: {}", info.name), &indent_text)),
+                    PrintItem::Info(info) => text.push_str(&get_line(format!("Info #{} {:?}", info.id, info.name), &indent_text)),
                     PrintItem::Condition(condition) => {
-                        text.push_str(&get_line(format!("Condition: {}", condition.name), &indent_text));
-                        if let Some(true_path) = &condition.true_path {
+                        text.push_str(&get_line(get_condition_text(condition), &indent_text));
+                        if let Some(true_path) = condition.true_path {
                             text.push_str(&get_line(String::from("  true:"), &indent_text));
-                            text.push_str(&get_items_as_text(true_path.clone(), format!("{}    ", &indent_text)));
+                            text.push_str(&get_items_as_text(true_path, format!("{}    ", &indent_text)));
                         }
-                        if let Some(false_path) = &condition.false_path {
+                        if let Some(false_path) = condition.false_path {
                             text.push_str(&get_line(String::from("  false:"), &indent_text));
-                            text.push_str(&get_items_as_text(false_path.clone(), format!("{}    ", &indent_text)));
+                            text.push_str(&get_items_as_text(false_path, format!("{}    ", &indent_text)));
                         }
                     },
-                    PrintItem::String(str_text) => text.push_str(&get_line(format!("`{}`", str_text.text.to_string()), &indent_text)),
-                    PrintItem::RcPath(path) => text.push_str(&get_items_as_text(path.clone(), indent_text.clone())),
+                    PrintItem::String(str_text) => text.push_str(&get_line(format!("`{}`", str_text.text), &indent_text)),
+                    PrintItem::Alignment(alignment) => text.push_str(&get_line(format!("Alignment #{} {:?}", alignment.info.id, alignment.info.name), &indent_text)),
+                    PrintItem::RcPath(path) => text.push_str(&get_items_as_text(path, indent_text.clone())),
                 }
             }
 
             return text;
 
-            fn get_line(text: String, indent_text: &String) -> String {
+            fn get_condition_text(condition: &Condition) -> String {
+                let mut text = format!("Condition #{} {:?}", condition.id, condition.name);
+                if let Some(dependent_infos) = &condition.dependent_infos {
+                    let info_ids = dependent_infos.iter().map(|info| format!("#{}", info.id)).collect::<Vec<_>>();
+                    text.push_str(&format!(" (dependent infos: {})", info_ids.join(", ")));
+                } else if condition.is_stored {
+                    text.push_str(" (stored)");
+                }
+                text
+            }
+
+            fn get_line(text: String, indent_text: &str) -> String {
                 format!("{}{}\n", indent_text, text)
             }
         }
     }
 
-    pub fn iter(&self) -> PrintItemsIterator {
+    pub fn iter(&self) -> PrintItemsIterator<'a> {
         PrintItemsIterator {
-            node: self.first_node.clone(),
+            node: self.first_node,
         }
     }
 }
 
-pub struct PrintItemsIterator {
-    node: Option<PrintItemPath>,
+pub struct PrintItemsIterator<'a> {
+    node: Option<PrintItemPath<'a>>,
 }
 
-impl PrintItemsIterator {
-    pub fn new(path: PrintItemPath) -> PrintItemsIterator {
+impl<'a> PrintItemsIterator<'a> {
+    pub fn new(path: PrintItemPath<'a>) -> PrintItemsIterator<'a> {
         PrintItemsIterator {
             node: Some(path),
         }
     }
 }
 
-impl Iterator for PrintItemsIterator {
-    type Item = PrintItem;
+impl<'a> Iterator for PrintItemsIterator<'a> {
+    type Item = PrintItem<'a>;
 
-    fn next(&mut self) -> Option<PrintItem> {
-        let node = self.node.take();
+    fn next(&mut self) -> Option<PrintItem<'a>> {
+        let node = self.node.take()?;
+        self.node = node.next.get();
+        Some(node.item)
+    }
+}
 
-        match node {
-            Some(node) => {
-                self.node = node.get_next();
-                Some(node.get_item())
-            },
-            None => None
-        }
+/// A value that can be converted to print items stored in an arena.
+pub trait IntoPrintItems<'a> {
+    fn into_print_items(self, arena: &'a PrintItemsArena<'a>) -> PrintItems<'a>;
+}
+
+impl<'a> IntoPrintItems<'a> for PrintItems<'a> {
+    fn into_print_items(self, _: &'a PrintItemsArena<'a>) -> PrintItems<'a> {
+        self
     }
 }
 
-impl Into<PrintItems> for &str {
-    fn into(self) -> PrintItems {
-        let mut items = PrintItems::new();
+impl<'a> IntoPrintItems<'a> for &str {
+    fn into_print_items(self, arena: &'a PrintItemsArena<'a>) -> PrintItems<'a> {
+        let mut items = PrintItems::new(arena);
         items.push_str(self);
         items
     }
 }
 
-impl Into<PrintItems> for String {
-    fn into(self) -> PrintItems {
-        let mut items = PrintItems::new();
+impl<'a> IntoPrintItems<'a> for String {
+    fn into_print_items(self, arena: &'a PrintItemsArena<'a>) -> PrintItems<'a> {
+        let mut items = PrintItems::new(arena);
         items.push_str(&self);
         items
     }
 }
 
-impl Into<PrintItems> for &String {
-    fn into(self) -> PrintItems {
-        let mut items = PrintItems::new();
+impl<'a> IntoPrintItems<'a> for &String {
+    fn into_print_items(self, arena: &'a PrintItemsArena<'a>) -> PrintItems<'a> {
+        let mut items = PrintItems::new(arena);
         items.push_str(self);
         items
     }
 }
 
-impl Into<PrintItems> for Condition {
-    fn into(self) -> PrintItems {
-        let mut items = PrintItems::new();
+impl<'a> IntoPrintItems<'a> for Condition<'a> {
+    fn into_print_items(self, arena: &'a PrintItemsArena<'a>) -> PrintItems<'a> {
+        let mut items = PrintItems::new(arena);
         items.push_condition(self);
         items
     }
 }
 
-impl Into<PrintItems> for Signal {
-    fn into(self) -> PrintItems {
-        let mut items = PrintItems::new();
+impl<'a> IntoPrintItems<'a> for Signal {
+    fn into_print_items(self, arena: &'a PrintItemsArena<'a>) -> PrintItems<'a> {
+        let mut items = PrintItems::new(arena);
         items.push_signal(self);
         items
     }
 }
 
-impl Into<PrintItems> for Option<PrintItemPath> {
-    fn into(self) -> PrintItems {
-        let mut items = PrintItems::new();
+impl<'a> IntoPrintItems<'a> for Option<PrintItemPath<'a>> {
+    fn into_print_items(self, arena: &'a PrintItemsArena<'a>) -> PrintItems<'a> {
+        let mut items = PrintItems::new(arena);
         if let Some(path) = self {
             items.push_path(path);
         }
@@ -207,123 +330,37 @@ impl Into<PrintItems> for Option<PrintItemPath> {
     }
 }
 
-/** Print Node */
+// Print Node
 
-pub struct PrintNode {
-    pub(super) next: Option<PrintItemPath>,
-    pub(super) item: PrintItem,
-}
-
-impl Drop for PrintNode {
-    // Implement a custom drop in order to prevent a stack overflow error when dropping objects of this type
-    fn drop(&mut self) {
-        let mut next = mem::replace(&mut self.next, None);
-
-        loop {
-            next = match next {
-                Some(node) => match Rc::try_unwrap(node) {
-                    Ok(node) => node.take_next(),
-                    Err(_) => break,
-                },
-                None => break
-            }
-        }
-    }
+/// A print item along with the next node in its path.
+pub struct PrintNode<'a> {
+    pub(super) next: Cell<Option<PrintItemPath<'a>>>,
+    pub(super) item: PrintItem<'a>,
 }
 
-impl PrintNode {
-    fn new(item: PrintItem) -> PrintNode {
+impl<'a> PrintNode<'a> {
+    fn new(item: PrintItem<'a>) -> PrintNode<'a> {
         PrintNode {
             item,
-            next: None,
+            next: Cell::new(None),
         }
     }
-
-    fn set_next(&mut self, new_next: Option<PrintItemPath>) {
-        let past_next = mem::replace(&mut self.next, new_next.clone());
-
-        if let Some(past_next) = past_next {
-            if let Some(new_next) = new_next {
-                new_next.get_last_next().unwrap_or(new_next).set_next(Some(past_next));
-            }
-        }
-    }
-}
-
-/// A fast implementation of RefCell<PrintNode> that avoids runtime checks on borrows.
-pub struct PrintNodeCell {
-    value: UnsafeCell<PrintNode>,
 }
 
-impl PrintNodeCell {
-    pub(super) fn new(item: PrintItem) -> PrintNodeCell {
-        PrintNodeCell {
-            value: UnsafeCell::new(PrintNode::new(item))
-        }
-    }
-
-    #[inline]
-    pub(super) fn get_item(&self) -> PrintItem {
-        unsafe {
-            (*self.value.get()).item.clone()
-        }
-    }
-
-    #[inline]
-    pub(super) fn get_next(&self) -> Option<PrintItemPath> {
-        unsafe {
-            (*self.value.get()).next.clone()
-        }
-    }
-
-    #[inline]
-    pub(super) fn set_next(&self, new_next: Option<PrintItemPath>) {
-        unsafe {
-            (*self.value.get()).set_next(new_next);
-        }
-    }
-
-    #[inline]
-    pub(super) fn get_last_next(&self) -> Option<PrintItemPath> {
-        let mut current = self.get_next();
-        loop {
-            if let Some(last) = &current {
-                if let Some(next) = last.get_next() {
-                    current.replace(next);
-                    continue;
-                }
-            }
-            break;
-        }
-
-        return current;
-    }
-
-    /// Gets the node unsafely. Be careful when using this and ensure no mutation is
-    /// happening during the borrow.
-    #[inline]
-    pub(super) unsafe fn get_node(&self) -> *mut PrintNode {
-        self.value.get()
-    }
-
-    #[inline]
-    pub fn take_next(self) -> Option<PrintItemPath> {
-        self.value.into_inner().next.take()
-    }
-}
-
-pub type PrintItemPath = Rc<PrintNodeCell>;
+/// A reference to the first node of a path of print items.
+pub type PrintItemPath<'a> = &'a PrintNode<'a>;
 
 /* Print item and kinds */
 
 /// The different items the printer could encounter.
-#[derive(Clone)]
-pub enum PrintItem {
-    String(Rc<StringContainer>),
-    Condition(Rc<Condition>),
-    Info(Rc<Info>),
+#[derive(Clone, Copy)]
+pub enum PrintItem<'a> {
+    String(&'a StringContainer<'a>),
+    Condition(&'a Condition<'a>),
+    Info(Info),
     Signal(Signal),
-    RcPath(PrintItemPath),
+    RcPath(PrintItemPath<'a>),
+    Alignment(&'a Alignment),
 }
 
 #[derive(Clone, PartialEq, Copy, Debug)]
@@ -373,18 +410,16 @@ pub struct Info {
     /// Unique identifier.
     id: usize,
     /// Name for debugging purposes.
-    #[cfg(debug_assertions)]
     name: &'static str,
 }
 
 static INFO_COUNTER: AtomicUsize = AtomicUsize::new(0);
 
 impl Info {
-    pub fn new(_name: &'static str) -> Info {
+    pub fn new(name: &'static str) -> Info {
         Info {
             id: INFO_COUNTER.fetch_add(1, Ordering::SeqCst),
-            #[cfg(debug_assertions)]
-            name: _name
+            name,
         }
     }
 
@@ -395,23 +430,54 @@ impl Info {
 
     #[inline]
     pub fn get_name(&self) -> &'static str {
-        #[cfg(debug_assertions)]
-        return self.name;
-        #[cfg(not(debug_assertions))]
-        return "";
+        self.name
     }
 }
 
+/// A group of locations that should be padded to the same column.
+///
+/// Add members to the group with `PrintItems::push_alignment(&group)`. The printer
+/// pads each member with spaces up to the furthest column of all the members.
+#[derive(Clone)]
+pub struct AlignmentGroup {
+    /// Name for debugging purposes.
+    name: &'static str,
+    members: Rc<RefCell<Vec<Info>>>,
+}
+
+impl AlignmentGroup {
+    pub fn new(name: &'static str) -> AlignmentGroup {
+        AlignmentGroup {
+            name,
+            members: Rc::new(RefCell::new(Vec::new())),
+        }
+    }
+
+    /// Gets if no alignments were pushed for the group.
+    pub fn is_empty(&self) -> bool {
+        self.members.borrow().is_empty()
+    }
+}
+
+/// Pads the location of its info to the furthest column of the members of its group.
+pub struct Alignment {
+    /// The info at the location of this member.
+    pub(super) info: Info,
+    /// The infos of all the members in the group, in the order they're printed.
+    pub(super) members: Rc<RefCell<Vec<Info>>>,
+}
+
 /// Conditionally print items based on a condition.
 ///
 /// These conditions are extremely flexible and can even be resolved based on
 /// information found later on in the file.
 #[derive(Clone)]
-pub struct Condition {
+pub struct Condition<'a> {
     /// Unique identifier.
     id: usize,
+    /// The index of the condition in the arena it's stored in, which is set once it's pushed.
+    pub(super) index: usize,
     /// Name for debugging purposes.
-    #[cfg(debug_assertions)]
     name: &'static str,
     /// If a reference has been created for the condition via `get_reference()`. If so, the printer
     /// will store the condition and it will be retrievable via a condition resolver.
@@ -419,9 +485,9 @@ pub struct Condition {
     /// The condition to resolve.
     pub(super) condition: Rc<Box<ConditionResolver>>,
     /// The items to print when the condition is true.
-    pub(super) true_path: Option<PrintItemPath>,
+    pub(super) true_path: Option<PrintItemPath<'a>>,
     /// The items to print when the condition is false or undefined (not yet resolved).
-    pub(super) false_path: Option<PrintItemPath>,
+    pub(super) false_path: Option<PrintItemPath<'a>>,
     /// Any infos that should cause the re-evaluation of this condition.
     /// This is only done on request for performance reasons.
     pub(super) dependent_infos: Option<Vec<Info>>,
@@ -429,12 +495,12 @@ pub struct Condition {
 
 static CONDITION_COUNTER: AtomicUsize = AtomicUsize::new(0);
 
-impl Condition {
-    pub fn new(name: &'static str, properties: ConditionProperties) -> Condition {
+impl<'a> Condition<'a> {
+    pub fn new(name: &'static str, properties: ConditionProperties<'a>) -> Condition<'a> {
         Condition::new_internal(name, properties, None)
     }
 
-    pub fn new_true() -> Condition {
+    pub fn new_true() -> Condition<'a> {
         Condition::new_internal("trueCondition", ConditionProperties {
             condition: Rc::new(Box::new(|_| Some(true))),
             true_path: None,
@@ -442,7 +508,7 @@ impl Condition {
         }, None)
     }
 
-    pub fn new_false() -> Condition {
+    pub fn new_false() -> Condition<'a> {
         Condition::new_internal("falseCondition", ConditionProperties {
             condition: Rc::new(Box::new(|_| Some(false))),
             true_path: None,
@@ -450,19 +516,19 @@ impl Condition {
         }, None)
     }
 
-    pub fn new_with_dependent_infos(name: &'static str, properties: ConditionProperties, dependent_infos: Vec<Info>) -> Condition {
+    pub fn new_with_dependent_infos(name: &'static str, properties: ConditionProperties<'a>, dependent_infos: Vec<Info>) -> Condition<'a> {
         Condition::new_internal(name, properties, Some(dependent_infos))
     }
 
-    fn new_internal(_name: &'static str, properties: ConditionProperties, dependent_infos: Option<Vec<Info>>) -> Condition {
+    fn new_internal(name: &'static str, properties: ConditionProperties<'a>, dependent_infos: Option<Vec<Info>>) -> Condition<'a> {
         Condition {
             id: CONDITION_COUNTER.fetch_add(1, Ordering::SeqCst),
+            index: 0,
             is_stored: dependent_infos.is_some(),
-            #[cfg(debug_assertions)]
-            name: _name,
+            name,
             condition: properties.condition,
-            true_path: properties.true_path.map(|x| x.first_node).flatten(),
-            false_path: properties.false_path.map(|x| x.first_node).flatten(),
+            true_path: properties.true_path.and_then(|x| x.first_node),
+            false_path: properties.false_path.and_then(|x| x.first_node),
             dependent_infos,
         }
     }
@@ -474,19 +540,16 @@ impl Condition {
 
     #[inline]
     pub fn get_name(&self) -> &'static str {
-        #[cfg(debug_assertions)]
-        return self.name;
-        #[cfg(not(debug_assertions))]
-        return "condition";
+        self.name
     }
 
     #[inline]
-    pub fn get_true_path(&self) -> &Option<PrintItemPath> {
+    pub fn get_true_path(&self) -> &Option<PrintItemPath<'a>> {
         &self.true_path
     }
 
     #[inline]
-    pub fn get_false_path(&self) -> &Option<PrintItemPath> {
+    pub fn get_false_path(&self) -> &Option<PrintItemPath<'a>> {
         &self.false_path
     }
 
@@ -535,27 +598,27 @@ impl ConditionReference {
 }
 
 /// Properties for the condition.
-pub struct ConditionProperties {
+pub struct ConditionProperties<'a> {
     /// The condition to resolve.
     pub condition: Rc<Box<ConditionResolver>>,
     /// The items to print when the condition is true.
-    pub true_path: Option<PrintItems>,
+    pub true_path: Option<PrintItems<'a>>,
     /// The items to print when the condition is false or undefined (not yet resolved).
-    pub false_path: Option<PrintItems>,
+    pub false_path: Option<PrintItems<'a>>,
 }
 
 /// Function used to resolve a condition.
 pub type ConditionResolver = dyn Fn(&mut ConditionResolverContext) -> Option<bool>;
 
 /// Context used when resolving a condition.
-pub struct ConditionResolverContext<'a> {
-    printer: &'a mut Printer,
+pub struct ConditionResolverContext<'a, 'b> {
+    printer: &'a mut Printer<'b>,
     /// Gets the writer info at the condition's location.
     pub writer_info: WriterInfo,
 }
 
-impl<'a> ConditionResolverContext<'a> {
-    pub(super) fn new(printer: &'a mut Printer, writer_info: WriterInfo) -> Self {
+impl<'a, 'b> ConditionResolverContext<'a, 'b> {
+    pub(super) fn new(printer: &'a mut Printer<'b>, writer_info: WriterInfo) -> Self {
         ConditionResolverContext {
             printer,
             writer_info,
@@ -587,21 +650,44 @@ impl<'a> ConditionResolverContext<'a> {
 
 /// A container that holds the string's value and character count.
 #[derive(Clone)]
-pub struct StringContainer {
+pub struct StringContainer<'a> {
     /// The string value.
-    pub text: String,
+    pub text: &'a str,
     /// The cached character count.
     /// It is much faster to cache this than to recompute it all the time.
     pub(super) char_count: u32,
+    /// The display width (ex. CJK characters and emoji take up two columns), which is
+    /// computed the first time it's needed since it's only used with `unicode_width`.
+    display_width: Cell<Option<u32>>,
+    /// The byte position of the string in the original text, if known.
+    pub(super) source_position: Cell<Option<usize>>,
 }
 
-impl StringContainer {
+impl<'a> StringContainer<'a> {
     /// Creates a new string container.
-    pub fn new(text: String) -> StringContainer {
-        let char_count = text.chars().count() as u32;
+    pub fn new(text: &'a str) -> StringContainer<'a> {
+        let char_count = if text.is_ascii() { text.len() } else { text.chars().count() } as u32;
         StringContainer {
             text,
-            char_count
+            char_count,
+            display_width: Cell::new(None),
+            source_position: Cell::new(None),
+        }
+    }
+
+    /// Gets the number of columns the string takes up when written.
+    #[inline]
+    pub(super) fn get_width(&self, unicode_width: bool) -> u32 {
+        if !unicode_width {
+            return self.char_count;
+        }
+
+        if let Some(display_width) = self.display_width.get() {
+            display_width
+        } else {
+            let display_width = get_display_width(self.text) as u32;
+            self.display_width.set(Some(display_width));
+            display_width
         }
     }
 }
@@ -0,0 +1,37 @@
+use std::rc::Rc;
+use super::*;
+
+#[test]
+fn get_as_text_includes_all_items() {
+    let arena = PrintItemsArena::new();
+    let info = Info::new("start");
+    let mut true_path = PrintItems::new(&arena);
+    true_path.push_str("a");
+    true_path.push_signal(Signal::NewLine);
+    let condition = Condition::new_with_dependent_infos("isMultiLine", ConditionProperties {
+        condition: Rc::new(Box::new(|_| Some(true))),
+        true_path: Some(true_path),
+        false_path: Some(arena.items("b")),
+    }, vec![info]);
+    let condition_id = condition.get_unique_id();
+
+    let mut items = PrintItems::new(&arena);
+    items.push_info(info);
+    items.push_condition(condition);
+    items.push_str("c");
+
+    assert_eq!(items.get_as_text(), format!(
+        concat!(
+            "Info #{0} \"start\"\n",
+            "Condition #{1} \"isMultiLine\" (dependent infos: #{0})\n",
+            "  true:\n",
+            "    `a`\n",
+            "    Signal::NewLine\n",
+            "  false:\n",
+            "    `b`\n",
+            "`c`\n",
+        ),
+        info.get_unique_id(),
+        condition_id,
+    ));
+}
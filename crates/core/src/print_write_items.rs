@@ -1,4 +1,7 @@
+use std::fmt;
+use std::io;
 use super::WriteItem;
+use super::position_map::{PositionMap, PositionMapping};
 
 pub struct PrintWriteItemsOptions {
     /// The number of spaces to use when indenting when use_tabs is false,
@@ -11,20 +14,97 @@ pub struct PrintWriteItemsOptions {
 }
 
 /// Prints string based writer items.
-pub fn print_write_items(write_items: impl Iterator<Item = WriteItem>, options: PrintWriteItemsOptions) -> String {
-    // todo: faster string manipulation? or is this as good as it gets?
+pub fn print_write_items<'a>(write_items: impl Iterator<Item = WriteItem<'a>>, options: PrintWriteItemsOptions) -> String {
     let mut final_string = String::new();
+    print_write_items_to(write_items, options, &mut final_string).expect("Writing to a string should never fail.");
+    final_string
+}
+
+/// Prints string based writer items to a `fmt::Write` sink (ex. a `String` or `fmt::Formatter`).
+pub fn print_write_items_to<'a>(
+    write_items: impl Iterator<Item = WriteItem<'a>>,
+    options: PrintWriteItemsOptions,
+    writer: &mut impl fmt::Write,
+) -> fmt::Result {
+    write_text_chunks(write_items, &options, |text, _| writer.write_str(text))
+}
+
+/// Prints string based writer items to an `io::Write` sink (ex. a file or stdout).
+///
+/// The sink is not buffered, so wrap it in an `io::BufWriter` when writing to a file.
+pub fn print_write_items_to_io<'a>(
+    write_items: impl Iterator<Item = WriteItem<'a>>,
+    options: PrintWriteItemsOptions,
+    writer: &mut impl io::Write,
+) -> io::Result<()> {
+    write_text_chunks(write_items, &options, |text, _| writer.write_all(text.as_bytes()))
+}
+
+/// Gets if the string based writer items print out the provided text.
+///
+/// This stops at the first difference without building the printed text.
+pub fn print_write_items_equals<'a>(
+    write_items: impl Iterator<Item = WriteItem<'a>>,
+    options: PrintWriteItemsOptions,
+    text: &str,
+) -> bool {
+    let text = text.as_bytes();
+    let mut position = 0;
+    let result = write_text_chunks(write_items, &options, |chunk, _| {
+        let chunk = chunk.as_bytes();
+        if text[position..].starts_with(chunk) {
+            position += chunk.len();
+            Ok(())
+        } else {
+            Err(())
+        }
+    });
+
+    result.is_ok() && position == text.len()
+}
+
+/// Prints string based writer items along with a map of the positions of the strings
+/// in the original text to their positions in the printed text.
+pub fn print_write_items_with_mapping<'a>(write_items: impl Iterator<Item = WriteItem<'a>>, options: PrintWriteItemsOptions) -> (String, PositionMap) {
+    let mut final_string = String::new();
+    let mut mappings = Vec::new();
+    let result: Result<(), ()> = write_text_chunks(write_items, &options, |text, source_position| {
+        if let Some(old_position) = source_position {
+            mappings.push(PositionMapping {
+                old_position,
+                new_position: final_string.len(),
+                len: text.len(),
+            });
+        }
+        final_string.push_str(text);
+        Ok(())
+    });
+    result.unwrap();
+
+    let new_text_len = final_string.len();
+    (final_string, PositionMap::new(mappings, new_text_len))
+}
+
+fn write_text_chunks<'a, TError>(
+    write_items: impl Iterator<Item = WriteItem<'a>>,
+    options: &PrintWriteItemsOptions,
+    mut write_text: impl FnMut(&str, Option<usize>) -> Result<(), TError>,
+) -> Result<(), TError> {
     let indent_string = if options.use_tabs { String::from("\t") } else { " ".repeat(options.indent_width as usize) };
 
-    for item in write_items.into_iter() {
+    for item in write_items {
         match item {
-            WriteItem::Indent(times) => final_string.push_str(&indent_string.repeat(times as usize)),
-            WriteItem::NewLine => final_string.push_str(&options.new_line_text),
-            WriteItem::Tab => final_string.push_str("\t"),
-            WriteItem::Space => final_string.push_str(" "),
-            WriteItem::String(text) => final_string.push_str(&text.text),
+            WriteItem::Indent(times) => {
+                for _ in 0..times {
+                    write_text(&indent_string, None)?;
+                }
+            },
+            WriteItem::NewLine => write_text(options.new_line_text, None)?,
+            WriteItem::Tab => write_text("\t", None)?,
+            WriteItem::Space => write_text(" ", None)?,
+            WriteItem::String(text) => write_text(text.text, text.source_position.get())?,
         }
     }
 
-    final_string
+    Ok(())
 }
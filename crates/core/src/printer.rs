@@ -3,66 +3,68 @@ use super::print_items::*;
 use super::writer::*;
 use super::collections::{FastCellMap};
 use super::get_write_items::{GetWriteItemsOptions};
-use std::collections::HashMap;
-use std::mem::{self, MaybeUninit};
+use super::print_budget::PrintBudget;
+use super::print_error::PrintError;
+use std::collections::{HashMap, HashSet};
+use std::mem;
 use std::rc::Rc;
 
 // todo: Needs slight redesign. See issue #71 and #195.
 
-struct SavePoint {
+struct SavePoint<'a> {
     /// Name for debugging purposes.
     pub name: &'static str,
     pub new_line_group_depth: u16,
     pub force_no_newlines_depth: u8,
-    pub writer_state: WriterState,
-    pub possible_new_line_save_point: Option<Rc<SavePoint>>,
-    pub node: Option<PrintItemPath>,
-    pub look_ahead_condition_save_points: HashMap<usize, Rc<SavePoint>>,
-    pub look_ahead_info_save_points: HashMap<usize, Rc<SavePoint>>,
-    pub next_node_stack: Vec<Option<PrintItemPath>>,
+    pub writer_state: WriterState<'a>,
+    pub possible_new_line_save_point: Option<Rc<SavePoint<'a>>>,
+    pub node: Option<PrintItemPath<'a>>,
+    pub look_ahead_condition_save_points: HashMap<usize, Rc<SavePoint<'a>>>,
+    pub look_ahead_info_save_points: HashMap<usize, Rc<SavePoint<'a>>>,
+    pub next_node_stack: Vec<Option<PrintItemPath<'a>>>,
 }
 
-struct PrintItemContainer<'a> {
-    items: &'a Vec<PrintItem>,
-    index: i32,
-}
-
-impl<'a> Clone for PrintItemContainer<'a> {
-    fn clone(&self) -> PrintItemContainer<'a> {
-        PrintItemContainer {
-            items: self.items,
-            index: self.index,
-        }
-    }
-}
-
-pub struct Printer {
-    possible_new_line_save_point: Option<Rc<SavePoint>>,
+pub struct Printer<'a> {
+    possible_new_line_save_point: Option<Rc<SavePoint<'a>>>,
     new_line_group_depth: u16,
     force_no_newlines_depth: u8,
-    current_node: Option<PrintItemPath>,
-    writer: Writer,
+    current_node: Option<PrintItemPath<'a>>,
+    writer: Writer<'a>,
     resolved_conditions: HashMap<usize, Option<bool>>,
     resolved_infos: HashMap<usize, WriterInfo>,
-    look_ahead_condition_save_points: HashMap<usize, Rc<SavePoint>>,
-    look_ahead_info_save_points: FastCellMap<usize, SavePoint>,
-    next_node_stack: Vec<Option<PrintItemPath>>,
-    conditions_for_infos: HashMap<usize, HashMap<usize, (Rc<Condition>, Rc<SavePoint>)>>,
+    look_ahead_condition_save_points: HashMap<usize, Rc<SavePoint<'a>>>,
+    look_ahead_info_save_points: FastCellMap<usize, SavePoint<'a>>,
+    next_node_stack: Vec<Option<PrintItemPath<'a>>>,
+    conditions_for_infos: HashMap<usize, HashMap<usize, (&'a Condition<'a>, Rc<SavePoint<'a>>)>>,
     max_width: u32,
     skip_moving_next: bool,
-    resolving_save_point: Option<Rc<SavePoint>>,
+    resolving_save_point: Option<Rc<SavePoint<'a>>>,
     stored_info_positions: HashMap<usize, (u32, u32)>,
+    budget: PrintBudget,
+    node_visits: u64,
+    max_node_visits: u64,
+    /// The number of times each condition in the arena was evaluated.
+    condition_evaluations: Vec<u32>,
+    /// The condition that was evaluated the most times.
+    most_evaluated_condition: Option<&'a Condition<'a>>,
+    budget_error: Option<PrintError>,
+    /// The alignment member infos that were looked ahead to.
+    alignment_look_ahead_infos: Vec<usize>,
+    /// The alignment member infos that were looked ahead to, but never printed (ex. in a condition's unprinted path).
+    unprinted_alignment_infos: HashSet<usize>,
 }
 
-impl Printer {
-    pub fn new(start_node: Option<PrintItemPath>, options: GetWriteItemsOptions) -> Printer {
+impl<'a> Printer<'a> {
+    pub fn new(print_items: &PrintItems<'a>, options: GetWriteItemsOptions) -> Printer<'a> {
+        let arena = print_items.arena();
         Printer {
             possible_new_line_save_point: None,
             new_line_group_depth: 0,
             force_no_newlines_depth: 0,
-            current_node: start_node,
+            current_node: print_items.first_node,
             writer: Writer::new(WriterOptions {
                 indent_width: options.indent_width,
+                unicode_width: options.unicode_width,
             }),
             resolved_conditions: HashMap::new(),
             resolved_infos: HashMap::new(),
@@ -74,21 +76,59 @@ impl Printer {
             skip_moving_next: false,
             resolving_save_point: None,
             stored_info_positions: HashMap::new(),
+            budget: options.budget,
+            node_visits: 0,
+            max_node_visits: std::cmp::max(arena.get_node_count() as u64, 1) * options.budget.max_visits_per_node as u64,
+            condition_evaluations: vec![0; arena.get_condition_count()],
+            most_evaluated_condition: None,
+            budget_error: None,
+            alignment_look_ahead_infos: Vec::new(),
+            unprinted_alignment_infos: HashSet::new(),
         }
     }
 
     /// Turns the print items into a collection of writer items according to the options.
-    pub fn print(mut self) -> impl Iterator<Item = WriteItem> {
-        while let Some(current_node) = &self.current_node {
-            let current_node = unsafe { &*current_node.get_node() }; // ok because values won't be mutated while printing
+    ///
+    /// Errors when the printer exceeds its budget instead of looping indefinitely.
+    pub fn print(mut self) -> Result<impl Iterator<Item = WriteItem<'a>>, PrintError> {
+        loop {
+            self.print_nodes()?;
+            if !self.restore_for_unprinted_alignment_member() {
+                break;
+            }
+        }
+
+        #[cfg(debug_assertions)]
+        self.verify_no_look_ahead_save_points();
+        #[cfg(debug_assertions)]
+        self.ensure_counts_zero();
+
+        // the save points hold references to the writer's items, so drop them before getting the items
+        Ok(self.into_writer().get_items())
+    }
+
+    fn print_nodes(&mut self) -> Result<(), PrintError> {
+        while let Some(current_node) = self.current_node {
+            self.node_visits += 1;
+            if self.node_visits > self.max_node_visits {
+                return Err(PrintError::NodeVisitsExceeded {
+                    node_visits: self.node_visits,
+                    most_evaluated_condition_name: self.most_evaluated_condition.map(|condition| condition.get_name()),
+                });
+            }
+
             self.handle_print_node(current_node);
 
+            if let Some(err) = self.budget_error.take() {
+                return Err(err);
+            }
+
             // println!("{}", self.writer.to_string_for_debugging());
 
             if self.skip_moving_next {
                 self.skip_moving_next = false;
-            } else if let Some(current_node) = self.current_node {
-                self.current_node = current_node.get_next();
+            } else {
+                self.current_node = current_node.next.get();
             }
 
             while self.current_node.is_none() && !self.next_node_stack.is_empty() {
@@ -96,14 +136,27 @@ impl Printer {
             }
         }
 
-        #[cfg(debug_assertions)]
-        self.verify_no_look_ahead_save_points();
-        #[cfg(debug_assertions)]
-        self.ensure_counts_zero();
+        Ok(())
+    }
 
-        let writer = mem::replace(&mut self.writer, unsafe { MaybeUninit::zeroed().assume_init() });
-        mem::drop(self);
-        writer.get_items()
+    /// Goes back to an alignment that looked ahead to a member that was never printed so
+    /// that it's printed with the members before that one instead.
+    fn restore_for_unprinted_alignment_member(&mut self) -> bool {
+        while let Some(info_id) = self.alignment_look_ahead_infos.pop() {
+            if let Some(save_point) = self.look_ahead_info_save_points.remove(&info_id) {
+                self.unprinted_alignment_infos.insert(info_id);
+                self.update_state_to_save_point(save_point, false);
+                // the restored node is the alignment, so print it again instead of moving past it
+                self.skip_moving_next = false;
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn into_writer(self) -> Writer<'a> {
+        self.writer
     }
 
     pub fn get_writer_info(&self) -> WriterInfo {
@@ -155,13 +208,14 @@ impl Printer {
     }
 
     #[inline]
-    fn handle_print_node(&mut self, print_node: &PrintNode) {
-        match &print_node.item {
+    fn handle_print_node(&mut self, print_node: &'a PrintNode<'a>) {
+        match print_node.item {
             PrintItem::String(text) => self.handle_string(text),
-            PrintItem::Condition(condition) => self.handle_condition(condition, &print_node.next),
-            PrintItem::Info(info) => self.handle_info(info),
-            PrintItem::Signal(signal) => self.handle_signal(signal),
-            PrintItem::RcPath(rc_path) => self.handle_rc_path(rc_path, &print_node.next),
+            PrintItem::Condition(condition) => self.handle_condition(condition, print_node.next.get()),
+            PrintItem::Info(info) => self.handle_info(&info),
+            PrintItem::Signal(signal) => self.handle_signal(&signal, print_node.next.get()),
+            PrintItem::RcPath(rc_path) => self.handle_rc_path(rc_path, print_node.next.get()),
+            PrintItem::Alignment(alignment) => self.handle_alignment(alignment),
         }
     }
 
@@ -170,7 +224,7 @@ impl Printer {
         self.possible_new_line_save_point = None;
     }
 
-    fn create_save_point(&self, name: &'static str, next_node: Option<PrintItemPath>) -> Rc<SavePoint> {
+    fn create_save_point(&self, name: &'static str, next_node: Option<PrintItemPath<'a>>) -> Rc<SavePoint<'a>> {
         Rc::new(SavePoint {
             name,
             possible_new_line_save_point: self.possible_new_line_save_point.clone(),
@@ -185,22 +239,21 @@ impl Printer {
     }
 
     #[inline]
-    fn get_save_point_for_restoring_condition(&self, name: &'static str) -> Rc<SavePoint> {
+    fn get_save_point_for_restoring_condition(&self, name: &'static str) -> Rc<SavePoint<'a>> {
         if let Some(save_point) = &self.resolving_save_point {
             save_point.clone()
         } else {
-            self.create_save_point(name, self.current_node.clone())
+            self.create_save_point(name, self.current_node)
         }
     }
 
-    fn mark_possible_new_line_if_able(&mut self) {
+    fn mark_possible_new_line_if_able(&mut self, next_node: Option<PrintItemPath<'a>>) {
         if let Some(new_line_save_point) = &self.possible_new_line_save_point {
             if self.new_line_group_depth > new_line_save_point.new_line_group_depth {
                 return;
             }
         }
 
-        let next_node = self.current_node.as_ref().unwrap().get_next();
         self.possible_new_line_save_point = Some(self.create_save_point("newline", next_node));
     }
 
@@ -209,7 +262,7 @@ impl Printer {
         self.writer.get_line_column() + offset > self.max_width
     }
 
-    fn update_state_to_save_point(&mut self, save_point: Rc<SavePoint>, is_for_new_line: bool) {
+    fn update_state_to_save_point(&mut self, save_point: Rc<SavePoint<'a>>, is_for_new_line: bool) {
         match Rc::try_unwrap(save_point) {
             Ok(save_point) => {
                 self.writer.set_state(save_point.writer_state);
@@ -224,7 +277,7 @@ impl Printer {
             Err(save_point) => {
                 self.writer.set_state(save_point.writer_state.clone());
                 self.possible_new_line_save_point = if is_for_new_line { None } else { save_point.possible_new_line_save_point.clone() };
-                self.current_node = save_point.node.clone();
+                self.current_node = save_point.node;
                 self.new_line_group_depth = save_point.new_line_group_depth;
                 self.force_no_newlines_depth = save_point.force_no_newlines_depth;
                 self.look_ahead_condition_save_points = save_point.look_ahead_condition_save_points.clone();
@@ -241,7 +294,7 @@ impl Printer {
     }
 
     #[inline]
-    fn handle_signal(&mut self, signal: &Signal) {
+    fn handle_signal(&mut self, signal: &Signal, next_node: Option<PrintItemPath<'a>>) {
         match signal {
             Signal::NewLine => if self.allow_new_lines() { self.write_new_line() },
             Signal::Tab => self.writer.tab(),
@@ -250,7 +303,7 @@ impl Printer {
                 self.writer.mark_expect_new_line();
                 self.possible_new_line_save_point = None;
             }
-            Signal::PossibleNewLine => if self.allow_new_lines() { self.mark_possible_new_line_if_able() },
+            Signal::PossibleNewLine => if self.allow_new_lines() { self.mark_possible_new_line_if_able(next_node) },
             Signal::SpaceOrNewLine => {
                 if self.allow_new_lines() {
                     if self.is_above_max_width(1) {
@@ -266,7 +319,7 @@ impl Printer {
                             }
                         }
                     } else {
-                        self.mark_possible_new_line_if_able();
+                        self.mark_possible_new_line_if_able(next_node);
                         self.writer.space();
                     }
                 } else {
@@ -323,7 +376,22 @@ impl Printer {
     }
 
     #[inline]
-    fn handle_condition(&mut self, condition: &Rc<Condition>, next_node: &Option<PrintItemPath>) {
+    fn handle_condition(&mut self, condition: &'a Condition<'a>, next_node: Option<PrintItemPath<'a>>) {
+        // the printer always comes back to the condition when its value changes, so counting here catches it flip-flopping
+        let evaluations = self.condition_evaluations[condition.index] + 1;
+        self.condition_evaluations[condition.index] = evaluations;
+        if evaluations > self.budget.max_condition_evaluations {
+            self.budget_error = Some(PrintError::ConditionEvaluationsExceeded {
+                condition_name: condition.get_name(),
+                evaluations,
+            });
+            return;
+        }
+        match self.most_evaluated_condition {
+            Some(most_evaluated_condition) if self.condition_evaluations[most_evaluated_condition.index] >= evaluations => {},
+            _ => self.most_evaluated_condition = Some(condition),
+        }
+
         let condition_id = condition.get_unique_id();
         if let Some(dependent_infos) = &condition.dependent_infos {
             for info in dependent_infos {
@@ -337,7 +405,7 @@ impl Printer {
                 };
 
                 let condition_id = condition.get_unique_id();
-                conditions_for_info.insert(condition_id, (condition.clone(), save_point));
+                conditions_for_info.insert(condition_id, (condition, save_point));
             }
         }
 
@@ -354,37 +422,62 @@ impl Printer {
         }
 
         if condition_value.is_some() && condition_value.unwrap() {
-            if let Some(true_path) = &condition.true_path {
-                self.current_node = Some(true_path.clone());
-                self.next_node_stack.push(next_node.clone());
+            if let Some(true_path) = condition.true_path {
+                self.current_node = Some(true_path);
+                self.next_node_stack.push(next_node);
                 self.skip_moving_next = true;
             }
         } else {
-            if let Some(false_path) = &condition.false_path {
-                self.current_node = Some(false_path.clone());
-                self.next_node_stack.push(next_node.clone());
+            if let Some(false_path) = condition.false_path {
+                self.current_node = Some(false_path);
+                self.next_node_stack.push(next_node);
                 self.skip_moving_next = true;
             }
         }
     }
 
     #[inline]
-    fn handle_rc_path(&mut self, print_item_path: &PrintItemPath, next_node: &Option<PrintItemPath>) {
-        self.next_node_stack.push(next_node.clone());
-        self.current_node = Some(print_item_path.clone());
+    fn handle_rc_path(&mut self, print_item_path: PrintItemPath<'a>, next_node: Option<PrintItemPath<'a>>) {
+        self.next_node_stack.push(next_node);
+        self.current_node = Some(print_item_path);
         self.skip_moving_next = true;
     }
 
+    fn handle_alignment(&mut self, alignment: &Alignment) {
+        let members = alignment.members.borrow();
+        // Only look ahead to the last printed member. The earlier members are printed before it, so once
+        // it's resolved the printer comes back here with every member resolved.
+        let last_member = members.iter()
+            .rev()
+            .find(|info| !self.unprinted_alignment_infos.contains(&info.get_unique_id()))
+            .expect("Expected the alignment to be a member of its group.");
+        if self.get_resolved_info(last_member).is_none() {
+            self.alignment_look_ahead_infos.push(last_member.get_unique_id());
+            return;
+        }
+
+        let own_column = self.writer.get_line_column();
+        let max_column = members.iter()
+            .filter_map(|info| self.resolved_infos.get(&info.get_unique_id()))
+            .map(|info| info.column_number)
+            .max()
+            .unwrap_or(own_column);
+
+        for _ in own_column..max_column {
+            self.writer.space();
+        }
+    }
+
     #[inline]
-    fn handle_string(&mut self, text: &Rc<StringContainer>) {
+    fn handle_string(&mut self, text: &'a StringContainer<'a>) {
         #[cfg(debug_assertions)]
-        self.validate_string(&text.text);
+        self.validate_string(text.text);
 
-        if self.possible_new_line_save_point.is_some() && self.is_above_max_width(text.char_count) && self.allow_new_lines() {
+        if self.possible_new_line_save_point.is_some() && self.is_above_max_width(self.writer.get_text_width(text)) && self.allow_new_lines() {
             let save_point = mem::replace(&mut self.possible_new_line_save_point, Option::None);
             self.update_state_to_save_point(save_point.unwrap(), true);
         } else {
-            self.writer.write(text.clone());
+            self.writer.write(text);
         }
     }
 
@@ -420,7 +513,7 @@ impl Printer {
     }
 
     #[cfg(debug_assertions)]
-    fn panic_for_save_point_existing(&self, save_point: &SavePoint) {
+    fn panic_for_save_point_existing(&self, save_point: &SavePoint<'a>) {
         panic!(
             concat!(
                 "Debug panic! '{}' was never added to the print items in this scenario. This can ",
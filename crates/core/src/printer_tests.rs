@@ -0,0 +1,157 @@
+use std::rc::Rc;
+use super::*;
+
+#[test]
+fn print_errors_when_condition_exceeds_evaluation_budget() {
+    let err = print_flip_flop(PrintBudget {
+        max_condition_evaluations: 50,
+        max_visits_per_node: 1_000,
+    });
+
+    match err {
+        PrintError::ConditionEvaluationsExceeded { condition_name, evaluations } => {
+            assert_eq!(condition_name, "flipFlop");
+            assert_eq!(evaluations, 51);
+        },
+        _ => panic!("Unexpected error: {}", err),
+    }
+}
+
+#[test]
+fn print_errors_when_exceeding_node_visit_budget() {
+    let err = print_flip_flop(PrintBudget {
+        max_condition_evaluations: 10_000,
+        max_visits_per_node: 10,
+    });
+
+    match &err {
+        PrintError::NodeVisitsExceeded { node_visits, most_evaluated_condition_name } => {
+            assert_eq!(*most_evaluated_condition_name, Some("flipFlop"));
+            assert_eq!(err.to_string(), format!(
+                "Printing exceeded its budget after visiting {} print nodes (most re-evaluated condition: 'flipFlop'). This is a bug in the formatter.",
+                node_visits,
+            ));
+        },
+        _ => panic!("Unexpected error: {}", err),
+    }
+}
+
+#[test]
+fn print_pads_alignment_group_members_to_the_same_column() {
+    let text = format(|arena| {
+        let group = AlignmentGroup::new("assignments");
+        let mut items = PrintItems::new(arena);
+        for (i, name) in ["a", "bbbb", "cc"].iter().enumerate() {
+            if i > 0 {
+                items.push_signal(Signal::NewLine);
+            }
+            items.push_str(name);
+            items.push_alignment(&group);
+            items.push_str(" = 1;");
+        }
+        items
+    }, create_options(40)).unwrap();
+
+    assert_eq!(text, "a    = 1;\nbbbb = 1;\ncc   = 1;");
+}
+
+#[test]
+fn print_aligns_alignment_group_members_by_line_column() {
+    let text = format(|arena| {
+        let group = AlignmentGroup::new("comments");
+        let mut items = PrintItems::new(arena);
+        items.push_str("a;");
+        items.push_alignment(&group);
+        items.push_str(" // 1");
+        items.push_signal(Signal::NewLine);
+        items.push_signal(Signal::StartIndent);
+        items.push_str("b;");
+        items.push_alignment(&group);
+        items.push_str(" // 2");
+        items.push_signal(Signal::FinishIndent);
+        items
+    }, create_options(40)).unwrap();
+
+    assert_eq!(text, "a;   // 1\n  b; // 2");
+}
+
+#[test]
+fn print_aligns_alignment_group_members_without_unprinted_members() {
+    let text = format(|arena| {
+        let group = AlignmentGroup::new("assignments");
+        let mut items = PrintItems::new(arena);
+        items.push_str("a");
+        items.push_alignment(&group);
+        items.push_str(" = 1;");
+        items.push_signal(Signal::NewLine);
+        items.push_str("bbb");
+        items.push_alignment(&group);
+        items.push_str(" = 2;");
+        items.push_condition(Condition::new("neverTrue", ConditionProperties {
+            condition: Rc::new(Box::new(|_| Some(false))),
+            true_path: Some({
+                let mut items = PrintItems::new(arena);
+                items.push_signal(Signal::NewLine);
+                items.push_str("ccccc");
+                items.push_alignment(&group);
+                items.push_str(" = 3;");
+                items
+            }),
+            false_path: None,
+        }));
+        items
+    }, create_options(40)).unwrap();
+
+    assert_eq!(text, "a   = 1;\nbbb = 2;");
+}
+
+#[test]
+fn format_equals_compares_printed_text() {
+    assert!(format_equals(get_print_items, create_options(40), "a\n  b").unwrap());
+    assert!(!format_equals(get_print_items, create_options(40), "a\n  c").unwrap());
+    assert!(!format_equals(get_print_items, create_options(40), "a\n  b\n").unwrap());
+    assert!(!format_equals(get_print_items, create_options(40), "a\n").unwrap());
+
+    fn get_print_items<'a>(arena: &'a PrintItemsArena<'a>) -> PrintItems<'a> {
+        let mut items = PrintItems::new(arena);
+        items.push_str("a");
+        items.push_signal(Signal::NewLine);
+        items.push_signal(Signal::StartIndent);
+        items.push_str("b");
+        items.push_signal(Signal::FinishIndent);
+        items
+    }
+}
+
+/// Prints a condition that changes the position of the info it depends on in a way
+/// that flips its own value every time it's re-evaluated.
+fn print_flip_flop(budget: PrintBudget) -> PrintError {
+    let arena = PrintItemsArena::new();
+    let end_info = Info::new("end");
+    let mut items = PrintItems::new(&arena);
+    items.push_condition(Condition::new_with_dependent_infos("flipFlop", ConditionProperties {
+        condition: Rc::new(Box::new(move |context| {
+            let end_info = context.get_resolved_info(&end_info)?;
+            Some(end_info.column_number < 5)
+        })),
+        true_path: Some(arena.items("long text")),
+        false_path: None,
+    }, vec![end_info]));
+    items.push_info(end_info);
+
+    print(items, PrintOptions {
+        budget,
+        ..create_options(40)
+    }).unwrap_err()
+}
+
+fn create_options(max_width: u32) -> PrintOptions {
+    PrintOptions {
+        indent_width: 2,
+        max_width,
+        use_tabs: false,
+        new_line_text: "\n",
+        unicode_width: false,
+        budget: PrintBudget::default(),
+    }
+}
@@ -0,0 +1,454 @@
+use std::collections::BTreeSet;
+use super::string_utils::{get_column_number_of_pos, get_line_number_of_pos};
+use super::unicode_width::get_display_width;
+
+/// A range of the file text related to a diagnostic along with a message that describes it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DiagnosticLabel {
+    /// The byte range of the file text.
+    pub range: (usize, usize),
+    pub message: String,
+}
+
+/// A range of the file text to underline when rendering a diagnostic.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DiagnosticSpan<'a> {
+    /// The byte range of the file text.
+    pub range: (usize, usize),
+    /// Text shown after the underline on the last line of the span.
+    pub label: Option<&'a str>,
+    /// Whether this is the main location of the diagnostic. Primary spans are underlined
+    /// with `~` and provide the line and column of the diagnostic while others use `-`.
+    pub is_primary: bool,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DiagnosticOptions {
+    /// The number of lines to show before and after each span.
+    pub context_lines: usize,
+    /// Whether to color the output with ANSI escape codes.
+    pub use_color: bool,
+}
+
+impl Default for DiagnosticOptions {
+    fn default() -> Self {
+        DiagnosticOptions {
+            context_lines: 1,
+            use_color: false,
+        }
+    }
+}
+
+const TAB_WIDTH: usize = 4;
+/// The number of lines shown at the start of a multi-line span before skipping to its last line.
+const MAX_SPAN_START_LINES: usize = 2;
+/// The maximum number of columns of each line to show.
+const MAX_LINE_WIDTH: usize = 120;
+/// The number of columns shown before the first span when lines are wider than `MAX_LINE_WIDTH`.
+const WIDTH_BEFORE_SPAN: usize = 20;
+
+/// Renders the message along with the lines of the file text the spans are on.
+///
+/// Each line is shown with its line number in a gutter and the spans are underlined
+/// below it. Tabs are expanded and wide characters are accounted for so the underlines
+/// line up with the text.
+pub fn render_diagnostic(message: &str, spans: &[DiagnosticSpan], file_text: &str, options: &DiagnosticOptions) -> String {
+    let colors = Colors { enabled: options.use_color };
+    let mut result = String::new();
+
+    if let Some(span) = spans.iter().find(|s| s.is_primary) {
+        let pos = std::cmp::min(span.range.0, file_text.len());
+        let line_number = get_line_number_of_pos(file_text, pos);
+        let column_number = get_column_number_of_pos(file_text, pos);
+        result.push_str(&format!("Line {}, column {}: ", line_number, column_number));
+    }
+    result.push_str(&colors.paint(message, BOLD));
+
+    if !spans.is_empty() {
+        result.push_str("\n\n");
+        result.push_str(&render_snippet(spans, file_text, options, &colors));
+    }
+
+    result
+}
+
+/// An underline of a span on a single line given in display columns.
+struct Underline<'a> {
+    start_column: usize,
+    end_column: usize,
+    is_primary: bool,
+    label: Option<&'a str>,
+}
+
+struct RenderedLine<'a> {
+    index: usize,
+    text: String,
+    width: usize,
+    underlines: Vec<Underline<'a>>,
+}
+
+fn render_snippet(spans: &[DiagnosticSpan], file_text: &str, options: &DiagnosticOptions, colors: &Colors) -> String {
+    let lines = get_lines(file_text);
+    // don't show the empty line after a trailing newline as context
+    let last_context_line_index = if lines.len() > 1 && file_text.ends_with('\n') { lines.len() - 2 } else { lines.len() - 1 };
+    let span_line_ranges = spans.iter().map(|span| get_span_line_range(span.range, file_text, &lines)).collect::<Vec<_>>();
+
+    let mut line_indexes = BTreeSet::new();
+    for &(start_line, end_line) in span_line_ranges.iter() {
+        if end_line - start_line > MAX_SPAN_START_LINES {
+            line_indexes.extend(start_line..start_line + MAX_SPAN_START_LINES);
+            line_indexes.insert(end_line);
+        } else {
+            line_indexes.extend(start_line..=end_line);
+        }
+        line_indexes.extend(start_line.saturating_sub(options.context_lines)..start_line);
+        line_indexes.extend(end_line + 1..=std::cmp::min(end_line + options.context_lines, last_context_line_index));
+    }
+
+    let rendered_lines = line_indexes.into_iter().map(|line_index| {
+        let (line_start, line_end) = lines[line_index];
+        let line_text = &file_text[line_start..line_end];
+        let (text, columns) = expand_line(line_text);
+        let mut underlines = Vec::new();
+
+        for (span, &(start_line, end_line)) in spans.iter().zip(span_line_ranges.iter()) {
+            if line_index < start_line || line_index > end_line {
+                continue;
+            }
+
+            let (start, end) = clamp_range(span.range, file_text);
+            let start_offset = if line_index == start_line { std::cmp::min(start - line_start, line_text.len()) } else { 0 };
+            let end_offset = if line_index == end_line { std::cmp::min(end.saturating_sub(line_start), line_text.len()) } else { line_text.len() };
+            let start_column = columns[start_offset];
+            let mut end_column = std::cmp::max(columns[std::cmp::max(end_offset, start_offset)], start_column);
+            let label = if line_index == end_line { span.label } else { None };
+
+            if end_column == start_column {
+                // don't underline the empty lines within a multi-line span
+                if start_line != end_line && label.is_none() {
+                    continue;
+                }
+                end_column += 1;
+            }
+
+            underlines.push(Underline { start_column, end_column, is_primary: span.is_primary, label });
+        }
+
+        underlines.sort_by_key(|u| (u.start_column, u.end_column));
+        let width = columns[line_text.len()];
+        RenderedLine { index: line_index, text, width, underlines }
+    }).collect::<Vec<_>>();
+
+    // only show the part of long lines around the spans
+    let max_width = rendered_lines.iter().map(|l| l.width).max().unwrap_or(0);
+    let window_start = if max_width > MAX_LINE_WIDTH {
+        rendered_lines.iter().flat_map(|l| l.underlines.iter()).map(|u| u.start_column).min().unwrap_or(0).saturating_sub(WIDTH_BEFORE_SPAN)
+    } else {
+        0
+    };
+    let window_end = window_start + MAX_LINE_WIDTH;
+    let prefix = if window_start > 0 { "..." } else { "" };
+
+    let gutter_width = rendered_lines.last().map(|l| (l.index + 1).to_string().len()).unwrap_or(1);
+    let empty_gutter = colors.paint(&format!("{} |", " ".repeat(gutter_width)), GUTTER);
+    let mut result_lines = Vec::new();
+    let mut last_index = None;
+
+    for line in rendered_lines.iter() {
+        if let Some(last_index) = last_index {
+            if line.index > last_index + 1 {
+                result_lines.push(format!("  {}", colors.paint("...", GUTTER)));
+            }
+        }
+        last_index = Some(line.index);
+
+        let mut text = String::from(prefix);
+        text.push_str(&get_text_in_columns(&line.text, window_start, window_end));
+        if line.width > window_end {
+            text.push_str("...");
+        }
+        let gutter = colors.paint(&format!("{:>width$} |", line.index + 1, width = gutter_width), GUTTER);
+        result_lines.push(format!("  {} {}", gutter, text).trim_end().to_string());
+
+        for underline in line.underlines.iter() {
+            let start_column = clamp_column(underline.start_column, window_start, window_end) + prefix.len();
+            let end_column = std::cmp::max(clamp_column(underline.end_column, window_start, window_end) + prefix.len(), start_column + 1);
+            let (mark, color) = if underline.is_primary { ("~", PRIMARY) } else { ("-", SECONDARY) };
+            let mut marks = mark.repeat(end_column - start_column);
+            if let Some(label) = underline.label {
+                marks.push(' ');
+                marks.push_str(label);
+            }
+            result_lines.push(format!("  {} {}{}", empty_gutter, " ".repeat(start_column), colors.paint(&marks, color)));
+        }
+    }
+
+    result_lines.join("\n")
+}
+
+/// Gets the byte range of each line not including its newline characters.
+fn get_lines(text: &str) -> Vec<(usize, usize)> {
+    let mut lines = Vec::new();
+    let mut line_start = 0;
+    for line in text.split_inclusive('\n') {
+        let content = line.trim_end_matches('\n').trim_end_matches('\r');
+        lines.push((line_start, line_start + content.len()));
+        line_start += line.len();
+    }
+    if lines.is_empty() || text.ends_with('\n') {
+        lines.push((line_start, line_start));
+    }
+    lines
+}
+
+fn get_line_index(lines: &[(usize, usize)], pos: usize) -> usize {
+    lines.partition_point(|&(line_start, _)| line_start <= pos).saturating_sub(1)
+}
+
+fn get_span_line_range(range: (usize, usize), file_text: &str, lines: &[(usize, usize)]) -> (usize, usize) {
+    let (start, end) = clamp_range(range, file_text);
+    let start_line = get_line_index(lines, start);
+    // a span ending after a newline ends on the previous line
+    let end_line = if end > start { get_line_index(lines, end - 1) } else { start_line };
+    (start_line, end_line)
+}
+
+fn clamp_range(range: (usize, usize), file_text: &str) -> (usize, usize) {
+    let start = std::cmp::min(range.0, file_text.len());
+    let end = std::cmp::max(std::cmp::min(range.1, file_text.len()), start);
+    (start, end)
+}
+
+fn clamp_column(column: usize, window_start: usize, window_end: usize) -> usize {
+    std::cmp::min(column, window_end).saturating_sub(window_start)
+}
+
+/// Expands the tabs of the line to spaces and gets the display column of each byte position.
+fn expand_line(text: &str) -> (String, Vec<usize>) {
+    let mut expanded = String::with_capacity(text.len());
+    let mut columns = vec![0; text.len() + 1];
+    let mut column = 0;
+    for (i, c) in text.char_indices() {
+        for item in columns.iter_mut().skip(i).take(c.len_utf8()) {
+            *item = column;
+        }
+        if c == '\t' {
+            let width = TAB_WIDTH - column % TAB_WIDTH;
+            expanded.push_str(&" ".repeat(width));
+            column += width;
+        } else {
+            expanded.push(c);
+            column += get_display_width(c.encode_utf8(&mut [0; 4]));
+        }
+    }
+    columns[text.len()] = column;
+    (expanded, columns)
+}
+
+/// Gets the characters of the expanded text that are fully within the provided columns.
+fn get_text_in_columns(text: &str, start_column: usize, end_column: usize) -> String {
+    let mut result = String::new();
+    let mut column = 0;
+    for c in text.chars() {
+        let width = get_display_width(c.encode_utf8(&mut [0; 4]));
+        if column >= start_column && column + width <= end_column {
+            result.push(c);
+        }
+        column += width;
+    }
+    result
+}
+
+const BOLD: &str = "1";
+const GUTTER: &str = "1;34";
+const PRIMARY: &str = "1;31";
+const SECONDARY: &str = "1;36";
+
+struct Colors {
+    enabled: bool,
+}
+
+impl Colors {
+    fn paint(&self, text: &str, code: &str) -> String {
+        if self.enabled && !text.is_empty() {
+            format!("\x1b[{}m{}\x1b[0m", code, text)
+        } else {
+            String::from(text)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(spans: Vec<DiagnosticSpan>, file_text: &str) -> String {
+        render_diagnostic("Message.", &spans, file_text, &DiagnosticOptions::default())
+    }
+
+    fn primary(range: (usize, usize)) -> DiagnosticSpan<'static> {
+        DiagnosticSpan { range, label: None, is_primary: true }
+    }
+
+    #[test]
+    fn should_render_message_without_spans() {
+        assert_eq!(render(vec![], "test"), "Message.");
+    }
+
+    #[test]
+    fn should_render_span_with_context_lines() {
+        assert_eq!(
+            render(vec![primary((8, 12))], "a;\nb;\nc = 5;\nd;\ne;\n"),
+            concat!(
+                "Line 3, column 3: Message.\n",
+                "\n",
+                "  2 | b;\n",
+                "  3 | c = 5;\n",
+                "    |   ~~~~\n",
+                "  4 | d;"
+            )
+        );
+    }
+
+    #[test]
+    fn should_not_show_empty_line_after_trailing_newline_as_context() {
+        assert_eq!(
+            render(vec![primary((0, 1))], "a;\n"),
+            concat!(
+                "Line 1, column 1: Message.\n",
+                "\n",
+                "  1 | a;\n",
+                "    | ~"
+            )
+        );
+    }
+
+    #[test]
+    fn should_render_zero_width_span_at_end_of_file() {
+        assert_eq!(
+            render(vec![primary((4, 4))], "a;\nb"),
+            concat!(
+                "Line 2, column 2: Message.\n",
+                "\n",
+                "  1 | a;\n",
+                "  2 | b\n",
+                "    |  ~"
+            )
+        );
+    }
+
+    #[test]
+    fn should_render_multi_line_span() {
+        assert_eq!(
+            render(vec![primary((3, 12))], "a;\nb {\n\n  c;\n}\nd;"),
+            concat!(
+                "Line 2, column 1: Message.\n",
+                "\n",
+                "  1 | a;\n",
+                "  2 | b {\n",
+                "    | ~~~\n",
+                "  3 |\n",
+                "  4 |   c;\n",
+                "    | ~~~~\n",
+                "  5 | }"
+            )
+        );
+    }
+
+    #[test]
+    fn should_skip_middle_lines_of_long_spans() {
+        let file_text = "1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n11\n";
+        assert_eq!(
+            render(vec![primary((2, 17))], file_text),
+            concat!(
+                "Line 2, column 1: Message.\n",
+                "\n",
+                "   1 | 1\n",
+                "   2 | 2\n",
+                "     | ~\n",
+                "   3 | 3\n",
+                "     | ~\n",
+                "  ...\n",
+                "   9 | 9\n",
+                "     | ~\n",
+                "  10 | 10"
+            )
+        );
+    }
+
+    #[test]
+    fn should_render_multiple_spans_with_labels() {
+        let spans = vec![
+            DiagnosticSpan { range: (10, 11), label: Some("expected `)`"), is_primary: true },
+            DiagnosticSpan { range: (3, 4), label: Some("to close this"), is_primary: false },
+        ];
+        assert_eq!(
+            render(spans, "a;\n(b, c\n\nd;"),
+            concat!(
+                "Line 4, column 1: Message.\n",
+                "\n",
+                "  1 | a;\n",
+                "  2 | (b, c\n",
+                "    | - to close this\n",
+                "  3 |\n",
+                "  4 | d;\n",
+                "    | ~ expected `)`"
+            )
+        );
+    }
+
+    #[test]
+    fn should_expand_tabs() {
+        assert_eq!(
+            render(vec![primary((4, 5))], "\tab\tc;"),
+            concat!(
+                "Line 1, column 5: Message.\n",
+                "\n",
+                "  1 |     ab  c;\n",
+                "    |         ~"
+            )
+        );
+    }
+
+    #[test]
+    fn should_handle_wide_characters() {
+        assert_eq!(
+            render(vec![primary((6, 12))], "const 日本 = 5;\nb;"),
+            concat!(
+                "Line 1, column 7: Message.\n",
+                "\n",
+                "  1 | const 日本 = 5;\n",
+                "    |       ~~~~\n",
+                "  2 | b;"
+            )
+        );
+    }
+
+    #[test]
+    fn should_only_show_part_of_long_lines() {
+        let file_text = format!("{}error{}", "a".repeat(200), "b".repeat(200));
+        assert_eq!(
+            render(vec![primary((200, 205))], &file_text),
+            format!(
+                "Line 1, column 201: Message.\n\n  1 | ...{}error{}...\n    | {}~~~~~",
+                "a".repeat(20),
+                "b".repeat(95),
+                " ".repeat(23),
+            )
+        );
+    }
+
+    #[test]
+    fn should_color_output() {
+        let options = DiagnosticOptions { context_lines: 0, use_color: true };
+        assert_eq!(
+            render_diagnostic("Message.", &[primary((0, 1))], "a;", &options),
+            concat!(
+                "Line 1, column 1: \u{1b}[1mMessage.\u{1b}[0m\n",
+                "\n",
+                "  \u{1b}[1;34m1 |\u{1b}[0m a;\n",
+                "  \u{1b}[1;34m  |\u{1b}[0m \u{1b}[1;31m~\u{1b}[0m"
+            )
+        );
+    }
+}
@@ -1 +1,4 @@
 pub mod string_utils;
+pub mod unicode_width;
+pub mod text_edits;
+pub mod diagnostics;
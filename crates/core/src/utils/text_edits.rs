@@ -0,0 +1,280 @@
+/// A replacement of a byte range of the original text.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextEdit {
+    /// The byte range of the original text to replace.
+    pub range: (usize, usize),
+    pub new_text: String,
+}
+
+/// The maximum number of insertions and deletions searched for when diffing before
+/// giving up and replacing the entire changed region.
+const MAX_EDIT_DISTANCE: usize = 2_000;
+
+/// Gets a minimal list of edits that turn the original text into the formatted text.
+///
+/// The texts are first compared by line and then the changed lines are compared by token
+/// (words, whitespace, and punctuation) so only the changed parts of a line are replaced.
+/// The edits are ordered by their range in the original text and don't overlap.
+pub fn get_text_edits(original: &str, formatted: &str) -> Vec<TextEdit> {
+    let original_lines = split_with_offsets(original, get_lines);
+    let formatted_lines = split_with_offsets(formatted, get_lines);
+    let mut edits = Vec::new();
+
+    for hunk in get_diff_hunks(&original_lines.texts, &formatted_lines.texts) {
+        let original_range = original_lines.get_range(hunk.original_start, hunk.original_end);
+        let formatted_range = formatted_lines.get_range(hunk.new_start, hunk.new_end);
+        let original_text = &original[original_range.0..original_range.1];
+        let formatted_text = &formatted[formatted_range.0..formatted_range.1];
+
+        if original_text.is_empty() || formatted_text.is_empty() {
+            edits.push(TextEdit { range: original_range, new_text: String::from(formatted_text) });
+            continue;
+        }
+
+        // refine the changed lines by token
+        let original_tokens = split_with_offsets(original_text, get_tokens);
+        let formatted_tokens = split_with_offsets(formatted_text, get_tokens);
+        for token_hunk in get_diff_hunks(&original_tokens.texts, &formatted_tokens.texts) {
+            let (start, end) = original_tokens.get_range(token_hunk.original_start, token_hunk.original_end);
+            let (new_start, new_end) = formatted_tokens.get_range(token_hunk.new_start, token_hunk.new_end);
+            edits.push(TextEdit {
+                range: (original_range.0 + start, original_range.0 + end),
+                new_text: String::from(&formatted_text[new_start..new_end]),
+            });
+        }
+    }
+
+    edits
+}
+
+/// Pieces of a text along with the byte position each one starts at.
+struct TextPieces<'a> {
+    texts: Vec<&'a str>,
+    starts: Vec<usize>,
+    text_len: usize,
+}
+
+impl<'a> TextPieces<'a> {
+    fn get_range(&self, start_index: usize, end_index: usize) -> (usize, usize) {
+        let get_pos = |index: usize| self.starts.get(index).copied().unwrap_or(self.text_len);
+        (get_pos(start_index), get_pos(end_index))
+    }
+}
+
+fn split_with_offsets<'a>(text: &'a str, split: impl Fn(&'a str) -> Vec<&'a str>) -> TextPieces<'a> {
+    let texts = split(text);
+    let mut starts = Vec::with_capacity(texts.len());
+    let mut pos = 0;
+    for piece in texts.iter() {
+        starts.push(pos);
+        pos += piece.len();
+    }
+    TextPieces { texts, starts, text_len: text.len() }
+}
+
+/// Gets the lines of the text including their newline.
+fn get_lines(text: &str) -> Vec<&str> {
+    text.split_inclusive('\n').collect()
+}
+
+/// Gets the words, runs of whitespace, newlines, and individual punctuation characters of the text.
+fn get_tokens(text: &str) -> Vec<&str> {
+    #[derive(PartialEq)]
+    enum TokenKind { Word, Whitespace, Other }
+
+    fn get_kind(c: char) -> TokenKind {
+        if c.is_alphanumeric() || c == '_' {
+            TokenKind::Word
+        } else if c.is_whitespace() && c != '\n' {
+            TokenKind::Whitespace
+        } else {
+            TokenKind::Other
+        }
+    }
+
+    let mut tokens = Vec::new();
+    let mut start = 0;
+    let mut last_kind = None;
+    for (i, c) in text.char_indices() {
+        let kind = get_kind(c);
+        if i > 0 && (kind == TokenKind::Other || last_kind.as_ref() != Some(&kind)) {
+            tokens.push(&text[start..i]);
+            start = i;
+        }
+        last_kind = Some(kind);
+    }
+    if start < text.len() {
+        tokens.push(&text[start..]);
+    }
+    tokens
+}
+
+/// A region of differing items given as index ranges of the original and new items.
+#[derive(Debug, PartialEq)]
+struct DiffHunk {
+    original_start: usize,
+    original_end: usize,
+    new_start: usize,
+    new_end: usize,
+}
+
+fn get_diff_hunks<T: PartialEq>(original: &[T], new: &[T]) -> Vec<DiffHunk> {
+    let prefix_len = original.iter().zip(new.iter()).take_while(|(a, b)| a == b).count();
+    let suffix_len = original[prefix_len..].iter().rev()
+        .zip(new[prefix_len..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let original = &original[prefix_len..original.len() - suffix_len];
+    let new = &new[prefix_len..new.len() - suffix_len];
+
+    if original.is_empty() && new.is_empty() {
+        return Vec::new();
+    }
+
+    let mut hunks = Vec::new();
+    let mut original_index = 0;
+    let mut new_index = 0;
+    let matches = get_matching_indexes(original, new).unwrap_or_default();
+    for (original_match, new_match) in matches.into_iter().chain(std::iter::once((original.len(), new.len()))) {
+        if original_match > original_index || new_match > new_index {
+            hunks.push(DiffHunk {
+                original_start: prefix_len + original_index,
+                original_end: prefix_len + original_match,
+                new_start: prefix_len + new_index,
+                new_end: prefix_len + new_match,
+            });
+        }
+        original_index = original_match + 1;
+        new_index = new_match + 1;
+    }
+    hunks
+}
+
+/// Gets the indexes of the items in the longest common subsequence using Myers' algorithm.
+///
+/// Returns `None` when the number of insertions and deletions exceeds `MAX_EDIT_DISTANCE`.
+fn get_matching_indexes<T: PartialEq>(original: &[T], new: &[T]) -> Option<Vec<(usize, usize)>> {
+    let n = original.len() as isize;
+    let m = new.len() as isize;
+    let max = std::cmp::min((n + m) as usize, MAX_EDIT_DISTANCE) as isize;
+    let offset = max + 1;
+    let mut v = vec![0isize; (2 * offset + 1) as usize];
+    // the furthest reaching x of each diagonal before each step
+    let mut trace = Vec::new();
+    let mut final_d = None;
+
+    'outer: for d in 0..=max {
+        trace.push(v[(offset - d - 1) as usize..=(offset + d + 1) as usize].to_vec());
+        for k in (-d..=d).step_by(2) {
+            let index = (offset + k) as usize;
+            let mut x = if k == -d || (k != d && v[index - 1] < v[index + 1]) { v[index + 1] } else { v[index - 1] + 1 };
+            let mut y = x - k;
+            while x < n && y < m && original[x as usize] == new[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[index] = x;
+            if x >= n && y >= m {
+                final_d = Some(d);
+                break 'outer;
+            }
+        }
+    }
+
+    let final_d = final_d?;
+    let mut matches = Vec::new();
+    let mut x = n;
+    let mut y = m;
+    for d in (0..=final_d).rev() {
+        let v = &trace[d as usize];
+        let get_v = |k: isize| v[(k + d + 1) as usize];
+        let k = x - y;
+        let prev_k = if k == -d || (k != d && get_v(k - 1) < get_v(k + 1)) { k + 1 } else { k - 1 };
+        let prev_x = get_v(prev_k);
+        let prev_y = prev_x - prev_k;
+        while x > prev_x && y > prev_y {
+            x -= 1;
+            y -= 1;
+            matches.push((x as usize, y as usize));
+        }
+        x = prev_x;
+        y = prev_y;
+    }
+
+    matches.reverse();
+    Some(matches)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_edits(text: &str, edits: &[TextEdit]) -> String {
+        let mut result = String::new();
+        let mut last_end = 0;
+        for edit in edits.iter() {
+            result.push_str(&text[last_end..edit.range.0]);
+            result.push_str(&edit.new_text);
+            last_end = edit.range.1;
+        }
+        result.push_str(&text[last_end..]);
+        result
+    }
+
+    fn assert_edits(original: &str, formatted: &str, expected: Vec<((usize, usize), &str)>) {
+        let edits = get_text_edits(original, formatted);
+        assert_eq!(apply_edits(original, &edits), formatted);
+        assert_eq!(
+            edits,
+            expected.into_iter().map(|(range, new_text)| TextEdit { range, new_text: String::from(new_text) }).collect::<Vec<_>>(),
+        );
+    }
+
+    #[test]
+    fn should_not_have_edits_for_same_text() {
+        assert_edits("a\nb\n", "a\nb\n", vec![]);
+        assert_edits("", "", vec![]);
+    }
+
+    #[test]
+    fn should_replace_tokens_in_changed_line() {
+        assert_edits("a\nconst t=4;\nb\n", "a\nconst t = 4;\nb\n", vec![((9, 9), " "), ((10, 10), " ")]);
+    }
+
+    #[test]
+    fn should_insert_and_remove_lines() {
+        assert_edits("a\nb\nc\n", "a\nc\nd\n", vec![((2, 4), ""), ((6, 6), "d\n")]);
+        assert_edits("", "a\n", vec![((0, 0), "a\n")]);
+        assert_edits("a\n", "", vec![((0, 2), "")]);
+    }
+
+    #[test]
+    fn should_change_indentation() {
+        assert_edits("if (a) {\n    b;\n}\n", "if (a) {\n  b;\n}\n", vec![((9, 13), "  ")]);
+    }
+
+    #[test]
+    fn should_handle_multi_byte_characters() {
+        assert_edits("é=ü\n", "é = ü\n", vec![((2, 2), " "), ((3, 3), " ")]);
+    }
+
+    #[test]
+    fn should_handle_different_newlines() {
+        assert_edits("a\r\nb\r\n", "a\nb\n", vec![((1, 2), ""), ((4, 5), "")]);
+    }
+
+    #[test]
+    fn should_get_edits_for_many_changes() {
+        let original = (0..100).map(|i| format!("let a{}=[{},{}];\n", i, i, i + 1)).collect::<String>();
+        let formatted = (0..100).filter(|i| i % 3 != 0).map(|i| format!("let a{} = [{}, {}];\n", i, i, i + 1)).collect::<String>();
+        assert_eq!(apply_edits(&original, &get_text_edits(&original, &formatted)), formatted);
+    }
+
+    #[test]
+    fn should_get_diff_hunks() {
+        assert_eq!(get_diff_hunks(&["a", "b", "c", "d"], &["a", "x", "c", "y", "d"]), vec![
+            DiffHunk { original_start: 1, original_end: 2, new_start: 1, new_end: 2 },
+            DiffHunk { original_start: 3, original_end: 3, new_start: 3, new_end: 4 },
+        ]);
+    }
+}
@@ -0,0 +1,226 @@
+/// Gets the number of columns the text takes up when displayed in a monospace font.
+///
+/// East Asian wide and fullwidth characters (ex. CJK characters) and emoji take up two columns, while
+/// characters that extend the previous grapheme cluster (ex. combining marks, zero width joiner sequences,
+/// variation selectors, and skin tone modifiers) don't take up any columns.
+pub fn get_display_width(text: &str) -> usize {
+    if text.is_ascii() {
+        return text.len();
+    }
+
+    let mut width = 0;
+    let mut last_width = 0;
+    let mut is_after_zero_width_joiner = false;
+    let mut is_after_regional_indicator = false;
+
+    for c in text.chars() {
+        if is_after_zero_width_joiner {
+            // the character is joined to the previous grapheme cluster (ex. family emoji)
+            is_after_zero_width_joiner = false;
+            continue;
+        }
+
+        match c {
+            '\u{200D}' => is_after_zero_width_joiner = true,
+            '\u{FE0F}' => {
+                // emoji presentation selector makes the previous character display as a wide emoji
+                if last_width == 1 {
+                    width += 1;
+                    last_width = 2;
+                }
+            },
+            '\u{1F1E6}'..='\u{1F1FF}' => {
+                // two regional indicators form a single flag
+                if is_after_regional_indicator {
+                    is_after_regional_indicator = false;
+                } else {
+                    is_after_regional_indicator = true;
+                    width += 2;
+                    last_width = 2;
+                }
+                continue;
+            },
+            c if is_zero_width(c) => {},
+            c => {
+                last_width = if is_wide(c) { 2 } else { 1 };
+                width += last_width;
+            },
+        }
+
+        is_after_regional_indicator = false;
+    }
+
+    width
+}
+
+fn is_zero_width(c: char) -> bool {
+    // ascii control characters (ex. tabs) take up a column like they do for ascii only text
+    in_ranges(c, &[
+        ('\u{0080}', '\u{009F}'),
+        ('\u{00AD}', '\u{00AD}'),
+        ('\u{0300}', '\u{036F}'),
+        ('\u{0483}', '\u{0489}'),
+        ('\u{0591}', '\u{05BD}'),
+        ('\u{05BF}', '\u{05C7}'),
+        ('\u{0610}', '\u{061A}'),
+        ('\u{064B}', '\u{065F}'),
+        ('\u{0670}', '\u{0670}'),
+        ('\u{06D6}', '\u{06ED}'),
+        ('\u{0900}', '\u{0903}'),
+        ('\u{093A}', '\u{094F}'),
+        ('\u{0951}', '\u{0957}'),
+        ('\u{0962}', '\u{0963}'),
+        ('\u{0E31}', '\u{0E31}'),
+        ('\u{0E34}', '\u{0E3A}'),
+        ('\u{0E47}', '\u{0E4E}'),
+        ('\u{1160}', '\u{11FF}'),
+        ('\u{1AB0}', '\u{1AFF}'),
+        ('\u{1DC0}', '\u{1DFF}'),
+        ('\u{200B}', '\u{200F}'),
+        ('\u{2028}', '\u{202E}'),
+        ('\u{2060}', '\u{2064}'),
+        ('\u{20D0}', '\u{20FF}'),
+        ('\u{302A}', '\u{302F}'),
+        ('\u{3099}', '\u{309A}'),
+        ('\u{FE00}', '\u{FE0F}'),
+        ('\u{FE20}', '\u{FE2F}'),
+        ('\u{FEFF}', '\u{FEFF}'),
+        ('\u{1F3FB}', '\u{1F3FF}'),
+        ('\u{E0000}', '\u{E0FFF}'),
+    ])
+}
+
+fn is_wide(c: char) -> bool {
+    in_ranges(c, &[
+        ('\u{1100}', '\u{115F}'),
+        ('\u{231A}', '\u{231B}'),
+        ('\u{2329}', '\u{232A}'),
+        ('\u{23E9}', '\u{23EC}'),
+        ('\u{23F0}', '\u{23F0}'),
+        ('\u{23F3}', '\u{23F3}'),
+        ('\u{25FD}', '\u{25FE}'),
+        ('\u{2614}', '\u{2615}'),
+        ('\u{2648}', '\u{2653}'),
+        ('\u{267F}', '\u{267F}'),
+        ('\u{2693}', '\u{2693}'),
+        ('\u{26A1}', '\u{26A1}'),
+        ('\u{26AA}', '\u{26AB}'),
+        ('\u{26BD}', '\u{26BE}'),
+        ('\u{26C4}', '\u{26C5}'),
+        ('\u{26CE}', '\u{26CE}'),
+        ('\u{26D4}', '\u{26D4}'),
+        ('\u{26EA}', '\u{26EA}'),
+        ('\u{26F2}', '\u{26F3}'),
+        ('\u{26F5}', '\u{26F5}'),
+        ('\u{26FA}', '\u{26FA}'),
+        ('\u{26FD}', '\u{26FD}'),
+        ('\u{2705}', '\u{2705}'),
+        ('\u{270A}', '\u{270B}'),
+        ('\u{2728}', '\u{2728}'),
+        ('\u{274C}', '\u{274C}'),
+        ('\u{274E}', '\u{274E}'),
+        ('\u{2753}', '\u{2755}'),
+        ('\u{2757}', '\u{2757}'),
+        ('\u{2795}', '\u{2797}'),
+        ('\u{27B0}', '\u{27B0}'),
+        ('\u{27BF}', '\u{27BF}'),
+        ('\u{2B1B}', '\u{2B1C}'),
+        ('\u{2B50}', '\u{2B50}'),
+        ('\u{2B55}', '\u{2B55}'),
+        ('\u{2E80}', '\u{303E}'),
+        ('\u{3041}', '\u{33FF}'),
+        ('\u{3400}', '\u{4DBF}'),
+        ('\u{4E00}', '\u{9FFF}'),
+        ('\u{A000}', '\u{A4CF}'),
+        ('\u{A960}', '\u{A97F}'),
+        ('\u{AC00}', '\u{D7A3}'),
+        ('\u{F900}', '\u{FAFF}'),
+        ('\u{FE10}', '\u{FE19}'),
+        ('\u{FE30}', '\u{FE6F}'),
+        ('\u{FF00}', '\u{FF60}'),
+        ('\u{FFE0}', '\u{FFE6}'),
+        ('\u{16FE0}', '\u{18AFF}'),
+        ('\u{1B000}', '\u{1B2FF}'),
+        ('\u{1F004}', '\u{1F004}'),
+        ('\u{1F0CF}', '\u{1F0CF}'),
+        ('\u{1F18E}', '\u{1F18E}'),
+        ('\u{1F191}', '\u{1F19A}'),
+        ('\u{1F200}', '\u{1F2FF}'),
+        ('\u{1F300}', '\u{1F320}'),
+        ('\u{1F32D}', '\u{1F335}'),
+        ('\u{1F337}', '\u{1F37C}'),
+        ('\u{1F37E}', '\u{1F393}'),
+        ('\u{1F3A0}', '\u{1F3CA}'),
+        ('\u{1F3CF}', '\u{1F3D3}'),
+        ('\u{1F3E0}', '\u{1F3F0}'),
+        ('\u{1F3F4}', '\u{1F3F4}'),
+        ('\u{1F3F8}', '\u{1F43E}'),
+        ('\u{1F440}', '\u{1F440}'),
+        ('\u{1F442}', '\u{1F4FC}'),
+        ('\u{1F4FF}', '\u{1F53D}'),
+        ('\u{1F54B}', '\u{1F54E}'),
+        ('\u{1F550}', '\u{1F567}'),
+        ('\u{1F57A}', '\u{1F57A}'),
+        ('\u{1F595}', '\u{1F596}'),
+        ('\u{1F5A4}', '\u{1F5A4}'),
+        ('\u{1F5FB}', '\u{1F64F}'),
+        ('\u{1F680}', '\u{1F6C5}'),
+        ('\u{1F6CC}', '\u{1F6CC}'),
+        ('\u{1F6D0}', '\u{1F6D2}'),
+        ('\u{1F6D5}', '\u{1F6D7}'),
+        ('\u{1F6EB}', '\u{1F6EC}'),
+        ('\u{1F6F4}', '\u{1F6FC}'),
+        ('\u{1F7E0}', '\u{1F7EB}'),
+        ('\u{1F90C}', '\u{1F93A}'),
+        ('\u{1F93C}', '\u{1F945}'),
+        ('\u{1F947}', '\u{1F9FF}'),
+        ('\u{1FA70}', '\u{1FAFF}'),
+        ('\u{20000}', '\u{2FFFD}'),
+        ('\u{30000}', '\u{3FFFD}'),
+    ])
+}
+
+fn in_ranges(c: char, ranges: &[(char, char)]) -> bool {
+    ranges.binary_search_by(|(start, end)| {
+        if c < *start {
+            std::cmp::Ordering::Greater
+        } else if c > *end {
+            std::cmp::Ordering::Less
+        } else {
+            std::cmp::Ordering::Equal
+        }
+    }).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_get_width_of_ascii() {
+        assert_eq!(get_display_width("testing"), 7);
+    }
+
+    #[test]
+    fn it_should_get_width_of_wide_characters() {
+        assert_eq!(get_display_width("你好"), 4);
+        assert_eq!(get_display_width("a한b"), 4);
+        assert_eq!(get_display_width("ｆｕｌｌ"), 8);
+    }
+
+    #[test]
+    fn it_should_get_width_of_grapheme_clusters() {
+        assert_eq!(get_display_width("e\u{0301}"), 1); // combining accent
+        assert_eq!(get_display_width("👍🏽"), 2); // skin tone modifier
+        assert_eq!(get_display_width("👨‍👩‍👧"), 2); // zero width joiner sequence
+        assert_eq!(get_display_width("🇨🇦🇯🇵"), 4); // flags
+        assert_eq!(get_display_width("❤\u{FE0F}"), 2); // emoji presentation
+        assert_eq!(get_display_width("é"), 1);
+    }
+
+    #[test]
+    fn it_should_get_width_of_tabs_the_same_as_ascii_text() {
+        assert_eq!(get_display_width("\t"), 1);
+        assert_eq!(get_display_width("\t你"), 3);
+    }
+}
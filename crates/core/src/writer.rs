@@ -4,7 +4,7 @@ use super::collections::{GraphNode, GraphNodeIterator};
 use super::print_items::WriterInfo;
 use std::rc::Rc;
 
-pub struct WriterState {
+pub struct WriterState<'a> {
     current_line_column: u32,
     current_line_number: u32,
     last_line_indent_level: u8,
@@ -13,10 +13,10 @@ pub struct WriterState {
     indent_queue_count: u8,
     last_was_not_trailing_space: bool,
     ignore_indent_count: u8,
-    items: Option<Rc<GraphNode<WriteItem>>>,
+    items: Option<Rc<GraphNode<WriteItem<'a>>>>,
 }
 
-impl WriterState {
+impl<'a> WriterState<'a> {
     pub fn get_writer_info(&self, indent_width: u8) -> WriterInfo {
         WriterInfo {
             line_number: self.current_line_number,
@@ -28,8 +28,8 @@ impl WriterState {
     }
 }
 
-impl Clone for WriterState {
-    fn clone(&self) -> WriterState {
+impl<'a> Clone for WriterState<'a> {
+    fn clone(&self) -> WriterState<'a> {
         WriterState {
             current_line_column: self.current_line_column,
             current_line_number: self.current_line_number,
@@ -46,17 +46,20 @@ impl Clone for WriterState {
 
 pub struct WriterOptions {
     pub indent_width: u8,
+    pub unicode_width: bool,
 }
 
-pub struct Writer {
-    state: WriterState,
+pub struct Writer<'a> {
+    state: WriterState<'a>,
     indent_width: u8,
+    unicode_width: bool,
 }
 
-impl Writer {
-    pub fn new(options: WriterOptions) -> Writer {
+impl<'a> Writer<'a> {
+    pub fn new(options: WriterOptions) -> Writer<'a> {
         Writer {
             indent_width: options.indent_width,
+            unicode_width: options.unicode_width,
             state: WriterState {
                 current_line_column: 0,
                 current_line_number: 0,
@@ -71,11 +74,11 @@ impl Writer {
         }
     }
 
-    pub fn get_state(&self) -> WriterState {
+    pub fn get_state(&self) -> WriterState<'a> {
         self.state.clone()
     }
 
-    pub fn set_state(&mut self, state: WriterState) {
+    pub fn set_state(&mut self, state: WriterState<'a>) {
         self.state = state;
     }
 
@@ -167,6 +170,11 @@ impl Writer {
         self.state.current_line_number
     }
 
+    #[inline]
+    pub fn get_text_width(&self, text: &StringContainer) -> u32 {
+        text.get_width(self.unicode_width)
+    }
+
     pub fn new_line(&mut self) {
         if self.state.last_was_not_trailing_space {
             self.pop_item();
@@ -198,9 +206,9 @@ impl Writer {
         self.push_item(WriteItem::Space);
     }
 
-    pub fn write(&mut self, text: Rc<StringContainer>) {
+    pub fn write(&mut self, text: &'a StringContainer<'a>) {
         self.handle_first_column();
-        self.state.current_line_column += text.char_count;
+        self.state.current_line_column += self.get_text_width(text);
         self.push_item(WriteItem::String(text));
     }
 
@@ -224,7 +232,7 @@ impl Writer {
         }
     }
 
-    fn push_item(&mut self, item: WriteItem) {
+    fn push_item(&mut self, item: WriteItem<'a>) {
         let previous = std::mem::replace(&mut self.state.items, None);
         self.state.items = Some(Rc::new(GraphNode::new(item, previous)));
 
@@ -241,7 +249,7 @@ impl Writer {
         }
     }
 
-    pub fn get_items(self) -> impl Iterator<Item = WriteItem> {
+    pub fn get_items(self) -> impl Iterator<Item = WriteItem<'a>> {
         match self.state.items {
             Some(items) => Rc::try_unwrap(items).ok().expect("Expected to unwrap from RC at this point.").into_iter().collect::<Vec<_>>().into_iter().rev(),
             None => GraphNodeIterator::empty().collect::<Vec<_>>().into_iter().rev(),
@@ -260,7 +268,7 @@ impl Writer {
     }
 
     #[cfg(debug_assertions)]
-    fn get_items_cloned(&self) -> Vec<WriteItem> {
+    fn get_items_cloned(&self) -> Vec<WriteItem<'a>> {
         let mut items = Vec::new();
         let mut current_item = self.state.items.clone();
         while let Some(item) = current_item {
@@ -1,6 +1,5 @@
-use std::rc::Rc;
 use super::writer::*;
-use super::print_write_items;
+use super::{print_write_items, print_write_items_equals, print_write_items_to_io};
 use super::StringContainer;
 use super::PrintWriteItemsOptions;
 
@@ -52,19 +51,63 @@ fn markexpectnewline_writesnewline() {
     assert_writer_equal(writer, "1\n2");
 }
 
+#[test]
+fn write_wide_text_uses_display_width_when_unicode_width() {
+    let text = "你好";
+    let mut writer = create_writer();
+    write_text(&mut writer, text);
+    assert_eq!(writer.get_line_column(), 2);
+
+    let mut writer = Writer::new(WriterOptions { indent_width: 2, unicode_width: true });
+    write_text(&mut writer, text);
+    assert_eq!(writer.get_line_column(), 4);
+}
+
+#[test]
+fn print_write_items_to_io_writes() {
+    let mut writer = create_writer();
+    write_text(&mut writer, "1");
+    writer.new_line();
+    writer.single_indent();
+    write_text(&mut writer, "2");
+    let mut bytes = Vec::new();
+    print_write_items_to_io(writer.get_items(), create_options(), &mut bytes).unwrap();
+    assert_eq!(String::from_utf8(bytes).unwrap(), "1\n  2");
+}
+
+#[test]
+fn print_write_items_equals_compares_text() {
+    assert!(get_writer_equals("test", "test"));
+    assert!(!get_writer_equals("test", "tes"));
+    assert!(!get_writer_equals("test", "testing"));
+    assert!(!get_writer_equals("test", "tEst"));
+    assert!(!get_writer_equals("test", ""));
+}
+
+fn get_writer_equals(writer_text: &'static str, text: &str) -> bool {
+    let mut writer = create_writer();
+    write_text(&mut writer, writer_text);
+    print_write_items_equals(writer.get_items(), create_options(), text)
+}
+
 fn assert_writer_equal(writer: Writer, text: &str) {
-    let result = print_write_items(writer.get_items(), PrintWriteItemsOptions {
+    let result = print_write_items(writer.get_items(), create_options());
+    assert_eq!(result, String::from(text));
+}
+
+fn create_options() -> PrintWriteItemsOptions {
+    PrintWriteItemsOptions {
         indent_width: 2,
         use_tabs: false,
         new_line_text: "\n",
-    });
-    assert_eq!(result, String::from(text));
+    }
 }
 
-fn write_text(writer: &mut Writer, text: &'static str) {
-    writer.write(Rc::new(StringContainer::new(String::from(text))));
+fn write_text(writer: &mut Writer<'static>, text: &'static str) {
+    // leaked since the writer only references the strings, which is fine for a test
+    writer.write(Box::leak(Box::new(StringContainer::new(text))));
 }
 
-fn create_writer() -> Writer {
-    Writer::new(WriterOptions { indent_width: 2 })
+fn create_writer() -> Writer<'static> {
+    Writer::new(WriterOptions { indent_width: 2, unicode_width: false })
 }
@@ -89,11 +89,14 @@ fn it_formats_as_multi_line_when_items_exceed_print_width() {
 }
 
 fn do_test(expr: &ArrayLiteralExpression, expected_text: &str) {
-    let print_items = parse_node(Node::ArrayLiteralExpression(expr));
+    let arena = PrintItemsArena::new();
+    let print_items = parse_node(&arena, Node::ArrayLiteralExpression(expr));
     let write_items = dprint_core::get_write_items(&print_items, GetWriteItemsOptions {
         indent_width: 2,
         max_width: 40,
-    });
+        unicode_width: false,
+        budget: PrintBudget::default(),
+    }).unwrap();
     let result = print_write_items(write_items, PrintWriteItemsOptions {
         use_tabs: false,
         new_line_text: "\n",
@@ -104,17 +107,17 @@ fn do_test(expr: &ArrayLiteralExpression, expected_text: &str) {
 
 // node parsing functions
 
-fn parse_node(node: Node) -> PrintItems {
+fn parse_node<'a>(arena: &'a PrintItemsArena<'a>, node: Node) -> PrintItems<'a> {
     // in a real implementation this function would deal with surrounding comments
 
     match node {
-        Node::ArrayLiteralExpression(expr) => parse_array_literal_expression(&expr),
-        Node::ArrayElement(array_element) => parse_array_element(&array_element),
+        Node::ArrayLiteralExpression(expr) => parse_array_literal_expression(arena, &expr),
+        Node::ArrayElement(array_element) => parse_array_element(arena, &array_element),
     }
 }
 
-fn parse_array_literal_expression(expr: &ArrayLiteralExpression) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_array_literal_expression<'a>(arena: &'a PrintItemsArena<'a>, expr: &ArrayLiteralExpression) -> PrintItems<'a> {
+    let mut items = PrintItems::new(arena);
     let start_info = Info::new("start");
     let end_info = Info::new("end");
     let is_multiple_lines = create_is_multiple_lines_resolver(
@@ -130,21 +133,21 @@ fn parse_array_literal_expression(expr: &ArrayLiteralExpression) -> PrintItems {
     items.push_condition(conditions::if_true(
         "arrayStartNewLine",
         is_multiple_lines.clone(),
-        Signal::NewLine.into()
+        arena.items(Signal::NewLine)
     ));
 
-    let parsed_elements = parse_elements(&expr.elements, &is_multiple_lines).into_rc_path();
+    let parsed_elements = parse_elements(arena, &expr.elements, &is_multiple_lines).into_rc_path();
     items.push_condition(conditions::if_true_or(
         "indentIfMultipleLines",
         is_multiple_lines.clone(),
-        parser_helpers::with_indent(parsed_elements.clone().into()),
-        parsed_elements.into(),
+        parser_helpers::with_indent(arena.items(parsed_elements)),
+        arena.items(parsed_elements),
     ));
 
     items.push_condition(conditions::if_true(
         "arrayEndNewLine",
         is_multiple_lines,
-        Signal::NewLine.into()
+        arena.items(Signal::NewLine)
     ));
     items.push_str("]");
 
@@ -152,23 +155,24 @@ fn parse_array_literal_expression(expr: &ArrayLiteralExpression) -> PrintItems {
 
     return items;
 
-    fn parse_elements(
+    fn parse_elements<'a>(
+        arena: &'a PrintItemsArena<'a>,
         elements: &Vec<ArrayElement>,
         is_multiple_lines: &(impl Fn(&mut ConditionResolverContext) -> Option<bool> + Clone + 'static)
-    ) -> PrintItems {
-        let mut items = PrintItems::new();
+    ) -> PrintItems<'a> {
+        let mut items = PrintItems::new(arena);
         let elements_len = elements.len();
 
         for (i, elem) in elements.iter().enumerate() {
-            items.extend(parse_node(Node::ArrayElement(elem)));
+            items.extend(parse_node(arena, Node::ArrayElement(elem)));
 
             if i < elements_len - 1 {
                 items.push_str(",");
                 items.push_condition(conditions::if_true_or(
                     "afterCommaSeparator",
                     is_multiple_lines.clone(),
-                    Signal::NewLine.into(),
-                    Signal::SpaceOrNewLine.into()
+                    arena.items(Signal::NewLine),
+                    arena.items(Signal::SpaceOrNewLine)
                 ));
             }
         }
@@ -177,8 +181,8 @@ fn parse_array_literal_expression(expr: &ArrayLiteralExpression) -> PrintItems {
     }
 }
 
-fn parse_array_element(element: &ArrayElement) -> PrintItems {
-    (&element.text).into()
+fn parse_array_element<'a>(arena: &'a PrintItemsArena<'a>, element: &ArrayElement) -> PrintItems<'a> {
+    arena.items(&element.text)
 }
 
 // helper functions
@@ -1,8 +1,45 @@
-use dprint_core::configuration::ConfigurationDiagnostic;
+use std::collections::HashMap;
+use dprint_core::configuration::{ConfigOption, ConfigOptionKind, ConfigurationDiagnostic, get_config_option_declaration_errors};
 
 /// Checks for diagnostics and panics if it finds any.
 pub fn ensure_no_diagnostics(diagnostics: &Vec<ConfigurationDiagnostic>) {
     for diagnostic in diagnostics {
         panic!("Diagnostic error for '{}': {}", diagnostic.property_name, diagnostic.message);
     }
-}
\ No newline at end of file
+}
+
+/// Checks the declarations, then resolves the configuration without any values and with each declared
+/// option set to each of the values its kind allows, and panics if it finds any errors or diagnostics.
+///
+/// This ensures the defaults and the allowed values of the declarations can be parsed by the plugin,
+/// so that resolving the configuration can't panic.
+pub fn ensure_config_options_resolve(options: &[ConfigOption], resolve_config: impl Fn(HashMap<String, String>) -> Vec<ConfigurationDiagnostic>) {
+    let errors = get_config_option_declaration_errors(options);
+    if !errors.is_empty() {
+        panic!("Invalid configuration option declarations:\n{}", errors.join("\n"));
+    }
+
+    ensure_no_diagnostics(&resolve_config(HashMap::new()));
+
+    for option in options.iter() {
+        for value in get_kind_values(option.kind) {
+            let mut config = HashMap::new();
+            config.insert(String::from(option.key), String::from(value));
+            ensure_no_diagnostics(&resolve_config(config));
+        }
+    }
+}
+
+fn get_kind_values(kind: ConfigOptionKind) -> Vec<&'static str> {
+    match kind {
+        ConfigOptionKind::Bool => vec!["true", "false"],
+        ConfigOptionKind::Number => vec!["4"],
+        ConfigOptionKind::String => vec!["text"],
+        ConfigOptionKind::Enum(values) => values.to_vec(),
+        ConfigOptionKind::Auto(kind) => {
+            let mut values = vec!["auto"];
+            values.extend(get_kind_values(*kind));
+            values
+        }
+    }
+}
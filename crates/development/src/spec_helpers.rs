@@ -18,11 +18,11 @@ pub struct RunSpecsOptions {
     pub format_twice: bool,
 }
 
-pub fn run_specs(
+pub fn run_specs<TError: std::fmt::Debug>(
     directory_path: &PathBuf,
     parse_spec_options: &ParseSpecOptions,
     run_spec_options: &RunSpecsOptions,
-    format_text: impl Fn(&PathBuf, &str, &HashMap<String, String>) -> Result<String, String>
+    format_text: impl Fn(&PathBuf, &str, &HashMap<String, String>) -> Result<String, TError>
 ) {
     #[cfg(not(debug_assertions))]
     assert_not_fix_failures(run_spec_options);
@@ -0,0 +1,90 @@
+#[derive(Clone, PartialEq, Debug)]
+pub struct Range {
+    pub start: usize,
+    pub end: usize,
+    pub start_line: usize,
+    pub end_line: usize,
+}
+
+/// The language of the stylesheet.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Syntax {
+    Css,
+    Scss,
+    Less,
+}
+
+pub struct Stylesheet {
+    pub items: Vec<Item>,
+}
+
+/// A statement in the stylesheet or a block.
+pub enum Item {
+    Rule(Rule),
+    AtRule(AtRule),
+    Declaration(Declaration),
+    Comment(Comment),
+}
+
+impl Item {
+    pub fn range(&self) -> &Range {
+        match self {
+            Item::Rule(node) => &node.range,
+            Item::AtRule(node) => &node.range,
+            Item::Declaration(node) => &node.range,
+            Item::Comment(node) => &node.range,
+        }
+    }
+}
+
+/// A style rule (ex. `a, b { color: red; }`).
+pub struct Rule {
+    pub range: Range,
+    /// The text of each selector in the selector list.
+    pub selectors: Vec<String>,
+    pub block: Block,
+}
+
+/// An at-rule (ex. `@media screen { ... }` or `@import "file";`).
+pub struct AtRule {
+    pub range: Range,
+    /// The name without the `@`.
+    pub name: String,
+    /// The text between the name and the block or semi-colon.
+    pub prelude: RawText,
+    pub block: Option<Block>,
+}
+
+/// A declaration (ex. `color: red`) or a statement without a value (ex. a Less mixin call).
+pub struct Declaration {
+    pub range: Range,
+    pub property: String,
+    pub value: Option<RawText>,
+}
+
+pub struct Block {
+    pub range: Range,
+    pub items: Vec<Item>,
+}
+
+pub struct Comment {
+    pub range: Range,
+    /// The comment text including the comment delimiters.
+    pub text: RawText,
+}
+
+/// Text as written in the file where the lines after the first have
+/// had the indentation of the start of the node removed.
+pub struct RawText {
+    pub lines: Vec<String>,
+}
+
+impl RawText {
+    pub fn is_empty(&self) -> bool {
+        self.lines.len() == 1 && self.lines[0].is_empty()
+    }
+
+    pub fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+}
@@ -0,0 +1,5 @@
+mod ast;
+mod parse_text;
+
+pub use ast::*;
+pub use parse_text::{parse_text, split_top_level_commas};
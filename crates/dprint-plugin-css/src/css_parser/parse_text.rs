@@ -0,0 +1,412 @@
+use super::ast::*;
+
+pub struct ParseError {
+    pub range: (usize, usize),
+    pub message: String,
+}
+
+/// Parses the stylesheet text into its statements while keeping the comments.
+pub fn parse_text(text: &str, syntax: Syntax) -> Result<Stylesheet, ParseError> {
+    let mut parser = Parser {
+        text,
+        syntax,
+        pos: 0,
+        line_starts: get_line_starts(text),
+    };
+    Ok(Stylesheet {
+        items: parser.parse_items(false)?,
+    })
+}
+
+fn get_line_starts(text: &str) -> Vec<usize> {
+    let mut line_starts = vec![0];
+    for (i, c) in text.char_indices() {
+        if c == '\n' {
+            line_starts.push(i + 1);
+        }
+    }
+    line_starts
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    syntax: Syntax,
+    pos: usize,
+    line_starts: Vec<usize>,
+}
+
+impl<'a> Parser<'a> {
+    fn parse_items(&mut self, is_block: bool) -> Result<Vec<Item>, ParseError> {
+        let mut items = Vec::new();
+
+        loop {
+            self.skip_whitespace();
+            match self.peek_char() {
+                None => {
+                    if is_block {
+                        return Err(self.create_error("Expected '}'."));
+                    }
+                    break;
+                },
+                Some('}') => {
+                    if is_block {
+                        break;
+                    }
+                    return Err(self.create_error("Unexpected '}'."));
+                },
+                Some(';') => {
+                    self.next_char();
+                },
+                Some('/') if self.is_comment_start(self.pos) => {
+                    items.push(Item::Comment(self.parse_comment()?));
+                },
+                Some('@') if !self.text[self.pos..].starts_with("@{") => {
+                    items.push(self.parse_at_rule()?);
+                },
+                Some(_) => {
+                    items.push(self.parse_rule_or_declaration()?);
+                },
+            }
+        }
+
+        Ok(items)
+    }
+
+    fn parse_at_rule(&mut self) -> Result<Item, ParseError> {
+        let start = self.pos;
+        self.next_char(); // @
+        while self.peek_char().map(is_name_char).unwrap_or(false) {
+            self.next_char();
+        }
+        let name_end = self.pos;
+        if name_end == start + 1 {
+            return Err(self.create_error("Expected an at-rule name."));
+        }
+
+        // less variable declarations and detached rulesets (ex. `@color: red;`)
+        if self.peek_char() == Some(':') {
+            self.pos = start;
+            return self.parse_rule_or_declaration();
+        }
+
+        let (end, end_char) = self.find_statement_end(self.pos)?;
+        let mut name = String::from(&self.text[start + 1..name_end]);
+        let mut prelude_start = name_end;
+        // keep the arguments of calls on the name (ex. less' `@detached();`)
+        if end_char != Some('{') && self.text[name_end..].starts_with('(') {
+            name = String::from(self.text[start + 1..end].trim_end());
+            prelude_start = end;
+        }
+        let prelude = self.create_raw_text(start, &self.text[prelude_start..end]);
+        self.pos = end;
+
+        let block = if end_char == Some('{') {
+            Some(self.parse_block()?)
+        } else {
+            self.try_consume_char(';');
+            None
+        };
+
+        Ok(Item::AtRule(AtRule {
+            range: self.create_range(start),
+            name,
+            prelude,
+            block,
+        }))
+    }
+
+    fn parse_rule_or_declaration(&mut self) -> Result<Item, ParseError> {
+        let start = self.pos;
+        let (end, end_char) = self.find_statement_end(start)?;
+        let text = &self.text[start..end];
+        self.pos = end;
+
+        if end_char == Some('{') {
+            let selectors = split_top_level_commas(text).into_iter()
+                .map(|selector| String::from(selector.trim()))
+                .collect::<Vec<_>>();
+            if selectors.iter().any(|s| s.is_empty()) {
+                self.pos = start;
+                return Err(self.create_error("Expected a selector."));
+            }
+            Ok(Item::Rule(Rule {
+                selectors,
+                block: self.parse_block()?,
+                range: self.create_range(start),
+            }))
+        } else {
+            self.try_consume_char(';');
+            let (property, value) = match find_declaration_colon(text) {
+                Some(colon_pos) => {
+                    let value_start = start + colon_pos + 1;
+                    let value_text = &self.text[value_start..end];
+                    let value_start = value_start + (value_text.len() - value_text.trim_start().len());
+                    (String::from(text[..colon_pos].trim()), Some(self.create_raw_text(start, &self.text[value_start..end])))
+                },
+                None => (text.split_whitespace().collect::<Vec<_>>().join(" "), None),
+            };
+            Ok(Item::Declaration(Declaration {
+                range: self.create_range(start),
+                property,
+                value,
+            }))
+        }
+    }
+
+    fn parse_block(&mut self) -> Result<Block, ParseError> {
+        let start = self.pos;
+        self.expect_char('{')?;
+        let items = self.parse_items(true)?;
+        self.expect_char('}')?;
+
+        Ok(Block {
+            range: self.create_range(start),
+            items,
+        })
+    }
+
+    fn parse_comment(&mut self) -> Result<Comment, ParseError> {
+        let start = self.pos;
+        let end = self.get_comment_end(start)?;
+        let text = self.text[start..end].trim_end();
+        self.pos = end;
+
+        Ok(Comment {
+            text: self.create_raw_text(start, text),
+            range: self.create_range(start),
+        })
+    }
+
+    /// Finds the position of the `{`, `;`, or `}` that ends the statement starting at the provided position.
+    fn find_statement_end(&self, start: usize) -> Result<(usize, Option<char>), ParseError> {
+        let mut pos = start;
+        // whether each open parenthesis is for a `url(`
+        let mut parens: Vec<bool> = Vec::new();
+
+        while let Some(c) = self.text[pos..].chars().next() {
+            let is_in_url = parens.last().copied().unwrap_or(false);
+            match c {
+                ')' => {
+                    parens.pop();
+                },
+                _ if is_in_url => {},
+                '"' | '\'' => {
+                    pos = self.get_string_end(pos)?;
+                    continue;
+                },
+                '/' if self.is_comment_start(pos) => {
+                    pos = self.get_comment_end(pos)?;
+                    continue;
+                },
+                '#' | '@' if self.text[pos + 1..].starts_with('{') => {
+                    pos = self.get_interpolation_end(pos + 1)?;
+                    continue;
+                },
+                '(' => {
+                    parens.push(self.text[..pos].to_lowercase().ends_with("url"));
+                },
+                '{' | ';' | '}' if parens.is_empty() => return Ok((pos, Some(c))),
+                _ => {},
+            }
+            pos += c.len_utf8();
+        }
+
+        Ok((pos, None))
+    }
+
+    fn get_string_end(&self, start: usize) -> Result<usize, ParseError> {
+        let quote_char = self.text[start..].chars().next().unwrap();
+        let mut chars = self.text[start + 1..].char_indices();
+
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '\\' => {
+                    chars.next();
+                },
+                '\n' => break,
+                _ if c == quote_char => return Ok(start + 1 + i + 1),
+                _ => {},
+            }
+        }
+
+        Err(self.create_error_at(start, "Unterminated string."))
+    }
+
+    fn get_interpolation_end(&self, open_brace_pos: usize) -> Result<usize, ParseError> {
+        let mut depth = 0;
+        let mut pos = open_brace_pos;
+
+        while let Some(c) = self.text[pos..].chars().next() {
+            match c {
+                '"' | '\'' => {
+                    pos = self.get_string_end(pos)?;
+                    continue;
+                },
+                '{' => depth += 1,
+                '}' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Ok(pos + 1);
+                    }
+                },
+                _ => {},
+            }
+            pos += c.len_utf8();
+        }
+
+        Err(self.create_error_at(open_brace_pos, "Unterminated interpolation."))
+    }
+
+    fn is_comment_start(&self, pos: usize) -> bool {
+        let text = &self.text[pos..];
+        text.starts_with("/*") || self.syntax != Syntax::Css && text.starts_with("//")
+    }
+
+    fn get_comment_end(&self, start: usize) -> Result<usize, ParseError> {
+        let text = &self.text[start..];
+        if text.starts_with("//") {
+            Ok(start + text.find('\n').unwrap_or(text.len()))
+        } else {
+            match text[2..].find("*/") {
+                Some(index) => Ok(start + 2 + index + 2),
+                None => Err(self.create_error_at(start, "Unterminated comment.")),
+            }
+        }
+    }
+
+    /// Creates the raw text for a node where the continuation lines have the node's indentation removed.
+    fn create_raw_text(&self, node_start: usize, text: &str) -> RawText {
+        let line_start = self.line_starts[self.get_line(node_start)];
+        let indent_text = &self.text[line_start..node_start];
+        let indent_width = indent_text.len() - indent_text.trim_start().len();
+
+        let mut lines = Vec::new();
+        for (i, line) in text.trim_end().split('\n').enumerate() {
+            let line = line.trim_end();
+            if i == 0 {
+                lines.push(String::from(line));
+            } else {
+                let whitespace_len = line.len() - line.trim_start().len();
+                lines.push(String::from(&line[std::cmp::min(whitespace_len, indent_width)..]));
+            }
+        }
+
+        RawText { lines }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek_char().map(|c| c.is_whitespace()).unwrap_or(false) {
+            self.next_char();
+        }
+    }
+
+    fn expect_char(&mut self, c: char) -> Result<(), ParseError> {
+        if self.try_consume_char(c) {
+            Ok(())
+        } else {
+            Err(self.create_error(&format!("Expected '{}'.", c)))
+        }
+    }
+
+    fn try_consume_char(&mut self, c: char) -> bool {
+        if self.peek_char() == Some(c) {
+            self.next_char();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn peek_char(&self) -> Option<char> {
+        self.text[self.pos..].chars().next()
+    }
+
+    fn next_char(&mut self) -> Option<char> {
+        let c = self.peek_char()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn get_line(&self, pos: usize) -> usize {
+        match self.line_starts.binary_search(&pos) {
+            Ok(line) => line,
+            Err(line) => line - 1,
+        }
+    }
+
+    fn create_range(&self, start: usize) -> Range {
+        Range {
+            start,
+            end: self.pos,
+            start_line: self.get_line(start),
+            end_line: self.get_line(self.pos),
+        }
+    }
+
+    fn create_error(&self, message: &str) -> ParseError {
+        self.create_error_at(self.pos, message)
+    }
+
+    fn create_error_at(&self, pos: usize, message: &str) -> ParseError {
+        let end = pos + self.text[pos..].chars().next().map(|c| c.len_utf8()).unwrap_or(0);
+        ParseError {
+            range: (pos, end),
+            message: String::from(message),
+        }
+    }
+}
+
+/// Splits the text on the commas that aren't within parentheses, brackets, or strings.
+pub fn split_top_level_commas(text: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut quote_char = None;
+    let mut is_escaped = false;
+    let mut last_index = 0;
+
+    for (i, c) in text.char_indices() {
+        if let Some(current_quote_char) = quote_char {
+            if is_escaped {
+                is_escaped = false;
+            } else if c == '\\' {
+                is_escaped = true;
+            } else if c == current_quote_char {
+                quote_char = None;
+            }
+            continue;
+        }
+
+        match c {
+            '"' | '\'' => quote_char = Some(c),
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(&text[last_index..i]);
+                last_index = i + 1;
+            },
+            _ => {},
+        }
+    }
+
+    parts.push(&text[last_index..]);
+    parts
+}
+
+/// Finds the colon between the property and value of a declaration.
+fn find_declaration_colon(text: &str) -> Option<usize> {
+    let mut depth = 0;
+    for (i, c) in text.char_indices() {
+        match c {
+            '{' | '(' => depth += 1,
+            '}' | ')' => depth -= 1,
+            '"' | '\'' | '/' => return None,
+            ':' if depth == 0 => return Some(i),
+            _ => {},
+        }
+    }
+    None
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '-' || c == '_'
+}
@@ -0,0 +1,112 @@
+use std::path::Path;
+use dprint_core::{print, print_equals, print_with_long_lines, FormatError, LongLine, PrintBudget, PrintItemsArena, PrintOptions};
+use dprint_core::configuration::resolve_new_line_kind;
+use super::configuration::Configuration;
+use super::parser::parse_items;
+use super::css_parser::{parse_text, Stylesheet, Syntax};
+
+/// Formats the stylesheet text. The file extension is used to tell if the file is CSS, SCSS, or Less.
+pub fn format_text(file_path: &Path, text: &str, config: &Configuration) -> Result<String, FormatError> {
+    let syntax = get_syntax(file_path);
+    let stylesheet = parse_stylesheet(text, syntax)?;
+    let arena = PrintItemsArena::new();
+    Ok(print(parse_items(&arena, &stylesheet, syntax, config), get_print_options(text, config))?)
+}
+
+/// Formats the stylesheet text also getting the lines that are still wider than the line width.
+pub fn format_text_with_long_lines(file_path: &Path, text: &str, config: &Configuration) -> Result<(String, Vec<LongLine>), FormatError> {
+    let syntax = get_syntax(file_path);
+    let stylesheet = parse_stylesheet(text, syntax)?;
+    let arena = PrintItemsArena::new();
+    Ok(print_with_long_lines(parse_items(&arena, &stylesheet, syntax, config), get_print_options(text, config))?)
+}
+
+/// Gets if formatting the stylesheet text would leave it unchanged without creating the formatted text.
+pub fn format_text_equals(file_path: &Path, text: &str, config: &Configuration) -> Result<bool, FormatError> {
+    let syntax = get_syntax(file_path);
+    let stylesheet = parse_stylesheet(text, syntax)?;
+    let arena = PrintItemsArena::new();
+    Ok(print_equals(parse_items(&arena, &stylesheet, syntax, config), get_print_options(text, config), text)?)
+}
+
+/// Gets the print items of the text as an indented tree for debugging.
+pub fn get_print_ir(file_path: &Path, text: &str, config: &Configuration) -> Result<String, FormatError> {
+    let syntax = get_syntax(file_path);
+    let stylesheet = parse_stylesheet(text, syntax)?;
+    let arena = PrintItemsArena::new();
+    Ok(parse_items(&arena, &stylesheet, syntax, config).get_as_text())
+}
+
+fn get_print_options(text: &str, config: &Configuration) -> PrintOptions {
+    PrintOptions {
+        indent_width: config.indent_width,
+        max_width: config.line_width,
+        use_tabs: config.use_tabs,
+        new_line_text: resolve_new_line_kind(text, config.new_line_kind),
+        unicode_width: config.unicode_width,
+        budget: PrintBudget {
+            max_condition_evaluations: config.max_condition_evaluations,
+            max_visits_per_node: config.max_visits_per_node,
+        },
+    }
+}
+
+fn get_syntax(file_path: &Path) -> Syntax {
+    match file_path.extension().and_then(|e| e.to_str()).map(|e| e.to_lowercase()).as_deref() {
+        Some("scss") => Syntax::Scss,
+        Some("less") => Syntax::Less,
+        _ => Syntax::Css,
+    }
+}
+
+fn parse_stylesheet(text: &str, syntax: Syntax) -> Result<Stylesheet, FormatError> {
+    match parse_text(text, syntax) {
+        Ok(stylesheet) => Ok(stylesheet),
+        Err(err) => Err(FormatError::syntax(err.message, err.range, text)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dprint_core::configuration::*;
+    use std::collections::HashMap;
+    use dprint_core::FormatErrorKind;
+    use super::super::configuration::resolve_config;
+    use super::*;
+
+    #[test]
+    fn should_error_on_syntax_diagnostic() {
+        let global_config = resolve_global_config(HashMap::new()).config;
+        let config = resolve_config(HashMap::new(), &global_config).config;
+        let file_text = "a { color: \"red; }";
+        let err = format_text(Path::new("file.css"), file_text, &config).err().unwrap();
+        assert_eq!(err.kind, FormatErrorKind::Syntax);
+        assert_eq!(
+            err.get_diagnostic_text(file_text),
+            concat!(
+                "Line 1, column 12: Unterminated string.\n",
+                "\n",
+                "  1 | a { color: \"red; }\n",
+                "    |            ~"
+            )
+        );
+    }
+
+    #[test]
+    fn should_get_long_lines() {
+        let mut global_config = HashMap::new();
+        global_config.insert(String::from("lineWidth"), String::from("40"));
+        let global_config = resolve_global_config(global_config).config;
+        let config = resolve_config(HashMap::new(), &global_config).config;
+        let file_text = "a {\n    background: url(\"https://example.com/images/background.png\");\n    color: red;\n}\n";
+        let (formatted_text, long_lines) = format_text_with_long_lines(Path::new("file.css"), file_text, &config).unwrap();
+        assert_eq!(formatted_text, file_text);
+        assert_eq!(long_lines, vec![LongLine {
+            line_number: 2,
+            column_number: 17,
+            width: 65,
+            text: Some(String::from("url(\"https://example.com/images/background.png\")")),
+            source_position: None,
+        }]);
+    }
+}
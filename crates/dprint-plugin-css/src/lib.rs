@@ -0,0 +1,8 @@
+pub mod configuration;
+mod css_parser;
+mod format_text;
+mod parser;
+mod plugin;
+
+pub use format_text::format_text;
+pub use plugin::CssPlugin;
@@ -0,0 +1,4 @@
+mod normalize;
+mod parse;
+
+pub use parse::parse_items;
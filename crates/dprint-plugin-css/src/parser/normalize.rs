@@ -0,0 +1,255 @@
+use super::super::configuration::QuoteStyle;
+use super::super::css_parser::Syntax;
+
+pub struct NormalizeOptions {
+    pub syntax: Syntax,
+    /// The quote style to use for strings or `None` to keep the quotes as-is.
+    pub quote_style: Option<QuoteStyle>,
+    /// If a space should be added after colons within parentheses (ex. `(min-width: 100px)` in media queries).
+    pub space_after_colon_in_parens: bool,
+}
+
+/// Normalizes the spacing of a selector.
+///
+/// Returns `None` when the selector contains a line comment and can't be placed on a single line.
+pub fn normalize_selector(text: &str, options: &NormalizeOptions) -> Option<String> {
+    let chars = text.chars().collect::<Vec<_>>();
+    let mut writer = TextWriter::new();
+    let mut paren_depth = 0;
+    let mut bracket_depth = 0;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            _ if c.is_whitespace() => writer.queue_space(),
+            '/' if chars.get(i + 1) == Some(&'/') && options.syntax != Syntax::Css => return None,
+            '"' | '\'' | '/' | '#' | '@' if is_verbatim_start(&chars, i) => {
+                i = writer.write_verbatim(&chars, i, options);
+                continue;
+            },
+            '[' => {
+                writer.write(c);
+                writer.clear_space();
+                bracket_depth += 1;
+            },
+            ']' => {
+                writer.clear_space();
+                writer.write(c);
+                bracket_depth -= 1;
+            },
+            '(' => {
+                writer.write(c);
+                writer.clear_space();
+                paren_depth += 1;
+            },
+            ')' => {
+                writer.clear_space();
+                writer.write(c);
+                paren_depth -= 1;
+            },
+            ',' => {
+                writer.clear_space();
+                writer.write(c);
+                writer.queue_space();
+            },
+            '>' | '+' | '~' if paren_depth == 0 && bracket_depth == 0 => {
+                // combinators have a single space around them
+                writer.queue_space();
+                writer.write(c);
+                writer.queue_space();
+            },
+            _ => writer.write(c),
+        }
+        i += 1;
+    }
+
+    Some(writer.text)
+}
+
+/// Normalizes the spacing of a declaration value or at-rule prelude.
+///
+/// Returns `None` when the value contains a line comment and can't be placed on a single line.
+pub fn normalize_value(text: &str, options: &NormalizeOptions) -> Option<String> {
+    let chars = text.chars().collect::<Vec<_>>();
+    let mut writer = TextWriter::new();
+    // whether each open parenthesis is for a `url(` with an unquoted url
+    let mut parens: Vec<bool> = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        if parens.last().copied().unwrap_or(false) {
+            // keep unquoted urls as-is
+            if c == ')' {
+                writer.clear_space();
+                writer.write(c);
+                parens.pop();
+            } else if c.is_whitespace() {
+                writer.queue_space();
+            } else {
+                writer.write(c);
+            }
+            i += 1;
+            continue;
+        }
+
+        match c {
+            _ if c.is_whitespace() => writer.queue_space(),
+            '/' if chars.get(i + 1) == Some(&'/') && options.syntax != Syntax::Css => return None,
+            '"' | '\'' | '/' | '#' | '@' if is_verbatim_start(&chars, i) => {
+                i = writer.write_verbatim(&chars, i, options);
+                continue;
+            },
+            '(' => {
+                let is_url = writer.text.to_lowercase().ends_with("url")
+                    && chars[i + 1..].iter().find(|c| !c.is_whitespace()).map(|c| *c != '"' && *c != '\'').unwrap_or(false);
+                writer.write(c);
+                writer.clear_space();
+                parens.push(is_url);
+            },
+            ')' => {
+                writer.clear_space();
+                writer.write(c);
+                parens.pop();
+            },
+            ',' => {
+                writer.clear_space();
+                writer.write(c);
+                writer.queue_space();
+            },
+            ':' if options.space_after_colon_in_parens && !parens.is_empty() => {
+                writer.clear_space();
+                writer.write(c);
+                writer.queue_space();
+            },
+            '!' if chars[i + 1..].iter().find(|c| !c.is_whitespace()).map(|c| c.is_alphabetic()).unwrap_or(false) => {
+                // ex. `!important` or `!default`
+                writer.queue_space();
+                writer.write(c);
+                while chars.get(i + 1).map(|c| c.is_whitespace()).unwrap_or(false) {
+                    i += 1;
+                }
+            },
+            _ => writer.write(c),
+        }
+        i += 1;
+    }
+
+    Some(writer.text)
+}
+
+/// Gets the string using the preferred quote when it won't need to be escaped.
+pub fn get_quoted_string(text: &str, quote_style: QuoteStyle) -> String {
+    let inner_text = &text[1..text.len() - 1];
+    if inner_text.contains('"') || inner_text.contains('\'') {
+        return String::from(text);
+    }
+
+    match quote_style {
+        QuoteStyle::PreferDouble => format!("\"{}\"", inner_text),
+        QuoteStyle::PreferSingle => format!("'{}'", inner_text),
+    }
+}
+
+/// Gets if the text at the index starts a string, comment, or interpolation that should be written as-is.
+fn is_verbatim_start(chars: &[char], index: usize) -> bool {
+    match chars[index] {
+        '"' | '\'' => true,
+        '/' => chars.get(index + 1) == Some(&'*'),
+        '#' | '@' => chars.get(index + 1) == Some(&'{'),
+        _ => false,
+    }
+}
+
+struct TextWriter {
+    text: String,
+    has_queued_space: bool,
+}
+
+impl TextWriter {
+    fn new() -> TextWriter {
+        TextWriter {
+            text: String::new(),
+            has_queued_space: false,
+        }
+    }
+
+    fn queue_space(&mut self) {
+        self.has_queued_space = true;
+    }
+
+    fn clear_space(&mut self) {
+        self.has_queued_space = false;
+    }
+
+    fn write(&mut self, c: char) {
+        if self.has_queued_space && !self.text.is_empty() && !self.text.ends_with('(') && !self.text.ends_with('[') {
+            self.text.push(' ');
+        }
+        self.has_queued_space = false;
+        self.text.push(c);
+    }
+
+    /// Writes the string, comment, or interpolation at the index and returns the index after it.
+    fn write_verbatim(&mut self, chars: &[char], start: usize, options: &NormalizeOptions) -> usize {
+        let end = get_verbatim_end(chars, start);
+        let text = chars[start..end].iter().collect::<String>();
+        let text = match options.quote_style {
+            Some(quote_style) if (chars[start] == '"' || chars[start] == '\'') && text.len() > 1 && text.ends_with(chars[start]) => {
+                get_quoted_string(&text, quote_style)
+            },
+            _ => text,
+        };
+
+        let mut text_chars = text.chars();
+        if let Some(c) = text_chars.next() {
+            self.write(c);
+            self.text.extend(text_chars);
+        }
+        end
+    }
+}
+
+fn get_verbatim_end(chars: &[char], start: usize) -> usize {
+    let mut i = start + 1;
+    match chars[start] {
+        '"' | '\'' => {
+            while i < chars.len() {
+                if chars[i] == '\\' {
+                    i += 1;
+                } else if chars[i] == chars[start] {
+                    return i + 1;
+                }
+                i += 1;
+            }
+        },
+        '/' => {
+            i += 1;
+            while i < chars.len() {
+                if chars[i] == '/' && chars[i - 1] == '*' && i > start + 2 {
+                    return i + 1;
+                }
+                i += 1;
+            }
+        },
+        _ => {
+            let mut depth = 0;
+            while i < chars.len() {
+                match chars[i] {
+                    '{' => depth += 1,
+                    '}' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            return i + 1;
+                        }
+                    },
+                    _ => {},
+                }
+                i += 1;
+            }
+        },
+    }
+    chars.len()
+}
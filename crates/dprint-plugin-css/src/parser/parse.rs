@@ -0,0 +1,221 @@
+use dprint_core::*;
+use dprint_core::parser_helpers::*;
+use super::super::configuration::Configuration;
+use super::super::css_parser::*;
+use super::normalize::*;
+
+struct Context<'a> {
+    arena: &'a PrintItemsArena<'a>,
+    syntax: Syntax,
+    config: &'a Configuration,
+}
+
+pub fn parse_items<'a>(arena: &'a PrintItemsArena<'a>, stylesheet: &Stylesheet, syntax: Syntax, config: &'a Configuration) -> PrintItems<'a> {
+    let context = Context { arena, syntax, config };
+    let mut items = parse_statements(&stylesheet.items, None, &context);
+
+    items.push_condition(conditions::if_true(
+        "endOfFileNewLine",
+        |context| Some(context.writer_info.column_number > 0 || context.writer_info.line_number > 0),
+        context.arena.items(Signal::NewLine)
+    ));
+
+    items
+}
+
+fn parse_statements<'a>(nodes: &[Item], block_start_line: Option<usize>, context: &Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
+    let mut last_node: Option<&Item> = None;
+    let mut last_end_line = block_start_line;
+
+    for node in nodes.iter() {
+        let range = node.range();
+        let is_trailing_comment = matches!(node, Item::Comment(_)) && last_end_line == Some(range.start_line);
+        let is_else_at_rule = matches!(last_node, Some(Item::AtRule(AtRule { block: Some(_), .. })))
+            && matches!(node, Item::AtRule(at_rule) if at_rule.name == "else");
+
+        if is_trailing_comment || is_else_at_rule {
+            items.push_str(" ");
+        } else if last_node.is_some() || block_start_line.is_some() {
+            items.push_signal(Signal::NewLine);
+            if last_node.is_some() && range.start_line > last_end_line.unwrap() + 1 {
+                items.push_signal(Signal::NewLine);
+            }
+        }
+
+        items.extend(parse_node(node, context));
+        last_node = Some(node);
+        last_end_line = Some(range.end_line);
+    }
+
+    items
+}
+
+fn parse_node<'a>(node: &Item, context: &Context<'a>) -> PrintItems<'a> {
+    match node {
+        Item::Rule(node) => parse_rule(node, context),
+        Item::AtRule(node) => parse_at_rule(node, context),
+        Item::Declaration(node) => parse_declaration(node, context),
+        Item::Comment(node) => parse_raw_text(&node.text, context),
+    }
+}
+
+fn parse_rule<'a>(rule: &Rule, context: &Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
+    items.extend(parse_selectors(&rule.selectors, context));
+    items.push_str(" ");
+    items.extend(parse_block(&rule.block, context));
+    items
+}
+
+fn parse_selectors<'a>(selectors: &[String], context: &Context<'a>) -> PrintItems<'a> {
+    let options = NormalizeOptions {
+        syntax: context.syntax,
+        quote_style: Some(context.config.quote_style),
+        space_after_colon_in_parens: false,
+    };
+    let normalized_selectors = selectors.iter().map(|selector| normalize_selector(selector, &options)).collect::<Option<Vec<_>>>();
+    let normalized_selectors = match normalized_selectors {
+        Some(normalized_selectors) => normalized_selectors,
+        None => {
+            // has line comments, so keep each selector on its own line
+            let mut items = PrintItems::new(context.arena);
+            for (i, selector) in selectors.iter().enumerate() {
+                if i > 0 {
+                    items.push_str(",");
+                    items.push_signal(Signal::NewLine);
+                }
+                for (i, line) in selector.lines().enumerate() {
+                    if i > 0 {
+                        items.push_signal(Signal::NewLine);
+                    }
+                    items.extend(parse_string(context.arena, line.trim()));
+                }
+            }
+            return items;
+        },
+    };
+
+    parse_comma_separated(normalized_selectors, MultiLineOptions::same_line_no_indent(), false, context)
+}
+
+fn parse_at_rule<'a>(at_rule: &AtRule, context: &Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
+    let name = at_rule.name.to_lowercase();
+
+    items.push_str("@");
+    items.extend(parse_string(context.arena, &at_rule.name));
+
+    if !at_rule.prelude.is_empty() {
+        let options = NormalizeOptions {
+            syntax: context.syntax,
+            // the charset must be written with double quotes
+            quote_style: if name == "charset" { None } else { Some(context.config.quote_style) },
+            space_after_colon_in_parens: name == "media" || name == "supports",
+        };
+        items.push_str(" ");
+        match normalize_value(&at_rule.prelude.text(), &options) {
+            Some(prelude) => items.extend(parse_string(context.arena, &prelude)),
+            None => items.extend(parse_raw_text(&at_rule.prelude, context)),
+        }
+    }
+
+    match &at_rule.block {
+        Some(block) => {
+            items.push_str(" ");
+            items.extend(parse_block(block, context));
+        },
+        None => items.push_str(";"),
+    }
+
+    items
+}
+
+fn parse_declaration<'a>(declaration: &Declaration, context: &Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
+    items.extend(parse_string(context.arena, &declaration.property));
+
+    if let Some(value) = &declaration.value {
+        items.push_str(":");
+        if !value.is_empty() {
+            let options = NormalizeOptions {
+                syntax: context.syntax,
+                quote_style: Some(context.config.quote_style),
+                space_after_colon_in_parens: false,
+            };
+            match normalize_value(&value.text(), &options) {
+                Some(value) => {
+                    let values = split_top_level_commas(&value).into_iter().map(|value| String::from(value.trim())).collect::<Vec<_>>();
+                    if values.len() > 1 {
+                        items.extend(parse_comma_separated(values, MultiLineOptions::new_line_start(), true, context));
+                    } else {
+                        items.push_str(" ");
+                        items.extend(parse_string(context.arena, &value));
+                    }
+                },
+                None => {
+                    items.push_str(" ");
+                    items.extend(parse_raw_text(value, context));
+                },
+            }
+        }
+    }
+
+    items.push_str(";");
+    items
+}
+
+/// Parses comma separated values that are placed on separate lines when exceeding the line width.
+fn parse_comma_separated<'a>(values: Vec<String>, multi_line_options: MultiLineOptions, has_space_at_start: bool, context: &Context<'a>) -> PrintItems<'a> {
+    if values.len() == 1 {
+        return parse_string(context.arena, &values[0]);
+    }
+
+    let values_count = values.len();
+    parse_separated_values(|_| {
+        values.into_iter().enumerate().map(|(i, value)| {
+            let mut items = parse_string(context.arena, &value);
+            if i < values_count - 1 {
+                items.push_str(",");
+            }
+            ParsedValue::from_items(new_line_group(items))
+        }).collect()
+    }, ParseSeparatedValuesOptions {
+        prefer_hanging: false,
+        force_use_new_lines: false,
+        allow_blank_lines: false,
+        single_line_space_at_start: has_space_at_start,
+        single_line_space_at_end: false,
+        single_line_separator: context.arena.items(Signal::SpaceOrNewLine),
+        indent_width: context.config.indent_width,
+        multi_line_options,
+        force_possible_newline_at_start: false,
+    }).items
+}
+
+fn parse_block<'a>(block: &Block, context: &Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
+
+    items.push_str("{");
+    if !block.items.is_empty() {
+        items.extend(with_indent(parse_statements(&block.items, Some(block.range.start_line), context)));
+        items.push_signal(Signal::NewLine);
+    }
+    items.push_str("}");
+
+    items
+}
+
+/// Parses text that may span multiple lines where the lines after the first are written as-is.
+fn parse_raw_text<'a>(text: &RawText, context: &Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
+    for (i, line) in text.lines.iter().enumerate() {
+        if i > 0 {
+            items.push_signal(Signal::NewLine);
+        }
+        if !line.is_empty() {
+            items.extend(parse_string(context.arena, line));
+        }
+    }
+    items
+}
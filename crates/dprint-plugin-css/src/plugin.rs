@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+use dprint_core::configuration::{ConfigurationDiagnostic, GlobalConfiguration, ResolvedConfigOptions};
+use std::path::PathBuf;
+use dprint_core::plugins::*;
+use dprint_core::{FormatError, LongLine};
+use super::configuration::{Configuration, resolve_config_options};
+use super::format_text::{format_text, format_text_equals, format_text_with_long_lines, get_print_ir};
+
+/// CSS, SCSS, and Less Dprint CLI Plugin.
+pub struct CssPlugin {
+    config: Option<Configuration>,
+    resolved_options: Option<ResolvedConfigOptions>,
+}
+
+impl CssPlugin {
+    pub fn new() -> CssPlugin {
+        CssPlugin {
+            config: None,
+            resolved_options: None,
+        }
+    }
+
+    fn get_config(&self) -> &Configuration {
+        self.config.as_ref().expect("Plugin must be initialized.")
+    }
+
+    fn get_resolved_options(&self) -> &ResolvedConfigOptions {
+        self.resolved_options.as_ref().expect("Plugin must be initialized.")
+    }
+}
+
+impl Default for CssPlugin {
+    fn default() -> Self {
+        CssPlugin::new()
+    }
+}
+
+impl Plugin for CssPlugin {
+    fn name(&self) -> &'static str { env!("CARGO_PKG_NAME") }
+    fn version(&self) -> &'static str { env!("CARGO_PKG_VERSION") }
+    fn config_keys(&self) -> Vec<String> { vec![String::from("css")] }
+
+    fn initialize(&mut self, plugin_config: HashMap<String, String>, global_config: &GlobalConfiguration) {
+        let (config, resolved_options) = resolve_config_options(plugin_config, global_config);
+        self.config = Some(config);
+        self.resolved_options = Some(resolved_options);
+    }
+
+    fn should_format_file(&self, file_path: &PathBuf, _: &str) -> bool {
+        if let Some(ext) = file_path.extension().and_then(|e| e.to_str()) {
+            matches!(String::from(ext).to_lowercase().as_str(), "css" | "scss" | "less")
+        } else {
+            false
+        }
+    }
+
+    fn get_resolved_config(&self) -> String {
+        self.get_resolved_options().to_json_string()
+    }
+
+    fn get_configuration_diagnostics(&self) -> &Vec<ConfigurationDiagnostic> {
+        &self.get_resolved_options().diagnostics
+    }
+
+    fn format_text(&self, file_path: &PathBuf, file_text: &str, host: &FormatHost) -> Result<String, FormatError> {
+        let config = self.get_config();
+        match host.line_width() {
+            Some(line_width) => format_text(file_path, file_text, &Configuration { line_width, ..config.clone() }),
+            None => format_text(file_path, file_text, config),
+        }
+    }
+
+    fn format_text_equals(&self, file_path: &PathBuf, file_text: &str, host: &FormatHost) -> Result<bool, FormatError> {
+        let config = self.get_config();
+        match host.line_width() {
+            Some(line_width) => format_text_equals(file_path, file_text, &Configuration { line_width, ..config.clone() }),
+            None => format_text_equals(file_path, file_text, config),
+        }
+    }
+
+    fn get_print_ir(&self, file_path: &PathBuf, file_text: &str, _: &FormatHost) -> Result<String, FormatError> {
+        let config = self.get_config();
+        get_print_ir(file_path, file_text, config)
+    }
+
+    fn format_text_with_long_lines(&self, file_path: &PathBuf, file_text: &str, _: &FormatHost) -> Result<(String, Vec<LongLine>), FormatError> {
+        let config = self.get_config();
+        format_text_with_long_lines(file_path, file_text, config)
+    }
+}
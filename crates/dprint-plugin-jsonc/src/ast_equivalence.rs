@@ -0,0 +1,30 @@
+use jsonc_parser::ast::*;
+
+/// Gets if the two values have the same AST when ignoring ranges and comments.
+pub fn is_ast_equivalent(original: &Option<Value>, formatted: &Option<Value>) -> bool {
+    match (original, formatted) {
+        (Some(original), Some(formatted)) => are_values_equal(original, formatted),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+fn are_values_equal(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::StringLit(a), Value::StringLit(b)) => a.value.as_ref() == b.value.as_ref(),
+        (Value::NumberLit(a), Value::NumberLit(b)) => a.value.as_ref() == b.value.as_ref(),
+        (Value::BooleanLit(a), Value::BooleanLit(b)) => a.value == b.value,
+        (Value::NullKeyword(_), Value::NullKeyword(_)) => true,
+        (Value::Array(a), Value::Array(b)) => {
+            a.elements.len() == b.elements.len()
+                && a.elements.iter().zip(b.elements.iter()).all(|(a, b)| are_values_equal(a, b))
+        },
+        (Value::Object(a), Value::Object(b)) => {
+            a.properties.len() == b.properties.len()
+                && a.properties.iter().zip(b.properties.iter()).all(|(a, b)| {
+                    a.name.value.as_ref() == b.name.value.as_ref() && are_values_equal(&a.value, &b.value)
+                })
+        },
+        _ => false,
+    }
+}
@@ -7,7 +7,21 @@ pub struct Configuration {
     pub line_width: u32,
     pub use_tabs: bool,
     pub indent_width: u8,
+    /// Whether to detect using tabs from the file text, using `use_tabs` when it can't be detected.
+    pub auto_use_tabs: bool,
+    /// Whether to detect the indent width from the file text, using `indent_width` when it can't be detected.
+    pub auto_indent_width: bool,
     pub new_line_kind: NewLineKind,
+    pub unicode_width: bool,
+    pub max_condition_evaluations: u32,
+    pub max_visits_per_node: u32,
+    #[serde(rename = "array.fill")]
+    pub array_fill: bool,
+    #[serde(rename = "commentBlock.alignGutters")]
+    pub comment_block_align_gutters: bool,
+    #[serde(rename = "commentBlock.reflow")]
+    pub comment_block_reflow: bool,
     #[serde(rename = "commentLine.forceSpaceAfterSlashes")]
     pub comment_line_force_space_after_slashes: bool,
+    pub verify_semantic_equivalence: bool,
 }
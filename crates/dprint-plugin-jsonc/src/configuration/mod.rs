@@ -1,5 +1,7 @@
 mod resolve_config;
 mod configuration;
+mod options;
 
 pub use resolve_config::*;
-pub use configuration::*;
\ No newline at end of file
+pub use configuration::*;
+pub use options::CONFIG_OPTIONS;
\ No newline at end of file
@@ -0,0 +1,17 @@
+use dprint_core::configuration::*;
+
+/// The configuration options of the JSONC plugin.
+pub const CONFIG_OPTIONS: &[ConfigOption] = &[
+    ConfigOption::new("lineWidth", ConfigOptionKind::Number, ConfigOptionDefault::Global, "The width of a line the printer will try to stay under. Note that the printer may exceed this width in certain cases."),
+    ConfigOption::new("useTabs", ConfigOptionKind::Auto(&ConfigOptionKind::Bool), ConfigOptionDefault::Global, "Whether to use tabs (true) or spaces (false). Use \"auto\" to detect it from the indentation of each file."),
+    ConfigOption::new("indentWidth", ConfigOptionKind::Auto(&ConfigOptionKind::Number), ConfigOptionDefault::Global, "The number of spaces for an indent. This option is ignored when using tabs. Use \"auto\" to detect it from the indentation of each file."),
+    ConfigOption::new("newLineKind", ConfigOptionKind::Enum(NewLineKind::VALUES), ConfigOptionDefault::Global, "The kind of newline to use."),
+    ConfigOption::new("unicodeWidth", ConfigOptionKind::Bool, ConfigOptionDefault::Global, "Whether to measure text by its display width (ex. CJK characters and emoji take up two columns) instead of its character count."),
+    ConfigOption::new("maxConditionEvaluations", ConfigOptionKind::Number, ConfigOptionDefault::Global, "The maximum number of times the printer may evaluate a single condition before giving up on formatting a file."),
+    ConfigOption::new("maxVisitsPerNode", ConfigOptionKind::Number, ConfigOptionDefault::Global, "The maximum number of times the printer may visit each print item on average before giving up on formatting a file."),
+    ConfigOption::new("array.fill", ConfigOptionKind::Bool, ConfigOptionDefault::Value("false"), "Whether to pack as many elements on each line as fit when an array of numbers or short literals spans multiple lines."),
+    ConfigOption::new("commentBlock.alignGutters", ConfigOptionKind::Bool, ConfigOptionDefault::Value("false"), "Whether to re-align the leading `*` of each line in a block comment to the indentation of the comment."),
+    ConfigOption::new("commentBlock.reflow", ConfigOptionKind::Bool, ConfigOptionDefault::Value("false"), "Whether to wrap the text of block comments that have a leading `*` on each line to the line width. Code blocks, `@example` sections, and indented lines are left alone."),
+    ConfigOption::new("commentLine.forceSpaceAfterSlashes", ConfigOptionKind::Bool, ConfigOptionDefault::Value("true"), "Forces a space after the slashes of line comments (ex. `// comment` instead of `//comment`)."),
+    ConfigOption::new("verifySemanticEquivalence", ConfigOptionKind::Bool, ConfigOptionDefault::Value("false"), "Whether to re-parse the formatted output and ensure its AST is equivalent to the original's (ignoring spans and trivia). The file is refused with an error when they differ."),
+];
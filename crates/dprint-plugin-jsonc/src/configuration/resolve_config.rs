@@ -1,6 +1,7 @@
 use dprint_core::configuration::*;
 use std::collections::HashMap;
 use super::Configuration;
+use super::options::CONFIG_OPTIONS;
 
 /// Resolves configuration from a collection of key value strings.
 ///
@@ -25,26 +26,41 @@ use super::Configuration;
 /// // check config_result.diagnostics here and use config_result.config
 /// ```
 pub fn resolve_config(config: HashMap<String, String>, global_config: &GlobalConfiguration) -> ResolveConfigurationResult<Configuration> {
-    let mut diagnostics = Vec::new();
-    let mut config = config;
+    let (config, resolved_options) = resolve_config_options(config, global_config);
 
+    ResolveConfigurationResult {
+        config,
+        diagnostics: resolved_options.diagnostics,
+    }
+}
+
+/// Resolves configuration from a collection of key value strings along with the
+/// resolved values of each of the declared `CONFIG_OPTIONS`.
+pub fn resolve_config_options(config: HashMap<String, String>, global_config: &GlobalConfiguration) -> (Configuration, ResolvedConfigOptions) {
+    let mut resolver = ConfigOptionResolver::new(CONFIG_OPTIONS, config, global_config);
+
+    let use_tabs: AutoValue<bool> = resolver.get("useTabs");
+    let indent_width: AutoValue<u8> = resolver.get("indentWidth");
     let resolved_config = Configuration {
-        line_width: get_value(&mut config, "lineWidth", global_config.line_width.unwrap_or(DEFAULT_GLOBAL_CONFIGURATION.line_width), &mut diagnostics),
-        use_tabs: get_value(&mut config, "useTabs", global_config.use_tabs.unwrap_or(DEFAULT_GLOBAL_CONFIGURATION.use_tabs), &mut diagnostics),
-        indent_width: get_value(&mut config, "indentWidth", global_config.indent_width.unwrap_or(DEFAULT_GLOBAL_CONFIGURATION.indent_width), &mut diagnostics),
-        new_line_kind: get_value(&mut config, "newLineKind", global_config.new_line_kind.unwrap_or(DEFAULT_GLOBAL_CONFIGURATION.new_line_kind), &mut diagnostics),
-        comment_line_force_space_after_slashes: get_value(&mut config, "commentLine.forceSpaceAfterSlashes", true, &mut diagnostics),
+        line_width: resolver.get("lineWidth"),
+        use_tabs: use_tabs.value()
+            .or_else(|| global_config.use_tabs.and_then(AutoValue::value))
+            .unwrap_or(DEFAULT_GLOBAL_CONFIGURATION.use_tabs),
+        indent_width: indent_width.value()
+            .or_else(|| global_config.indent_width.and_then(AutoValue::value))
+            .unwrap_or(DEFAULT_GLOBAL_CONFIGURATION.indent_width),
+        auto_use_tabs: use_tabs == AutoValue::Auto,
+        auto_indent_width: indent_width == AutoValue::Auto,
+        new_line_kind: resolver.get("newLineKind"),
+        unicode_width: resolver.get("unicodeWidth"),
+        max_condition_evaluations: resolver.get("maxConditionEvaluations"),
+        max_visits_per_node: resolver.get("maxVisitsPerNode"),
+        array_fill: resolver.get("array.fill"),
+        comment_block_align_gutters: resolver.get("commentBlock.alignGutters"),
+        comment_block_reflow: resolver.get("commentBlock.reflow"),
+        comment_line_force_space_after_slashes: resolver.get("commentLine.forceSpaceAfterSlashes"),
+        verify_semantic_equivalence: resolver.get("verifySemanticEquivalence"),
     };
 
-    for (key, _) in config.iter() {
-        diagnostics.push(ConfigurationDiagnostic {
-            property_name: String::from(key),
-            message: format!("Unknown property in configuration: {}", key),
-        });
-    }
-
-    ResolveConfigurationResult {
-        config: resolved_config,
-        diagnostics,
-    }
+    (resolved_config, resolver.finish())
 }
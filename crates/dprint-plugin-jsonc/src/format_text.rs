@@ -1,22 +1,128 @@
-use dprint_core::{print, PrintOptions};
-use dprint_core::configuration::resolve_new_line_kind;
+use dprint_core::{print, print_equals, print_with_long_lines, print_with_mapping, FormatError, LongLine, PositionMap, PrintBudget, PrintItemsArena, PrintOptions};
+use std::borrow::Cow;
+use dprint_core::configuration::{resolve_new_line_kind, detect_indent_width, detect_use_tabs};
+use jsonc_parser::{parse_text as parse_jsonc_ast, ParseResult};
+use super::ast_equivalence::is_ast_equivalent;
 use super::configuration::Configuration;
 use super::parser::parse_items;
 
-pub fn format_text(text: &str, config: &Configuration) -> Result<String, String> {
-    let print_items = parse_items(text, config)?;
+pub fn format_text(text: &str, config: &Configuration) -> Result<String, FormatError> {
+    let parse_result = parse_text(text)?;
+    let config = &resolve_auto_indentation(config, text);
+    let arena = PrintItemsArena::new();
+    let formatted_text = print(parse_items(&arena, &parse_result, text, config), get_print_options(text, config))?;
 
-    Ok(print(print_items, PrintOptions {
+    if config.verify_semantic_equivalence {
+        verify_semantic_equivalence(&parse_result, &formatted_text)?;
+    }
+
+    Ok(formatted_text)
+}
+
+/// Formats the text and gets a map of the positions in the original text to the positions in the formatted text.
+pub fn format_text_with_mapping(text: &str, config: &Configuration) -> Result<(String, PositionMap), FormatError> {
+    let parse_result = parse_text(text)?;
+    let config = &resolve_auto_indentation(config, text);
+    let arena = PrintItemsArena::new();
+    let (formatted_text, position_map) = print_with_mapping(parse_items(&arena, &parse_result, text, config), get_print_options(text, config))?;
+
+    if config.verify_semantic_equivalence {
+        verify_semantic_equivalence(&parse_result, &formatted_text)?;
+    }
+
+    Ok((formatted_text, position_map))
+}
+
+/// Formats the text also getting the lines that are still wider than the line width.
+pub fn format_text_with_long_lines(text: &str, config: &Configuration) -> Result<(String, Vec<LongLine>), FormatError> {
+    let parse_result = parse_text(text)?;
+    let config = &resolve_auto_indentation(config, text);
+    let arena = PrintItemsArena::new();
+    let (formatted_text, long_lines) = print_with_long_lines(parse_items(&arena, &parse_result, text, config), get_print_options(text, config))?;
+
+    if config.verify_semantic_equivalence {
+        verify_semantic_equivalence(&parse_result, &formatted_text)?;
+    }
+
+    Ok((formatted_text, long_lines))
+}
+
+/// Gets if formatting the text would leave it unchanged.
+///
+/// The formatted text is only created when the semantic equivalence of it needs to be verified.
+pub fn format_text_equals(text: &str, config: &Configuration) -> Result<bool, FormatError> {
+    if config.verify_semantic_equivalence {
+        return format_text(text, config).map(|formatted_text| formatted_text == text);
+    }
+
+    let parse_result = parse_text(text)?;
+    let config = &resolve_auto_indentation(config, text);
+    let arena = PrintItemsArena::new();
+    Ok(print_equals(parse_items(&arena, &parse_result, text, config), get_print_options(text, config), text)?)
+}
+
+/// Gets the print items of the text as an indented tree for debugging.
+pub fn get_print_ir(text: &str, config: &Configuration) -> Result<String, FormatError> {
+    let parse_result = parse_text(text)?;
+    let config = &resolve_auto_indentation(config, text);
+    let arena = PrintItemsArena::new();
+    Ok(parse_items(&arena, &parse_result, text, config).get_as_text())
+}
+
+/// Gets the configuration with the indentation detected from the text when it's `auto`.
+fn resolve_auto_indentation<'a>(config: &'a Configuration, text: &str) -> Cow<'a, Configuration> {
+    if !config.auto_indent_width && !config.auto_use_tabs {
+        return Cow::Borrowed(config);
+    }
+
+    let mut config = config.clone();
+    if config.auto_indent_width {
+        config.indent_width = detect_indent_width(text).unwrap_or(config.indent_width);
+    }
+    if config.auto_use_tabs {
+        config.use_tabs = detect_use_tabs(text).unwrap_or(config.use_tabs);
+    }
+    Cow::Owned(config)
+}
+
+fn get_print_options(text: &str, config: &Configuration) -> PrintOptions {
+    PrintOptions {
         indent_width: config.indent_width,
         max_width: config.line_width,
         use_tabs: config.use_tabs,
         new_line_text: resolve_new_line_kind(text, config.new_line_kind),
-    }))
+        unicode_width: config.unicode_width,
+        budget: PrintBudget {
+            max_condition_evaluations: config.max_condition_evaluations,
+            max_visits_per_node: config.max_visits_per_node,
+        },
+    }
+}
+
+fn parse_text(text: &str) -> Result<ParseResult, FormatError> {
+    match parse_jsonc_ast(text) {
+        Ok(result) => Ok(result),
+        Err(err) => Err(FormatError::syntax(err.message, (err.range.start, err.range.end), text)),
+    }
+}
+
+fn verify_semantic_equivalence(original: &ParseResult, formatted_text: &str) -> Result<(), FormatError> {
+    let formatted = match parse_text(formatted_text) {
+        Ok(formatted) => formatted,
+        Err(err) => return Err(FormatError::internal(format!("Refused to format because the formatted output failed to parse. {}", err.get_diagnostic_text(formatted_text)))),
+    };
+
+    if is_ast_equivalent(&original.value, &formatted.value) {
+        Ok(())
+    } else {
+        Err(FormatError::internal(String::from("Refused to format because the formatted output was not semantically equivalent to the original.")))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use dprint_core::configuration::*;
+    use dprint_core::FormatErrorKind;
     use std::collections::HashMap;
     use super::super::configuration::resolve_config;
     use super::*;
@@ -25,15 +131,84 @@ mod tests {
     fn should_error_on_syntax_diagnostic() {
         let global_config = resolve_global_config(HashMap::new()).config;
         let config = resolve_config(HashMap::new(), &global_config).config;
-        let message = format_text("{ &*&* }", &config).err().unwrap();
+        let file_text = "{ &*&* }";
+        let err = format_text(file_text, &config).err().unwrap();
+        assert_eq!(err.kind, FormatErrorKind::Syntax);
         assert_eq!(
-            message,
+            err.get_diagnostic_text(file_text),
             concat!(
                 "Line 1, column 3: Unexpected token\n",
                 "\n",
-                "  { &*&* }\n",
-                "    ~"
+                "  1 | { &*&* }\n",
+                "    |   ~"
             )
         );
     }
+
+    #[test]
+    fn should_map_positions() {
+        let global_config = resolve_global_config(HashMap::new()).config;
+        let config = resolve_config(HashMap::new(), &global_config).config;
+        let text = "{ \"a\" :   [1,  2]   }";
+        let (result, position_map) = format_text_with_mapping(text, &config).unwrap();
+        assert_eq!(result, "{ \"a\": [1, 2] }\n");
+        assert_eq!(position_map.map_position(text.find("[").unwrap()), result.find("[").unwrap());
+        assert_eq!(position_map.map_position(text.find("2").unwrap()), result.find("2").unwrap());
+        assert_eq!(position_map.map_position(text.find("}").unwrap()), result.find("}").unwrap());
+        assert_eq!(position_map.map_position(text.find("a").unwrap()), result.find("a").unwrap());
+    }
+
+    #[test]
+    fn should_use_print_budget_of_configuration() {
+        let mut global_config_map = HashMap::new();
+        global_config_map.insert(String::from("maxVisitsPerNode"), String::from("0"));
+        let global_config = resolve_global_config(global_config_map).config;
+        let config = resolve_config(HashMap::new(), &global_config).config;
+        assert_eq!(config.max_visits_per_node, 0);
+        let err = format_text("{ \"a\": [1, 2] }", &config).err().unwrap();
+        assert_eq!(err.kind, FormatErrorKind::Internal);
+        assert_eq!(err.message.starts_with("Printing exceeded its budget"), true);
+
+        let mut config_map = HashMap::new();
+        config_map.insert(String::from("maxVisitsPerNode"), String::from("1000"));
+        let config = resolve_config(config_map, &global_config).config;
+        assert_eq!(format_text("{ \"a\": [1, 2] }", &config).unwrap(), "{ \"a\": [1, 2] }\n");
+    }
+
+    #[test]
+    fn should_detect_auto_indentation() {
+        let global_config = resolve_global_config(HashMap::new()).config;
+        let mut config_map = HashMap::new();
+        config_map.insert(String::from("indentWidth"), String::from("auto"));
+        config_map.insert(String::from("useTabs"), String::from("auto"));
+        let config = resolve_config(config_map, &global_config).config;
+        assert_eq!(format_text("{\n  \"a\": {\n    \"b\": 1\n  }\n}\n", &config).unwrap(), "{\n  \"a\": {\n    \"b\": 1\n  }\n}\n");
+        assert_eq!(format_text("{\n\t\"a\": 1\n}\n", &config).unwrap(), "{\n\t\"a\": 1\n}\n");
+    }
+
+    #[test]
+    fn should_format_when_verifying_semantic_equivalence() {
+        let global_config = resolve_global_config(HashMap::new()).config;
+        let mut config_map = HashMap::new();
+        config_map.insert(String::from("verifySemanticEquivalence"), String::from("true"));
+        let config = resolve_config(config_map, &global_config).config;
+        let result = format_text("{ \"a\" :   [1,2] // test\n}", &config).unwrap();
+        assert_eq!(result, "{\n    \"a\": [1, 2] // test\n}\n");
+    }
+
+    #[test]
+    fn should_get_if_text_is_formatted() {
+        let global_config = resolve_global_config(HashMap::new()).config;
+        let config = resolve_config(HashMap::new(), &global_config).config;
+        assert!(format_text_equals("{ \"a\": [1, 2] }\n", &config).unwrap());
+        assert!(!format_text_equals("{ \"a\": [1, 2] }", &config).unwrap());
+        assert!(!format_text_equals("{ \"a\" :   [1,  2]   }\n", &config).unwrap());
+        assert_eq!(format_text_equals("{ &*&* }", &config).err().unwrap().kind, FormatErrorKind::Syntax);
+
+        let mut config_map = HashMap::new();
+        config_map.insert(String::from("verifySemanticEquivalence"), String::from("true"));
+        let config = resolve_config(config_map, &global_config).config;
+        assert!(format_text_equals("{ \"a\": [1, 2] }\n", &config).unwrap());
+        assert!(!format_text_equals("{ \"a\" :   [1,  2]   }\n", &config).unwrap());
+    }
 }
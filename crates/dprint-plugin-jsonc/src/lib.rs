@@ -1,7 +1,8 @@
 pub mod configuration;
+mod ast_equivalence;
 mod format_text;
 mod parser;
 mod plugin;
 
-pub use format_text::format_text;
+pub use format_text::{format_text, format_text_equals, format_text_with_long_lines, format_text_with_mapping};
 pub use plugin::JsoncPlugin;
@@ -2,10 +2,12 @@ use std::collections::HashSet;
 use jsonc_parser::ast::*;
 use jsonc_parser::common::{Ranged};
 use jsonc_parser::CommentMap;
+use dprint_core::PrintItemsArena;
 use super::super::configuration::Configuration;
 use super::token_finder::TokenFinder;
 
 pub struct Context<'a> {
+    pub arena: &'a PrintItemsArena<'a>,
     pub config: &'a Configuration,
     pub text: &'a str,
     pub handled_comments: HashSet<usize>,
@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 use dprint_core::*;
-use jsonc_parser::parse_text as parse_jsonc_ast;
+use jsonc_parser::ParseResult;
 use jsonc_parser::ast::*;
 use jsonc_parser::common::{Position, Ranged, Range};
 use jsonc_parser::tokens::{TokenAndRange};
@@ -8,18 +8,9 @@ use super::super::configuration::Configuration;
 use super::context::Context;
 use super::token_finder::TokenFinder;
 
-pub fn parse_items(text: &str, config: &Configuration) -> Result<PrintItems, String> {
-    let parse_result = parse_jsonc_ast(text);
-    let parse_result = match parse_result {
-        Ok(result) => result,
-        Err(err) => return Err(dprint_core::utils::string_utils::format_diagnostic(
-            Some((err.range.start, err.range.end)),
-            &err.message,
-            text
-        )),
-    };
-    let node_value = parse_result.value;
+pub fn parse_items<'a>(arena: &'a PrintItemsArena<'a>, parse_result: &'a ParseResult, text: &'a str, config: &'a Configuration) -> PrintItems<'a> {
     let mut context = Context {
+        arena,
         config,
         text,
         handled_comments: HashSet::new(),
@@ -29,8 +20,8 @@ pub fn parse_items(text: &str, config: &Configuration) -> Result<PrintItems, Str
         token_finder: TokenFinder::new(&parse_result.tokens),
     };
 
-    let mut items = PrintItems::new();
-    if let Some(node_value) = &node_value {
+    let mut items = PrintItems::new(context.arena);
+    if let Some(node_value) = &parse_result.value {
         items.extend(parse_node(node_value.into(), &mut context));
         items.extend(parse_trailing_comments_as_statements(node_value, &mut context));
     } else {
@@ -41,21 +32,21 @@ pub fn parse_items(text: &str, config: &Configuration) -> Result<PrintItems, Str
     items.push_condition(conditions::if_true(
         "endOfFileNewLine",
         |context| Some(context.writer_info.column_number > 0 || context.writer_info.line_number > 0),
-        Signal::NewLine.into()
+        context.arena.items(Signal::NewLine)
     ));
 
-    Ok(items)
+    items
 }
 
-fn parse_node<'a>(node: Node<'a>, context: &mut Context<'a>) -> PrintItems {
+fn parse_node<'a>(node: Node<'a>, context: &mut Context<'a>) -> PrintItems<'a> {
     parse_node_with_inner(node, context, |items, _| items)
 }
 
 fn parse_node_with_inner<'a>(
     node: Node<'a>,
     context: &mut Context<'a>,
-    inner_parse: impl FnOnce(PrintItems, &mut Context<'a>) -> PrintItems
-) -> PrintItems {
+    inner_parse: impl FnOnce(PrintItems<'a>, &mut Context<'a>) -> PrintItems<'a>
+) -> PrintItems<'a> {
     // store info
     let past_current_node = context.current_node.replace(node.clone());
     let parent_end = past_current_node.as_ref().map(|n| n.end());
@@ -67,7 +58,7 @@ fn parse_node_with_inner<'a>(
     }
 
     // parse item
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(context.arena);
 
     // get the leading comments
     if let Some(comments) = context.comments.get(&node.start()) {
@@ -75,11 +66,13 @@ fn parse_node_with_inner<'a>(
     }
 
     // parse the node
-    items.extend(if has_ignore_comment(&node, context) {
-        parser_helpers::parse_raw_string(node.text(context.text))
+    let mut node_items = if has_ignore_comment(&node, context) {
+        parser_helpers::parse_raw_string(context.arena, node.text(context.text))
     } else {
         inner_parse(parse_node_inner(node.clone(), context), context)
-    });
+    };
+    node_items.set_source_position(node.start());
+    items.extend(node_items);
 
     // get the trailing comments
     if is_root || parent_end.is_some() && parent_end.unwrap() != node_end {
@@ -93,12 +86,12 @@ fn parse_node_with_inner<'a>(
     return items;
 
     #[inline]
-    fn parse_node_inner<'a>(node: Node<'a>, context: &mut Context<'a>) -> PrintItems {
+    fn parse_node_inner<'a>(node: Node<'a>, context: &mut Context<'a>) -> PrintItems<'a> {
         match node {
             Node::Array(node) => parse_array(node, context),
-            Node::BooleanLit(node) => node.value.to_string().into(),
-            Node::NullKeyword(_) => "null".into(),
-            Node::NumberLit(node) => node.value.as_ref().into(),
+            Node::BooleanLit(node) => context.arena.items(node.value.to_string()),
+            Node::NullKeyword(_) => context.arena.items("null"),
+            Node::NumberLit(node) => context.arena.items(node.value.as_ref()),
             Node::Object(node) => parse_object(node, context),
             Node::ObjectProp(node) => parse_object_prop(node, context),
             Node::StringLit(node) => parse_string_lit(node, context),
@@ -106,11 +99,12 @@ fn parse_node_with_inner<'a>(
     }
 }
 
-fn parse_array<'a>(node: &'a Array, context: &mut Context<'a>) -> PrintItems {
+fn parse_array<'a>(node: &'a Array, context: &mut Context<'a>) -> PrintItems<'a> {
     let force_multi_lines = node.range.start_line < node.elements.first().map(|p| p.start_line()).unwrap_or(node.range.start_line);
+    let fill = context.config.array_fill && node.elements.iter().all(is_fill_element);
 
     parse_surrounded_by_tokens(|context| {
-        let mut items = PrintItems::new();
+        let mut items = PrintItems::new(context.arena);
         items.extend(parse_comma_separated_values(ParseCommaSeparatedValuesOptions {
             nodes: node.elements.iter().map(|x| Some(x.into())).collect(),
             prefer_hanging: false,
@@ -119,7 +113,10 @@ fn parse_array<'a>(node: &'a Array, context: &mut Context<'a>) -> PrintItems {
             single_line_space_at_start: false,
             single_line_space_at_end: false,
             custom_single_line_separator: None,
-            multi_line_options: parser_helpers::MultiLineOptions::surround_newlines_indented(),
+            multi_line_options: parser_helpers::MultiLineOptions {
+                fill,
+                ..parser_helpers::MultiLineOptions::surround_newlines_indented()
+            },
             force_possible_newline_at_start: false,
         }, context));
         items
@@ -132,11 +129,24 @@ fn parse_array<'a>(node: &'a Array, context: &mut Context<'a>) -> PrintItems {
     }, context)
 }
 
-fn parse_object<'a>(obj: &'a Object, context: &mut Context<'a>) -> PrintItems {
+/// Gets if the array element is a number or short literal, which are packed on each line with `array.fill`.
+fn is_fill_element(value: &Value) -> bool {
+    const MAX_SHORT_LITERAL_WIDTH: usize = 12;
+    match value {
+        Value::NumberLit(_) => true,
+        Value::StringLit(_) | Value::BooleanLit(_) | Value::NullKeyword(_) => {
+            let range = value.range();
+            range.end - range.start <= MAX_SHORT_LITERAL_WIDTH
+        },
+        _ => false,
+    }
+}
+
+fn parse_object<'a>(obj: &'a Object, context: &mut Context<'a>) -> PrintItems<'a> {
     let force_multi_lines = obj.range.start_line < obj.properties.first().map(|p| p.range.start_line).unwrap_or(obj.range.end_line);
 
     parse_surrounded_by_tokens(|context| {
-        let mut items = PrintItems::new();
+        let mut items = PrintItems::new(context.arena);
         items.extend(parse_comma_separated_values(ParseCommaSeparatedValuesOptions {
             nodes: obj.properties.iter().map(|x| Some(Node::ObjectProp(x))).collect(),
             prefer_hanging: false,
@@ -158,8 +168,8 @@ fn parse_object<'a>(obj: &'a Object, context: &mut Context<'a>) -> PrintItems {
     }, context)
 }
 
-fn parse_object_prop<'a>(node: &'a ObjectProp, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_object_prop<'a>(node: &'a ObjectProp, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.extend(parse_node((&node.name).into(), context));
     items.push_str(": ");
     items.extend(parse_node((&node.value).into(), context));
@@ -167,8 +177,8 @@ fn parse_object_prop<'a>(node: &'a ObjectProp, context: &mut Context<'a>) -> Pri
     items
 }
 
-fn parse_string_lit<'a>(node: &'a StringLit, _: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_string_lit<'a>(node: &'a StringLit, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.push_str("\"");
     items.push_str(&node.value.as_ref().replace("\"", "\\\""));
     items.push_str("\"");
@@ -182,7 +192,7 @@ struct ParseCommaSeparatedValuesOptions<'a> {
     allow_blank_lines: bool,
     single_line_space_at_start: bool,
     single_line_space_at_end: bool,
-    custom_single_line_separator: Option<PrintItems>,
+    custom_single_line_separator: Option<PrintItems<'a>>,
     multi_line_options: parser_helpers::MultiLineOptions,
     force_possible_newline_at_start: bool,
 }
@@ -190,7 +200,8 @@ struct ParseCommaSeparatedValuesOptions<'a> {
 fn parse_comma_separated_values<'a>(
     opts: ParseCommaSeparatedValuesOptions<'a>,
     context: &mut Context<'a>
-) -> PrintItems {
+) -> PrintItems<'a> {
+    let arena = context.arena;
     let nodes = opts.nodes;
     let indent_width = context.config.indent_width;
     let compute_lines_span = opts.allow_blank_lines && opts.force_use_new_lines; // save time otherwise
@@ -208,7 +219,7 @@ fn parse_comma_separated_values<'a>(
                 })
             } else { None };
             let items = parser_helpers::new_line_group({
-                let parsed_comma = if i == nodes_count - 1 { PrintItems::new() } else { ",".into() };
+                let parsed_comma = if i == nodes_count - 1 { PrintItems::new(context.arena) } else { context.arena.items(",") };
                 parse_comma_separated_value(value, parsed_comma, context)
             });
             parsed_nodes.push(parser_helpers::ParsedValue {
@@ -226,22 +237,22 @@ fn parse_comma_separated_values<'a>(
         allow_blank_lines: opts.allow_blank_lines,
         single_line_space_at_start: opts.single_line_space_at_start,
         single_line_space_at_end: opts.single_line_space_at_end,
-        single_line_separator: opts.custom_single_line_separator.unwrap_or(Signal::SpaceOrNewLine.into()),
+        single_line_separator: opts.custom_single_line_separator.unwrap_or_else(|| arena.items(Signal::SpaceOrNewLine)),
         indent_width,
         multi_line_options: opts.multi_line_options,
         force_possible_newline_at_start: opts.force_possible_newline_at_start,
     }).items
 }
 
-fn parse_comma_separated_value<'a>(value: Option<Node<'a>>, parsed_comma: PrintItems, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_comma_separated_value<'a>(value: Option<Node<'a>>, parsed_comma: PrintItems<'a>, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     let comma_token = get_comma_token(&value, context);
 
     if let Some(element) = value {
         let parsed_comma = parsed_comma.into_rc_path();
         items.extend(parse_node_with_inner(element, context, move |mut items, _| {
             // this Rc clone is necessary because we can't move the captured parsed_comma out of this closure
-            items.push_optional_path(parsed_comma.clone());
+            items.push_optional_path(parsed_comma);
             items
         }));
     } else {
@@ -273,10 +284,10 @@ struct ParseSurroundedByTokensOptions<'a> {
 }
 
 fn parse_surrounded_by_tokens<'a>(
-    parse_inner: impl FnOnce(&mut Context<'a>) -> PrintItems,
+    parse_inner: impl FnOnce(&mut Context<'a>) -> PrintItems<'a>,
     opts: ParseSurroundedByTokensOptions<'a>,
     context: &mut Context<'a>
-) -> PrintItems {
+) -> PrintItems<'a> {
     let open_token_end = Position::new(opts.range.start + opts.open_token.len(), opts.range.start_line);
     let close_token_start = Position::new(opts.range.end - opts.close_token.len(), opts.range.end_line);
 
@@ -287,7 +298,7 @@ fn parse_surrounded_by_tokens<'a>(
     context.assert_text(close_token_start.range.start, opts.range.end, opts.close_token);
 
     // parse
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(context.arena);
     let open_token_start_line = opts.range.start_line;
 
     items.push_str(opts.open_token);
@@ -312,7 +323,7 @@ fn parse_surrounded_by_tokens<'a>(
                 let had_comments = !condition_resolvers::is_at_same_position(context, &before_trailing_comments_info)?;
                 return Some(had_comments && !context.writer_info.is_start_of_line())
             },
-            Signal::NewLine.into()
+            context.arena.items(Signal::NewLine)
         ));
     } else {
         let is_single_line = open_token_start_line == opts.range.end_line;
@@ -326,6 +337,7 @@ fn parse_surrounded_by_tokens<'a>(
             if has_unhandled_comment(comments.iter(), context) {
                 if is_single_line {
                     let indent_width = context.config.indent_width;
+                    let single_line_separator = context.arena.items(Signal::SpaceOrNewLine);
                     items.extend(parser_helpers::parse_separated_values(|_| {
                         let mut parsed_comments = Vec::new();
                         for c in comments.iter() {
@@ -347,7 +359,7 @@ fn parse_surrounded_by_tokens<'a>(
                         allow_blank_lines: true,
                         single_line_space_at_start: false,
                         single_line_space_at_end: false,
-                        single_line_separator: Signal::SpaceOrNewLine.into(),
+                        single_line_separator,
                         indent_width,
                         multi_line_options: parser_helpers::MultiLineOptions::surround_newlines_indented(),
                         force_possible_newline_at_start: false,
@@ -365,12 +377,14 @@ fn parse_surrounded_by_tokens<'a>(
         }
     }
 
-    items.push_str(opts.close_token);
+    let mut close_token_items = context.arena.items(opts.close_token);
+    close_token_items.set_source_position(close_token_start.start());
+    items.extend(close_token_items);
 
     return items;
 
-    fn parse_first_line_trailing_comment<'a>(open_token_start_line: usize, comments: impl Iterator<Item=&'a Comment>, context: &mut Context) -> PrintItems {
-        let mut items = PrintItems::new();
+    fn parse_first_line_trailing_comment<'a>(open_token_start_line: usize, comments: impl Iterator<Item=&'a Comment>, context: &mut Context<'a>) -> PrintItems<'a> {
+        let mut items = PrintItems::new(context.arena);
         let mut comments = comments;
         if let Some(first_comment) = comments.next() {
             if first_comment.kind() == CommentKind::Line && first_comment.start_line() == open_token_start_line {
@@ -388,19 +402,19 @@ fn parse_surrounded_by_tokens<'a>(
 
 // Comments
 
-fn has_unhandled_comment<'a>(comments: impl Iterator<Item=&'a Comment>, context: &mut Context) -> bool {
+fn has_unhandled_comment<'a>(comments: impl Iterator<Item=&'a Comment>, context: &mut Context<'a>) -> bool {
     comments.filter(|c| !context.has_handled_comment(c)).next().is_some()
 }
 
-fn parse_trailing_comments<'a>(node: &dyn Ranged, context: &mut Context<'a>) -> PrintItems {
+fn parse_trailing_comments<'a>(node: &dyn Ranged, context: &mut Context<'a>) -> PrintItems<'a> {
     if let Some(trailing_comments) = context.comments.get(&node.end()) {
         parse_comments_as_trailing(node, trailing_comments.iter(), context)
     } else {
-        PrintItems::new()
+        PrintItems::new(context.arena)
     }
 }
 
-fn parse_trailing_comments_as_statements<'a>(node: &dyn Ranged, context: &mut Context<'a>) -> PrintItems {
+fn parse_trailing_comments_as_statements<'a>(node: &dyn Ranged, context: &mut Context<'a>) -> PrintItems<'a> {
     let unhandled_comments = get_trailing_comments_as_statements(node, context);
     parse_comments_as_statements(unhandled_comments.into_iter(), Some(node), context)
 }
@@ -418,9 +432,9 @@ fn get_trailing_comments_as_statements<'a>(node: &dyn Ranged, context: &mut Cont
     comments
 }
 
-fn parse_comments_as_statements<'a>(comments: impl Iterator<Item=&'a Comment>, last_node: Option<&dyn Ranged>, context: &mut Context<'a>) -> PrintItems {
+fn parse_comments_as_statements<'a>(comments: impl Iterator<Item=&'a Comment>, last_node: Option<&dyn Ranged>, context: &mut Context<'a>) -> PrintItems<'a> {
     let mut last_node = last_node;
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(context.arena);
     for comment in comments {
         if !context.has_handled_comment(comment) {
             items.extend(parse_comment_based_on_last_node(comment, &last_node, ParseCommentBasedOnLastNodeOptions {
@@ -432,8 +446,8 @@ fn parse_comments_as_statements<'a>(comments: impl Iterator<Item=&'a Comment>, l
     items
 }
 
-fn parse_comments_as_leading<'a>(node: &dyn Ranged, comments: impl Iterator<Item=&'a Comment>, context: &mut Context) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_comments_as_leading<'a>(node: &dyn Ranged, comments: impl Iterator<Item=&'a Comment>, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     let comments = comments.filter(|c| !context.has_handled_comment(c)).collect::<Vec<_>>();
 
     if !comments.is_empty() {
@@ -458,7 +472,7 @@ fn parse_comments_as_leading<'a>(node: &dyn Ranged, comments: impl Iterator<Item
     items
 }
 
-fn parse_comments_as_trailing<'a>(node: &dyn Ranged, comments: impl Iterator<Item=&'a Comment>, context: &mut Context) -> PrintItems {
+fn parse_comments_as_trailing<'a>(node: &dyn Ranged, comments: impl Iterator<Item=&'a Comment>, context: &mut Context<'a>) -> PrintItems<'a> {
     // use the roslyn definition of trailing comments
     let node_end_line = node.end_line();
     let trailing_comments_on_same_line = comments
@@ -466,7 +480,7 @@ fn parse_comments_as_trailing<'a>(node: &dyn Ranged, comments: impl Iterator<Ite
         .collect::<Vec<_>>();
 
     let first_unhandled_comment = trailing_comments_on_same_line.iter().filter(|c| !context.has_handled_comment(c)).next();
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(context.arena);
 
     if let Some(Comment::Block(_)) = first_unhandled_comment {
         items.push_str(" ");
@@ -481,10 +495,10 @@ fn parse_comment_collection<'a>(
     comments: impl Iterator<Item=&'a Comment>,
     last_node: Option<&dyn Ranged>,
     next_node: Option<&dyn Ranged>,
-    context: &mut Context
-) -> PrintItems {
+    context: &mut Context<'a>
+) -> PrintItems<'a> {
     let mut last_node = last_node;
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(context.arena);
     let next_node_start_line = next_node.map(|n| n.start_line());
 
     for comment in comments {
@@ -507,13 +521,13 @@ struct ParseCommentBasedOnLastNodeOptions {
     separate_with_newlines: bool,
 }
 
-fn parse_comment_based_on_last_node(
+fn parse_comment_based_on_last_node<'a>(
     comment: &Comment,
     last_node: &Option<&dyn Ranged>,
     opts: ParseCommentBasedOnLastNodeOptions,
-    context: &mut Context
-) -> PrintItems {
-    let mut items = PrintItems::new();
+    context: &mut Context<'a>
+) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     let mut pushed_ignore_new_lines = false;
 
     if let Some(last_node) = last_node {
@@ -546,7 +560,7 @@ fn parse_comment_based_on_last_node(
     return items;
 }
 
-fn parse_comment(comment: &Comment, context: &mut Context) -> Option<PrintItems> {
+fn parse_comment<'a>(comment: &Comment, context: &mut Context<'a>) -> Option<PrintItems<'a>> {
     // only parse if handled
     if context.has_handled_comment(comment) {
         return None;
@@ -555,20 +569,20 @@ fn parse_comment(comment: &Comment, context: &mut Context) -> Option<PrintItems>
     // mark handled and parse
     context.mark_comment_handled(comment);
     return Some(match comment {
-        Comment::Block(comment) => parse_comment_block(comment),
+        Comment::Block(comment) => parse_comment_block(comment, context),
         Comment::Line(comment) => parse_comment_line(comment, context),
     });
 
-    fn parse_comment_block(comment: &CommentBlock) -> PrintItems {
-        let mut items = PrintItems::new();
-        items.push_str("/*");
-        items.extend(parser_helpers::parse_raw_string(comment.text.as_ref()));
-        items.push_str("*/");
-        items
+    fn parse_comment_block<'a>(comment: &CommentBlock, context: &mut Context<'a>) -> PrintItems<'a> {
+        parser_helpers::parse_js_like_comment_block(context.arena, comment.text.as_ref(), parser_helpers::CommentBlockOptions {
+            align_gutters: context.config.comment_block_align_gutters,
+            wrap_width: if context.config.comment_block_reflow { Some(context.config.line_width) } else { None },
+            unicode_width: context.config.unicode_width,
+        })
     }
 
-    fn parse_comment_line(comment: &CommentLine, context: &mut Context) -> PrintItems {
-        parser_helpers::parse_js_like_comment_line(&comment.text.as_ref(), context.config.comment_line_force_space_after_slashes)
+    fn parse_comment_line<'a>(comment: &CommentLine, context: &mut Context<'a>) -> PrintItems<'a> {
+        parser_helpers::parse_js_like_comment_line(context.arena, &comment.text.as_ref(), context.config.comment_line_force_space_after_slashes)
     }
 }
 
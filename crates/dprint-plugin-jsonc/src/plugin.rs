@@ -1,24 +1,48 @@
 use std::collections::HashMap;
-use dprint_core::configuration::{ConfigurationDiagnostic, ResolveConfigurationResult, GlobalConfiguration};
+use dprint_core::configuration::{ConfigurationDiagnostic, GlobalConfiguration, ResolvedConfigOptions, resolve_config_directive};
 use std::path::PathBuf;
 use dprint_core::plugins::*;
-use super::configuration::{Configuration, resolve_config};
-use super::format_text::format_text;
+use dprint_core::{FormatError, LongLine};
+use super::configuration::{Configuration, resolve_config, resolve_config_options, CONFIG_OPTIONS};
+use super::format_text::{format_text, format_text_equals, format_text_with_long_lines, get_print_ir};
 
 /// JSONC Dprint CLI Plugin.
 pub struct JsoncPlugin {
-    resolve_config_result: Option<ResolveConfigurationResult<Configuration>>,
+    plugin_config: HashMap<String, String>,
+    global_config: Option<GlobalConfiguration>,
+    config: Option<Configuration>,
+    resolved_options: Option<ResolvedConfigOptions>,
 }
 
 impl JsoncPlugin {
     pub fn new() -> JsoncPlugin {
         JsoncPlugin {
-            resolve_config_result: None,
+            plugin_config: HashMap::new(),
+            global_config: None,
+            config: None,
+            resolved_options: None,
         }
     }
 
-    fn get_resolved_config_result(&self) -> &ResolveConfigurationResult<Configuration> {
-        self.resolve_config_result.as_ref().expect("Plugin must be initialized.")
+    fn get_config(&self) -> &Configuration {
+        self.config.as_ref().expect("Plugin must be initialized.")
+    }
+
+    fn get_resolved_options(&self) -> &ResolvedConfigOptions {
+        self.resolved_options.as_ref().expect("Plugin must be initialized.")
+    }
+
+    /// Gets the file's configuration when it has a `dprint-config:` directive or the host provides a line width.
+    fn get_file_config(&self, file_text: &str, line_width: Option<u32>) -> Result<Option<Configuration>, FormatError> {
+        let global_config = self.global_config.as_ref().expect("Plugin must be initialized.");
+        let file_config = resolve_config_directive(file_text, &self.plugin_config, CONFIG_OPTIONS, |config| resolve_config(config, global_config))?;
+        Ok(match line_width {
+            Some(line_width) => Some(Configuration {
+                line_width,
+                ..file_config.unwrap_or_else(|| self.get_config().clone())
+            }),
+            None => file_config,
+        })
     }
 }
 
@@ -28,7 +52,11 @@ impl Plugin for JsoncPlugin {
     fn config_keys(&self) -> Vec<String> { vec![String::from("json"), String::from("jsonc")] }
 
     fn initialize(&mut self, plugin_config: HashMap<String, String>, global_config: &GlobalConfiguration) {
-        self.resolve_config_result = Some(resolve_config(plugin_config, &global_config));
+        let (config, resolved_options) = resolve_config_options(plugin_config.clone(), &global_config);
+        self.config = Some(config);
+        self.resolved_options = Some(resolved_options);
+        self.plugin_config = plugin_config;
+        self.global_config = Some(global_config.clone());
     }
 
     fn should_format_file(&self, file_path: &PathBuf, _: &str) -> bool {
@@ -40,16 +68,34 @@ impl Plugin for JsoncPlugin {
     }
 
     fn get_resolved_config(&self) -> String {
-        let config = &self.get_resolved_config_result().config;
-        serde_json::to_string_pretty(config).unwrap()
+        self.get_resolved_options().to_json_string()
     }
 
     fn get_configuration_diagnostics(&self) -> &Vec<ConfigurationDiagnostic> {
-        &self.get_resolved_config_result().diagnostics
+        &self.get_resolved_options().diagnostics
     }
 
-    fn format_text(&self, _: &PathBuf, file_text: &str) -> Result<String, String> {
-        let config = &self.get_resolved_config_result().config;
+    fn format_text(&self, _: &PathBuf, file_text: &str, host: &FormatHost) -> Result<String, FormatError> {
+        let file_config = self.get_file_config(file_text, host.line_width())?;
+        let config = file_config.as_ref().unwrap_or_else(|| self.get_config());
         format_text(file_text, config)
     }
+
+    fn format_text_equals(&self, _: &PathBuf, file_text: &str, host: &FormatHost) -> Result<bool, FormatError> {
+        let file_config = self.get_file_config(file_text, host.line_width())?;
+        let config = file_config.as_ref().unwrap_or_else(|| self.get_config());
+        format_text_equals(file_text, config)
+    }
+
+    fn get_print_ir(&self, _: &PathBuf, file_text: &str, host: &FormatHost) -> Result<String, FormatError> {
+        let file_config = self.get_file_config(file_text, host.line_width())?;
+        let config = file_config.as_ref().unwrap_or_else(|| self.get_config());
+        get_print_ir(file_text, config)
+    }
+
+    fn format_text_with_long_lines(&self, _: &PathBuf, file_text: &str, host: &FormatHost) -> Result<(String, Vec<LongLine>), FormatError> {
+        let file_config = self.get_file_config(file_text, host.line_width())?;
+        let config = file_config.as_ref().unwrap_or_else(|| self.get_config());
+        format_text_with_long_lines(file_text, config)
+    }
 }
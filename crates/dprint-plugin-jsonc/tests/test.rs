@@ -10,7 +10,7 @@ use std::path::PathBuf;
 use dprint_core::configuration::*;
 use dprint_development::*;
 use dprint_plugin_jsonc::format_text;
-use dprint_plugin_jsonc::configuration::{resolve_config};
+use dprint_plugin_jsonc::configuration::{resolve_config, CONFIG_OPTIONS};
 
 #[test]
 fn test_specs() {
@@ -29,3 +29,9 @@ fn test_specs() {
         }
     )
 }
+
+#[test]
+fn test_resolve_config_with_each_declared_option() {
+    let global_config = resolve_global_config(HashMap::new()).config;
+    ensure_config_options_resolve(CONFIG_OPTIONS, |config| resolve_config(config, &global_config).diagnostics);
+}
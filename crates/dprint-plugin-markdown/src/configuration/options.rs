@@ -0,0 +1,11 @@
+use dprint_core::configuration::*;
+use super::EmphasisKind;
+
+/// The configuration options of the Markdown plugin.
+pub const CONFIG_OPTIONS: &[ConfigOption] = &[
+    ConfigOption::new("lineWidth", ConfigOptionKind::Number, ConfigOptionDefault::Global, "The width lines are reported as long at. Prose is not re-wrapped to fit it."),
+    ConfigOption::new("newLineKind", ConfigOptionKind::Enum(NewLineKind::VALUES), ConfigOptionDefault::Global, "The kind of newline to use."),
+    ConfigOption::new("unicodeWidth", ConfigOptionKind::Bool, ConfigOptionDefault::Global, "Whether to measure text by its display width (ex. CJK characters and emoji take up two columns) instead of its character count."),
+    ConfigOption::new("emphasisKind", ConfigOptionKind::Enum(EmphasisKind::VALUES), ConfigOptionDefault::Value("underscores"), "The character to use for emphasized text (ex. `_text_`)."),
+    ConfigOption::new("strongKind", ConfigOptionKind::Enum(EmphasisKind::VALUES), ConfigOptionDefault::Value("asterisks"), "The character to use for strong text (ex. `**text**`)."),
+];
@@ -0,0 +1,39 @@
+use serde::{Serialize, Deserialize};
+use dprint_core::configuration::*;
+use dprint_core::generate_str_to_from;
+
+/// The character to use for emphasis and strong text.
+#[derive(Clone, PartialEq, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum EmphasisKind {
+    /// Uses asterisks (ex. `*text*`).
+    Asterisks,
+    /// Uses underscores (ex. `_text_`).
+    Underscores,
+}
+
+impl EmphasisKind {
+    pub(crate) fn get_char(&self) -> char {
+        match self {
+            EmphasisKind::Asterisks => '*',
+            EmphasisKind::Underscores => '_',
+        }
+    }
+}
+
+generate_str_to_from![
+    EmphasisKind,
+    [Asterisks, "asterisks"],
+    [Underscores, "underscores"]
+];
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Configuration {
+    /// The width lines are reported as long at. Prose is not re-wrapped to fit it.
+    pub line_width: u32,
+    pub new_line_kind: NewLineKind,
+    pub unicode_width: bool,
+    pub emphasis_kind: EmphasisKind,
+    pub strong_kind: EmphasisKind,
+}
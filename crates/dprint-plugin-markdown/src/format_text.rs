@@ -0,0 +1,45 @@
+use dprint_core::configuration::resolve_new_line_kind;
+use dprint_core::{get_long_lines, FormatError, LongLine};
+use super::configuration::Configuration;
+use super::generation::{generate, Context};
+use super::parser::parse_markdown;
+
+/// Formats markdown text.
+///
+/// The `format_code_block_text` function receives the tag of a fenced code block (ex. `ts`) along
+/// with its code and the line width remaining after the code block's indentation (ex. in a list item).
+/// It should return `Ok(None)` when it doesn't handle that tag. The code is kept as-is
+/// when this returns `Ok(None)` or an error.
+pub fn format_text(
+    file_text: &str,
+    config: &Configuration,
+    format_code_block_text: impl Fn(&str, &str, u32) -> Result<Option<String>, FormatError>,
+) -> Result<String, FormatError> {
+    let blocks = parse_markdown(file_text);
+    let context = Context {
+        config,
+        format_code_block_text: &format_code_block_text,
+        indent_width: 0,
+    };
+    let text = generate(&blocks, &context);
+
+    if text.is_empty() {
+        return Ok(String::new());
+    }
+
+    let new_line_text = resolve_new_line_kind(file_text, config.new_line_kind);
+    Ok(format!("{}\n", text).replace("\n", new_line_text))
+}
+
+/// Formats markdown text also getting the lines of the formatted text that are wider than the line width.
+///
+/// Prose is not re-wrapped, so this reports any line with a word past the line width.
+pub fn format_text_with_long_lines(
+    file_text: &str,
+    config: &Configuration,
+    format_code_block_text: impl Fn(&str, &str, u32) -> Result<Option<String>, FormatError>,
+) -> Result<(String, Vec<LongLine>), FormatError> {
+    let formatted_text = format_text(file_text, config, format_code_block_text)?;
+    let long_lines = get_long_lines(&formatted_text, config.line_width, 4, config.unicode_width);
+    Ok((formatted_text, long_lines))
+}
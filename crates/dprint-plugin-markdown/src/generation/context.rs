@@ -0,0 +1,24 @@
+use dprint_core::FormatError;
+use super::super::configuration::Configuration;
+
+/// Formats the code of a code block based on its tag (ex. `ts`) and the line width remaining
+/// after the indentation of the code block.
+pub type FormatCodeBlockText<'a> = &'a dyn Fn(&str, &str, u32) -> Result<Option<String>, FormatError>;
+
+#[derive(Clone, Copy)]
+pub struct Context<'a> {
+    pub config: &'a Configuration,
+    pub format_code_block_text: FormatCodeBlockText<'a>,
+    /// The width of the text the containing blocks prefix each line with (ex. list item indentation).
+    pub indent_width: u32,
+}
+
+impl<'a> Context<'a> {
+    /// Gets the context of the blocks within a block that prefixes each line with the provided width.
+    pub fn with_indent(&self, width: usize) -> Context<'a> {
+        Context {
+            indent_width: self.indent_width + width as u32,
+            ..*self
+        }
+    }
+}
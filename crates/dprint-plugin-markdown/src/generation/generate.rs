@@ -0,0 +1,146 @@
+use super::super::parser::*;
+use super::context::Context;
+use super::inline::normalize_inline;
+use super::table::generate_table;
+
+/// Generates the formatted text of the blocks without a trailing newline.
+pub fn generate(blocks: &[Block], context: &Context) -> String {
+    generate_blocks(blocks, "\n\n", context)
+}
+
+fn generate_blocks(blocks: &[Block], separator: &str, context: &Context) -> String {
+    let mut result = String::new();
+    let mut last_bullet_char = None;
+
+    for (i, block) in blocks.iter().enumerate() {
+        if i > 0 {
+            result.push_str(separator);
+        }
+
+        // alternate the bullet character of adjacent lists so they don't become one list
+        let bullet_char = match block {
+            Block::List(List { kind: ListKind::Bullet, .. }) => Some(if last_bullet_char == Some('-') { '*' } else { '-' }),
+            _ => None,
+        };
+        result.push_str(&generate_block(block, bullet_char.unwrap_or('-'), context));
+        last_bullet_char = bullet_char;
+    }
+
+    result
+}
+
+fn generate_block(block: &Block, bullet_char: char, context: &Context) -> String {
+    match block {
+        Block::FrontMatter(text) | Block::Html(text) => text.clone(),
+        Block::Heading(heading) => generate_heading(heading, context),
+        Block::Paragraph(paragraph) => normalize_inline(&paragraph.text, context),
+        Block::List(list) => generate_list(list, bullet_char, context),
+        Block::CodeBlock(code_block) => generate_code_block(code_block, context),
+        Block::BlockQuote(block_quote) => generate_block_quote(block_quote, context),
+        Block::Table(table) => generate_table(table, context),
+        Block::ThematicBreak => String::from("---"),
+    }
+}
+
+fn generate_heading(heading: &Heading, context: &Context) -> String {
+    let hashes = "#".repeat(heading.level);
+    let text = normalize_inline(&heading.text, context);
+
+    if text.is_empty() {
+        hashes
+    } else {
+        format!("{} {}", hashes, text)
+    }
+}
+
+fn generate_list(list: &List, bullet_char: char, context: &Context) -> String {
+    let separator = if list.is_loose { "\n\n" } else { "\n" };
+    let mut result = String::new();
+
+    for (i, item) in list.items.iter().enumerate() {
+        if i > 0 {
+            result.push_str(separator);
+        }
+
+        let marker = match list.kind {
+            ListKind::Bullet => bullet_char.to_string(),
+            ListKind::Ordered { start, delimiter, is_same_number } => {
+                let number = if is_same_number { start } else { start + i as u64 };
+                format!("{}{}", number, delimiter)
+            },
+        };
+        let indent_text = " ".repeat(marker.len() + 1);
+        let text = generate_blocks(&item.blocks, separator, &context.with_indent(indent_text.len()));
+
+        result.push_str(&marker);
+        for (line_index, line) in text.split('\n').enumerate() {
+            if line_index > 0 {
+                result.push('\n');
+                if !line.is_empty() {
+                    result.push_str(&indent_text);
+                }
+            } else if !line.is_empty() {
+                result.push(' ');
+            }
+            result.push_str(line);
+        }
+    }
+
+    result
+}
+
+fn generate_code_block(code_block: &CodeBlock, context: &Context) -> String {
+    let info = match &code_block.info {
+        Some(info) => info,
+        None => {
+            return code_block.text.split('\n')
+                .map(|line| if line.is_empty() { String::new() } else { format!("    {}", line) })
+                .collect::<Vec<_>>()
+                .join("\n");
+        },
+    };
+    let text = format_code_block_text(info, &code_block.text, context);
+    let fence = get_fence(info, &text);
+    let mut result = format!("{}{}\n", fence, info);
+
+    if !text.is_empty() {
+        result.push_str(&text);
+        result.push('\n');
+    }
+    result.push_str(&fence);
+
+    result
+}
+
+/// Formats the code with the plugin for the code block's tag or returns it as-is
+/// when it couldn't be formatted.
+fn format_code_block_text(info: &str, text: &str, context: &Context) -> String {
+    let tag = info.split_whitespace().next().unwrap_or("");
+    if tag.is_empty() || text.trim().is_empty() {
+        return String::from(text);
+    }
+
+    let line_width = context.config.line_width.saturating_sub(context.indent_width);
+    match (context.format_code_block_text)(tag, text, line_width) {
+        Ok(Some(formatted_text)) => String::from(formatted_text.replace("\r\n", "\n").trim_end_matches('\n')),
+        Ok(None) | Err(_) => String::from(text),
+    }
+}
+
+fn get_fence(info: &str, text: &str) -> String {
+    let fence_char = if info.contains('`') { '~' } else { '`' };
+    let max_run_length = text.split('\n')
+        .map(|line| line.trim_start().chars().take_while(|c| *c == fence_char).count())
+        .max()
+        .unwrap_or(0);
+
+    fence_char.to_string().repeat(std::cmp::max(3, max_run_length + 1))
+}
+
+fn generate_block_quote(block_quote: &BlockQuote, context: &Context) -> String {
+    generate_blocks(&block_quote.blocks, "\n\n", &context.with_indent("> ".len()))
+        .split('\n')
+        .map(|line| if line.is_empty() { String::from(">") } else { format!("> {}", line) })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
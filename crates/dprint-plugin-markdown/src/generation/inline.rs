@@ -0,0 +1,176 @@
+use super::context::Context;
+
+/// Normalizes the emphasis and strong markers in the inline text.
+pub fn normalize_inline(text: &str, context: &Context) -> String {
+    let chars = text.chars().collect::<Vec<_>>();
+    normalize_chars(&chars, context)
+}
+
+fn normalize_chars(chars: &[char], context: &Context) -> String {
+    let mut result = String::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        if let Some(end) = get_verbatim_end(chars, i) {
+            result.extend(&chars[i..end]);
+            i = end;
+            continue;
+        }
+
+        let c = chars[i];
+        if c == '*' || c == '_' {
+            let run_length = get_run_length(chars, i, c);
+            if run_length <= 2 && can_open(chars, i, run_length, c) {
+                if let Some(close_index) = find_closing_run(chars, i + run_length, run_length, c) {
+                    let inner_chars = &chars[i + run_length..close_index];
+                    let end = close_index + run_length;
+                    let kind = if run_length == 1 { context.config.emphasis_kind } else { context.config.strong_kind };
+                    let inner_text = normalize_chars(inner_chars, context);
+                    let marker_char = kind.get_char();
+                    let marker_char = if marker_char == c || can_use_marker_char(chars, i, end, &inner_text, marker_char) { marker_char } else { c };
+                    let marker = marker_char.to_string().repeat(run_length);
+
+                    result.push_str(&marker);
+                    result.push_str(&inner_text);
+                    result.push_str(&marker);
+                    i = end;
+                    continue;
+                }
+            }
+
+            result.extend(&chars[i..i + run_length]);
+            i += run_length;
+            continue;
+        }
+
+        result.push(c);
+        i += 1;
+    }
+
+    result
+}
+
+/// Gets the end index of text that should be kept as-is (escapes, code spans, link destinations, and autolinks).
+fn get_verbatim_end(chars: &[char], index: usize) -> Option<usize> {
+    match chars[index] {
+        '\\' => Some(std::cmp::min(index + 2, chars.len())),
+        '`' => {
+            let run_length = get_run_length(chars, index, '`');
+            let mut i = index + run_length;
+            while i < chars.len() {
+                if chars[i] == '`' {
+                    let closing_run_length = get_run_length(chars, i, '`');
+                    if closing_run_length == run_length {
+                        return Some(i + closing_run_length);
+                    }
+                    i += closing_run_length;
+                } else {
+                    i += 1;
+                }
+            }
+            Some(index + run_length)
+        },
+        ']' if chars.get(index + 1) == Some(&'(') => {
+            let mut depth = 0;
+            let mut i = index + 1;
+            while i < chars.len() {
+                match chars[i] {
+                    '\\' => i += 1,
+                    '(' => depth += 1,
+                    ')' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            return Some(i + 1);
+                        }
+                    },
+                    _ => {},
+                }
+                i += 1;
+            }
+            None
+        },
+        '<' => {
+            let mut i = index + 1;
+            while i < chars.len() && !chars[i].is_whitespace() {
+                if chars[i] == '>' {
+                    return if i > index + 1 { Some(i + 1) } else { None };
+                }
+                i += 1;
+            }
+            None
+        },
+        _ => None,
+    }
+}
+
+fn find_closing_run(chars: &[char], start: usize, run_length: usize, c: char) -> Option<usize> {
+    let mut i = start;
+
+    while i < chars.len() {
+        if let Some(end) = get_verbatim_end(chars, i) {
+            i = end;
+            continue;
+        }
+
+        if chars[i] == c {
+            let length = get_run_length(chars, i, c);
+            if length == run_length {
+                if i > start && can_close(chars, i, length, c) {
+                    return Some(i);
+                }
+                // skip over nested emphasis of the same kind
+                if can_open(chars, i, length, c) {
+                    if let Some(nested_close_index) = find_closing_run(chars, i + length, length, c) {
+                        i = nested_close_index + length;
+                        continue;
+                    }
+                }
+            }
+            i += length;
+            continue;
+        }
+
+        i += 1;
+    }
+
+    None
+}
+
+fn can_open(chars: &[char], index: usize, run_length: usize, c: char) -> bool {
+    let next_char = chars.get(index + run_length);
+    if next_char.map(|c| c.is_whitespace()).unwrap_or(true) {
+        return false;
+    }
+    c != '_' || index == 0 || !chars[index - 1].is_alphanumeric()
+}
+
+fn can_close(chars: &[char], index: usize, run_length: usize, c: char) -> bool {
+    if index == 0 || chars[index - 1].is_whitespace() {
+        return false;
+    }
+    c != '_' || chars.get(index + run_length).map(|c| !c.is_alphanumeric()).unwrap_or(true)
+}
+
+/// Gets if switching to the marker character won't change how the text is parsed.
+fn can_use_marker_char(chars: &[char], start: usize, end: usize, inner_text: &str, marker_char: char) -> bool {
+    let surrounding_chars = [
+        if start > 0 { chars.get(start - 1) } else { None },
+        chars.get(end),
+    ];
+    let touches_marker_char = surrounding_chars.contains(&Some(&marker_char))
+        || inner_text.starts_with(marker_char)
+        || inner_text.ends_with(marker_char);
+
+    if touches_marker_char {
+        false
+    } else if marker_char == '_' {
+        // underscores can't be used within words
+        surrounding_chars.iter().all(|c| c.map(|c| !c.is_alphanumeric()).unwrap_or(true))
+    } else {
+        true
+    }
+}
+
+fn get_run_length(chars: &[char], index: usize, c: char) -> usize {
+    chars[index..].iter().take_while(|x| **x == c).count()
+}
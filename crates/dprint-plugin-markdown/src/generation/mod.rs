@@ -0,0 +1,7 @@
+mod context;
+mod generate;
+mod inline;
+mod table;
+
+pub use context::Context;
+pub use generate::generate;
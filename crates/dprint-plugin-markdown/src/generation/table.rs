@@ -0,0 +1,60 @@
+use super::super::parser::{Alignment, Table};
+use super::context::Context;
+use super::inline::normalize_inline;
+
+/// Generates the table with its columns aligned.
+pub fn generate_table(table: &Table, context: &Context) -> String {
+    let header = table.header.iter().map(|cell| normalize_inline(cell, context)).collect::<Vec<_>>();
+    let rows = table.rows.iter()
+        .map(|row| row.iter().map(|cell| normalize_inline(cell, context)).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let column_widths = (0..header.len()).map(|column_index| {
+        std::iter::once(&header).chain(rows.iter())
+            .map(|row| get_text_width(&row[column_index]))
+            .max()
+            .unwrap_or(0)
+            .max(3)
+    }).collect::<Vec<_>>();
+
+    let mut lines = Vec::new();
+    lines.push(generate_row(&header, &table.alignments, &column_widths));
+    lines.push(generate_delimiter_row(&table.alignments, &column_widths));
+    for row in rows.iter() {
+        lines.push(generate_row(row, &table.alignments, &column_widths));
+    }
+
+    lines.join("\n")
+}
+
+fn generate_row(cells: &[String], alignments: &[Alignment], column_widths: &[usize]) -> String {
+    let cells = cells.iter().enumerate()
+        .map(|(i, cell)| pad_cell(cell, alignments[i], column_widths[i]))
+        .collect::<Vec<_>>();
+    format!("| {} |", cells.join(" | "))
+}
+
+fn generate_delimiter_row(alignments: &[Alignment], column_widths: &[usize]) -> String {
+    let cells = alignments.iter().zip(column_widths.iter()).map(|(alignment, width)| match alignment {
+        Alignment::None => "-".repeat(*width),
+        Alignment::Left => format!(":{}", "-".repeat(width - 1)),
+        Alignment::Right => format!("{}:", "-".repeat(width - 1)),
+        Alignment::Center => format!(":{}:", "-".repeat(width - 2)),
+    }).collect::<Vec<_>>();
+    format!("| {} |", cells.join(" | "))
+}
+
+fn pad_cell(text: &str, alignment: Alignment, width: usize) -> String {
+    let padding = width - get_text_width(text);
+    match alignment {
+        Alignment::None | Alignment::Left => format!("{}{}", text, " ".repeat(padding)),
+        Alignment::Right => format!("{}{}", " ".repeat(padding), text),
+        Alignment::Center => {
+            let left_padding = padding / 2;
+            format!("{}{}{}", " ".repeat(left_padding), text, " ".repeat(padding - left_padding))
+        },
+    }
+}
+
+fn get_text_width(text: &str) -> usize {
+    text.chars().count()
+}
@@ -0,0 +1,10 @@
+extern crate dprint_core;
+
+pub mod configuration;
+mod format_text;
+mod generation;
+mod parser;
+mod plugin;
+
+pub use format_text::{format_text, format_text_with_long_lines};
+pub use plugin::MarkdownPlugin;
@@ -0,0 +1,67 @@
+/// A block level markdown node.
+pub enum Block {
+    FrontMatter(String),
+    Heading(Heading),
+    Paragraph(Paragraph),
+    List(List),
+    CodeBlock(CodeBlock),
+    BlockQuote(BlockQuote),
+    Table(Table),
+    Html(String),
+    ThematicBreak,
+}
+
+pub struct Heading {
+    pub level: usize,
+    pub text: String,
+}
+
+pub struct Paragraph {
+    /// The lines of the paragraph joined by newlines with the indentation removed.
+    pub text: String,
+}
+
+pub struct List {
+    pub kind: ListKind,
+    /// If the items are separated by blank lines.
+    pub is_loose: bool,
+    pub items: Vec<ListItem>,
+}
+
+pub enum ListKind {
+    Bullet,
+    Ordered {
+        start: u64,
+        delimiter: char,
+        /// If each item should use the start number (ex. `1.` for every item).
+        is_same_number: bool,
+    },
+}
+
+pub struct ListItem {
+    pub blocks: Vec<Block>,
+}
+
+pub struct CodeBlock {
+    /// The info string of a fenced code block or `None` for an indented code block.
+    pub info: Option<String>,
+    pub text: String,
+}
+
+pub struct BlockQuote {
+    pub blocks: Vec<Block>,
+}
+
+pub struct Table {
+    pub header: Vec<String>,
+    pub alignments: Vec<Alignment>,
+    pub rows: Vec<Vec<String>>,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Alignment {
+    None,
+    Left,
+    Center,
+    Right,
+}
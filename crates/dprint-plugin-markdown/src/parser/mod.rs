@@ -0,0 +1,6 @@
+mod ast;
+mod parse_blocks;
+mod utils;
+
+pub use ast::*;
+pub use parse_blocks::parse_markdown;
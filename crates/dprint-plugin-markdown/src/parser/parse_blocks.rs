@@ -0,0 +1,602 @@
+use super::ast::*;
+use super::utils::*;
+
+/// Parses the markdown text into its blocks.
+pub fn parse_markdown(text: &str) -> Vec<Block> {
+    let text = text.replace("\r\n", "\n");
+    let lines = text.split('\n').map(String::from).collect::<Vec<_>>();
+    let mut blocks = Vec::new();
+    let mut start_index = 0;
+
+    if let Some((front_matter, end_index)) = parse_front_matter(&lines) {
+        blocks.push(Block::FrontMatter(front_matter));
+        start_index = end_index;
+    }
+
+    blocks.extend(parse_blocks(&lines[start_index..]).blocks);
+    blocks
+}
+
+fn parse_front_matter(lines: &[String]) -> Option<(String, usize)> {
+    if lines.first().map(|line| line.trim_end()) != Some("---") {
+        return None;
+    }
+
+    for i in 1..lines.len() {
+        let line = lines[i].trim_end();
+        if line == "---" || line == "..." {
+            return Some((lines[0..=i].join("\n"), i + 1));
+        }
+    }
+
+    None
+}
+
+struct ParsedBlocks {
+    blocks: Vec<Block>,
+    /// If any of the blocks were separated by a blank line.
+    has_blank_line_between: bool,
+}
+
+fn parse_blocks(lines: &[String]) -> ParsedBlocks {
+    let mut parser = BlockParser {
+        lines,
+        index: 0,
+    };
+    let mut blocks = Vec::new();
+    let mut has_blank_line_between = false;
+    let mut had_blank_line = false;
+
+    while parser.index < lines.len() {
+        if is_blank(&lines[parser.index]) {
+            parser.index += 1;
+            had_blank_line = true;
+            continue;
+        }
+
+        let block = parser.parse_block();
+        if had_blank_line && !blocks.is_empty() {
+            has_blank_line_between = true;
+        }
+        had_blank_line = false;
+        blocks.push(block);
+    }
+
+    ParsedBlocks {
+        blocks,
+        has_blank_line_between,
+    }
+}
+
+struct BlockParser<'a> {
+    lines: &'a [String],
+    index: usize,
+}
+
+impl<'a> BlockParser<'a> {
+    fn parse_block(&mut self) -> Block {
+        let lines = self.lines;
+        let line = &lines[self.index];
+
+        if get_indent(line) >= 4 {
+            self.parse_indented_code_block()
+        } else if let Some(fence) = parse_fence_start(line) {
+            self.parse_fenced_code_block(fence)
+        } else if let Some(heading) = parse_atx_heading(line) {
+            self.index += 1;
+            Block::Heading(heading)
+        } else if is_thematic_break(line) {
+            self.index += 1;
+            Block::ThematicBreak
+        } else if is_block_quote_start(line) {
+            self.parse_block_quote()
+        } else if let Some(marker) = parse_list_marker(line) {
+            self.parse_list(marker)
+        } else if is_html_block_start(line) {
+            self.parse_html_block()
+        } else if let Some(table) = self.try_parse_table() {
+            Block::Table(table)
+        } else {
+            self.parse_paragraph()
+        }
+    }
+
+    fn parse_indented_code_block(&mut self) -> Block {
+        let lines = self.lines;
+        let mut code_lines = Vec::new();
+
+        while self.index < lines.len() {
+            let line = &lines[self.index];
+            if !is_blank(line) && get_indent(line) < 4 {
+                break;
+            }
+            code_lines.push(remove_indent(line, 4));
+            self.index += 1;
+        }
+
+        // leave the trailing blank lines for the caller
+        while code_lines.last().map(|line| is_blank(line)).unwrap_or(false) {
+            code_lines.pop();
+            self.index -= 1;
+        }
+
+        Block::CodeBlock(CodeBlock {
+            info: None,
+            text: code_lines.join("\n"),
+        })
+    }
+
+    fn parse_fenced_code_block(&mut self, fence: Fence) -> Block {
+        let lines = self.lines;
+        let mut code_lines = Vec::new();
+        self.index += 1;
+
+        while self.index < lines.len() {
+            let line = &lines[self.index];
+            self.index += 1;
+            if is_fence_end(line, &fence) {
+                break;
+            }
+            code_lines.push(remove_indent(line, fence.indent));
+        }
+
+        Block::CodeBlock(CodeBlock {
+            info: Some(fence.info),
+            text: code_lines.join("\n"),
+        })
+    }
+
+    fn parse_block_quote(&mut self) -> Block {
+        let lines = self.lines;
+        let mut quote_lines: Vec<String> = Vec::new();
+
+        while self.index < lines.len() {
+            let line = &lines[self.index];
+            if is_block_quote_start(line) {
+                quote_lines.push(remove_block_quote_marker(line));
+            } else if !is_blank(line) && quote_lines.last().map(|l| !is_blank(l)).unwrap_or(false) && !can_interrupt_paragraph(line) {
+                // lazy continuation line
+                quote_lines.push(String::from(line.trim_start()));
+            } else {
+                break;
+            }
+            self.index += 1;
+        }
+
+        Block::BlockQuote(BlockQuote {
+            blocks: parse_blocks(&quote_lines).blocks,
+        })
+    }
+
+    fn parse_list(&mut self, first_marker: ListMarker) -> Block {
+        let lines = self.lines;
+        let list_marker_kind = first_marker.kind;
+        let mut marker = first_marker;
+        let mut items = Vec::new();
+        let mut numbers = Vec::new();
+        let mut is_loose = false;
+
+        loop {
+            let (parsed_blocks, trailing_blank_line_count) = self.parse_list_item(&marker);
+            if parsed_blocks.has_blank_line_between {
+                is_loose = true;
+            }
+            items.push(ListItem { blocks: parsed_blocks.blocks });
+            if let ListMarkerKind::Ordered { number, .. } = marker.kind {
+                numbers.push(number);
+            }
+
+            let next_marker = lines.get(self.index)
+                .filter(|line| !is_thematic_break(line))
+                .and_then(|line| parse_list_marker(line))
+                .filter(|next_marker| next_marker.kind.is_same_list(&list_marker_kind));
+
+            if let Some(next_marker) = next_marker {
+                if trailing_blank_line_count > 0 {
+                    is_loose = true;
+                }
+                marker = next_marker;
+            } else {
+                // leave the blank lines after the list for the caller
+                self.index -= trailing_blank_line_count;
+                break;
+            }
+        }
+
+        Block::List(List {
+            kind: match list_marker_kind {
+                ListMarkerKind::Bullet(_) => ListKind::Bullet,
+                ListMarkerKind::Ordered { delimiter, .. } => ListKind::Ordered {
+                    start: numbers[0],
+                    delimiter,
+                    is_same_number: numbers.len() > 1 && numbers[0] == numbers[1],
+                },
+            },
+            is_loose,
+            items,
+        })
+    }
+
+    fn parse_list_item(&mut self, marker: &ListMarker) -> (ParsedBlocks, usize) {
+        let lines = self.lines;
+        let mut item_lines = vec![marker.first_line_content.clone()];
+        self.index += 1;
+
+        while self.index < lines.len() {
+            let line = &lines[self.index];
+            if is_blank(line) {
+                item_lines.push(String::new());
+            } else if get_indent(line) >= marker.content_offset {
+                item_lines.push(remove_indent(line, marker.content_offset));
+            } else if item_lines.last().map(|l| !is_blank(l)).unwrap_or(false) && !can_interrupt_paragraph(line) && parse_list_marker(line).is_none() {
+                // lazy continuation line
+                item_lines.push(String::from(line.trim_start()));
+            } else {
+                break;
+            }
+            self.index += 1;
+        }
+
+        let mut trailing_blank_line_count = 0;
+        while item_lines.len() > 1 && item_lines.last().map(|l| is_blank(l)).unwrap_or(false) {
+            item_lines.pop();
+            trailing_blank_line_count += 1;
+        }
+
+        (parse_blocks(&item_lines), trailing_blank_line_count)
+    }
+
+    fn parse_html_block(&mut self) -> Block {
+        let lines = self.lines;
+        let start_index = self.index;
+
+        while self.index < lines.len() && !is_blank(&lines[self.index]) {
+            self.index += 1;
+        }
+
+        Block::Html(lines[start_index..self.index].join("\n"))
+    }
+
+    fn try_parse_table(&mut self) -> Option<Table> {
+        let lines = self.lines;
+        let header_line = &lines[self.index];
+        if !header_line.contains('|') {
+            return None;
+        }
+
+        let alignments = parse_table_delimiter_row(lines.get(self.index + 1)?)?;
+        let header = split_table_row(header_line);
+        if header.len() != alignments.len() {
+            return None;
+        }
+
+        self.index += 2;
+        let mut rows = Vec::new();
+        while self.index < lines.len() {
+            let line = &lines[self.index];
+            if is_blank(line) || !line.contains('|') || can_interrupt_paragraph(line) {
+                break;
+            }
+            let mut cells = split_table_row(line);
+            cells.resize(header.len(), String::new());
+            rows.push(cells);
+            self.index += 1;
+        }
+
+        Some(Table {
+            header,
+            alignments,
+            rows,
+        })
+    }
+
+    fn parse_paragraph(&mut self) -> Block {
+        let lines = self.lines;
+        let mut paragraph_lines = vec![&lines[self.index]];
+        self.index += 1;
+
+        while self.index < lines.len() {
+            let line = &lines[self.index];
+            if is_blank(line) {
+                break;
+            }
+            if let Some(level) = get_setext_heading_level(line) {
+                self.index += 1;
+                return Block::Heading(Heading {
+                    level,
+                    text: paragraph_lines.iter().map(|l| l.trim()).collect::<Vec<_>>().join(" "),
+                });
+            }
+            if can_interrupt_paragraph(line) {
+                break;
+            }
+            paragraph_lines.push(line);
+            self.index += 1;
+        }
+
+        Block::Paragraph(Paragraph {
+            text: get_paragraph_text(&paragraph_lines),
+        })
+    }
+}
+
+fn get_paragraph_text(lines: &[&String]) -> String {
+    let last_index = lines.len() - 1;
+    lines.iter().enumerate().map(|(i, line)| {
+        let line = line.trim_start();
+        let trimmed_line = line.trim_end();
+        // keep hard line breaks
+        if i < last_index && line.ends_with("  ") {
+            format!("{}  ", trimmed_line)
+        } else {
+            String::from(trimmed_line)
+        }
+    }).collect::<Vec<_>>().join("\n")
+}
+
+fn can_interrupt_paragraph(line: &str) -> bool {
+    parse_fence_start(line).is_some()
+        || parse_atx_heading(line).is_some()
+        || is_thematic_break(line)
+        || is_block_quote_start(line)
+        || is_html_block_start(line)
+        || parse_list_marker(line).map(|marker| marker.can_interrupt_paragraph()).unwrap_or(false)
+}
+
+struct Fence {
+    indent: usize,
+    fence_char: char,
+    length: usize,
+    info: String,
+}
+
+fn parse_fence_start(line: &str) -> Option<Fence> {
+    let indent = get_indent(line);
+    if indent >= 4 {
+        return None;
+    }
+
+    let text = line.trim_start();
+    let fence_char = text.chars().next()?;
+    if fence_char != '`' && fence_char != '~' {
+        return None;
+    }
+
+    let length = get_char_run_length(text, fence_char);
+    if length < 3 {
+        return None;
+    }
+
+    let info = text[length..].trim();
+    if fence_char == '`' && info.contains('`') {
+        return None;
+    }
+
+    Some(Fence {
+        indent,
+        fence_char,
+        length,
+        info: String::from(info),
+    })
+}
+
+fn is_fence_end(line: &str, fence: &Fence) -> bool {
+    let text = line.trim();
+    get_indent(line) < 4
+        && get_char_run_length(text, fence.fence_char) >= fence.length
+        && text.chars().all(|c| c == fence.fence_char)
+}
+
+fn parse_atx_heading(line: &str) -> Option<Heading> {
+    if get_indent(line) >= 4 {
+        return None;
+    }
+
+    let text = line.trim();
+    let level = get_char_run_length(text, '#');
+    if level == 0 || level > 6 {
+        return None;
+    }
+
+    let text = &text[level..];
+    if !text.is_empty() && !text.starts_with(' ') && !text.starts_with('\t') {
+        return None;
+    }
+
+    // remove the optional closing sequence
+    let text = text.trim();
+    let text_without_closing = text.trim_end_matches('#');
+    let text = if text_without_closing.is_empty() {
+        ""
+    } else if text_without_closing.ends_with(' ') || text_without_closing.ends_with('\t') {
+        text_without_closing.trim_end()
+    } else {
+        text
+    };
+
+    Some(Heading {
+        level,
+        text: String::from(text),
+    })
+}
+
+fn get_setext_heading_level(line: &str) -> Option<usize> {
+    if get_indent(line) >= 4 {
+        return None;
+    }
+
+    let text = line.trim();
+    if text.is_empty() {
+        None
+    } else if text.chars().all(|c| c == '=') {
+        Some(1)
+    } else if text.chars().all(|c| c == '-') {
+        Some(2)
+    } else {
+        None
+    }
+}
+
+fn is_thematic_break(line: &str) -> bool {
+    if get_indent(line) >= 4 {
+        return false;
+    }
+
+    let text = line.chars().filter(|c| !c.is_whitespace()).collect::<String>();
+    match text.chars().next() {
+        Some(c) if c == '-' || c == '*' || c == '_' => text.len() >= 3 && text.chars().all(|x| x == c),
+        _ => false,
+    }
+}
+
+fn is_block_quote_start(line: &str) -> bool {
+    get_indent(line) < 4 && line.trim_start().starts_with('>')
+}
+
+fn remove_block_quote_marker(line: &str) -> String {
+    let text = &line.trim_start()[1..];
+    // the space after the marker is optional
+    String::from(text.strip_prefix(' ').unwrap_or(text))
+}
+
+fn is_html_block_start(line: &str) -> bool {
+    if get_indent(line) >= 4 {
+        return false;
+    }
+
+    let mut chars = line.trim_start().chars();
+    chars.next() == Some('<')
+        && chars.next().map(|c| c.is_ascii_alphabetic() || c == '/' || c == '!' || c == '?').unwrap_or(false)
+}
+
+#[derive(Clone, Copy)]
+enum ListMarkerKind {
+    Bullet(char),
+    Ordered { number: u64, delimiter: char },
+}
+
+impl ListMarkerKind {
+    fn is_same_list(&self, other: &ListMarkerKind) -> bool {
+        match (self, other) {
+            (ListMarkerKind::Bullet(a), ListMarkerKind::Bullet(b)) => a == b,
+            (ListMarkerKind::Ordered { delimiter: a, .. }, ListMarkerKind::Ordered { delimiter: b, .. }) => a == b,
+            _ => false,
+        }
+    }
+}
+
+struct ListMarker {
+    kind: ListMarkerKind,
+    /// The column the item's content starts at.
+    content_offset: usize,
+    first_line_content: String,
+}
+
+impl ListMarker {
+    fn can_interrupt_paragraph(&self) -> bool {
+        if is_blank(&self.first_line_content) {
+            return false;
+        }
+
+        match self.kind {
+            ListMarkerKind::Bullet(_) => true,
+            ListMarkerKind::Ordered { number, .. } => number == 1,
+        }
+    }
+}
+
+fn parse_list_marker(line: &str) -> Option<ListMarker> {
+    let indent = get_indent(line);
+    if indent >= 4 {
+        return None;
+    }
+
+    let text = line.trim_start();
+    let first_char = text.chars().next()?;
+    let (kind, marker_width) = if first_char == '-' || first_char == '*' || first_char == '+' {
+        (ListMarkerKind::Bullet(first_char), 1)
+    } else {
+        let digit_count = text.chars().take_while(|c| c.is_ascii_digit()).count();
+        if digit_count == 0 || digit_count > 9 {
+            return None;
+        }
+        let delimiter = text[digit_count..].chars().next()?;
+        if delimiter != '.' && delimiter != ')' {
+            return None;
+        }
+        (ListMarkerKind::Ordered { number: text[..digit_count].parse().ok()?, delimiter }, digit_count + 1)
+    };
+
+    let text_after_marker = &text[marker_width..];
+    let has_content = !is_blank(text_after_marker);
+    if has_content && !text_after_marker.starts_with(' ') && !text_after_marker.starts_with('\t') {
+        return None;
+    }
+
+    let marker_end = indent + marker_width;
+    let space_count = get_indent(text_after_marker);
+    let (content_offset, first_line_content) = if !has_content {
+        (marker_end + 1, String::new())
+    } else if space_count > 4 {
+        // the content is an indented code block
+        (marker_end + 1, remove_indent(text_after_marker, 1))
+    } else {
+        (marker_end + space_count, remove_indent(text_after_marker, space_count))
+    };
+
+    Some(ListMarker {
+        kind,
+        content_offset,
+        first_line_content,
+    })
+}
+
+fn parse_table_delimiter_row(line: &str) -> Option<Vec<Alignment>> {
+    if !line.contains('-') {
+        return None;
+    }
+
+    let mut alignments = Vec::new();
+    for cell in split_table_row(line) {
+        let dashes = cell.trim_start_matches(':').trim_end_matches(':');
+        if dashes.is_empty() || !dashes.chars().all(|c| c == '-') {
+            return None;
+        }
+        alignments.push(match (cell.starts_with(':'), cell.ends_with(':')) {
+            (true, true) => Alignment::Center,
+            (true, false) => Alignment::Left,
+            (false, true) => Alignment::Right,
+            (false, false) => Alignment::None,
+        });
+    }
+
+    Some(alignments)
+}
+
+fn split_table_row(line: &str) -> Vec<String> {
+    let text = line.trim();
+    let text = text.strip_prefix('|').unwrap_or(text);
+    let text = if text.ends_with('|') && !text.ends_with("\\|") { &text[..text.len() - 1] } else { text };
+    let mut cells = Vec::new();
+    let mut current_cell = String::new();
+    let mut chars = text.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                current_cell.push(c);
+                if let Some(next_char) = chars.next() {
+                    current_cell.push(next_char);
+                }
+            },
+            '|' => {
+                cells.push(String::from(current_cell.trim()));
+                current_cell.clear();
+            },
+            _ => current_cell.push(c),
+        }
+    }
+
+    cells.push(String::from(current_cell.trim()));
+    cells
+}
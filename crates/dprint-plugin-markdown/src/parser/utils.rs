@@ -0,0 +1,43 @@
+const TAB_WIDTH: usize = 4;
+
+pub fn is_blank(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+/// Gets the column width of the leading whitespace.
+pub fn get_indent(line: &str) -> usize {
+    let mut column = 0;
+    for c in line.chars() {
+        match c {
+            ' ' => column += 1,
+            '\t' => column += TAB_WIDTH - (column % TAB_WIDTH),
+            _ => break,
+        }
+    }
+    column
+}
+
+/// Removes up to the specified column width of leading whitespace.
+pub fn remove_indent(line: &str, width: usize) -> String {
+    let mut column = 0;
+    for (byte_index, c) in line.char_indices() {
+        if column >= width {
+            return String::from(&line[byte_index..]);
+        }
+        match c {
+            ' ' => column += 1,
+            '\t' => column += TAB_WIDTH - (column % TAB_WIDTH),
+            _ => return String::from(&line[byte_index..]),
+        }
+        if column > width {
+            // a tab went past the width, so keep the remainder as spaces
+            return format!("{}{}", " ".repeat(column - width), &line[byte_index + 1..]);
+        }
+    }
+    String::new()
+}
+
+/// Gets the length of the run of the specified character at the start of the text.
+pub fn get_char_run_length(text: &str, c: char) -> usize {
+    text.chars().take_while(|x| *x == c).count()
+}
@@ -0,0 +1,80 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use dprint_core::configuration::{ConfigurationDiagnostic, GlobalConfiguration, ResolvedConfigOptions};
+use std::path::PathBuf;
+use dprint_core::plugins::*;
+use dprint_core::{FormatError, LongLine};
+use super::configuration::{Configuration, resolve_config_options};
+use super::format_text::{format_text, format_text_with_long_lines};
+
+/// Markdown Dprint CLI Plugin.
+pub struct MarkdownPlugin {
+    config: Option<Configuration>,
+    resolved_options: Option<ResolvedConfigOptions>,
+}
+
+impl MarkdownPlugin {
+    pub fn new() -> MarkdownPlugin {
+        MarkdownPlugin {
+            config: None,
+            resolved_options: None,
+        }
+    }
+
+    fn get_resolved_options(&self) -> &ResolvedConfigOptions {
+        self.resolved_options.as_ref().expect("Plugin must be initialized.")
+    }
+
+    /// Gets the configuration with the line width of the host when the text is embedded in another file.
+    fn get_config(&self, host: &FormatHost) -> Cow<'_, Configuration> {
+        let config = self.config.as_ref().expect("Plugin must be initialized.");
+        match host.line_width() {
+            Some(line_width) => Cow::Owned(Configuration { line_width, ..config.clone() }),
+            None => Cow::Borrowed(config),
+        }
+    }
+}
+
+impl Default for MarkdownPlugin {
+    fn default() -> Self {
+        MarkdownPlugin::new()
+    }
+}
+
+impl Plugin for MarkdownPlugin {
+    fn name(&self) -> &'static str { env!("CARGO_PKG_NAME") }
+    fn version(&self) -> &'static str { env!("CARGO_PKG_VERSION") }
+    fn config_keys(&self) -> Vec<String> { vec![String::from("markdown")] }
+
+    fn initialize(&mut self, plugin_config: HashMap<String, String>, global_config: &GlobalConfiguration) {
+        let (config, resolved_options) = resolve_config_options(plugin_config, global_config);
+        self.config = Some(config);
+        self.resolved_options = Some(resolved_options);
+    }
+
+    fn should_format_file(&self, file_path: &PathBuf, _: &str) -> bool {
+        if let Some(ext) = file_path.extension().and_then(|e| e.to_str()) {
+            matches!(String::from(ext).to_lowercase().as_str(), "md" | "markdown")
+        } else {
+            false
+        }
+    }
+
+    fn get_resolved_config(&self) -> String {
+        self.get_resolved_options().to_json_string()
+    }
+
+    fn get_configuration_diagnostics(&self) -> &Vec<ConfigurationDiagnostic> {
+        &self.get_resolved_options().diagnostics
+    }
+
+    fn format_text(&self, _: &PathBuf, file_text: &str, host: &FormatHost) -> Result<String, FormatError> {
+        let config = self.get_config(host);
+        format_text(file_text, &config, |tag, text, line_width| host.format_text(tag, text, "", Some(line_width)))
+    }
+
+    fn format_text_with_long_lines(&self, _: &PathBuf, file_text: &str, host: &FormatHost) -> Result<(String, Vec<LongLine>), FormatError> {
+        let config = self.get_config(host);
+        format_text_with_long_lines(file_text, &config, |tag, text, line_width| host.format_text(tag, text, "", Some(line_width)))
+    }
+}
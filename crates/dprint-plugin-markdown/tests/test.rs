@@ -0,0 +1,69 @@
+extern crate dprint_plugin_markdown;
+extern crate dprint_development;
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use dprint_core::configuration::*;
+use dprint_development::*;
+use dprint_plugin_markdown::{format_text, format_text_with_long_lines};
+use dprint_plugin_markdown::configuration::{resolve_config, CONFIG_OPTIONS};
+
+#[test]
+fn test_specs() {
+    let global_config = resolve_global_config(HashMap::new()).config;
+    let json_config = dprint_plugin_jsonc::configuration::resolve_config(HashMap::new(), &global_config).config;
+
+    run_specs(
+        &PathBuf::from("./tests/specs"),
+        &ParseSpecOptions { default_file_name: "file.md" },
+        &RunSpecsOptions { fix_failures: false, format_twice: true },
+        move |_, file_text, spec_config| {
+            let config_result = resolve_config(spec_config.clone(), &global_config);
+            ensure_no_diagnostics(&config_result.diagnostics);
+
+            format_text(file_text, &config_result.config, |tag, text, line_width| {
+                if tag == "json" {
+                    let json_config = dprint_plugin_jsonc::configuration::Configuration { line_width, ..json_config.clone() };
+                    dprint_plugin_jsonc::format_text(text, &json_config).map(Some)
+                } else {
+                    Ok(None)
+                }
+            })
+        }
+    )
+}
+
+#[test]
+fn test_format_text_with_long_lines() {
+    let global_config = resolve_global_config(HashMap::new()).config;
+    let mut config = HashMap::new();
+    config.insert(String::from("lineWidth"), String::from("20"));
+    let config = resolve_config(config, &global_config).config;
+    let (text, long_lines) = format_text_with_long_lines("# Title\n\nSome text with a https://example.com link.\n", &config, |_, _, _| Ok(None)).unwrap();
+    assert_eq!(text, "# Title\n\nSome text with a https://example.com link.\n");
+    assert_eq!(long_lines.len(), 1);
+    assert_eq!(long_lines[0].line_number, 3);
+    assert_eq!(long_lines[0].column_number, 18);
+    assert_eq!(long_lines[0].width, 42);
+    assert_eq!(long_lines[0].text, Some(String::from("https://example.com")));
+}
+
+#[test]
+fn test_code_block_line_width_is_width_after_indentation() {
+    let global_config = resolve_global_config(HashMap::new()).config;
+    let config = resolve_config(HashMap::new(), &global_config).config;
+    let line_widths = std::cell::RefCell::new(Vec::new());
+    let file_text = "```ts\na\n```\n\n1. item\n\n   ```ts\n   b\n   ```\n\n> ```ts\n> c\n> ```\n";
+    format_text(file_text, &config, |_, _, line_width| {
+        line_widths.borrow_mut().push(line_width);
+        Ok(None)
+    }).unwrap();
+    assert_eq!(line_widths.into_inner(), vec![120, 117, 118]);
+}
+
+#[test]
+fn test_resolve_config_with_each_declared_option() {
+    let global_config = resolve_global_config(HashMap::new()).config;
+    ensure_config_options_resolve(CONFIG_OPTIONS, |config| resolve_config(config, &global_config).diagnostics);
+}
@@ -0,0 +1,13 @@
+use dprint_core::configuration::*;
+
+/// The configuration options of the TOML plugin.
+pub const CONFIG_OPTIONS: &[ConfigOption] = &[
+    ConfigOption::new("lineWidth", ConfigOptionKind::Number, ConfigOptionDefault::Global, "The width of a line the printer will try to stay under. Note that the printer may exceed this width in certain cases."),
+    ConfigOption::new("useTabs", ConfigOptionKind::Bool, ConfigOptionDefault::Global, "Whether to use tabs (true) or spaces (false)."),
+    ConfigOption::new("indentWidth", ConfigOptionKind::Number, ConfigOptionDefault::Global, "The number of spaces for an indent. This option is ignored when using tabs."),
+    ConfigOption::new("newLineKind", ConfigOptionKind::Enum(NewLineKind::VALUES), ConfigOptionDefault::Global, "The kind of newline to use."),
+    ConfigOption::new("unicodeWidth", ConfigOptionKind::Bool, ConfigOptionDefault::Global, "Whether to measure text by its display width (ex. CJK characters and emoji take up two columns) instead of its character count."),
+    ConfigOption::new("maxConditionEvaluations", ConfigOptionKind::Number, ConfigOptionDefault::Global, "The maximum number of times the printer may evaluate a single condition before giving up on formatting a file."),
+    ConfigOption::new("maxVisitsPerNode", ConfigOptionKind::Number, ConfigOptionDefault::Global, "The maximum number of times the printer may visit each print item on average before giving up on formatting a file."),
+    ConfigOption::new("cargo.sortDependencies", ConfigOptionKind::Bool, ConfigOptionDefault::Value("false"), "Whether to sort the entries of the dependency tables in Cargo.toml files alphabetically within each group of lines separated by a blank line."),
+];
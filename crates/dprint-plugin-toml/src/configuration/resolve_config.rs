@@ -0,0 +1,54 @@
+use dprint_core::configuration::*;
+use std::collections::HashMap;
+use super::Configuration;
+use super::options::CONFIG_OPTIONS;
+
+/// Resolves configuration from a collection of key value strings.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashMap;
+/// use dprint_core::configuration::{resolve_global_config};
+/// use dprint_plugin_toml::configuration::{resolve_config};
+///
+/// let config_map = HashMap::new(); // get a collection of key value pairs from somewhere
+/// let global_config_result = resolve_global_config(config_map);
+///
+/// // check global_config_result.diagnostics here...
+///
+/// let toml_config_map = HashMap::new(); // get a collection of k/v pairs from somewhere
+/// let config_result = resolve_config(
+///     toml_config_map,
+///     &global_config_result.config
+/// );
+///
+/// // check config_result.diagnostics here and use config_result.config
+/// ```
+pub fn resolve_config(config: HashMap<String, String>, global_config: &GlobalConfiguration) -> ResolveConfigurationResult<Configuration> {
+    let (config, resolved_options) = resolve_config_options(config, global_config);
+
+    ResolveConfigurationResult {
+        config,
+        diagnostics: resolved_options.diagnostics,
+    }
+}
+
+/// Resolves configuration from a collection of key value strings along with the
+/// resolved values of each of the declared `CONFIG_OPTIONS`.
+pub fn resolve_config_options(config: HashMap<String, String>, global_config: &GlobalConfiguration) -> (Configuration, ResolvedConfigOptions) {
+    let mut resolver = ConfigOptionResolver::new(CONFIG_OPTIONS, config, global_config);
+
+    let resolved_config = Configuration {
+        line_width: resolver.get("lineWidth"),
+        use_tabs: resolver.get("useTabs"),
+        indent_width: resolver.get("indentWidth"),
+        new_line_kind: resolver.get("newLineKind"),
+        unicode_width: resolver.get("unicodeWidth"),
+        max_condition_evaluations: resolver.get("maxConditionEvaluations"),
+        max_visits_per_node: resolver.get("maxVisitsPerNode"),
+        cargo_sort_dependencies: resolver.get("cargo.sortDependencies"),
+    };
+
+    (resolved_config, resolver.finish())
+}
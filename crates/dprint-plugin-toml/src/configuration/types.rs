@@ -0,0 +1,16 @@
+use dprint_core::configuration::NewLineKind;
+use serde::{Serialize, Deserialize};
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Configuration {
+    pub line_width: u32,
+    pub use_tabs: bool,
+    pub indent_width: u8,
+    pub new_line_kind: NewLineKind,
+    pub unicode_width: bool,
+    pub max_condition_evaluations: u32,
+    pub max_visits_per_node: u32,
+    #[serde(rename = "cargo.sortDependencies")]
+    pub cargo_sort_dependencies: bool,
+}
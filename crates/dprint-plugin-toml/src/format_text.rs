@@ -0,0 +1,90 @@
+use std::path::Path;
+use dprint_core::{print, print_equals, print_with_long_lines, FormatError, LongLine, PrintBudget, PrintItems, PrintItemsArena, PrintOptions};
+use dprint_core::configuration::resolve_new_line_kind;
+use super::configuration::Configuration;
+use super::parser::parse_items;
+use super::toml_parser::{parse_text, Document};
+
+/// Formats the TOML text. The file path is used to tell if the file is a _Cargo.toml_ file.
+pub fn format_text(file_path: &Path, text: &str, config: &Configuration) -> Result<String, FormatError> {
+    let arena = PrintItemsArena::new();
+    let print_items = parse_file(file_path, text, config, &arena)?;
+    Ok(print(print_items, get_print_options(text, config))?)
+}
+
+/// Formats the TOML text also getting the lines that are still wider than the line width.
+pub fn format_text_with_long_lines(file_path: &Path, text: &str, config: &Configuration) -> Result<(String, Vec<LongLine>), FormatError> {
+    let arena = PrintItemsArena::new();
+    let print_items = parse_file(file_path, text, config, &arena)?;
+    Ok(print_with_long_lines(print_items, get_print_options(text, config))?)
+}
+
+/// Gets if formatting the TOML text would leave it unchanged without creating the formatted text.
+pub fn format_text_equals(file_path: &Path, text: &str, config: &Configuration) -> Result<bool, FormatError> {
+    let arena = PrintItemsArena::new();
+    let print_items = parse_file(file_path, text, config, &arena)?;
+    Ok(print_equals(print_items, get_print_options(text, config), text)?)
+}
+
+/// Gets the print items of the text as an indented tree for debugging.
+pub fn get_print_ir(file_path: &Path, text: &str, config: &Configuration) -> Result<String, FormatError> {
+    let arena = PrintItemsArena::new();
+    let print_items = parse_file(file_path, text, config, &arena)?;
+    Ok(print_items.get_as_text())
+}
+
+fn get_print_options(text: &str, config: &Configuration) -> PrintOptions {
+    PrintOptions {
+        indent_width: config.indent_width,
+        max_width: config.line_width,
+        use_tabs: config.use_tabs,
+        new_line_text: resolve_new_line_kind(text, config.new_line_kind),
+        unicode_width: config.unicode_width,
+        budget: PrintBudget {
+            max_condition_evaluations: config.max_condition_evaluations,
+            max_visits_per_node: config.max_visits_per_node,
+        },
+    }
+}
+
+/// Parses the text to print items, sorting the dependencies when it's a _Cargo.toml_ file.
+fn parse_file<'a>(file_path: &Path, text: &str, config: &'a Configuration, arena: &'a PrintItemsArena<'a>) -> Result<PrintItems<'a>, FormatError> {
+    let document = parse_document(text)?;
+    let is_cargo_toml = file_path.file_name().and_then(|name| name.to_str()) == Some("Cargo.toml");
+    let sort_dependencies = config.cargo_sort_dependencies && is_cargo_toml;
+    Ok(parse_items(arena, &document, config, sort_dependencies))
+}
+
+fn parse_document(text: &str) -> Result<Document, FormatError> {
+    match parse_text(text) {
+        Ok(document) => Ok(document),
+        Err(err) => Err(FormatError::syntax(err.message, err.range, text)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dprint_core::configuration::*;
+    use std::collections::HashMap;
+    use dprint_core::FormatErrorKind;
+    use super::super::configuration::resolve_config;
+    use super::*;
+
+    #[test]
+    fn should_error_on_syntax_diagnostic() {
+        let global_config = resolve_global_config(HashMap::new()).config;
+        let config = resolve_config(HashMap::new(), &global_config).config;
+        let file_text = "a = &";
+        let err = format_text(Path::new("file.toml"), file_text, &config).err().unwrap();
+        assert_eq!(err.kind, FormatErrorKind::Syntax);
+        assert_eq!(
+            err.get_diagnostic_text(file_text),
+            concat!(
+                "Line 1, column 5: Expected a value.\n",
+                "\n",
+                "  1 | a = &\n",
+                "    |     ~"
+            )
+        );
+    }
+}
@@ -0,0 +1,8 @@
+pub mod configuration;
+mod format_text;
+mod parser;
+mod plugin;
+mod toml_parser;
+
+pub use format_text::format_text;
+pub use plugin::TomlPlugin;
@@ -0,0 +1,273 @@
+use dprint_core::*;
+use super::super::configuration::Configuration;
+use super::super::toml_parser::*;
+
+struct Context<'a> {
+    arena: &'a PrintItemsArena<'a>,
+    config: &'a Configuration,
+}
+
+pub fn parse_items<'a>(arena: &'a PrintItemsArena<'a>, document: &Document, config: &'a Configuration, sort_dependencies: bool) -> PrintItems<'a> {
+    let context = Context { arena, config };
+    let mut items = PrintItems::new(arena);
+    let mut is_dependencies_table = false;
+
+    for (i, group) in get_item_groups(&document.items).into_iter().enumerate() {
+        if i > 0 {
+            items.push_signal(Signal::NewLine);
+            items.push_signal(Signal::NewLine);
+        }
+
+        let mut group_items = Vec::new();
+        let mut last_header_index = 0;
+        for item in group.into_iter() {
+            if let Item::TableHeader(header) = item {
+                if sort_dependencies && is_dependencies_table {
+                    sort_entries(&mut group_items[last_header_index..]);
+                }
+                is_dependencies_table = !header.is_array && header.key.parts.last().map(|p| p.ends_with("dependencies")).unwrap_or(false);
+                last_header_index = group_items.len() + 1;
+            }
+            group_items.push(item);
+        }
+        if sort_dependencies && is_dependencies_table {
+            sort_entries(&mut group_items[last_header_index..]);
+        }
+
+        for (i, item) in group_items.into_iter().enumerate() {
+            if i > 0 {
+                items.push_signal(Signal::NewLine);
+            }
+            items.extend(parse_item(item, &context));
+        }
+    }
+
+    items.push_condition(conditions::if_true(
+        "endOfFileNewLine",
+        |context| Some(context.writer_info.column_number > 0 || context.writer_info.line_number > 0),
+        arena.items(Signal::NewLine)
+    ));
+
+    items
+}
+
+/// Gets the items grouped by the blank lines between them.
+fn get_item_groups(items: &[Item]) -> Vec<Vec<&Item>> {
+    let mut groups: Vec<Vec<&Item>> = Vec::new();
+    let mut last_end_line = None;
+
+    for item in items.iter() {
+        let range = item.range();
+        let is_new_group = match last_end_line {
+            Some(last_end_line) => range.start_line > last_end_line + 1,
+            None => true,
+        };
+        if is_new_group {
+            groups.push(Vec::new());
+        }
+        groups.last_mut().unwrap().push(item);
+        last_end_line = Some(get_item_end_line(item));
+    }
+
+    groups
+}
+
+fn get_item_end_line(item: &Item) -> usize {
+    let trailing_comment = match item {
+        Item::TableHeader(header) => header.trailing_comment.as_ref(),
+        Item::KeyValue(key_value) => key_value.trailing_comment.as_ref(),
+        Item::Comment(_) => None,
+    };
+    trailing_comment.map(|c| c.range.end_line).unwrap_or(item.range().end_line)
+}
+
+/// Sorts the key values by their key while keeping any comments above a
+/// key value with it and the comments at the end of the items at the end.
+fn sort_entries(items: &mut [&Item]) {
+    let mut entries: Vec<Vec<&Item>> = Vec::new();
+    let mut current_entry = Vec::new();
+
+    for item in items.iter() {
+        current_entry.push(*item);
+        if let Item::KeyValue(_) = item {
+            entries.push(std::mem::take(&mut current_entry));
+        }
+    }
+
+    entries.sort_by_cached_key(|entry| match entry.last() {
+        Some(Item::KeyValue(key_value)) => get_sort_text(&key_value.key),
+        _ => String::new(),
+    });
+
+    let sorted_items = entries.into_iter().flatten().chain(current_entry).collect::<Vec<_>>();
+    items.copy_from_slice(&sorted_items);
+}
+
+fn get_sort_text(key: &Key) -> String {
+    key.parts.iter()
+        .map(|part| part.trim_matches(|c| c == '"' || c == '\'').to_lowercase())
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+fn parse_item<'a>(item: &Item, context: &Context<'a>) -> PrintItems<'a> {
+    match item {
+        Item::TableHeader(header) => parse_table_header(header, context),
+        Item::KeyValue(key_value) => parse_key_value(key_value, context),
+        Item::Comment(comment) => parse_comment(comment, context),
+    }
+}
+
+fn parse_table_header<'a>(header: &TableHeader, context: &Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
+    let (open_token, close_token) = if header.is_array { ("[[", "]]") } else { ("[", "]") };
+
+    items.push_str(open_token);
+    items.push_str(&header.key.text());
+    items.push_str(close_token);
+    items.extend(parse_trailing_comment(&header.trailing_comment, context));
+
+    items
+}
+
+fn parse_key_value<'a>(key_value: &KeyValue, context: &Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
+
+    items.push_str(&key_value.key.text());
+    items.push_str(" = ");
+    items.extend(parse_value(&key_value.value, context));
+    items.extend(parse_trailing_comment(&key_value.trailing_comment, context));
+
+    items
+}
+
+fn parse_value<'a>(value: &Value, context: &Context<'a>) -> PrintItems<'a> {
+    match value {
+        Value::Scalar(scalar) => parser_helpers::parse_raw_string(context.arena, &scalar.text),
+        Value::Array(array) => parse_array(array, context),
+        Value::InlineTable(inline_table) => parse_inline_table(inline_table, context),
+    }
+}
+
+fn parse_array<'a>(array: &Array, context: &Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
+    let has_comments = !array.trailing_comments.is_empty()
+        || array.elements.iter().any(|e| !e.leading_comments.is_empty() || e.trailing_comment.is_some());
+
+    items.push_str("[");
+
+    if array.elements.is_empty() {
+        if has_comments {
+            let mut comment_items = PrintItems::new(context.arena);
+            for comment in array.trailing_comments.iter() {
+                comment_items.push_signal(Signal::NewLine);
+                comment_items.extend(parse_comment(comment, context));
+            }
+            items.extend(parser_helpers::with_indent(comment_items));
+            items.push_signal(Signal::NewLine);
+        }
+        items.push_str("]");
+        return items;
+    }
+
+    let force_use_new_lines = has_comments || array.range.start_line < array.elements[0].start_line();
+    let elements_count = array.elements.len();
+
+    items.extend(parser_helpers::parse_separated_values(|is_multi_line_ref| {
+        let is_multi_line = is_multi_line_ref.create_resolver();
+        let mut parsed_values = Vec::new();
+
+        for (i, element) in array.elements.iter().enumerate() {
+            let is_last = i == elements_count - 1;
+            let mut element_items = PrintItems::new(context.arena);
+
+            for comment in element.leading_comments.iter() {
+                element_items.extend(parse_comment(comment, context));
+                element_items.push_signal(Signal::NewLine);
+            }
+            element_items.extend(parse_value(&element.value, context));
+            if is_last {
+                element_items.push_condition(conditions::if_true("trailingCommaIfMultiLine", is_multi_line.clone(), context.arena.items(",")));
+            } else {
+                element_items.push_str(",");
+            }
+            element_items.extend(parse_trailing_comment(&element.trailing_comment, context));
+
+            if is_last {
+                let mut last_end_line = element.end_line();
+                for comment in array.trailing_comments.iter() {
+                    element_items.push_signal(Signal::NewLine);
+                    if comment.range.start_line > last_end_line + 1 {
+                        element_items.push_signal(Signal::NewLine);
+                    }
+                    element_items.extend(parse_comment(comment, context));
+                    last_end_line = comment.range.end_line;
+                }
+            }
+
+            parsed_values.push(parser_helpers::ParsedValue {
+                items: parser_helpers::new_line_group(element_items),
+                lines_span: if force_use_new_lines {
+                    Some(parser_helpers::LinesSpan {
+                        start_line: element.start_line(),
+                        end_line: element.end_line(),
+                    })
+                } else {
+                    None
+                },
+                allow_inline_multi_line: false,
+                allow_inline_single_line: false,
+            });
+        }
+
+        parsed_values
+    }, parser_helpers::ParseSeparatedValuesOptions {
+        prefer_hanging: false,
+        force_use_new_lines,
+        allow_blank_lines: true,
+        single_line_space_at_start: false,
+        single_line_space_at_end: false,
+        single_line_separator: context.arena.items(Signal::SpaceOrNewLine),
+        indent_width: context.config.indent_width,
+        multi_line_options: parser_helpers::MultiLineOptions::surround_newlines_indented(),
+        force_possible_newline_at_start: false,
+    }).items);
+
+    items.push_str("]");
+
+    items
+}
+
+/// Inline tables must be on a single line, so only the spacing is formatted.
+fn parse_inline_table<'a>(inline_table: &InlineTable, context: &Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
+
+    if inline_table.entries.is_empty() {
+        items.push_str("{}");
+        return items;
+    }
+
+    items.push_str("{ ");
+    for (i, entry) in inline_table.entries.iter().enumerate() {
+        if i > 0 {
+            items.push_str(", ");
+        }
+        items.extend(parse_key_value(entry, context));
+    }
+    items.push_str(" }");
+
+    items
+}
+
+fn parse_trailing_comment<'a>(comment: &Option<Comment>, context: &Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
+    if let Some(comment) = comment {
+        items.push_str(" ");
+        items.extend(parse_comment(comment, context));
+    }
+    items
+}
+
+fn parse_comment<'a>(comment: &Comment, context: &Context<'a>) -> PrintItems<'a> {
+    context.arena.items(format!("#{}", comment.text))
+}
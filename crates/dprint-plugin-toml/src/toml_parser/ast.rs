@@ -0,0 +1,111 @@
+#[derive(Clone, PartialEq, Debug)]
+pub struct Range {
+    pub start: usize,
+    pub end: usize,
+    pub start_line: usize,
+    pub end_line: usize,
+}
+
+pub struct Document {
+    pub items: Vec<Item>,
+}
+
+/// A top level line of the document.
+pub enum Item {
+    TableHeader(TableHeader),
+    KeyValue(KeyValue),
+    Comment(Comment),
+}
+
+impl Item {
+    pub fn range(&self) -> &Range {
+        match self {
+            Item::TableHeader(node) => &node.range,
+            Item::KeyValue(node) => &node.range,
+            Item::Comment(node) => &node.range,
+        }
+    }
+}
+
+/// A table header (ex. `[dependencies]`) or array of tables header (ex. `[[bin]]`).
+pub struct TableHeader {
+    pub range: Range,
+    pub key: Key,
+    pub is_array: bool,
+    pub trailing_comment: Option<Comment>,
+}
+
+pub struct KeyValue {
+    pub range: Range,
+    pub key: Key,
+    pub value: Value,
+    pub trailing_comment: Option<Comment>,
+}
+
+/// A dotted key.
+pub struct Key {
+    /// The text of each part of the key including any quotes.
+    pub parts: Vec<String>,
+}
+
+impl Key {
+    pub fn text(&self) -> String {
+        self.parts.join(".")
+    }
+}
+
+pub struct Comment {
+    pub range: Range,
+    /// The text after the `#`.
+    pub text: String,
+}
+
+pub enum Value {
+    Scalar(Scalar),
+    Array(Array),
+    InlineTable(InlineTable),
+}
+
+impl Value {
+    pub fn range(&self) -> &Range {
+        match self {
+            Value::Scalar(node) => &node.range,
+            Value::Array(node) => &node.range,
+            Value::InlineTable(node) => &node.range,
+        }
+    }
+}
+
+/// A string, number, boolean, or date-time as written in the file.
+pub struct Scalar {
+    pub range: Range,
+    pub text: String,
+}
+
+pub struct Array {
+    pub range: Range,
+    pub elements: Vec<ArrayElement>,
+    /// Comments after the last element.
+    pub trailing_comments: Vec<Comment>,
+}
+
+pub struct ArrayElement {
+    pub leading_comments: Vec<Comment>,
+    pub value: Value,
+    pub trailing_comment: Option<Comment>,
+}
+
+impl ArrayElement {
+    pub fn start_line(&self) -> usize {
+        self.leading_comments.first().map(|c| c.range.start_line).unwrap_or(self.value.range().start_line)
+    }
+
+    pub fn end_line(&self) -> usize {
+        self.trailing_comment.as_ref().map(|c| c.range.end_line).unwrap_or(self.value.range().end_line)
+    }
+}
+
+pub struct InlineTable {
+    pub range: Range,
+    pub entries: Vec<KeyValue>,
+}
@@ -0,0 +1,431 @@
+use super::ast::*;
+
+pub struct ParseError {
+    pub range: (usize, usize),
+    pub message: String,
+}
+
+/// Parses the TOML text into a document that keeps its comments.
+pub fn parse_text(text: &str) -> Result<Document, ParseError> {
+    let mut parser = Parser {
+        text,
+        pos: 0,
+        line: 0,
+    };
+    parser.parse_document()
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    pos: usize,
+    line: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn parse_document(&mut self) -> Result<Document, ParseError> {
+        let mut items = Vec::new();
+
+        loop {
+            self.skip_whitespace();
+            match self.peek_char() {
+                None => break,
+                Some('\n') | Some('\r') => self.parse_new_line()?,
+                Some('#') => {
+                    items.push(Item::Comment(self.parse_comment()));
+                    self.assert_line_end()?;
+                },
+                Some('[') => {
+                    items.push(Item::TableHeader(self.parse_table_header()?));
+                    self.assert_line_end()?;
+                },
+                Some(_) => {
+                    let mut key_value = self.parse_key_value()?;
+                    key_value.trailing_comment = self.parse_trailing_comment();
+                    items.push(Item::KeyValue(key_value));
+                    self.assert_line_end()?;
+                },
+            }
+        }
+
+        Ok(Document { items })
+    }
+
+    fn parse_table_header(&mut self) -> Result<TableHeader, ParseError> {
+        let start = self.pos;
+        let start_line = self.line;
+        self.expect_char('[')?;
+        let is_array = self.try_consume_char('[');
+        self.skip_whitespace();
+        let key = self.parse_key()?;
+        self.skip_whitespace();
+        self.expect_char(']')?;
+        if is_array {
+            self.expect_char(']')?;
+        }
+        let range = self.create_range(start, start_line);
+
+        Ok(TableHeader {
+            range,
+            key,
+            is_array,
+            trailing_comment: self.parse_trailing_comment(),
+        })
+    }
+
+    fn parse_key_value(&mut self) -> Result<KeyValue, ParseError> {
+        let start = self.pos;
+        let start_line = self.line;
+        let key = self.parse_key()?;
+        self.skip_whitespace();
+        self.expect_char('=')?;
+        self.skip_whitespace();
+        let value = self.parse_value()?;
+
+        Ok(KeyValue {
+            range: self.create_range(start, start_line),
+            key,
+            value,
+            trailing_comment: None,
+        })
+    }
+
+    fn parse_key(&mut self) -> Result<Key, ParseError> {
+        let mut parts = Vec::new();
+
+        loop {
+            self.skip_whitespace();
+            parts.push(self.parse_simple_key()?);
+            self.skip_whitespace();
+            if !self.try_consume_char('.') {
+                break;
+            }
+        }
+
+        Ok(Key { parts })
+    }
+
+    fn parse_simple_key(&mut self) -> Result<String, ParseError> {
+        let start = self.pos;
+        match self.peek_char() {
+            Some('"') => self.parse_basic_string()?,
+            Some('\'') => self.parse_literal_string()?,
+            _ => {
+                while self.peek_char().map(is_bare_key_char).unwrap_or(false) {
+                    self.next_char();
+                }
+                if self.pos == start {
+                    return Err(self.create_error("Expected a key."));
+                }
+            },
+        }
+
+        Ok(String::from(&self.text[start..self.pos]))
+    }
+
+    fn parse_value(&mut self) -> Result<Value, ParseError> {
+        match self.peek_char() {
+            Some('[') => Ok(Value::Array(self.parse_array()?)),
+            Some('{') => Ok(Value::InlineTable(self.parse_inline_table()?)),
+            _ => Ok(Value::Scalar(self.parse_scalar()?)),
+        }
+    }
+
+    fn parse_scalar(&mut self) -> Result<Scalar, ParseError> {
+        let start = self.pos;
+        let start_line = self.line;
+
+        if self.text[self.pos..].starts_with("\"\"\"") {
+            self.parse_multi_line_string("\"\"\"", true)?;
+        } else if self.text[self.pos..].starts_with("'''") {
+            self.parse_multi_line_string("'''", false)?;
+        } else if self.peek_char() == Some('"') {
+            self.parse_basic_string()?;
+        } else if self.peek_char() == Some('\'') {
+            self.parse_literal_string()?;
+        } else {
+            self.parse_bare_scalar()?;
+        }
+
+        Ok(Scalar {
+            range: self.create_range(start, start_line),
+            text: String::from(&self.text[start..self.pos]),
+        })
+    }
+
+    fn parse_bare_scalar(&mut self) -> Result<(), ParseError> {
+        let start = self.pos;
+        self.skip_bare_scalar_chars();
+
+        // date-times may have a space between the date and time (ex. `1979-05-27 07:32:00Z`)
+        let text = &self.text[start..self.pos];
+        if text.len() == 10 && text.chars().filter(|c| *c == '-').count() == 2 && self.is_space_before_time() {
+            self.next_char();
+            self.skip_bare_scalar_chars();
+        }
+
+        let text = &self.text[start..self.pos];
+        let is_valid = match text.chars().next() {
+            Some(c) => c.is_ascii_digit() || c == '+' || c == '-' || text == "true" || text == "false" || text == "inf" || text == "nan",
+            None => false,
+        };
+        if is_valid {
+            Ok(())
+        } else {
+            self.pos = start;
+            Err(self.create_error("Expected a value."))
+        }
+    }
+
+    fn skip_bare_scalar_chars(&mut self) {
+        while self.peek_char().map(|c| c.is_ascii_alphanumeric() || c == '_' || c == '+' || c == '-' || c == '.' || c == ':').unwrap_or(false) {
+            self.next_char();
+        }
+    }
+
+    fn is_space_before_time(&self) -> bool {
+        let mut chars = self.text[self.pos..].chars();
+        chars.next() == Some(' ')
+            && chars.next().map(|c| c.is_ascii_digit()).unwrap_or(false)
+            && chars.next().map(|c| c.is_ascii_digit()).unwrap_or(false)
+            && chars.next() == Some(':')
+    }
+
+    fn parse_basic_string(&mut self) -> Result<(), ParseError> {
+        self.expect_char('"')?;
+        loop {
+            match self.peek_char() {
+                Some('"') => {
+                    self.next_char();
+                    return Ok(());
+                },
+                Some('\\') => {
+                    self.next_char();
+                    self.next_char();
+                },
+                Some('\n') | None => return Err(self.create_error("Unterminated string.")),
+                Some(_) => {
+                    self.next_char();
+                },
+            }
+        }
+    }
+
+    fn parse_literal_string(&mut self) -> Result<(), ParseError> {
+        self.expect_char('\'')?;
+        loop {
+            match self.peek_char() {
+                Some('\'') => {
+                    self.next_char();
+                    return Ok(());
+                },
+                Some('\n') | None => return Err(self.create_error("Unterminated string.")),
+                Some(_) => {
+                    self.next_char();
+                },
+            }
+        }
+    }
+
+    fn parse_multi_line_string(&mut self, delimiter: &str, allows_escapes: bool) -> Result<(), ParseError> {
+        let quote_char = delimiter.chars().next().unwrap();
+        self.pos += delimiter.len();
+
+        loop {
+            if self.text[self.pos..].starts_with(delimiter) {
+                self.pos += delimiter.len();
+                // up to two quotes are allowed directly before the closing delimiter
+                for _ in 0..2 {
+                    self.try_consume_char(quote_char);
+                }
+                return Ok(());
+            }
+
+            match self.next_char() {
+                Some('\\') if allows_escapes => {
+                    self.next_char();
+                },
+                Some(_) => {},
+                None => return Err(self.create_error("Unterminated string.")),
+            }
+        }
+    }
+
+    fn parse_array(&mut self) -> Result<Array, ParseError> {
+        let start = self.pos;
+        let start_line = self.line;
+        let mut elements = Vec::new();
+        let mut comments = Vec::new();
+        self.expect_char('[')?;
+
+        loop {
+            self.skip_whitespace_and_comments(&mut comments)?;
+            if self.try_consume_char(']') {
+                break;
+            }
+            if self.peek_char().is_none() {
+                return Err(self.create_error("Unterminated array."));
+            }
+
+            let value = self.parse_value()?;
+            self.skip_whitespace();
+            let has_comma = self.try_consume_char(',');
+            elements.push(ArrayElement {
+                leading_comments: std::mem::take(&mut comments),
+                value,
+                trailing_comment: self.parse_trailing_comment(),
+            });
+
+            if !has_comma {
+                self.skip_whitespace_and_comments(&mut comments)?;
+                if !self.try_consume_char(']') {
+                    return Err(self.create_error("Expected a comma or closing bracket."));
+                }
+                break;
+            }
+        }
+
+        Ok(Array {
+            range: self.create_range(start, start_line),
+            elements,
+            trailing_comments: comments,
+        })
+    }
+
+    fn parse_inline_table(&mut self) -> Result<InlineTable, ParseError> {
+        let start = self.pos;
+        let start_line = self.line;
+        let mut entries = Vec::new();
+        self.expect_char('{')?;
+        self.skip_whitespace();
+
+        if !self.try_consume_char('}') {
+            loop {
+                self.skip_whitespace();
+                entries.push(self.parse_key_value()?);
+                self.skip_whitespace();
+                if self.try_consume_char('}') {
+                    break;
+                }
+                if !self.try_consume_char(',') {
+                    return Err(self.create_error("Expected a comma or closing brace."));
+                }
+            }
+        }
+
+        Ok(InlineTable {
+            range: self.create_range(start, start_line),
+            entries,
+        })
+    }
+
+    fn parse_comment(&mut self) -> Comment {
+        let start = self.pos;
+        let start_line = self.line;
+        self.next_char(); // #
+        while let Some(c) = self.peek_char() {
+            if c == '\n' || self.text[self.pos..].starts_with("\r\n") {
+                break;
+            }
+            self.next_char();
+        }
+
+        Comment {
+            range: self.create_range(start, start_line),
+            text: String::from(self.text[start + 1..self.pos].trim_end()),
+        }
+    }
+
+    fn parse_trailing_comment(&mut self) -> Option<Comment> {
+        self.skip_whitespace();
+        if self.peek_char() == Some('#') {
+            Some(self.parse_comment())
+        } else {
+            None
+        }
+    }
+
+    fn parse_new_line(&mut self) -> Result<(), ParseError> {
+        if self.try_consume_char('\n') || self.text[self.pos..].starts_with("\r\n") && self.next_char().is_some() && self.try_consume_char('\n') {
+            Ok(())
+        } else {
+            Err(self.create_error("Unexpected character."))
+        }
+    }
+
+    fn assert_line_end(&mut self) -> Result<(), ParseError> {
+        self.skip_whitespace();
+        match self.peek_char() {
+            None | Some('\n') => Ok(()),
+            Some('\r') if self.text[self.pos..].starts_with("\r\n") => Ok(()),
+            _ => Err(self.create_error("Expected a new line.")),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(' ') | Some('\t') = self.peek_char() {
+            self.next_char();
+        }
+    }
+
+    fn skip_whitespace_and_comments(&mut self, comments: &mut Vec<Comment>) -> Result<(), ParseError> {
+        loop {
+            self.skip_whitespace();
+            match self.peek_char() {
+                Some('#') => comments.push(self.parse_comment()),
+                Some('\n') | Some('\r') => self.parse_new_line()?,
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    fn expect_char(&mut self, c: char) -> Result<(), ParseError> {
+        if self.try_consume_char(c) {
+            Ok(())
+        } else {
+            Err(self.create_error(&format!("Expected '{}'.", c)))
+        }
+    }
+
+    fn try_consume_char(&mut self, c: char) -> bool {
+        if self.peek_char() == Some(c) {
+            self.next_char();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn peek_char(&self) -> Option<char> {
+        self.text[self.pos..].chars().next()
+    }
+
+    fn next_char(&mut self) -> Option<char> {
+        let c = self.peek_char()?;
+        self.pos += c.len_utf8();
+        if c == '\n' {
+            self.line += 1;
+        }
+        Some(c)
+    }
+
+    fn create_range(&self, start: usize, start_line: usize) -> Range {
+        Range {
+            start,
+            end: self.pos,
+            start_line,
+            end_line: self.line,
+        }
+    }
+
+    fn create_error(&self, message: &str) -> ParseError {
+        let end = self.pos + self.peek_char().map(|c| c.len_utf8()).unwrap_or(0);
+        ParseError {
+            range: (self.pos, end),
+            message: String::from(message),
+        }
+    }
+}
+
+fn is_bare_key_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || c == '-'
+}
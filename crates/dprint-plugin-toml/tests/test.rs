@@ -0,0 +1,33 @@
+extern crate dprint_plugin_toml;
+extern crate dprint_development;
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use dprint_core::configuration::*;
+use dprint_development::*;
+use dprint_plugin_toml::format_text;
+use dprint_plugin_toml::configuration::{resolve_config, CONFIG_OPTIONS};
+
+#[test]
+fn test_specs() {
+    let global_config = resolve_global_config(HashMap::new()).config;
+
+    run_specs(
+        &PathBuf::from("./tests/specs"),
+        &ParseSpecOptions { default_file_name: "file.toml" },
+        &RunSpecsOptions { fix_failures: false, format_twice: true },
+        move |file_path, file_text, spec_config| {
+            let config_result = resolve_config(spec_config.clone(), &global_config);
+            ensure_no_diagnostics(&config_result.diagnostics);
+
+            format_text(file_path, file_text, &config_result.config)
+        }
+    )
+}
+
+#[test]
+fn test_resolve_config_with_each_declared_option() {
+    let global_config = resolve_global_config(HashMap::new()).config;
+    ensure_config_options_resolve(CONFIG_OPTIONS, |config| resolve_config(config, &global_config).diagnostics);
+}
@@ -0,0 +1,41 @@
+//! Measures formatting TypeScript's checker.ts, which is a large file with a lot of print items.
+//!
+//! Run with `cargo bench`.
+extern crate dprint_plugin_typescript;
+
+use std::fs;
+use std::path::PathBuf;
+use std::time::Instant;
+
+use dprint_plugin_typescript::*;
+use dprint_plugin_typescript::configuration::*;
+
+const ITERATIONS: usize = 10;
+
+fn main() {
+    let config = ConfigurationBuilder::new()
+        .line_width(80)
+        .quote_style(QuoteStyle::PreferSingle)
+        .build();
+    let file_text = fs::read_to_string("tests/performance/checker.txt").expect("Expected to read.");
+    let file_path = PathBuf::from("checker.ts");
+    let formatter = Formatter::new(config);
+
+    // the first run warms up the allocator and caches
+    formatter.format_text(&file_path, &file_text).expect("Expected to format.");
+
+    let mut times = (0..ITERATIONS).map(|_| {
+        let start = Instant::now();
+        formatter.format_text(&file_path, &file_text).expect("Expected to format.");
+        start.elapsed()
+    }).collect::<Vec<_>>();
+    times.sort();
+
+    println!(
+        "format checker.ts: median {}ms, min {}ms, max {}ms ({} iterations)",
+        times[ITERATIONS / 2].as_millis(),
+        times[0].as_millis(),
+        times[ITERATIONS - 1].as_millis(),
+        ITERATIONS,
+    );
+}
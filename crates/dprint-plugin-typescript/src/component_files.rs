@@ -0,0 +1,183 @@
+use std::path::PathBuf;
+
+/// The contents of a `<script>` block in a component file.
+pub struct ScriptBlock {
+    /// The start of the text after the opening tag.
+    pub start: usize,
+    /// The start of the closing tag.
+    pub end: usize,
+    /// The file extension to use when formatting the block's text.
+    pub extension: &'static str,
+}
+
+/// Gets if the file is a Vue or Svelte component.
+pub fn is_component_file(file_path: &PathBuf) -> bool {
+    match file_path.extension().and_then(|e| e.to_str()).map(|e| e.to_lowercase()) {
+        Some(ext) => ext == "vue" || ext == "svelte",
+        None => false,
+    }
+}
+
+/// Gets the JavaScript and TypeScript `<script>` blocks in the file text.
+pub fn get_script_blocks(file_text: &str) -> Vec<ScriptBlock> {
+    // lowercasing ascii characters keeps the byte indexes the same
+    let lower_text = file_text.to_ascii_lowercase();
+    let mut blocks = Vec::new();
+    let mut pos = 0;
+
+    while let Some(index) = lower_text[pos..].find('<').map(|i| pos + i) {
+        let text = &lower_text[index..];
+        if text.starts_with("<!--") {
+            pos = match text.find("-->") {
+                Some(end_index) => index + end_index + 3,
+                None => break,
+            };
+        } else if text.starts_with("<script") && text[7..].starts_with(|c: char| c.is_whitespace() || c == '>') {
+            let tag_end = match get_tag_end(file_text, index) {
+                Some(tag_end) => tag_end,
+                None => break,
+            };
+            let attributes_text = &file_text[index + 7..tag_end - 1];
+            if attributes_text.trim_end().ends_with('/') {
+                // self-closing tag
+                pos = tag_end;
+                continue;
+            }
+            let content_end = match lower_text[tag_end..].find("</script").map(|i| tag_end + i) {
+                Some(content_end) => content_end,
+                None => break,
+            };
+
+            if let Some(extension) = get_script_extension(attributes_text) {
+                blocks.push(ScriptBlock {
+                    start: tag_end,
+                    end: content_end,
+                    extension,
+                });
+            }
+            pos = content_end;
+        } else {
+            pos = index + 1;
+        }
+    }
+
+    blocks
+}
+
+/// Gets the position after the `>` of the tag starting at the provided index.
+fn get_tag_end(file_text: &str, start: usize) -> Option<usize> {
+    let mut quote_char = None;
+    for (i, c) in file_text[start..].char_indices() {
+        match quote_char {
+            Some(current_quote_char) => {
+                if c == current_quote_char {
+                    quote_char = None;
+                }
+            },
+            None => match c {
+                '"' | '\'' => quote_char = Some(c),
+                '>' => return Some(start + i + 1),
+                _ => {},
+            },
+        }
+    }
+    None
+}
+
+/// Gets the file extension to format the script with or `None` when it's not JavaScript or TypeScript.
+fn get_script_extension(attributes_text: &str) -> Option<&'static str> {
+    let attributes = get_attributes(attributes_text);
+    let get_value = |name: &str| attributes.iter().find(|(key, _)| key == name).map(|(_, value)| value.as_str());
+
+    if let Some(script_type) = get_value("type") {
+        if script_type != "module" && !script_type.ends_with("javascript") && !script_type.ends_with("typescript") {
+            return None;
+        }
+    }
+
+    match get_value("lang") {
+        None | Some("js") | Some("javascript") => Some("js"),
+        Some("jsx") => Some("jsx"),
+        Some("ts") | Some("typescript") => Some("ts"),
+        Some("tsx") => Some("tsx"),
+        Some(_) => None,
+    }
+}
+
+/// Gets the lowercase attribute names and values of a tag.
+fn get_attributes(attributes_text: &str) -> Vec<(String, String)> {
+    let mut attributes = Vec::new();
+    let mut chars = attributes_text.chars().peekable();
+
+    loop {
+        while chars.peek().map(|c| c.is_whitespace() || *c == '/').unwrap_or(false) {
+            chars.next();
+        }
+        if chars.peek().is_none() {
+            break;
+        }
+
+        let mut name = String::new();
+        while let Some(c) = chars.peek().copied() {
+            if c.is_whitespace() || c == '=' || c == '/' {
+                break;
+            }
+            name.push(c);
+            chars.next();
+        }
+        while chars.peek().map(|c| c.is_whitespace()).unwrap_or(false) {
+            chars.next();
+        }
+
+        let mut value = String::new();
+        if chars.peek() == Some(&'=') {
+            chars.next();
+            while chars.peek().map(|c| c.is_whitespace()).unwrap_or(false) {
+                chars.next();
+            }
+            match chars.peek().copied() {
+                Some(quote_char) if quote_char == '"' || quote_char == '\'' => {
+                    chars.next();
+                    value.extend(chars.by_ref().take_while(|c| *c != quote_char));
+                },
+                _ => {
+                    while let Some(c) = chars.peek().copied() {
+                        if c.is_whitespace() {
+                            break;
+                        }
+                        value.push(c);
+                        chars.next();
+                    }
+                },
+            }
+        }
+
+        attributes.push((name.to_lowercase(), value.trim().to_lowercase()));
+    }
+
+    attributes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_get_script_blocks() {
+        let text = concat!(
+            "<template><div>{{ a }}</div></template>\n",
+            "<!-- <script>commented</script> -->\n",
+            "<script setup lang=\"ts\">const a = 1;</script>\n",
+            "<script type=\"text/x-template\"><div></div></script>\n",
+            "<script lang='coffee'>a = 1</script>\n",
+            "<script src=\"a.js\" />\n",
+            "<SCRIPT>const b = 2;</SCRIPT>\n",
+        );
+        let blocks = get_script_blocks(text);
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(&text[blocks[0].start..blocks[0].end], "const a = 1;");
+        assert_eq!(blocks[0].extension, "ts");
+        assert_eq!(&text[blocks[1].start..blocks[1].end], "const b = 2;");
+        assert_eq!(blocks[1].extension, "js");
+    }
+}
@@ -96,6 +96,30 @@ impl ConfigurationBuilder {
         self.insert("newLineKind", value)
     }
 
+    /// Whether to measure text by its display width (ex. CJK characters and emoji take up two columns)
+    /// instead of its character count.
+    ///
+    /// Default: `false`
+    pub fn unicode_width(&mut self, value: bool) -> &mut Self {
+        self.insert("unicodeWidth", value)
+    }
+
+    /// The maximum number of times the printer may evaluate a single condition before
+    /// giving up on formatting a file.
+    ///
+    /// Default: `10000`
+    pub fn max_condition_evaluations(&mut self, value: u32) -> &mut Self {
+        self.insert("maxConditionEvaluations", value)
+    }
+
+    /// The maximum number of times the printer may visit each print item on average before
+    /// giving up on formatting a file.
+    ///
+    /// Default: `1000`
+    pub fn max_visits_per_node(&mut self, value: u32) -> &mut Self {
+        self.insert("maxVisitsPerNode", value)
+    }
+
     /// The quote style to use.
     ///
     /// Default: `QuoteStyle::AlwaysDouble`
@@ -363,6 +387,15 @@ impl ConfigurationBuilder {
 
     /* situational */
 
+    /// Whether to pack as many elements on each line as fit when an array of numbers or
+    /// short literals spans multiple lines.
+    ///
+    /// * `true` - Ex. `[\n    1, 2, 3,\n    4, 5,\n]`
+    /// * `false` (default) - Formats with each element on a new line.
+    pub fn array_expression_fill(&mut self, value: bool) -> &mut Self {
+        self.insert("arrayExpression.fill", value)
+    }
+
     /// Whether to use parentheses for arrow functions.
     ///
     /// Default: `UseParentheses::Maintain`
@@ -378,6 +411,47 @@ impl ConfigurationBuilder {
         self.insert("binaryExpression.linePerExpression", value)
     }
 
+    /// Whether to re-align the leading `*` of each line in a block comment to the
+    /// indentation of the comment.
+    ///
+    /// * `true` - Ex. `/**\n * Text\n */` is re-indented along with the code around it.
+    /// * `false` (default) - Maintains the original text of the comment.
+    pub fn comment_block_align_gutters(&mut self, value: bool) -> &mut Self {
+        self.insert("commentBlock.alignGutters", value)
+    }
+
+    /// Whether to wrap the text of block comments that have a leading `*` on each line to
+    /// the line width. Code blocks, `@example` sections, and indented lines are left alone.
+    ///
+    /// Default: `false`
+    pub fn comment_block_reflow(&mut self, value: bool) -> &mut Self {
+        self.insert("commentBlock.reflow", value)
+    }
+
+    /// Whether to align the trailing line comments of consecutive statements and members to
+    /// the same column.
+    ///
+    /// * `true` - Ex. `a = 1;    // one\nbbb = 22; // two`
+    /// * `false` (default) - Uses a single space before each comment.
+    pub fn comment_line_align_trailing(&mut self, value: bool) -> &mut Self {
+        self.insert("commentLine.alignTrailing", value)
+    }
+
+    /// Whether to align the `=` of consecutive enum members to the same column.
+    ///
+    /// Default: `false`
+    pub fn enum_declaration_align_values(&mut self, value: bool) -> &mut Self {
+        self.insert("enumDeclaration.alignValues", value)
+    }
+
+    /// Whether to pack as many words of JSX text on each line as fit when the children span
+    /// multiple lines instead of maintaining the line breaks in the text.
+    ///
+    /// Default: `false`
+    pub fn jsx_element_fill_text(&mut self, value: bool) -> &mut Self {
+        self.insert("jsxElement.fillText", value)
+    }
+
     /// Whether to force a line per expression when spanning multiple lines.
     ///
     /// * `true` - Formats with each part on a new line.
@@ -386,6 +460,23 @@ impl ConfigurationBuilder {
         self.insert("memberExpression.linePerExpression", value)
     }
 
+    /// Whether to align the values of consecutive properties in a multi-line object literal.
+    ///
+    /// * `true` - Ex. `{\n    a:   1,\n    bbb: 2,\n}`
+    /// * `false` (default) - Uses a single space after each colon.
+    pub fn object_expression_align_values(&mut self, value: bool) -> &mut Self {
+        self.insert("objectExpression.alignValues", value)
+    }
+
+    /// Whether to align the `=` of consecutive single declaration variable statements to the
+    /// same column.
+    ///
+    /// * `true` - Ex. `const a   = 1;\nconst bbb = 2;`
+    /// * `false` (default) - Uses a single space before each `=`.
+    pub fn variable_statement_align_assignments(&mut self, value: bool) -> &mut Self {
+        self.insert("variableStatement.alignAssignments", value)
+    }
+
     /* ignore comments */
 
     /// The text to use for an ignore comment (ex. `// dprint-ignore`).
@@ -402,6 +493,54 @@ impl ConfigurationBuilder {
         self.insert("ignoreFileCommentText", value)
     }
 
+    /* verification */
+
+    /// Whether to re-parse the formatted output and ensure its AST is equivalent to
+    /// the original's (ignoring spans, parentheses, and trivia). The file is refused
+    /// with an error when they differ.
+    ///
+    /// This is slow on large files because both ASTs are serialized to compare them.
+    ///
+    /// Default: `false`
+    pub fn verify_semantic_equivalence(&mut self, value: bool) -> &mut Self {
+        self.insert("verifySemanticEquivalence", value)
+    }
+
+    /* component files */
+
+    /// Whether to indent the code in the `<script>` blocks of Vue and Svelte files.
+    ///
+    /// Default: `false`
+    pub fn script_block_indent(&mut self, value: bool) -> &mut Self {
+        self.insert("scriptBlock.indent", value)
+    }
+
+    /* embedded languages */
+
+    /// Comma separated tag names of template literals to format as CSS. A name
+    /// ending in `.*` also matches member and call tags (ex. `styled.div` and `styled(Button)`).
+    ///
+    /// Default: `"css,styled.*,createGlobalStyle,keyframes"`
+    pub fn tagged_template_css_tags(&mut self, value: &str) -> &mut Self {
+        self.insert("taggedTemplate.cssTags", value)
+    }
+
+    /// Comma separated tag names of template literals to format as GraphQL. A name
+    /// ending in `.*` also matches member and call tags.
+    ///
+    /// Default: `"gql,graphql"`
+    pub fn tagged_template_graphql_tags(&mut self, value: &str) -> &mut Self {
+        self.insert("taggedTemplate.graphqlTags", value)
+    }
+
+    /// Comma separated tag names of template literals to format as HTML. A name
+    /// ending in `.*` also matches member and call tags.
+    ///
+    /// Default: `"html"`
+    pub fn tagged_template_html_tags(&mut self, value: &str) -> &mut Self {
+        self.insert("taggedTemplate.htmlTags", value)
+    }
+
     /* brace position */
 
     pub fn arrow_function_brace_position(&mut self, value: BracePosition) -> &mut Self {
@@ -822,6 +961,9 @@ mod tests {
             .line_width(80)
             .use_tabs(false)
             .indent_width(4)
+            .unicode_width(true)
+            .max_condition_evaluations(10_000)
+            .max_visits_per_node(1_000)
             /* common */
             .quote_style(QuoteStyle::AlwaysDouble)
             .semi_colons(SemiColons::Prefer)
@@ -833,12 +975,28 @@ mod tests {
             .use_braces(UseBraces::WhenNotSingleLine)
             .prefer_hanging(false)
             /* situational */
+            .array_expression_fill(false)
             .arrow_function_use_parentheses(UseParentheses::Maintain)
             .binary_expression_line_per_expression(false)
+            .comment_block_align_gutters(false)
+            .comment_block_reflow(false)
+            .comment_line_align_trailing(false)
+            .enum_declaration_align_values(false)
+            .jsx_element_fill_text(false)
             .member_expression_line_per_expression(false)
+            .object_expression_align_values(false)
+            .variable_statement_align_assignments(false)
             /* ignore comments */
             .ignore_node_comment_text("ignore")
             .ignore_file_comment_text("ignore-file")
+            /* verification */
+            .verify_semantic_equivalence(true)
+            /* component files */
+            .script_block_indent(true)
+            /* embedded languages */
+            .tagged_template_css_tags("css")
+            .tagged_template_graphql_tags("gql")
+            .tagged_template_html_tags("html")
             /* brace position*/
             .arrow_function_brace_position(BracePosition::NextLine)
             .class_declaration_brace_position(BracePosition::NextLine)
@@ -970,7 +1128,7 @@ mod tests {
             .while_statement_space_after_while_keyword(true);
 
         let inner_config = config.get_inner_config();
-        assert_eq!(inner_config.len(), 137);
+        assert_eq!(inner_config.len(), 153);
         let diagnostics = resolve_config(inner_config, &resolve_global_config(HashMap::new()).config).diagnostics;
         assert_eq!(diagnostics.len(), 0);
     }
@@ -1,7 +1,9 @@
 mod builder;
+mod options;
 mod resolve_config;
 mod types;
 
 pub use builder::*;
+pub use options::*;
 pub use resolve_config::*;
 pub use types::*;
@@ -0,0 +1,176 @@
+use dprint_core::configuration::*;
+use super::types::*;
+
+/// The configuration options of the TypeScript plugin.
+pub const CONFIG_OPTIONS: &[ConfigOption] = &[
+    ConfigOption::new("deno", ConfigOptionKind::Bool, ConfigOptionDefault::Value("false"), "Sets the defaults of the options to what is used for Deno."),
+    ConfigOption::new("semiColons", ConfigOptionKind::Enum(SemiColons::VALUES), ConfigOptionDefault::Value("prefer"), "Whether statements should end in a semi-colon."),
+    ConfigOption::new("bracePosition", ConfigOptionKind::Enum(BracePosition::VALUES), ConfigOptionDefault::Value("nextLineIfHanging"), "Where to place the opening brace."),
+    ConfigOption::new("nextControlFlowPosition", ConfigOptionKind::Enum(NextControlFlowPosition::VALUES), ConfigOptionDefault::Value("sameLine"), "Where to place the next control flow within a control flow statement."),
+    ConfigOption::new("operatorPosition", ConfigOptionKind::Enum(OperatorPosition::VALUES), ConfigOptionDefault::Value("nextLine"), "Where to place the operator for expressions that span multiple lines."),
+    ConfigOption::new("singleBodyPosition", ConfigOptionKind::Enum(SingleBodyPosition::VALUES), ConfigOptionDefault::Value("maintain"), "Where to place the expression of a statement that could possibly be on one line (ex. `if (true) console.log(5);`)."),
+    ConfigOption::new("trailingCommas", ConfigOptionKind::Enum(TrailingCommas::VALUES), ConfigOptionDefault::Value("onlyMultiLine"), "If trailing commas should be used."),
+    ConfigOption::new("useBraces", ConfigOptionKind::Enum(UseBraces::VALUES), ConfigOptionDefault::Value("whenNotSingleLine"), "If braces should be used or not."),
+    ConfigOption::new("preferHanging", ConfigOptionKind::Bool, ConfigOptionDefault::Value("false"), "Set to prefer hanging indentation when exceeding the line width."),
+    ConfigOption::new("preferSingleLine", ConfigOptionKind::Bool, ConfigOptionDefault::Value("false"), "If code should revert back from being on multiple lines to being on a single line when able."),
+    ConfigOption::new("lineWidth", ConfigOptionKind::Number, ConfigOptionDefault::Global, "The width of a line the printer will try to stay under. Note that the printer may exceed this width in certain cases."),
+    ConfigOption::new("useTabs", ConfigOptionKind::Auto(&ConfigOptionKind::Bool), ConfigOptionDefault::Global, "Whether to use tabs (true) or spaces (false). Use \"auto\" to detect it from the indentation of each file."),
+    ConfigOption::new("indentWidth", ConfigOptionKind::Auto(&ConfigOptionKind::Number), ConfigOptionDefault::Global, "The number of columns for an indent. Use \"auto\" to detect it from the indentation of each file."),
+    ConfigOption::new("newLineKind", ConfigOptionKind::Enum(NewLineKind::VALUES), ConfigOptionDefault::Global, "The kind of newline to use."),
+    ConfigOption::new("unicodeWidth", ConfigOptionKind::Bool, ConfigOptionDefault::Global, "Whether to measure text by its display width (ex. CJK characters and emoji take up two columns) instead of its character count."),
+    ConfigOption::new("maxConditionEvaluations", ConfigOptionKind::Number, ConfigOptionDefault::Global, "The maximum number of times the printer may evaluate a single condition before giving up on formatting a file."),
+    ConfigOption::new("maxVisitsPerNode", ConfigOptionKind::Number, ConfigOptionDefault::Global, "The maximum number of times the printer may visit each print item on average before giving up on formatting a file."),
+    ConfigOption::new("quoteStyle", ConfigOptionKind::Enum(QuoteStyle::VALUES), ConfigOptionDefault::Value("alwaysDouble"), "The quote style to use."),
+    /* situational */
+    ConfigOption::new("arrayExpression.fill", ConfigOptionKind::Bool, ConfigOptionDefault::Value("false"), "Whether to pack as many elements on each line as fit when an array of numbers or short literals spans multiple lines."),
+    ConfigOption::new("arrowFunction.useParentheses", ConfigOptionKind::Enum(UseParentheses::VALUES), ConfigOptionDefault::Value("maintain"), "Whether to use parentheses for arrow functions."),
+    ConfigOption::new("binaryExpression.linePerExpression", ConfigOptionKind::Bool, ConfigOptionDefault::Value("false"), "Whether to force a line per expression when spanning multiple lines."),
+    ConfigOption::new("commentBlock.alignGutters", ConfigOptionKind::Bool, ConfigOptionDefault::Value("false"), "Whether to re-align the leading `*` of each line in a block comment to the indentation of the comment."),
+    ConfigOption::new("commentBlock.reflow", ConfigOptionKind::Bool, ConfigOptionDefault::Value("false"), "Whether to wrap the text of block comments that have a leading `*` on each line to the line width. Code blocks, `@example` sections, and indented lines are left alone."),
+    ConfigOption::new("commentLine.alignTrailing", ConfigOptionKind::Bool, ConfigOptionDefault::Value("false"), "Whether to align the trailing line comments of consecutive statements and members to the same column."),
+    ConfigOption::new("enumDeclaration.alignValues", ConfigOptionKind::Bool, ConfigOptionDefault::Value("false"), "Whether to align the `=` of consecutive enum members to the same column."),
+    ConfigOption::new("jsxElement.fillText", ConfigOptionKind::Bool, ConfigOptionDefault::Value("false"), "Whether to pack as many words of JSX text on each line as fit when the children span multiple lines instead of maintaining the line breaks in the text."),
+    ConfigOption::new("memberExpression.linePerExpression", ConfigOptionKind::Bool, ConfigOptionDefault::Value("false"), "Whether to force a line per expression when spanning multiple lines."),
+    ConfigOption::new("objectExpression.alignValues", ConfigOptionKind::Bool, ConfigOptionDefault::Value("false"), "Whether to align the values of consecutive properties in a multi-line object literal."),
+    ConfigOption::new("variableStatement.alignAssignments", ConfigOptionKind::Bool, ConfigOptionDefault::Value("false"), "Whether to align the `=` of consecutive single declaration variable statements to the same column."),
+    /* ignore comments */
+    ConfigOption::new("ignoreNodeCommentText", ConfigOptionKind::String, ConfigOptionDefault::Value("dprint-ignore"), "The text to use for an ignore comment (ex. `// dprint-ignore`)."),
+    ConfigOption::new("ignoreFileCommentText", ConfigOptionKind::String, ConfigOptionDefault::Value("dprint-ignore-file"), "The text to use for a file ignore comment (ex. `// dprint-ignore-file`)."),
+    /* verification */
+    ConfigOption::new("verifySemanticEquivalence", ConfigOptionKind::Bool, ConfigOptionDefault::Value("false"), "Whether to re-parse the formatted output and ensure its AST is equivalent to the original's (ignoring spans, parentheses, and trivia). The file is refused with an error when they differ. This is slow on large files because both ASTs are serialized to compare them."),
+    /* component files */
+    ConfigOption::new("scriptBlock.indent", ConfigOptionKind::Bool, ConfigOptionDefault::Value("false"), "Whether to indent the code in the `<script>` blocks of Vue and Svelte files."),
+    /* embedded languages */
+    ConfigOption::new("taggedTemplate.cssTags", ConfigOptionKind::String, ConfigOptionDefault::Value("css,styled.*,createGlobalStyle,keyframes"), "Comma separated tag names of template literals to format as CSS. A name ending in `.*` also matches member and call tags (ex. `styled.div` and `styled(Button)`)."),
+    ConfigOption::new("taggedTemplate.graphqlTags", ConfigOptionKind::String, ConfigOptionDefault::Value("gql,graphql"), "Comma separated tag names of template literals to format as GraphQL. A name ending in `.*` also matches member and call tags."),
+    ConfigOption::new("taggedTemplate.htmlTags", ConfigOptionKind::String, ConfigOptionDefault::Value("html"), "Comma separated tag names of template literals to format as HTML. A name ending in `.*` also matches member and call tags."),
+    /* brace position */
+    ConfigOption::new("arrowFunction.bracePosition", ConfigOptionKind::Enum(BracePosition::VALUES), ConfigOptionDefault::Option("bracePosition"), "Overrides `bracePosition` for `arrowFunction`."),
+    ConfigOption::new("classDeclaration.bracePosition", ConfigOptionKind::Enum(BracePosition::VALUES), ConfigOptionDefault::Option("bracePosition"), "Overrides `bracePosition` for `classDeclaration`."),
+    ConfigOption::new("classExpression.bracePosition", ConfigOptionKind::Enum(BracePosition::VALUES), ConfigOptionDefault::Option("bracePosition"), "Overrides `bracePosition` for `classExpression`."),
+    ConfigOption::new("constructor.bracePosition", ConfigOptionKind::Enum(BracePosition::VALUES), ConfigOptionDefault::Option("bracePosition"), "Overrides `bracePosition` for `constructor`."),
+    ConfigOption::new("doWhileStatement.bracePosition", ConfigOptionKind::Enum(BracePosition::VALUES), ConfigOptionDefault::Option("bracePosition"), "Overrides `bracePosition` for `doWhileStatement`."),
+    ConfigOption::new("enumDeclaration.bracePosition", ConfigOptionKind::Enum(BracePosition::VALUES), ConfigOptionDefault::Option("bracePosition"), "Overrides `bracePosition` for `enumDeclaration`."),
+    ConfigOption::new("forStatement.bracePosition", ConfigOptionKind::Enum(BracePosition::VALUES), ConfigOptionDefault::Option("bracePosition"), "Overrides `bracePosition` for `forStatement`."),
+    ConfigOption::new("forInStatement.bracePosition", ConfigOptionKind::Enum(BracePosition::VALUES), ConfigOptionDefault::Option("bracePosition"), "Overrides `bracePosition` for `forInStatement`."),
+    ConfigOption::new("forOfStatement.bracePosition", ConfigOptionKind::Enum(BracePosition::VALUES), ConfigOptionDefault::Option("bracePosition"), "Overrides `bracePosition` for `forOfStatement`."),
+    ConfigOption::new("getAccessor.bracePosition", ConfigOptionKind::Enum(BracePosition::VALUES), ConfigOptionDefault::Option("bracePosition"), "Overrides `bracePosition` for `getAccessor`."),
+    ConfigOption::new("ifStatement.bracePosition", ConfigOptionKind::Enum(BracePosition::VALUES), ConfigOptionDefault::Option("bracePosition"), "Overrides `bracePosition` for `ifStatement`."),
+    ConfigOption::new("interfaceDeclaration.bracePosition", ConfigOptionKind::Enum(BracePosition::VALUES), ConfigOptionDefault::Option("bracePosition"), "Overrides `bracePosition` for `interfaceDeclaration`."),
+    ConfigOption::new("functionDeclaration.bracePosition", ConfigOptionKind::Enum(BracePosition::VALUES), ConfigOptionDefault::Option("bracePosition"), "Overrides `bracePosition` for `functionDeclaration`."),
+    ConfigOption::new("functionExpression.bracePosition", ConfigOptionKind::Enum(BracePosition::VALUES), ConfigOptionDefault::Option("bracePosition"), "Overrides `bracePosition` for `functionExpression`."),
+    ConfigOption::new("method.bracePosition", ConfigOptionKind::Enum(BracePosition::VALUES), ConfigOptionDefault::Option("bracePosition"), "Overrides `bracePosition` for `method`."),
+    ConfigOption::new("moduleDeclaration.bracePosition", ConfigOptionKind::Enum(BracePosition::VALUES), ConfigOptionDefault::Option("bracePosition"), "Overrides `bracePosition` for `moduleDeclaration`."),
+    ConfigOption::new("setAccessor.bracePosition", ConfigOptionKind::Enum(BracePosition::VALUES), ConfigOptionDefault::Option("bracePosition"), "Overrides `bracePosition` for `setAccessor`."),
+    ConfigOption::new("switchCase.bracePosition", ConfigOptionKind::Enum(BracePosition::VALUES), ConfigOptionDefault::Option("bracePosition"), "Overrides `bracePosition` for `switchCase`."),
+    ConfigOption::new("switchStatement.bracePosition", ConfigOptionKind::Enum(BracePosition::VALUES), ConfigOptionDefault::Option("bracePosition"), "Overrides `bracePosition` for `switchStatement`."),
+    ConfigOption::new("tryStatement.bracePosition", ConfigOptionKind::Enum(BracePosition::VALUES), ConfigOptionDefault::Option("bracePosition"), "Overrides `bracePosition` for `tryStatement`."),
+    ConfigOption::new("whileStatement.bracePosition", ConfigOptionKind::Enum(BracePosition::VALUES), ConfigOptionDefault::Option("bracePosition"), "Overrides `bracePosition` for `whileStatement`."),
+    /* prefer hanging */
+    ConfigOption::new("arguments.preferHanging", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferHanging"), "Overrides `preferHanging` for `arguments`."),
+    ConfigOption::new("arrayExpression.preferHanging", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferHanging"), "Overrides `preferHanging` for `arrayExpression`."),
+    ConfigOption::new("arrayPattern.preferHanging", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferHanging"), "Overrides `preferHanging` for `arrayPattern`."),
+    ConfigOption::new("doWhileStatement.preferHanging", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferHanging"), "Overrides `preferHanging` for `doWhileStatement`."),
+    ConfigOption::new("exportDeclaration.preferHanging", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferHanging"), "Overrides `preferHanging` for `exportDeclaration`."),
+    ConfigOption::new("extendsClause.preferHanging", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferHanging"), "Overrides `preferHanging` for `extendsClause`."),
+    ConfigOption::new("forInStatement.preferHanging", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferHanging"), "Overrides `preferHanging` for `forInStatement`."),
+    ConfigOption::new("forOfStatement.preferHanging", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferHanging"), "Overrides `preferHanging` for `forOfStatement`."),
+    ConfigOption::new("forStatement.preferHanging", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferHanging"), "Overrides `preferHanging` for `forStatement`."),
+    ConfigOption::new("ifStatement.preferHanging", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferHanging"), "Overrides `preferHanging` for `ifStatement`."),
+    ConfigOption::new("implementsClause.preferHanging", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferHanging"), "Overrides `preferHanging` for `implementsClause`."),
+    ConfigOption::new("importDeclaration.preferHanging", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferHanging"), "Overrides `preferHanging` for `importDeclaration`."),
+    ConfigOption::new("jsxAttributes.preferHanging", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferHanging"), "Overrides `preferHanging` for `jsxAttributes`."),
+    ConfigOption::new("objectExpression.preferHanging", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferHanging"), "Overrides `preferHanging` for `objectExpression`."),
+    ConfigOption::new("objectPattern.preferHanging", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferHanging"), "Overrides `preferHanging` for `objectPattern`."),
+    ConfigOption::new("parameters.preferHanging", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferHanging"), "Overrides `preferHanging` for `parameters`."),
+    ConfigOption::new("sequenceExpression.preferHanging", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferHanging"), "Overrides `preferHanging` for `sequenceExpression`."),
+    ConfigOption::new("switchStatement.preferHanging", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferHanging"), "Overrides `preferHanging` for `switchStatement`."),
+    ConfigOption::new("tupleType.preferHanging", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferHanging"), "Overrides `preferHanging` for `tupleType`."),
+    ConfigOption::new("typeLiteral.preferHanging", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferHanging"), "Overrides `preferHanging` for `typeLiteral`."),
+    ConfigOption::new("typeParameters.preferHanging", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferHanging"), "Overrides `preferHanging` for `typeParameters`."),
+    ConfigOption::new("unionAndIntersectionType.preferHanging", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferHanging"), "Overrides `preferHanging` for `unionAndIntersectionType`."),
+    ConfigOption::new("variableStatement.preferHanging", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferHanging"), "Overrides `preferHanging` for `variableStatement`."),
+    ConfigOption::new("whileStatement.preferHanging", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferHanging"), "Overrides `preferHanging` for `whileStatement`."),
+    /* member spacing */
+    ConfigOption::new("enumDeclaration.memberSpacing", ConfigOptionKind::Enum(MemberSpacing::VALUES), ConfigOptionDefault::Value("maintain"), "How to space the members of an enum."),
+    /* next control flow position */
+    ConfigOption::new("ifStatement.nextControlFlowPosition", ConfigOptionKind::Enum(NextControlFlowPosition::VALUES), ConfigOptionDefault::Option("nextControlFlowPosition"), "Overrides `nextControlFlowPosition` for `ifStatement`."),
+    ConfigOption::new("tryStatement.nextControlFlowPosition", ConfigOptionKind::Enum(NextControlFlowPosition::VALUES), ConfigOptionDefault::Option("nextControlFlowPosition"), "Overrides `nextControlFlowPosition` for `tryStatement`."),
+    /* operator position */
+    ConfigOption::new("binaryExpression.operatorPosition", ConfigOptionKind::Enum(OperatorPosition::VALUES), ConfigOptionDefault::Option("operatorPosition"), "Overrides `operatorPosition` for `binaryExpression`."),
+    ConfigOption::new("conditionalExpression.operatorPosition", ConfigOptionKind::Enum(OperatorPosition::VALUES), ConfigOptionDefault::Option("operatorPosition"), "Overrides `operatorPosition` for `conditionalExpression`."),
+    /* single body position */
+    ConfigOption::new("ifStatement.singleBodyPosition", ConfigOptionKind::Enum(SingleBodyPosition::VALUES), ConfigOptionDefault::Option("singleBodyPosition"), "Overrides `singleBodyPosition` for `ifStatement`."),
+    ConfigOption::new("forStatement.singleBodyPosition", ConfigOptionKind::Enum(SingleBodyPosition::VALUES), ConfigOptionDefault::Option("singleBodyPosition"), "Overrides `singleBodyPosition` for `forStatement`."),
+    ConfigOption::new("forInStatement.singleBodyPosition", ConfigOptionKind::Enum(SingleBodyPosition::VALUES), ConfigOptionDefault::Option("singleBodyPosition"), "Overrides `singleBodyPosition` for `forInStatement`."),
+    ConfigOption::new("forOfStatement.singleBodyPosition", ConfigOptionKind::Enum(SingleBodyPosition::VALUES), ConfigOptionDefault::Option("singleBodyPosition"), "Overrides `singleBodyPosition` for `forOfStatement`."),
+    ConfigOption::new("whileStatement.singleBodyPosition", ConfigOptionKind::Enum(SingleBodyPosition::VALUES), ConfigOptionDefault::Option("singleBodyPosition"), "Overrides `singleBodyPosition` for `whileStatement`."),
+    /* trailing commas */
+    ConfigOption::new("arguments.trailingCommas", ConfigOptionKind::Enum(TrailingCommas::VALUES), ConfigOptionDefault::Option("trailingCommas"), "Overrides `trailingCommas` for `arguments`."),
+    ConfigOption::new("parameters.trailingCommas", ConfigOptionKind::Enum(TrailingCommas::VALUES), ConfigOptionDefault::Option("trailingCommas"), "Overrides `trailingCommas` for `parameters`."),
+    ConfigOption::new("arrayExpression.trailingCommas", ConfigOptionKind::Enum(TrailingCommas::VALUES), ConfigOptionDefault::Option("trailingCommas"), "Overrides `trailingCommas` for `arrayExpression`."),
+    ConfigOption::new("arrayPattern.trailingCommas", ConfigOptionKind::Enum(TrailingCommas::VALUES), ConfigOptionDefault::Option("trailingCommas"), "Overrides `trailingCommas` for `arrayPattern`."),
+    ConfigOption::new("enumDeclaration.trailingCommas", ConfigOptionKind::Enum(TrailingCommas::VALUES), ConfigOptionDefault::Option("trailingCommas"), "Overrides `trailingCommas` for `enumDeclaration`."),
+    ConfigOption::new("exportDeclaration.trailingCommas", ConfigOptionKind::Enum(TrailingCommas::VALUES), ConfigOptionDefault::Option("trailingCommas"), "Overrides `trailingCommas` for `exportDeclaration`."),
+    ConfigOption::new("importDeclaration.trailingCommas", ConfigOptionKind::Enum(TrailingCommas::VALUES), ConfigOptionDefault::Option("trailingCommas"), "Overrides `trailingCommas` for `importDeclaration`."),
+    ConfigOption::new("objectExpression.trailingCommas", ConfigOptionKind::Enum(TrailingCommas::VALUES), ConfigOptionDefault::Option("trailingCommas"), "Overrides `trailingCommas` for `objectExpression`."),
+    ConfigOption::new("objectPattern.trailingCommas", ConfigOptionKind::Enum(TrailingCommas::VALUES), ConfigOptionDefault::Option("trailingCommas"), "Overrides `trailingCommas` for `objectPattern`."),
+    ConfigOption::new("tupleType.trailingCommas", ConfigOptionKind::Enum(TrailingCommas::VALUES), ConfigOptionDefault::Option("trailingCommas"), "Overrides `trailingCommas` for `tupleType`."),
+    ConfigOption::new("typeParameters.trailingCommas", ConfigOptionKind::Enum(TrailingCommas::VALUES), ConfigOptionDefault::Option("trailingCommas"), "Overrides `trailingCommas` for `typeParameters`."),
+    /* use braces */
+    ConfigOption::new("ifStatement.useBraces", ConfigOptionKind::Enum(UseBraces::VALUES), ConfigOptionDefault::Option("useBraces"), "Overrides `useBraces` for `ifStatement`."),
+    ConfigOption::new("forStatement.useBraces", ConfigOptionKind::Enum(UseBraces::VALUES), ConfigOptionDefault::Option("useBraces"), "Overrides `useBraces` for `forStatement`."),
+    ConfigOption::new("forInStatement.useBraces", ConfigOptionKind::Enum(UseBraces::VALUES), ConfigOptionDefault::Option("useBraces"), "Overrides `useBraces` for `forInStatement`."),
+    ConfigOption::new("forOfStatement.useBraces", ConfigOptionKind::Enum(UseBraces::VALUES), ConfigOptionDefault::Option("useBraces"), "Overrides `useBraces` for `forOfStatement`."),
+    ConfigOption::new("whileStatement.useBraces", ConfigOptionKind::Enum(UseBraces::VALUES), ConfigOptionDefault::Option("useBraces"), "Overrides `useBraces` for `whileStatement`."),
+    /* prefer single line */
+    ConfigOption::new("arrayExpression.preferSingleLine", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferSingleLine"), "Overrides `preferSingleLine` for `arrayExpression`."),
+    ConfigOption::new("arrayPattern.preferSingleLine", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferSingleLine"), "Overrides `preferSingleLine` for `arrayPattern`."),
+    ConfigOption::new("arguments.preferSingleLine", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferSingleLine"), "Overrides `preferSingleLine` for `arguments`."),
+    ConfigOption::new("binaryExpression.preferSingleLine", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferSingleLine"), "Overrides `preferSingleLine` for `binaryExpression`."),
+    ConfigOption::new("computed.preferSingleLine", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferSingleLine"), "Overrides `preferSingleLine` for `computed`."),
+    ConfigOption::new("conditionalExpression.preferSingleLine", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferSingleLine"), "Overrides `preferSingleLine` for `conditionalExpression`."),
+    ConfigOption::new("conditionalType.preferSingleLine", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferSingleLine"), "Overrides `preferSingleLine` for `conditionalType`."),
+    ConfigOption::new("decorators.preferSingleLine", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferSingleLine"), "Overrides `preferSingleLine` for `decorators`."),
+    ConfigOption::new("exportDeclaration.preferSingleLine", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferSingleLine"), "Overrides `preferSingleLine` for `exportDeclaration`."),
+    ConfigOption::new("forStatement.preferSingleLine", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferSingleLine"), "Overrides `preferSingleLine` for `forStatement`."),
+    ConfigOption::new("importDeclaration.preferSingleLine", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferSingleLine"), "Overrides `preferSingleLine` for `importDeclaration`."),
+    ConfigOption::new("jsxAttributes.preferSingleLine", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferSingleLine"), "Overrides `preferSingleLine` for `jsxAttributes`."),
+    ConfigOption::new("jsxElement.preferSingleLine", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferSingleLine"), "Overrides `preferSingleLine` for `jsxElement`."),
+    ConfigOption::new("mappedType.preferSingleLine", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferSingleLine"), "Overrides `preferSingleLine` for `mappedType`."),
+    ConfigOption::new("memberExpression.preferSingleLine", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferSingleLine"), "Overrides `preferSingleLine` for `memberExpression`."),
+    ConfigOption::new("objectExpression.preferSingleLine", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferSingleLine"), "Overrides `preferSingleLine` for `objectExpression`."),
+    ConfigOption::new("objectPattern.preferSingleLine", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferSingleLine"), "Overrides `preferSingleLine` for `objectPattern`."),
+    ConfigOption::new("parameters.preferSingleLine", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferSingleLine"), "Overrides `preferSingleLine` for `parameters`."),
+    ConfigOption::new("parentheses.preferSingleLine", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferSingleLine"), "Overrides `preferSingleLine` for `parentheses`."),
+    ConfigOption::new("tupleType.preferSingleLine", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferSingleLine"), "Overrides `preferSingleLine` for `tupleType`."),
+    ConfigOption::new("typeLiteral.preferSingleLine", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferSingleLine"), "Overrides `preferSingleLine` for `typeLiteral`."),
+    ConfigOption::new("typeParameters.preferSingleLine", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferSingleLine"), "Overrides `preferSingleLine` for `typeParameters`."),
+    ConfigOption::new("unionAndIntersectionType.preferSingleLine", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferSingleLine"), "Overrides `preferSingleLine` for `unionAndIntersectionType`."),
+    ConfigOption::new("variableStatement.preferSingleLine", ConfigOptionKind::Bool, ConfigOptionDefault::Option("preferSingleLine"), "Overrides `preferSingleLine` for `variableStatement`."),
+    /* space settings */
+    ConfigOption::new("binaryExpression.spaceSurroundingBitwiseAndArithmeticOperator", ConfigOptionKind::Bool, ConfigOptionDefault::Value("true"), "Whether to surround bitwise and arithmetic operators in a binary expression with spaces."),
+    ConfigOption::new("commentLine.forceSpaceAfterSlashes", ConfigOptionKind::Bool, ConfigOptionDefault::Value("true"), "Forces a space after the double slash in a comment line."),
+    ConfigOption::new("constructSignature.spaceAfterNewKeyword", ConfigOptionKind::Bool, ConfigOptionDefault::Value("false"), "Whether to add a space after the `new` keyword in a construct signature."),
+    ConfigOption::new("constructor.spaceBeforeParentheses", ConfigOptionKind::Bool, ConfigOptionDefault::Value("false"), "Whether to add a space before the parentheses of a constructor."),
+    ConfigOption::new("constructorType.spaceAfterNewKeyword", ConfigOptionKind::Bool, ConfigOptionDefault::Value("false"), "Whether to add a space after the `new` keyword in a constructor type."),
+    ConfigOption::new("doWhileStatement.spaceAfterWhileKeyword", ConfigOptionKind::Bool, ConfigOptionDefault::Value("true"), "Whether to add a space after the `while` keyword in a do while statement."),
+    ConfigOption::new("exportDeclaration.spaceSurroundingNamedExports", ConfigOptionKind::Bool, ConfigOptionDefault::Value("true"), "Whether to add spaces around named exports in an export declaration."),
+    ConfigOption::new("forStatement.spaceAfterForKeyword", ConfigOptionKind::Bool, ConfigOptionDefault::Value("true"), "Whether to add a space after the `for` keyword in a \"for\" statement."),
+    ConfigOption::new("forStatement.spaceAfterSemiColons", ConfigOptionKind::Bool, ConfigOptionDefault::Value("true"), "Whether to add a space after the semi-colons in a \"for\" statement."),
+    ConfigOption::new("forInStatement.spaceAfterForKeyword", ConfigOptionKind::Bool, ConfigOptionDefault::Value("true"), "Whether to add a space after the `for` keyword in a \"for in\" statement."),
+    ConfigOption::new("forOfStatement.spaceAfterForKeyword", ConfigOptionKind::Bool, ConfigOptionDefault::Value("true"), "Whether to add a space after the `for` keyword in a \"for of\" statement."),
+    ConfigOption::new("functionDeclaration.spaceBeforeParentheses", ConfigOptionKind::Bool, ConfigOptionDefault::Value("false"), "Whether to add a space before the parentheses of a function declaration."),
+    ConfigOption::new("functionExpression.spaceBeforeParentheses", ConfigOptionKind::Bool, ConfigOptionDefault::Value("false"), "Whether to add a space before the parentheses of a function expression."),
+    ConfigOption::new("functionExpression.spaceAfterFunctionKeyword", ConfigOptionKind::Bool, ConfigOptionDefault::Value("false"), "Whether to add a space after the function keyword of a function expression."),
+    ConfigOption::new("getAccessor.spaceBeforeParentheses", ConfigOptionKind::Bool, ConfigOptionDefault::Value("false"), "Whether to add a space before the parentheses of a get accessor."),
+    ConfigOption::new("ifStatement.spaceAfterIfKeyword", ConfigOptionKind::Bool, ConfigOptionDefault::Value("true"), "Whether to add a space after the `if` keyword in an \"if\" statement."),
+    ConfigOption::new("importDeclaration.spaceSurroundingNamedImports", ConfigOptionKind::Bool, ConfigOptionDefault::Value("true"), "Whether to add spaces around named imports in an import declaration."),
+    ConfigOption::new("jsxExpressionContainer.spaceSurroundingExpression", ConfigOptionKind::Bool, ConfigOptionDefault::Value("false"), "Whether to add a space surrounding the expression of a JSX container."),
+    ConfigOption::new("method.spaceBeforeParentheses", ConfigOptionKind::Bool, ConfigOptionDefault::Value("false"), "Whether to add a space before the parentheses of a method."),
+    ConfigOption::new("setAccessor.spaceBeforeParentheses", ConfigOptionKind::Bool, ConfigOptionDefault::Value("false"), "Whether to add a space before the parentheses of a set accessor."),
+    ConfigOption::new("taggedTemplate.spaceBeforeLiteral", ConfigOptionKind::Bool, ConfigOptionDefault::Value("true"), "Whether to add a space before the literal in a tagged template."),
+    ConfigOption::new("typeAnnotation.spaceBeforeColon", ConfigOptionKind::Bool, ConfigOptionDefault::Value("false"), "Whether to add a space before the colon of a type annotation."),
+    ConfigOption::new("typeAssertion.spaceBeforeExpression", ConfigOptionKind::Bool, ConfigOptionDefault::Value("true"), "Whether to add a space before the expression in a type assertion."),
+    ConfigOption::new("whileStatement.spaceAfterWhileKeyword", ConfigOptionKind::Bool, ConfigOptionDefault::Value("true"), "Whether to add a space after the `while` keyword in a while statement."),
+];
@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 use dprint_core::configuration::*;
 use super::builder::*;
+use super::options::CONFIG_OPTIONS;
 use super::types::*;
 
 /// Resolves configuration from a collection of key value strings.
@@ -26,187 +27,196 @@ use super::types::*;
 /// // check config_result.diagnostics here and use config_result.config
 /// ```
 pub fn resolve_config(config: HashMap<String, String>, global_config: &GlobalConfiguration) -> ResolveConfigurationResult<Configuration> {
-    let mut diagnostics = Vec::new();
-    let mut config = config;
+    let (config, resolved_options) = resolve_config_options(config, global_config);
 
-    if get_value(&mut config, "deno", false, &mut diagnostics) {
-        fill_deno_config(&mut config);
+    ResolveConfigurationResult {
+        config,
+        diagnostics: resolved_options.diagnostics,
     }
+}
+
+/// Resolves configuration from a collection of key value strings along with the
+/// resolved values of each of the declared `CONFIG_OPTIONS`.
+pub fn resolve_config_options(config: HashMap<String, String>, global_config: &GlobalConfiguration) -> (Configuration, ResolvedConfigOptions) {
+    let mut resolver = ConfigOptionResolver::new(CONFIG_OPTIONS, config, global_config);
 
-    let semi_colons = get_value(&mut config, "semiColons", SemiColons::Prefer, &mut diagnostics);
-    let brace_position = get_value(&mut config, "bracePosition", BracePosition::NextLineIfHanging, &mut diagnostics);
-    let next_control_flow_position = get_value(&mut config, "nextControlFlowPosition", NextControlFlowPosition::SameLine, &mut diagnostics);
-    let operator_position = get_value(&mut config, "operatorPosition", OperatorPosition::NextLine, &mut diagnostics);
-    let single_body_position = get_value(&mut config, "singleBodyPosition", SingleBodyPosition::Maintain, &mut diagnostics);
-    let trailing_commas = get_value(&mut config, "trailingCommas", TrailingCommas::OnlyMultiLine, &mut diagnostics);
-    let use_braces = get_value(&mut config, "useBraces", UseBraces::WhenNotSingleLine, &mut diagnostics);
-    let prefer_hanging = get_value(&mut config, "preferHanging", false, &mut diagnostics);
-    let prefer_single_line = get_value(&mut config, "preferSingleLine", false, &mut diagnostics);
+    if resolver.get("deno") {
+        resolver.fill_unset_values(&ConfigurationBuilder::new().deno().config);
+    }
 
+    let use_tabs: AutoValue<bool> = resolver.get("useTabs");
+    let indent_width: AutoValue<u8> = resolver.get("indentWidth");
     let resolved_config = Configuration {
-        line_width: get_value(&mut config, "lineWidth", global_config.line_width.unwrap_or(DEFAULT_GLOBAL_CONFIGURATION.line_width), &mut diagnostics),
-        use_tabs: get_value(&mut config, "useTabs", global_config.use_tabs.unwrap_or(DEFAULT_GLOBAL_CONFIGURATION.use_tabs), &mut diagnostics),
-        indent_width: get_value(&mut config, "indentWidth", global_config.indent_width.unwrap_or(DEFAULT_GLOBAL_CONFIGURATION.indent_width), &mut diagnostics),
-        new_line_kind: get_value(&mut config, "newLineKind", global_config.new_line_kind.unwrap_or(DEFAULT_GLOBAL_CONFIGURATION.new_line_kind), &mut diagnostics),
-        quote_style: get_value(&mut config, "quoteStyle", QuoteStyle::AlwaysDouble, &mut diagnostics),
-        semi_colons,
+        line_width: resolver.get("lineWidth"),
+        use_tabs: use_tabs.value()
+            .or_else(|| global_config.use_tabs.and_then(AutoValue::value))
+            .unwrap_or(DEFAULT_GLOBAL_CONFIGURATION.use_tabs),
+        indent_width: indent_width.value()
+            .or_else(|| global_config.indent_width.and_then(AutoValue::value))
+            .unwrap_or(DEFAULT_GLOBAL_CONFIGURATION.indent_width),
+        auto_use_tabs: use_tabs == AutoValue::Auto,
+        auto_indent_width: indent_width == AutoValue::Auto,
+        new_line_kind: resolver.get("newLineKind"),
+        unicode_width: resolver.get("unicodeWidth"),
+        max_condition_evaluations: resolver.get("maxConditionEvaluations"),
+        max_visits_per_node: resolver.get("maxVisitsPerNode"),
+        quote_style: resolver.get("quoteStyle"),
+        semi_colons: resolver.get("semiColons"),
         /* situational */
-        arrow_function_use_parentheses: get_value(&mut config, "arrowFunction.useParentheses", UseParentheses::Maintain, &mut diagnostics),
-        binary_expression_line_per_expression: get_value(&mut config, "binaryExpression.linePerExpression", false, &mut diagnostics),
-        member_expression_line_per_expression: get_value(&mut config, "memberExpression.linePerExpression", false, &mut diagnostics),
+        array_expression_fill: resolver.get("arrayExpression.fill"),
+        arrow_function_use_parentheses: resolver.get("arrowFunction.useParentheses"),
+        binary_expression_line_per_expression: resolver.get("binaryExpression.linePerExpression"),
+        comment_block_align_gutters: resolver.get("commentBlock.alignGutters"),
+        comment_block_reflow: resolver.get("commentBlock.reflow"),
+        comment_line_align_trailing: resolver.get("commentLine.alignTrailing"),
+        enum_declaration_align_values: resolver.get("enumDeclaration.alignValues"),
+        jsx_element_fill_text: resolver.get("jsxElement.fillText"),
+        member_expression_line_per_expression: resolver.get("memberExpression.linePerExpression"),
+        object_expression_align_values: resolver.get("objectExpression.alignValues"),
+        variable_statement_align_assignments: resolver.get("variableStatement.alignAssignments"),
         /* ignore comments */
-        ignore_node_comment_text: get_value(&mut config, "ignoreNodeCommentText", String::from("dprint-ignore"), &mut diagnostics),
-        ignore_file_comment_text: get_value(&mut config, "ignoreFileCommentText", String::from("dprint-ignore-file"), &mut diagnostics),
+        ignore_node_comment_text: resolver.get("ignoreNodeCommentText"),
+        ignore_file_comment_text: resolver.get("ignoreFileCommentText"),
+        /* verification */
+        verify_semantic_equivalence: resolver.get("verifySemanticEquivalence"),
+        /* component files */
+        script_block_indent: resolver.get("scriptBlock.indent"),
+        /* embedded languages */
+        tagged_template_css_tags: resolver.get("taggedTemplate.cssTags"),
+        tagged_template_graphql_tags: resolver.get("taggedTemplate.graphqlTags"),
+        tagged_template_html_tags: resolver.get("taggedTemplate.htmlTags"),
         /* brace position */
-        arrow_function_brace_position: get_value(&mut config, "arrowFunction.bracePosition", brace_position, &mut diagnostics),
-        class_declaration_brace_position: get_value(&mut config, "classDeclaration.bracePosition", brace_position, &mut diagnostics),
-        class_expression_brace_position: get_value(&mut config, "classExpression.bracePosition", brace_position, &mut diagnostics),
-        constructor_brace_position: get_value(&mut config, "constructor.bracePosition", brace_position, &mut diagnostics),
-        do_while_statement_brace_position: get_value(&mut config, "doWhileStatement.bracePosition", brace_position, &mut diagnostics),
-        enum_declaration_brace_position: get_value(&mut config, "enumDeclaration.bracePosition", brace_position, &mut diagnostics),
-        for_statement_brace_position: get_value(&mut config, "forStatement.bracePosition", brace_position, &mut diagnostics),
-        for_in_statement_brace_position: get_value(&mut config, "forInStatement.bracePosition", brace_position, &mut diagnostics),
-        for_of_statement_brace_position: get_value(&mut config, "forOfStatement.bracePosition", brace_position, &mut diagnostics),
-        get_accessor_brace_position: get_value(&mut config, "getAccessor.bracePosition", brace_position, &mut diagnostics),
-        if_statement_brace_position: get_value(&mut config, "ifStatement.bracePosition", brace_position, &mut diagnostics),
-        interface_declaration_brace_position: get_value(&mut config, "interfaceDeclaration.bracePosition", brace_position, &mut diagnostics),
-        function_declaration_brace_position: get_value(&mut config, "functionDeclaration.bracePosition", brace_position, &mut diagnostics),
-        function_expression_brace_position: get_value(&mut config, "functionExpression.bracePosition", brace_position, &mut diagnostics),
-        method_brace_position: get_value(&mut config, "method.bracePosition", brace_position, &mut diagnostics),
-        module_declaration_brace_position: get_value(&mut config, "moduleDeclaration.bracePosition", brace_position, &mut diagnostics),
-        set_accessor_brace_position: get_value(&mut config, "setAccessor.bracePosition", brace_position, &mut diagnostics),
-        switch_case_brace_position: get_value(&mut config, "switchCase.bracePosition", brace_position, &mut diagnostics),
-        switch_statement_brace_position: get_value(&mut config, "switchStatement.bracePosition", brace_position, &mut diagnostics),
-        try_statement_brace_position: get_value(&mut config, "tryStatement.bracePosition", brace_position, &mut diagnostics),
-        while_statement_brace_position: get_value(&mut config, "whileStatement.bracePosition", brace_position, &mut diagnostics),
+        arrow_function_brace_position: resolver.get("arrowFunction.bracePosition"),
+        class_declaration_brace_position: resolver.get("classDeclaration.bracePosition"),
+        class_expression_brace_position: resolver.get("classExpression.bracePosition"),
+        constructor_brace_position: resolver.get("constructor.bracePosition"),
+        do_while_statement_brace_position: resolver.get("doWhileStatement.bracePosition"),
+        enum_declaration_brace_position: resolver.get("enumDeclaration.bracePosition"),
+        for_statement_brace_position: resolver.get("forStatement.bracePosition"),
+        for_in_statement_brace_position: resolver.get("forInStatement.bracePosition"),
+        for_of_statement_brace_position: resolver.get("forOfStatement.bracePosition"),
+        get_accessor_brace_position: resolver.get("getAccessor.bracePosition"),
+        if_statement_brace_position: resolver.get("ifStatement.bracePosition"),
+        interface_declaration_brace_position: resolver.get("interfaceDeclaration.bracePosition"),
+        function_declaration_brace_position: resolver.get("functionDeclaration.bracePosition"),
+        function_expression_brace_position: resolver.get("functionExpression.bracePosition"),
+        method_brace_position: resolver.get("method.bracePosition"),
+        module_declaration_brace_position: resolver.get("moduleDeclaration.bracePosition"),
+        set_accessor_brace_position: resolver.get("setAccessor.bracePosition"),
+        switch_case_brace_position: resolver.get("switchCase.bracePosition"),
+        switch_statement_brace_position: resolver.get("switchStatement.bracePosition"),
+        try_statement_brace_position: resolver.get("tryStatement.bracePosition"),
+        while_statement_brace_position: resolver.get("whileStatement.bracePosition"),
         /* prefer hanging */
-        arguments_prefer_hanging: get_value(&mut config, "arguments.preferHanging", prefer_hanging, &mut diagnostics),
-        array_expression_prefer_hanging: get_value(&mut config, "arrayExpression.preferHanging", prefer_hanging, &mut diagnostics),
-        array_pattern_prefer_hanging: get_value(&mut config, "arrayPattern.preferHanging", prefer_hanging, &mut diagnostics),
-        do_while_statement_prefer_hanging: get_value(&mut config, "doWhileStatement.preferHanging", prefer_hanging, &mut diagnostics),
-        export_declaration_prefer_hanging: get_value(&mut config, "exportDeclaration.preferHanging", prefer_hanging, &mut diagnostics),
-        extends_clause_prefer_hanging: get_value(&mut config, "extendsClause.preferHanging", prefer_hanging, &mut diagnostics),
-        for_in_statement_prefer_hanging: get_value(&mut config, "forInStatement.preferHanging", prefer_hanging, &mut diagnostics),
-        for_of_statement_prefer_hanging: get_value(&mut config, "forOfStatement.preferHanging", prefer_hanging, &mut diagnostics),
-        for_statement_prefer_hanging: get_value(&mut config, "forStatement.preferHanging", prefer_hanging, &mut diagnostics),
-        if_statement_prefer_hanging: get_value(&mut config, "ifStatement.preferHanging", prefer_hanging, &mut diagnostics),
-        implements_clause_prefer_hanging: get_value(&mut config, "implementsClause.preferHanging", prefer_hanging, &mut diagnostics),
-        import_declaration_prefer_hanging: get_value(&mut config, "importDeclaration.preferHanging", prefer_hanging, &mut diagnostics),
-        jsx_attributes_prefer_hanging: get_value(&mut config, "jsxAttributes.preferHanging", prefer_hanging, &mut diagnostics),
-        object_expression_prefer_hanging: get_value(&mut config, "objectExpression.preferHanging", prefer_hanging, &mut diagnostics),
-        object_pattern_prefer_hanging: get_value(&mut config, "objectPattern.preferHanging", prefer_hanging, &mut diagnostics),
-        parameters_prefer_hanging: get_value(&mut config, "parameters.preferHanging", prefer_hanging, &mut diagnostics),
-        sequence_expression_prefer_hanging: get_value(&mut config, "sequenceExpression.preferHanging", prefer_hanging, &mut diagnostics),
-        switch_statement_prefer_hanging: get_value(&mut config, "switchStatement.preferHanging", prefer_hanging, &mut diagnostics),
-        tuple_type_prefer_hanging: get_value(&mut config, "tupleType.preferHanging", prefer_hanging, &mut diagnostics),
-        type_literal_prefer_hanging: get_value(&mut config, "typeLiteral.preferHanging", prefer_hanging, &mut diagnostics),
-        type_parameters_prefer_hanging: get_value(&mut config, "typeParameters.preferHanging", prefer_hanging, &mut diagnostics),
-        union_and_intersection_type_prefer_hanging: get_value(&mut config, "unionAndIntersectionType.preferHanging", prefer_hanging, &mut diagnostics),
-        variable_statement_prefer_hanging: get_value(&mut config, "variableStatement.preferHanging", prefer_hanging, &mut diagnostics),
-        while_statement_prefer_hanging: get_value(&mut config, "whileStatement.preferHanging", prefer_hanging, &mut diagnostics),
+        arguments_prefer_hanging: resolver.get("arguments.preferHanging"),
+        array_expression_prefer_hanging: resolver.get("arrayExpression.preferHanging"),
+        array_pattern_prefer_hanging: resolver.get("arrayPattern.preferHanging"),
+        do_while_statement_prefer_hanging: resolver.get("doWhileStatement.preferHanging"),
+        export_declaration_prefer_hanging: resolver.get("exportDeclaration.preferHanging"),
+        extends_clause_prefer_hanging: resolver.get("extendsClause.preferHanging"),
+        for_in_statement_prefer_hanging: resolver.get("forInStatement.preferHanging"),
+        for_of_statement_prefer_hanging: resolver.get("forOfStatement.preferHanging"),
+        for_statement_prefer_hanging: resolver.get("forStatement.preferHanging"),
+        if_statement_prefer_hanging: resolver.get("ifStatement.preferHanging"),
+        implements_clause_prefer_hanging: resolver.get("implementsClause.preferHanging"),
+        import_declaration_prefer_hanging: resolver.get("importDeclaration.preferHanging"),
+        jsx_attributes_prefer_hanging: resolver.get("jsxAttributes.preferHanging"),
+        object_expression_prefer_hanging: resolver.get("objectExpression.preferHanging"),
+        object_pattern_prefer_hanging: resolver.get("objectPattern.preferHanging"),
+        parameters_prefer_hanging: resolver.get("parameters.preferHanging"),
+        sequence_expression_prefer_hanging: resolver.get("sequenceExpression.preferHanging"),
+        switch_statement_prefer_hanging: resolver.get("switchStatement.preferHanging"),
+        tuple_type_prefer_hanging: resolver.get("tupleType.preferHanging"),
+        type_literal_prefer_hanging: resolver.get("typeLiteral.preferHanging"),
+        type_parameters_prefer_hanging: resolver.get("typeParameters.preferHanging"),
+        union_and_intersection_type_prefer_hanging: resolver.get("unionAndIntersectionType.preferHanging"),
+        variable_statement_prefer_hanging: resolver.get("variableStatement.preferHanging"),
+        while_statement_prefer_hanging: resolver.get("whileStatement.preferHanging"),
         /* member spacing */
-        enum_declaration_member_spacing: get_value(&mut config, "enumDeclaration.memberSpacing", MemberSpacing::Maintain, &mut diagnostics),
+        enum_declaration_member_spacing: resolver.get("enumDeclaration.memberSpacing"),
         /* next control flow position */
-        if_statement_next_control_flow_position: get_value(&mut config, "ifStatement.nextControlFlowPosition", next_control_flow_position, &mut diagnostics),
-        try_statement_next_control_flow_position: get_value(&mut config, "tryStatement.nextControlFlowPosition", next_control_flow_position, &mut diagnostics),
+        if_statement_next_control_flow_position: resolver.get("ifStatement.nextControlFlowPosition"),
+        try_statement_next_control_flow_position: resolver.get("tryStatement.nextControlFlowPosition"),
         /* operator position */
-        binary_expression_operator_position: get_value(&mut config, "binaryExpression.operatorPosition", operator_position, &mut diagnostics),
-        conditional_expression_operator_position: get_value(&mut config, "conditionalExpression.operatorPosition", operator_position, &mut diagnostics),
+        binary_expression_operator_position: resolver.get("binaryExpression.operatorPosition"),
+        conditional_expression_operator_position: resolver.get("conditionalExpression.operatorPosition"),
         /* single body position */
-        if_statement_single_body_position: get_value(&mut config, "ifStatement.singleBodyPosition", single_body_position, &mut diagnostics),
-        for_statement_single_body_position: get_value(&mut config, "forStatement.singleBodyPosition", single_body_position, &mut diagnostics),
-        for_in_statement_single_body_position: get_value(&mut config, "forInStatement.singleBodyPosition", single_body_position, &mut diagnostics),
-        for_of_statement_single_body_position: get_value(&mut config, "forOfStatement.singleBodyPosition", single_body_position, &mut diagnostics),
-        while_statement_single_body_position: get_value(&mut config, "whileStatement.singleBodyPosition", single_body_position, &mut diagnostics),
+        if_statement_single_body_position: resolver.get("ifStatement.singleBodyPosition"),
+        for_statement_single_body_position: resolver.get("forStatement.singleBodyPosition"),
+        for_in_statement_single_body_position: resolver.get("forInStatement.singleBodyPosition"),
+        for_of_statement_single_body_position: resolver.get("forOfStatement.singleBodyPosition"),
+        while_statement_single_body_position: resolver.get("whileStatement.singleBodyPosition"),
         /* trailing commas */
-        arguments_trailing_commas: get_value(&mut config, "arguments.trailingCommas", trailing_commas, &mut diagnostics),
-        parameters_trailing_commas: get_value(&mut config, "parameters.trailingCommas", trailing_commas, &mut diagnostics),
-        array_expression_trailing_commas: get_value(&mut config, "arrayExpression.trailingCommas", trailing_commas, &mut diagnostics),
-        array_pattern_trailing_commas: get_value(&mut config, "arrayPattern.trailingCommas", trailing_commas, &mut diagnostics),
-        enum_declaration_trailing_commas: get_value(&mut config, "enumDeclaration.trailingCommas", trailing_commas, &mut diagnostics),
-        export_declaration_trailing_commas: get_value(&mut config, "exportDeclaration.trailingCommas", trailing_commas, &mut diagnostics),
-        import_declaration_trailing_commas: get_value(&mut config, "importDeclaration.trailingCommas", trailing_commas, &mut diagnostics),
-        object_expression_trailing_commas: get_value(&mut config, "objectExpression.trailingCommas", trailing_commas, &mut diagnostics),
-        object_pattern_trailing_commas: get_value(&mut config, "objectPattern.trailingCommas", trailing_commas, &mut diagnostics),
-        tuple_type_trailing_commas: get_value(&mut config, "tupleType.trailingCommas", trailing_commas, &mut diagnostics),
-        type_parameters_trailing_commas: get_value(&mut config, "typeParameters.trailingCommas", trailing_commas, &mut diagnostics),
+        arguments_trailing_commas: resolver.get("arguments.trailingCommas"),
+        parameters_trailing_commas: resolver.get("parameters.trailingCommas"),
+        array_expression_trailing_commas: resolver.get("arrayExpression.trailingCommas"),
+        array_pattern_trailing_commas: resolver.get("arrayPattern.trailingCommas"),
+        enum_declaration_trailing_commas: resolver.get("enumDeclaration.trailingCommas"),
+        export_declaration_trailing_commas: resolver.get("exportDeclaration.trailingCommas"),
+        import_declaration_trailing_commas: resolver.get("importDeclaration.trailingCommas"),
+        object_expression_trailing_commas: resolver.get("objectExpression.trailingCommas"),
+        object_pattern_trailing_commas: resolver.get("objectPattern.trailingCommas"),
+        tuple_type_trailing_commas: resolver.get("tupleType.trailingCommas"),
+        type_parameters_trailing_commas: resolver.get("typeParameters.trailingCommas"),
         /* use braces */
-        if_statement_use_braces: get_value(&mut config, "ifStatement.useBraces", use_braces, &mut diagnostics),
-        for_statement_use_braces: get_value(&mut config, "forStatement.useBraces", use_braces, &mut diagnostics),
-        for_in_statement_use_braces: get_value(&mut config, "forInStatement.useBraces", use_braces, &mut diagnostics),
-        for_of_statement_use_braces: get_value(&mut config, "forOfStatement.useBraces", use_braces, &mut diagnostics),
-        while_statement_use_braces: get_value(&mut config, "whileStatement.useBraces", use_braces, &mut diagnostics),
+        if_statement_use_braces: resolver.get("ifStatement.useBraces"),
+        for_statement_use_braces: resolver.get("forStatement.useBraces"),
+        for_in_statement_use_braces: resolver.get("forInStatement.useBraces"),
+        for_of_statement_use_braces: resolver.get("forOfStatement.useBraces"),
+        while_statement_use_braces: resolver.get("whileStatement.useBraces"),
         /* prefer single line */
-        array_expression_prefer_single_line: get_value(&mut config, "arrayExpression.preferSingleLine", prefer_single_line, &mut diagnostics),
-        array_pattern_prefer_single_line: get_value(&mut config, "arrayPattern.preferSingleLine", prefer_single_line, &mut diagnostics),
-        arguments_prefer_single_line: get_value(&mut config, "arguments.preferSingleLine", prefer_single_line, &mut diagnostics),
-        binary_expression_prefer_single_line: get_value(&mut config, "binaryExpression.preferSingleLine", prefer_single_line, &mut diagnostics),
-        computed_prefer_single_line: get_value(&mut config, "computed.preferSingleLine", prefer_single_line, &mut diagnostics),
-        conditional_expression_prefer_single_line: get_value(&mut config, "conditionalExpression.preferSingleLine", prefer_single_line, &mut diagnostics),
-        conditional_type_prefer_single_line: get_value(&mut config, "conditionalType.preferSingleLine", prefer_single_line, &mut diagnostics),
-        decorators_prefer_single_line: get_value(&mut config, "decorators.preferSingleLine", prefer_single_line, &mut diagnostics),
-        export_declaration_prefer_single_line: get_value(&mut config, "exportDeclaration.preferSingleLine", prefer_single_line, &mut diagnostics),
-        for_statement_prefer_single_line: get_value(&mut config, "forStatement.preferSingleLine", prefer_single_line, &mut diagnostics),
-        import_declaration_prefer_single_line: get_value(&mut config, "importDeclaration.preferSingleLine", prefer_single_line, &mut diagnostics),
-        jsx_attributes_prefer_single_line: get_value(&mut config, "jsxAttributes.preferSingleLine", prefer_single_line, &mut diagnostics),
-        jsx_element_prefer_single_line: get_value(&mut config, "jsxElement.preferSingleLine", prefer_single_line, &mut diagnostics),
-        mapped_type_prefer_single_line: get_value(&mut config, "mappedType.preferSingleLine", prefer_single_line, &mut diagnostics),
-        member_expression_prefer_single_line: get_value(&mut config, "memberExpression.preferSingleLine", prefer_single_line, &mut diagnostics),
-        object_expression_prefer_single_line: get_value(&mut config, "objectExpression.preferSingleLine", prefer_single_line, &mut diagnostics),
-        object_pattern_prefer_single_line: get_value(&mut config, "objectPattern.preferSingleLine", prefer_single_line, &mut diagnostics),
-        parameters_prefer_single_line: get_value(&mut config, "parameters.preferSingleLine", prefer_single_line, &mut diagnostics),
-        parentheses_prefer_single_line: get_value(&mut config, "parentheses.preferSingleLine", prefer_single_line, &mut diagnostics),
-        tuple_type_prefer_single_line: get_value(&mut config, "tupleType.preferSingleLine", prefer_single_line, &mut diagnostics),
-        type_literal_prefer_single_line: get_value(&mut config, "typeLiteral.preferSingleLine", prefer_single_line, &mut diagnostics),
-        type_parameters_prefer_single_line: get_value(&mut config, "typeParameters.preferSingleLine", prefer_single_line, &mut diagnostics),
-        union_and_intersection_type_prefer_single_line: get_value(&mut config, "unionAndIntersectionType.preferSingleLine", prefer_single_line, &mut diagnostics),
-        variable_statement_prefer_single_line: get_value(&mut config, "variableStatement.preferSingleLine", prefer_single_line, &mut diagnostics),
+        array_expression_prefer_single_line: resolver.get("arrayExpression.preferSingleLine"),
+        array_pattern_prefer_single_line: resolver.get("arrayPattern.preferSingleLine"),
+        arguments_prefer_single_line: resolver.get("arguments.preferSingleLine"),
+        binary_expression_prefer_single_line: resolver.get("binaryExpression.preferSingleLine"),
+        computed_prefer_single_line: resolver.get("computed.preferSingleLine"),
+        conditional_expression_prefer_single_line: resolver.get("conditionalExpression.preferSingleLine"),
+        conditional_type_prefer_single_line: resolver.get("conditionalType.preferSingleLine"),
+        decorators_prefer_single_line: resolver.get("decorators.preferSingleLine"),
+        export_declaration_prefer_single_line: resolver.get("exportDeclaration.preferSingleLine"),
+        for_statement_prefer_single_line: resolver.get("forStatement.preferSingleLine"),
+        import_declaration_prefer_single_line: resolver.get("importDeclaration.preferSingleLine"),
+        jsx_attributes_prefer_single_line: resolver.get("jsxAttributes.preferSingleLine"),
+        jsx_element_prefer_single_line: resolver.get("jsxElement.preferSingleLine"),
+        mapped_type_prefer_single_line: resolver.get("mappedType.preferSingleLine"),
+        member_expression_prefer_single_line: resolver.get("memberExpression.preferSingleLine"),
+        object_expression_prefer_single_line: resolver.get("objectExpression.preferSingleLine"),
+        object_pattern_prefer_single_line: resolver.get("objectPattern.preferSingleLine"),
+        parameters_prefer_single_line: resolver.get("parameters.preferSingleLine"),
+        parentheses_prefer_single_line: resolver.get("parentheses.preferSingleLine"),
+        tuple_type_prefer_single_line: resolver.get("tupleType.preferSingleLine"),
+        type_literal_prefer_single_line: resolver.get("typeLiteral.preferSingleLine"),
+        type_parameters_prefer_single_line: resolver.get("typeParameters.preferSingleLine"),
+        union_and_intersection_type_prefer_single_line: resolver.get("unionAndIntersectionType.preferSingleLine"),
+        variable_statement_prefer_single_line: resolver.get("variableStatement.preferSingleLine"),
         /* space settings */
-        binary_expression_space_surrounding_bitwise_and_arithmetic_operator: get_value(&mut config, "binaryExpression.spaceSurroundingBitwiseAndArithmeticOperator", true, &mut diagnostics),
-        comment_line_force_space_after_slashes: get_value(&mut config, "commentLine.forceSpaceAfterSlashes", true, &mut diagnostics),
-        construct_signature_space_after_new_keyword: get_value(&mut config, "constructSignature.spaceAfterNewKeyword", false, &mut diagnostics),
-        constructor_space_before_parentheses: get_value(&mut config, "constructor.spaceBeforeParentheses", false, &mut diagnostics),
-        constructor_type_space_after_new_keyword: get_value(&mut config, "constructorType.spaceAfterNewKeyword", false, &mut diagnostics),
-        do_while_statement_space_after_while_keyword: get_value(&mut config, "doWhileStatement.spaceAfterWhileKeyword", true, &mut diagnostics),
-        export_declaration_space_surrounding_named_exports: get_value(&mut config, "exportDeclaration.spaceSurroundingNamedExports", true, &mut diagnostics),
-        for_statement_space_after_for_keyword: get_value(&mut config, "forStatement.spaceAfterForKeyword", true, &mut diagnostics),
-        for_statement_space_after_semi_colons: get_value(&mut config, "forStatement.spaceAfterSemiColons", true, &mut diagnostics),
-        for_in_statement_space_after_for_keyword: get_value(&mut config, "forInStatement.spaceAfterForKeyword", true, &mut diagnostics),
-        for_of_statement_space_after_for_keyword: get_value(&mut config, "forOfStatement.spaceAfterForKeyword", true, &mut diagnostics),
-        function_declaration_space_before_parentheses: get_value(&mut config, "functionDeclaration.spaceBeforeParentheses", false, &mut diagnostics),
-        function_expression_space_before_parentheses: get_value(&mut config, "functionExpression.spaceBeforeParentheses", false, &mut diagnostics),
-        function_expression_space_after_function_keyword: get_value(&mut config, "functionExpression.spaceAfterFunctionKeyword", false, &mut diagnostics),
-        get_accessor_space_before_parentheses: get_value(&mut config, "getAccessor.spaceBeforeParentheses", false, &mut diagnostics),
-        if_statement_space_after_if_keyword: get_value(&mut config, "ifStatement.spaceAfterIfKeyword", true, &mut diagnostics),
-        import_declaration_space_surrounding_named_imports: get_value(&mut config, "importDeclaration.spaceSurroundingNamedImports", true, &mut diagnostics),
-        jsx_expression_container_space_surrounding_expression: get_value(&mut config, "jsxExpressionContainer.spaceSurroundingExpression", false, &mut diagnostics),
-        method_space_before_parentheses: get_value(&mut config, "method.spaceBeforeParentheses", false, &mut diagnostics),
-        set_accessor_space_before_parentheses: get_value(&mut config, "setAccessor.spaceBeforeParentheses", false, &mut diagnostics),
-        tagged_template_space_before_literal: get_value(&mut config, "taggedTemplate.spaceBeforeLiteral", true, &mut diagnostics),
-        type_annotation_space_before_colon: get_value(&mut config, "typeAnnotation.spaceBeforeColon", false, &mut diagnostics),
-        type_assertion_space_before_expression: get_value(&mut config, "typeAssertion.spaceBeforeExpression", true, &mut diagnostics),
-        while_statement_space_after_while_keyword: get_value(&mut config, "whileStatement.spaceAfterWhileKeyword", true, &mut diagnostics),
-    };
-
-    for (key, _) in config.iter() {
-        diagnostics.push(ConfigurationDiagnostic {
-            property_name: String::from(key),
-            message: format!("Unknown property in configuration: {}", key),
-        });
-    }
-
-    return ResolveConfigurationResult {
-        config: resolved_config,
-        diagnostics,
+        binary_expression_space_surrounding_bitwise_and_arithmetic_operator: resolver.get("binaryExpression.spaceSurroundingBitwiseAndArithmeticOperator"),
+        comment_line_force_space_after_slashes: resolver.get("commentLine.forceSpaceAfterSlashes"),
+        construct_signature_space_after_new_keyword: resolver.get("constructSignature.spaceAfterNewKeyword"),
+        constructor_space_before_parentheses: resolver.get("constructor.spaceBeforeParentheses"),
+        constructor_type_space_after_new_keyword: resolver.get("constructorType.spaceAfterNewKeyword"),
+        do_while_statement_space_after_while_keyword: resolver.get("doWhileStatement.spaceAfterWhileKeyword"),
+        export_declaration_space_surrounding_named_exports: resolver.get("exportDeclaration.spaceSurroundingNamedExports"),
+        for_statement_space_after_for_keyword: resolver.get("forStatement.spaceAfterForKeyword"),
+        for_statement_space_after_semi_colons: resolver.get("forStatement.spaceAfterSemiColons"),
+        for_in_statement_space_after_for_keyword: resolver.get("forInStatement.spaceAfterForKeyword"),
+        for_of_statement_space_after_for_keyword: resolver.get("forOfStatement.spaceAfterForKeyword"),
+        function_declaration_space_before_parentheses: resolver.get("functionDeclaration.spaceBeforeParentheses"),
+        function_expression_space_before_parentheses: resolver.get("functionExpression.spaceBeforeParentheses"),
+        function_expression_space_after_function_keyword: resolver.get("functionExpression.spaceAfterFunctionKeyword"),
+        get_accessor_space_before_parentheses: resolver.get("getAccessor.spaceBeforeParentheses"),
+        if_statement_space_after_if_keyword: resolver.get("ifStatement.spaceAfterIfKeyword"),
+        import_declaration_space_surrounding_named_imports: resolver.get("importDeclaration.spaceSurroundingNamedImports"),
+        jsx_expression_container_space_surrounding_expression: resolver.get("jsxExpressionContainer.spaceSurroundingExpression"),
+        method_space_before_parentheses: resolver.get("method.spaceBeforeParentheses"),
+        set_accessor_space_before_parentheses: resolver.get("setAccessor.spaceBeforeParentheses"),
+        tagged_template_space_before_literal: resolver.get("taggedTemplate.spaceBeforeLiteral"),
+        type_annotation_space_before_colon: resolver.get("typeAnnotation.spaceBeforeColon"),
+        type_assertion_space_before_expression: resolver.get("typeAssertion.spaceBeforeExpression"),
+        while_statement_space_after_while_keyword: resolver.get("whileStatement.spaceAfterWhileKeyword"),
     };
 
-    fn fill_deno_config(config: &mut HashMap<String, String>) {
-        for (key, value) in ConfigurationBuilder::new().deno().config.iter() {
-            if !config.contains_key(key) {
-                config.insert(key.clone(), value.clone());
-            }
-        }
-    }
+    (resolved_config, resolver.finish())
 }
 
 #[cfg(test)]
@@ -260,4 +270,38 @@ mod tests {
         assert_eq!(result.config.line_width, expected_config.line_width);
         assert_eq!(result.diagnostics.len(), 0);
     }
+
+    #[test]
+    fn handle_auto_indentation() {
+        let mut config = HashMap::new();
+        config.insert(String::from("indentWidth"), String::from("auto"));
+        let mut global_config = HashMap::new();
+        global_config.insert(String::from("indentWidth"), String::from("2"));
+        global_config.insert(String::from("useTabs"), String::from("auto"));
+        let global_config = resolve_global_config(global_config).config;
+        let result = resolve_config(config, &global_config);
+        assert_eq!(result.config.indent_width, 2);
+        assert!(result.config.auto_indent_width);
+        assert!(!result.config.use_tabs);
+        assert!(result.config.auto_use_tabs);
+        assert_eq!(result.diagnostics.len(), 0);
+    }
+
+    #[test]
+    fn resolve_config_options_with_values_of_all_options() {
+        let mut config = HashMap::new();
+        config.insert(String::from("bracePosition"), String::from("sameLine"));
+        let global_config = resolve_global_config(HashMap::new()).config;
+        let (_, resolved_options) = resolve_config_options(config, &global_config);
+        let json_text = resolved_options.to_json_string();
+        assert_eq!(resolved_options.values.len(), CONFIG_OPTIONS.len());
+        assert_eq!(json_text.contains("\"lineWidth\": 120,"), true);
+        assert_eq!(json_text.contains("\"ifStatement.bracePosition\": \"sameLine\","), true);
+    }
+
+    #[test]
+    fn resolve_config_with_each_declared_option() {
+        let global_config = resolve_global_config(HashMap::new()).config;
+        dprint_development::ensure_config_options_resolve(CONFIG_OPTIONS, |config| resolve_config(config, &global_config).diagnostics);
+    }
 }
@@ -1,28 +1,6 @@
 use serde::{Serialize, Deserialize};
 use dprint_core::configuration::*;
-
-macro_rules! generate_str_to_from {
-    ($enum_name:ident, $([$member_name:ident, $string_value:expr]),* ) => {
-        impl std::str::FromStr for $enum_name {
-            type Err = ParseConfigurationError;
-
-            fn from_str(s: &str) -> Result<Self, Self::Err> {
-                match s {
-                    $($string_value => Ok($enum_name::$member_name)),*,
-                    _ => Err(ParseConfigurationError(String::from(s))),
-                }
-            }
-        }
-
-        impl std::string::ToString for $enum_name {
-            fn to_string(&self) -> String {
-                match self {
-                    $($enum_name::$member_name => String::from($string_value)),*,
-                }
-            }
-        }
-    };
-}
+use dprint_core::generate_str_to_from;
 
 /// Semi colon possibilities.
 #[derive(Clone, PartialEq, Copy, Serialize, Deserialize)]
@@ -245,19 +223,54 @@ pub struct Configuration {
     pub indent_width: u8,
     pub line_width: u32,
     pub use_tabs: bool,
+    /// Whether to detect the indent width from the file text, using `indent_width` when it can't be detected.
+    pub auto_indent_width: bool,
+    /// Whether to detect using tabs from the file text, using `use_tabs` when it can't be detected.
+    pub auto_use_tabs: bool,
     pub new_line_kind: NewLineKind,
+    pub unicode_width: bool,
+    pub max_condition_evaluations: u32,
+    pub max_visits_per_node: u32,
     pub quote_style: QuoteStyle,
     pub semi_colons: SemiColons,
     /* situational */
+    #[serde(rename = "arrayExpression.fill")]
+    pub array_expression_fill: bool,
     #[serde(rename = "arrowFunction.useParentheses")]
     pub arrow_function_use_parentheses: UseParentheses,
     #[serde(rename = "binaryExpression.linePerExpression")]
     pub binary_expression_line_per_expression: bool,
+    #[serde(rename = "commentBlock.alignGutters")]
+    pub comment_block_align_gutters: bool,
+    #[serde(rename = "commentBlock.reflow")]
+    pub comment_block_reflow: bool,
+    #[serde(rename = "commentLine.alignTrailing")]
+    pub comment_line_align_trailing: bool,
+    #[serde(rename = "enumDeclaration.alignValues")]
+    pub enum_declaration_align_values: bool,
+    #[serde(rename = "jsxElement.fillText")]
+    pub jsx_element_fill_text: bool,
     #[serde(rename = "memberExpression.linePerExpression")]
     pub member_expression_line_per_expression: bool,
+    #[serde(rename = "objectExpression.alignValues")]
+    pub object_expression_align_values: bool,
+    #[serde(rename = "variableStatement.alignAssignments")]
+    pub variable_statement_align_assignments: bool,
     /* ignore comments */
     pub ignore_node_comment_text: String,
     pub ignore_file_comment_text: String,
+    /* verification */
+    pub verify_semantic_equivalence: bool,
+    /* component files */
+    #[serde(rename = "scriptBlock.indent")]
+    pub script_block_indent: bool,
+    /* embedded languages */
+    #[serde(rename = "taggedTemplate.cssTags")]
+    pub tagged_template_css_tags: String,
+    #[serde(rename = "taggedTemplate.graphqlTags")]
+    pub tagged_template_graphql_tags: String,
+    #[serde(rename = "taggedTemplate.htmlTags")]
+    pub tagged_template_html_tags: String,
     /* brace position */
     #[serde(rename = "arrowFunction.bracePosition")]
     pub arrow_function_brace_position: BracePosition,
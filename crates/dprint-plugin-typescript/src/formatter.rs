@@ -1,10 +1,12 @@
 use swc_common::{GLOBALS, Globals};
 use dprint_core::*;
-use dprint_core::configuration::{resolve_new_line_kind};
+use dprint_core::configuration::{resolve_new_line_kind, detect_indent_width, detect_use_tabs};
+use std::borrow::Cow;
 use std::path::PathBuf;
-use super::parsing::parse;
-use super::swc::parse_swc_ast;
+use super::parsing::{parse, EmbeddedIndents, FormatEmbeddedText};
+use super::swc::{parse_swc_ast, is_ast_equivalent, ParsedSourceFile};
 use super::configuration::Configuration;
+use super::component_files::{is_component_file, get_script_blocks};
 
 /// Formatter for formatting JavaScript and TypeScript code.
 ///
@@ -50,26 +52,252 @@ impl Formatter {
 
     /// Formats a file.
     ///
+    /// For Vue and Svelte files, only the contents of the `<script>` blocks are formatted.
+    ///
     /// Returns the file text `Ok(formatted_text) or an error when it failed to parse.
-    pub fn format_text(&self, file_path: &PathBuf, file_text: &str) -> Result<String, String> {
+    /// When `verify_semantic_equivalence` is set, this also errors when the formatted
+    /// text's AST differs from the original.
+    pub fn format_text(&self, file_path: &PathBuf, file_text: &str) -> Result<String, FormatError> {
+        self.format_text_with_embedded(file_path, file_text, |_, _, _| Ok(None))
+    }
+
+    /// Formats a file using the provided function to format the embedded languages of
+    /// tagged templates (ex. css`...`).
+    ///
+    /// The function receives the file extension of the language (`css`, `graphql`, or `html`) along with the text
+    /// and the line width remaining after the template's indentation. It should return `Ok(None)`
+    /// when it can't format the language.
+    /// Tagged templates that fail to format are left as-is.
+    pub fn format_text_with_embedded(
+        &self,
+        file_path: &PathBuf,
+        file_text: &str,
+        format_embedded_text: impl Fn(&str, &str, u32) -> Result<Option<String>, FormatError>,
+    ) -> Result<String, FormatError> {
+        if is_component_file(file_path) {
+            let format_block = |file_path: &PathBuf, file_text: &str, indent_level: u32| {
+                self.format_script_text(file_path, file_text, indent_level, &format_embedded_text).map(|text| (text, ()))
+            };
+            self.format_component_text(file_path, file_text, format_block).map(|(formatted_text, _)| formatted_text)
+        } else {
+            self.format_script_text(file_path, file_text, 0, &format_embedded_text)
+        }
+    }
+
+    /// Gets if formatting a file would leave it unchanged using the provided function to format the
+    /// embedded languages of tagged templates.
+    ///
+    /// The printed text is compared to the file text without being created, except for Vue and Svelte
+    /// files and when `verify_semantic_equivalence` is set since those need the formatted text.
+    pub fn format_text_equals(
+        &self,
+        file_path: &PathBuf,
+        file_text: &str,
+        format_embedded_text: impl Fn(&str, &str, u32) -> Result<Option<String>, FormatError>,
+    ) -> Result<bool, FormatError> {
+        if is_component_file(file_path) || self.config.verify_semantic_equivalence {
+            return self.format_text_with_embedded(file_path, file_text, format_embedded_text).map(|formatted_text| formatted_text == file_text);
+        }
+
+        // the printed text is never created, so an empty string is returned in its place
+        let print = |print_items: PrintItems, print_options| print_equals(print_items, print_options, file_text).map(|is_equal| (String::new(), is_equal));
+        self.format_script_text_with_options(file_path, file_text, 0, &format_embedded_text, print, || true)
+            .map(|(_, is_equal)| is_equal)
+    }
+
+    /// Formats a JavaScript or TypeScript file and gets a map of the positions in the original text
+    /// to the positions in the formatted text (ex. for keeping the cursor in place in an editor).
+    pub fn format_text_with_mapping(&self, file_path: &PathBuf, file_text: &str) -> Result<(String, PositionMap), FormatError> {
+        if is_component_file(file_path) {
+            return Err(FormatError::internal(String::from("Mapping the positions of Vue and Svelte files is not supported.")));
+        }
+
+        let print = |print_items: PrintItems, print_options| print_with_mapping(print_items, print_options);
+        self.format_script_text_with_options(file_path, file_text, 0, &|_, _, _| Ok(None), print, || PositionMap::unchanged(file_text.len()))
+    }
+
+    /// Formats a JavaScript or TypeScript file and gets the lines of the formatted text that
+    /// are still wider than the line width.
+    pub fn format_text_with_long_lines(
+        &self,
+        file_path: &PathBuf,
+        file_text: &str,
+        format_embedded_text: impl Fn(&str, &str, u32) -> Result<Option<String>, FormatError>,
+    ) -> Result<(String, Vec<LongLine>), FormatError> {
+        let format_script_text = |file_path: &PathBuf, file_text: &str, indent_level: u32| {
+            let print = |print_items: PrintItems, print_options| print_with_long_lines(print_items, print_options);
+            self.format_script_text_with_options(file_path, file_text, indent_level, &format_embedded_text, print, Vec::new)
+        };
+
+        if !is_component_file(file_path) {
+            return format_script_text(file_path, file_text, 0);
+        }
+
+        let (formatted_text, block_results) = self.format_component_text(file_path, file_text, format_script_text)?;
+        let long_lines = block_results.into_iter().flat_map(|block_result| {
+            let ComponentBlockResult { line_offset, start, result } = block_result;
+            // make the long lines relative to the component file
+            result.into_iter().map(move |long_line| LongLine {
+                line_number: long_line.line_number + line_offset,
+                source_position: long_line.source_position.map(|position| position + start),
+                ..long_line
+            })
+        }).collect();
+        Ok((formatted_text, long_lines))
+    }
+
+    /// Gets the print items of a JavaScript or TypeScript file as an indented tree for debugging.
+    ///
+    /// For Vue and Svelte files, this is the print items of each `<script>` block.
+    pub fn get_print_ir(&self, file_path: &PathBuf, file_text: &str) -> Result<String, FormatError> {
+        if !is_component_file(file_path) {
+            return self.get_script_print_ir(file_path, file_text, 0);
+        }
+
+        let indent_level = if self.config.script_block_indent { 1 } else { 0 };
+        let mut text = String::new();
+        for block in get_script_blocks(file_text).into_iter() {
+            let block_text = &file_text[block.start..block.end];
+            if block_text.trim().is_empty() {
+                continue;
+            }
+
+            let script_file_path = file_path.with_extension(block.extension);
+            let block_print_ir = self.get_script_print_ir(&script_file_path, block_text, indent_level)
+                .map_err(|err| get_component_block_error(err, block.start, file_text))?;
+            text.push_str(&format!("<script> block on line {}:\n", get_line_number(file_text, block.start)));
+            text.push_str(&block_print_ir);
+        }
+        Ok(text)
+    }
+
+    fn get_script_print_ir(&self, file_path: &PathBuf, file_text: &str, indent_level: u32) -> Result<String, FormatError> {
+        self.run(|| {
+            let parsed_source_file = parse_swc_ast(file_path, file_text)?;
+            let config = resolve_auto_indentation(&self.config, file_text);
+            let embedded_indents = EmbeddedIndents::default();
+            let arena = PrintItemsArena::new();
+            let print_items = parse(&arena, &parsed_source_file, &config, &|_, _, _| Ok(None), &embedded_indents);
+            let print_items = if indent_level > 0 { parser_helpers::with_indent_times(print_items, indent_level) } else { print_items };
+            Ok(print_items.get_as_text())
+        })
+    }
+
+    /// Formats the `<script>` blocks of a Vue or Svelte component leaving the rest of the file as-is.
+    ///
+    /// The `format_block` function formats the text of a block with the provided indent level and may
+    /// also get information about the formatted block (ex. its long lines).
+    fn format_component_text<T>(
+        &self,
+        file_path: &PathBuf,
+        file_text: &str,
+        format_block: impl Fn(&PathBuf, &str, u32) -> Result<(String, T), FormatError>,
+    ) -> Result<(String, Vec<ComponentBlockResult<T>>), FormatError> {
+        let indent_level = if self.config.script_block_indent { 1 } else { 0 };
+        let mut final_text = String::new();
+        let mut block_results = Vec::new();
+        let mut last_end = 0;
+
+        for block in get_script_blocks(file_text).into_iter() {
+            let block_text = &file_text[block.start..block.end];
+            if block_text.trim().is_empty() {
+                continue;
+            }
+
+            let script_file_path = file_path.with_extension(block.extension);
+            let (formatted_text, result) = format_block(&script_file_path, block_text, indent_level)
+                .map_err(|err| get_component_block_error(err, block.start, file_text))?;
+
+            final_text.push_str(&file_text[last_end..block.start]);
+            if formatted_text != block_text {
+                final_text.push_str(resolve_new_line_kind(file_text, self.config.new_line_kind));
+            }
+            block_results.push(ComponentBlockResult {
+                line_offset: final_text.matches('\n').count() as u32,
+                start: block.start,
+                result,
+            });
+            final_text.push_str(&formatted_text);
+            last_end = block.end;
+        }
+
+        final_text.push_str(&file_text[last_end..]);
+        Ok((final_text, block_results))
+    }
+
+    fn format_script_text(&self, file_path: &PathBuf, file_text: &str, indent_level: u32, format_embedded_text: FormatEmbeddedText) -> Result<String, FormatError> {
+        let print = |print_items: PrintItems, print_options| dprint_core::print(print_items, print_options).map(|text| (text, ()));
+        self.format_script_text_with_options(file_path, file_text, indent_level, format_embedded_text, print, || ())
+            .map(|(formatted_text, _)| formatted_text)
+    }
+
+    /// Formats the script text using the provided function to print the print items, which may also
+    /// get information about the printed text (ex. a position map).
+    ///
+    /// The `get_ignored_result` function provides that information when the file has an ignore comment.
+    fn format_script_text_with_options<T>(
+        &self,
+        file_path: &PathBuf,
+        file_text: &str,
+        indent_level: u32,
+        format_embedded_text: FormatEmbeddedText,
+        print: impl FnOnce(PrintItems, PrintOptions) -> Result<(String, T), PrintError>,
+        get_ignored_result: impl FnOnce() -> T,
+    ) -> Result<(String, T), FormatError> {
         return self.run(|| {
             if has_ignore_comment(file_text, &self.config) {
-                return Ok(String::from(file_text));
+                return Ok((String::from(file_text), get_ignored_result()));
             }
 
             let parsed_source_file = parse_swc_ast(&file_path, &file_text)?;
-            let print_items = parse(&parsed_source_file, &self.config);
+            let config = resolve_auto_indentation(&self.config, file_text);
+            let get_print_options = || PrintOptions {
+                indent_width: config.indent_width,
+                max_width: config.line_width,
+                use_tabs: config.use_tabs,
+                new_line_text: resolve_new_line_kind(file_text, self.config.new_line_kind),
+                unicode_width: self.config.unicode_width,
+                budget: PrintBudget {
+                    max_condition_evaluations: config.max_condition_evaluations,
+                    max_visits_per_node: config.max_visits_per_node,
+                },
+            };
+            let embedded_indents = EmbeddedIndents::default();
+            let arena = PrintItemsArena::new();
+            let get_print_items = || {
+                let print_items = parse(&arena, &parsed_source_file, &config, format_embedded_text, &embedded_indents);
+                if indent_level > 0 { parser_helpers::with_indent_times(print_items, indent_level) } else { print_items }
+            };
+            let mut print_items = get_print_items();
+            if embedded_indents.has_templates() {
+                // the embedded templates were formatted with an estimated indentation, so print to find
+                // the actual one and parse again when it differs
+                let print_items_path = print_items.into_rc_path();
+                dprint_core::print(arena.items(print_items_path), get_print_options())?;
+                print_items = if embedded_indents.take_printed_widths() { get_print_items() } else { arena.items(print_items_path) };
+            }
+            let (formatted_text, result) = print(print_items, get_print_options())?;
 
-            // println!("{}", print_items.get_as_text());
+            if self.config.verify_semantic_equivalence {
+                verify_semantic_equivalence(file_path, &parsed_source_file, &formatted_text)?;
+            }
 
-            Ok(print(print_items, PrintOptions {
-                indent_width: self.config.indent_width,
-                max_width: self.config.line_width,
-                use_tabs: self.config.use_tabs,
-                new_line_text: resolve_new_line_kind(file_text, self.config.new_line_kind),
-            }))
+            Ok((formatted_text, result))
         });
 
+        fn verify_semantic_equivalence(file_path: &PathBuf, original: &ParsedSourceFile, formatted_text: &str) -> Result<(), FormatError> {
+            let formatted = match parse_swc_ast(file_path, formatted_text) {
+                Ok(formatted) => formatted,
+                Err(err) => return Err(FormatError::internal(format!("Refused to format because the formatted output failed to parse. {}", err.get_diagnostic_text(formatted_text)))),
+            };
+
+            if is_ast_equivalent(&original.module, &formatted.module)? {
+                Ok(())
+            } else {
+                Err(FormatError::internal(String::from("Refused to format because the formatted output was not semantically equivalent to the original.")))
+            }
+        }
+
         fn has_ignore_comment(file_text: &str, config: &Configuration) -> bool {
             let mut iterator = super::utils::CharIterator::new(file_text.chars());
             iterator.skip_whitespace();
@@ -88,3 +316,44 @@ impl Formatter {
         GLOBALS.set(&self.globals, action)
     }
 }
+
+/// Information about a formatted `<script>` block of a Vue or Svelte component.
+struct ComponentBlockResult<T> {
+    /// The number of lines before the block in the formatted component text.
+    line_offset: u32,
+    /// The byte position of the block in the original component text.
+    start: usize,
+    result: T,
+}
+
+/// Gets the error of a `<script>` block relative to the Vue or Svelte component file.
+fn get_component_block_error(err: FormatError, block_start: usize, file_text: &str) -> FormatError {
+    match err.range {
+        // make the ranges relative to the component file
+        Some(_) => err.with_offset(block_start, file_text),
+        None => FormatError {
+            message: format!("Error formatting the script block on line {}. {}", get_line_number(file_text, block_start), err.message),
+            ..err
+        },
+    }
+}
+
+fn get_line_number(file_text: &str, pos: usize) -> usize {
+    file_text[..pos].matches('\n').count() + 1
+}
+
+/// Gets the configuration with the indentation detected from the file text when it's `auto`.
+fn resolve_auto_indentation<'a>(config: &'a Configuration, file_text: &str) -> Cow<'a, Configuration> {
+    if !config.auto_indent_width && !config.auto_use_tabs {
+        return Cow::Borrowed(config);
+    }
+
+    let mut config = config.clone();
+    if config.auto_indent_width {
+        config.indent_width = detect_indent_width(file_text).unwrap_or(config.indent_width);
+    }
+    if config.auto_use_tabs {
+        config.use_tabs = detect_use_tabs(file_text).unwrap_or(config.use_tabs);
+    }
+    Cow::Owned(config)
+}
@@ -2,6 +2,7 @@ extern crate dprint_core;
 
 pub mod configuration;
 mod parsing;
+mod component_files;
 mod formatter;
 mod plugin;
 mod swc;
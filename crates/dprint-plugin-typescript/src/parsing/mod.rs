@@ -10,3 +10,4 @@ use parser_types::*;
 use tokens::*;
 
 pub use parser::parse;
+pub use parser_types::{EmbeddedIndents, FormatEmbeddedText};
@@ -12,10 +12,19 @@ use super::super::swc::ParsedSourceFile;
 use super::super::utils;
 use super::swc::{get_flattened_bin_expr};
 
-pub fn parse<'a>(source_file: &'a ParsedSourceFile, config: &Configuration) -> PrintItems {
+pub fn parse<'a>(
+    arena: &'a PrintItemsArena<'a>,
+    source_file: &'a ParsedSourceFile,
+    config: &'a Configuration,
+    format_embedded_text: FormatEmbeddedText<'a>,
+    embedded_indents: &'a EmbeddedIndents,
+) -> PrintItems<'a> {
     let module = Node::Module(&source_file.module);
     let mut context = Context::new(
+        arena,
         config,
+        format_embedded_text,
+        embedded_indents,
         &source_file.leading_comments,
         &source_file.trailing_comments,
         &source_file.tokens,
@@ -27,7 +36,7 @@ pub fn parse<'a>(source_file: &'a ParsedSourceFile, config: &Configuration) -> P
     items.push_condition(if_true(
         "endOfFileNewLine",
         |context| Some(context.writer_info.column_number > 0 || context.writer_info.line_number > 0),
-        Signal::NewLine.into()
+        context.arena.items(Signal::NewLine)
     ));
 
     #[cfg(debug_assertions)]
@@ -36,11 +45,11 @@ pub fn parse<'a>(source_file: &'a ParsedSourceFile, config: &Configuration) -> P
     items
 }
 
-fn parse_node<'a>(node: Node<'a>, context: &mut Context<'a>) -> PrintItems {
+fn parse_node<'a>(node: Node<'a>, context: &mut Context<'a>) -> PrintItems<'a> {
     parse_node_with_inner_parse(node, context, |items, _| items)
 }
 
-fn parse_node_with_inner_parse<'a>(node: Node<'a>, context: &mut Context<'a>, inner_parse: impl FnOnce(PrintItems, &mut Context<'a>) -> PrintItems) -> PrintItems {
+fn parse_node_with_inner_parse<'a>(node: Node<'a>, context: &mut Context<'a>, inner_parse: impl FnOnce(PrintItems<'a>, &mut Context<'a>) -> PrintItems<'a>) -> PrintItems<'a> {
     // println!("Node kind: {:?}", node.kind());
     // println!("Text: {:?}", node.text(context));
 
@@ -80,12 +89,16 @@ fn parse_node_with_inner_parse<'a>(node: Node<'a>, context: &mut Context<'a>, in
     }
 
     // parse the node
-    if has_ignore_comment {
-        items.push_str(""); // force the current line indentation
-        items.extend(parser_helpers::parse_raw_string(&node.text(context)));
+    let mut node_items = if has_ignore_comment {
+        let mut node_items = PrintItems::new(context.arena);
+        node_items.push_str(""); // force the current line indentation
+        node_items.extend(parser_helpers::parse_raw_string(context.arena, &node.text(context)));
+        node_items
     } else {
-        items.extend(inner_parse(parse_node_inner(node, context), context));
-    }
+        inner_parse(parse_node_inner(node, context), context)
+    };
+    node_items.set_source_position(node_lo.0 as usize);
+    items.extend(node_items);
 
     // get the trailing comments
     if node_hi != parent_hi || context.parent().kind() == NodeKind::Module {
@@ -94,7 +107,7 @@ fn parse_node_with_inner_parse<'a>(node: Node<'a>, context: &mut Context<'a>, in
     }
 
     let items = if let Some(info) = context.take_current_before_comments_start_info() {
-        let mut new_items = PrintItems::new();
+        let mut new_items = PrintItems::new(context.arena);
         new_items.push_info(info);
         new_items.extend(items);
         new_items
@@ -107,7 +120,7 @@ fn parse_node_with_inner_parse<'a>(node: Node<'a>, context: &mut Context<'a>, in
 
     return items;
 
-    fn parse_node_inner<'a>(node: Node<'a>, context: &mut Context<'a>) -> PrintItems {
+    fn parse_node_inner<'a>(node: Node<'a>, context: &mut Context<'a>) -> PrintItems<'a> {
         match node {
             /* class */
             Node::ClassMethod(node) => parse_class_method(node, context),
@@ -160,9 +173,9 @@ fn parse_node_with_inner_parse<'a>(node: Node<'a>, context: &mut Context<'a>, in
             Node::SeqExpr(node) => parse_sequence_expr(node, context),
             Node::SetterProp(node) => parse_setter_prop(node, context),
             Node::SpreadElement(node) => parse_spread_element(node, context),
-            Node::Super(_) => "super".into(),
+            Node::Super(_) => context.arena.items("super"),
             Node::TaggedTpl(node) => parse_tagged_tpl(node, context),
-            Node::ThisExpr(_) => "this".into(),
+            Node::ThisExpr(_) => context.arena.items("this"),
             Node::Tpl(node) => parse_tpl(node, context),
             Node::TplElement(node) => parse_tpl_element(node, context),
             Node::TsAsExpr(node) => parse_as_expr(node, context),
@@ -205,8 +218,8 @@ fn parse_node_with_inner_parse<'a>(node: Node<'a>, context: &mut Context<'a>, in
             Node::JSXText(node) => parse_jsx_text(node, context),
             /* literals */
             Node::BigInt(node) => parse_big_int_literal(node, context),
-            Node::Bool(node) => parse_bool_literal(node),
-            Node::Null(_) => "null".into(),
+            Node::Bool(node) => parse_bool_literal(node, context),
+            Node::Null(_) => context.arena.items("null"),
             Node::Number(node) => parse_num_literal(node, context),
             Node::Regex(node) => parse_reg_exp_literal(node, context),
             Node::Str(node) => parse_string_literal(node, context),
@@ -259,7 +272,7 @@ fn parse_node_with_inner_parse<'a>(node: Node<'a>, context: &mut Context<'a>, in
             Node::TsQualifiedName(node) => parse_qualified_name(node, context),
             Node::TsParenthesizedType(node) => parse_parenthesized_type(node, context),
             Node::TsRestType(node) => parse_rest_type(node, context),
-            Node::TsThisType(_) => "this".into(),
+            Node::TsThisType(_) => context.arena.items("this"),
             Node::TsTupleType(node) => parse_tuple_type(node, context),
             Node::TsTypeAnn(node) => parse_type_ann(node, context),
             Node::TsTypeParam(node) => parse_type_param(node, context),
@@ -271,13 +284,13 @@ fn parse_node_with_inner_parse<'a>(node: Node<'a>, context: &mut Context<'a>, in
             Node::TsTypeRef(node) => parse_type_reference(node, context),
             Node::TsUnionType(node) => parse_union_type(node, context),
             /* unknown */
-            _ => parse_raw_string(node.text(context).into()),
+            _ => parse_raw_string(context.arena, node.text(context).into()),
         }
     }
 
     #[inline]
-    fn handle_decorators_if_necessary<'a>(node: &Node<'a>, context: &mut Context<'a>) -> PrintItems {
-        let mut items = PrintItems::new();
+    fn handle_decorators_if_necessary<'a>(node: &Node<'a>, context: &mut Context<'a>) -> PrintItems<'a> {
+        let mut items = PrintItems::new(context.arena);
 
         // decorators in these cases will have starts before their parent so they need to be handled specially
         if let Node::ExportDecl(decl) = node {
@@ -373,7 +386,7 @@ fn parse_node_with_inner_parse<'a>(node: Node<'a>, context: &mut Context<'a>, in
 
 /* class */
 
-fn parse_class_method<'a>(node: &'a ClassMethod, context: &mut Context<'a>) -> PrintItems {
+fn parse_class_method<'a>(node: &'a ClassMethod, context: &mut Context<'a>) -> PrintItems<'a> {
     return parse_class_or_object_method(ClassOrObjectMethod {
         parameters_span_data: node.get_parameters_span_data(context),
         decorators: Some(&node.function.decorators),
@@ -392,7 +405,7 @@ fn parse_class_method<'a>(node: &'a ClassMethod, context: &mut Context<'a>) -> P
     }, context);
 }
 
-fn parse_class_prop<'a>(node: &'a ClassProp, context: &mut Context<'a>) -> PrintItems {
+fn parse_class_prop<'a>(node: &'a ClassProp, context: &mut Context<'a>) -> PrintItems<'a> {
     parse_class_prop_common(ParseClassPropCommon {
         key: (&node.key).into(),
         value: &node.value,
@@ -408,7 +421,7 @@ fn parse_class_prop<'a>(node: &'a ClassProp, context: &mut Context<'a>) -> Print
     }, context)
 }
 
-fn parse_constructor<'a>(node: &'a Constructor, context: &mut Context<'a>) -> PrintItems {
+fn parse_constructor<'a>(node: &'a Constructor, context: &mut Context<'a>) -> PrintItems<'a> {
     parse_class_or_object_method(ClassOrObjectMethod {
         parameters_span_data: node.get_parameters_span_data(context),
         decorators: None,
@@ -427,15 +440,15 @@ fn parse_constructor<'a>(node: &'a Constructor, context: &mut Context<'a>) -> Pr
     }, context)
 }
 
-fn parse_decorator<'a>(node: &'a Decorator, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_decorator<'a>(node: &'a Decorator, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.push_str("@");
     items.extend(parse_node((&node.expr).into(), context));
     return items;
 }
 
-fn parse_parameter_prop<'a>(node: &'a TsParamProp, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_parameter_prop<'a>(node: &'a TsParamProp, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.extend(parse_decorators(&node.decorators, true, context));
     if let Some(accessibility) = node.accessibility {
         items.push_str(&format!("{} ", accessibility_to_str(&accessibility)));
@@ -445,14 +458,14 @@ fn parse_parameter_prop<'a>(node: &'a TsParamProp, context: &mut Context<'a>) ->
     return items;
 }
 
-fn parse_private_name<'a>(node: &'a PrivateName, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_private_name<'a>(node: &'a PrivateName, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.push_str("#");
     items.extend(parse_node((&node.id).into(), context));
     items
 }
 
-fn parse_private_prop<'a>(node: &'a PrivateProp, context: &mut Context<'a>) -> PrintItems {
+fn parse_private_prop<'a>(node: &'a PrivateProp, context: &mut Context<'a>) -> PrintItems<'a> {
     parse_class_prop_common(ParseClassPropCommon {
         key: (&node.key).into(),
         value: &node.value,
@@ -482,8 +495,8 @@ struct ParseClassPropCommon<'a> {
     pub definite: bool,
 }
 
-fn parse_class_prop_common<'a>(node: ParseClassPropCommon<'a>, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_class_prop_common<'a>(node: ParseClassPropCommon<'a>, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.extend(parse_decorators(node.decorators, false, context));
     if let Some(accessibility) = node.accessibility {
         items.push_str(&format!("{} ", accessibility_to_str(accessibility)));
@@ -518,11 +531,11 @@ fn parse_class_prop_common<'a>(node: ParseClassPropCommon<'a>, context: &mut Con
 
 /* clauses */
 
-fn parse_catch_clause<'a>(node: &'a CatchClause, context: &mut Context<'a>) -> PrintItems {
+fn parse_catch_clause<'a>(node: &'a CatchClause, context: &mut Context<'a>) -> PrintItems<'a> {
     // a bit overkill since the param will currently always just be an identifer
     let start_header_info = Info::new("catchClauseHeaderStart");
     let end_header_info = Info::new("catchClauseHeaderEnd");
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(context.arena);
 
     items.push_info(start_header_info);
     items.push_str("catch");
@@ -558,15 +571,15 @@ fn parse_catch_clause<'a>(node: &'a CatchClause, context: &mut Context<'a>) -> P
 
 /* common */
 
-fn parse_computed_prop_name<'a>(node: &'a ComputedPropName, context: &mut Context<'a>) -> PrintItems {
+fn parse_computed_prop_name<'a>(node: &'a ComputedPropName, context: &mut Context<'a>) -> PrintItems<'a> {
     parse_computed_prop_like(ParseComputedPropLikeOptions {
         inner_node_span_data: node.expr.span_data(),
         inner_items: parse_node((&node.expr).into(), context),
     }, context)
 }
 
-fn parse_identifier<'a>(node: &'a Ident, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_identifier<'a>(node: &'a Ident, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.push_str(&node.sym as &str);
 
     if node.optional {
@@ -585,7 +598,7 @@ fn parse_identifier<'a>(node: &'a Ident, context: &mut Context<'a>) -> PrintItem
 
 /* declarations */
 
-fn parse_class_decl<'a>(node: &'a ClassDecl, context: &mut Context<'a>) -> PrintItems {
+fn parse_class_decl<'a>(node: &'a ClassDecl, context: &mut Context<'a>) -> PrintItems<'a> {
     return parse_class_decl_or_expr(ClassDeclOrExpr {
         span_data: node.class.span.data(),
         decorators: &node.class.decorators,
@@ -617,8 +630,8 @@ struct ClassDeclOrExpr<'a> {
     brace_position: BracePosition,
 }
 
-fn parse_class_decl_or_expr<'a>(node: ClassDeclOrExpr<'a>, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_class_decl_or_expr<'a>(node: ClassDeclOrExpr<'a>, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     let start_before_owned_comments_info = context.get_or_create_current_before_comments_start_info();
 
     // parse decorators
@@ -644,16 +657,16 @@ fn parse_class_decl_or_expr<'a>(node: ClassDeclOrExpr<'a>, context: &mut Context
         items.extend(parse_node(type_params, context));
     }
     if let Some(super_class) = node.super_class {
-        items.push_condition(conditions::new_line_if_hanging_space_otherwise(conditions::NewLineIfHangingSpaceOtherwiseOptions {
+        items.push_condition(conditions::new_line_if_hanging_space_otherwise(context.arena, conditions::NewLineIfHangingSpaceOtherwiseOptions {
             start_info: start_header_info,
             end_info: None,
-            space_char: Some(conditions::if_above_width_or(context.config.indent_width, Signal::SpaceOrNewLine.into(), " ".into()).into()),
+            space_char: Some(context.arena.items(conditions::if_above_width_or(context.config.indent_width, context.arena.items(Signal::SpaceOrNewLine), context.arena.items(" ")))),
         }));
         items.push_condition(conditions::indent_if_start_of_line({
-            let mut items = PrintItems::new();
+            let mut items = PrintItems::new(context.arena);
             items.push_str("extends ");
             items.extend(new_line_group({
-                let mut items = PrintItems::new();
+                let mut items = PrintItems::new(context.arena);
                 items.extend(parse_node(super_class, context));
                 if let Some(super_type_params) = node.super_type_params {
                     items.extend(parse_node(super_type_params, context));
@@ -683,7 +696,7 @@ fn parse_class_decl_or_expr<'a>(node: ClassDeclOrExpr<'a>, context: &mut Context
 
     if node.is_class_expr {
         let items = items.into_rc_path();
-        if_true_or(
+        context.arena.items(if_true_or(
             "classExprConditionalIndent",
             move |context| {
                 let resolved_start_before_comments_info = context.get_resolved_info(&start_before_owned_comments_info)?;
@@ -693,41 +706,41 @@ fn parse_class_decl_or_expr<'a>(node: ClassDeclOrExpr<'a>, context: &mut Context
                     condition_resolvers::is_multiple_lines(context, &start_before_owned_comments_info, &start_header_info)
                 }
             },
-            with_indent(items.clone().into()),
-            items.into(),
-        ).into()
+            with_indent(context.arena.items(items)),
+            context.arena.items(items),
+        ))
     } else {
         items
     }
 }
 
-fn parse_export_decl<'a>(node: &'a ExportDecl, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_export_decl<'a>(node: &'a ExportDecl, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     // decorators are handled in parse_node because their starts come before the ExportDecl
     items.push_str("export ");
     items.extend(parse_node((&node.decl).into(), context));
     items
 }
 
-fn parse_export_default_decl<'a>(node: &'a ExportDefaultDecl, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_export_default_decl<'a>(node: &'a ExportDefaultDecl, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     // decorators are handled in parse_node because their starts come before the ExportDefaultDecl
     items.push_str("export default ");
     items.extend(parse_node((&node.decl).into(), context));
     items
 }
 
-fn parse_export_default_expr<'a>(node: &'a ExportDefaultExpr, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_export_default_expr<'a>(node: &'a ExportDefaultExpr, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.push_str("export default ");
     items.extend(parse_node((&node.expr).into(), context));
     if context.config.semi_colons.is_true() { items.push_str(";"); }
     items
 }
 
-fn parse_enum_decl<'a>(node: &'a TsEnumDecl, context: &mut Context<'a>) -> PrintItems {
+fn parse_enum_decl<'a>(node: &'a TsEnumDecl, context: &mut Context<'a>) -> PrintItems<'a> {
     let start_header_info = Info::new("startHeader");
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(context.arena);
 
     // header
     items.push_info(start_header_info);
@@ -738,10 +751,18 @@ fn parse_enum_decl<'a>(node: &'a TsEnumDecl, context: &mut Context<'a>) -> Print
     items.extend(parse_node((&node.id).into(), context));
 
     // body
+    let members = node.members.iter().map(|x| x.into()).collect::<Vec<_>>();
+    if context.config.enum_declaration_align_values {
+        store_alignment_groups("enumMemberValues", &members, |member, context| match member {
+            Node::TsEnumMember(member) => get_assignment_op_span_data(member.init.as_ref()?, context),
+            _ => None,
+        }, context);
+    }
+
     let member_spacing = context.config.enum_declaration_member_spacing;
     items.extend(parse_membered_body(ParseMemberedBodyOptions {
         span_data: node.span.data(),
-        members: node.members.iter().map(|x| x.into()).collect(),
+        members,
         start_header_info: Some(start_header_info),
         brace_position: context.config.enum_declaration_brace_position,
         should_use_blank_line: move |previous, next, context| {
@@ -758,8 +779,8 @@ fn parse_enum_decl<'a>(node: &'a TsEnumDecl, context: &mut Context<'a>) -> Print
     return items;
 }
 
-fn parse_enum_member<'a>(node: &'a TsEnumMember, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_enum_member<'a>(node: &'a TsEnumMember, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.extend(parse_node((&node.id).into(), context));
 
     if let Some(init) = &node.init {
@@ -769,7 +790,7 @@ fn parse_enum_member<'a>(node: &'a TsEnumMember, context: &mut Context<'a>) -> P
     items
 }
 
-fn parse_export_named_decl<'a>(node: &'a NamedExport, context: &mut Context<'a>) -> PrintItems {
+fn parse_export_named_decl<'a>(node: &'a NamedExport, context: &mut Context<'a>) -> PrintItems<'a> {
     // fill specifiers
     let mut default_export: Option<&ExportDefaultSpecifier> = None;
     let mut namespace_export: Option<&ExportNamespaceSpecifier> = None;
@@ -788,7 +809,7 @@ fn parse_export_named_decl<'a>(node: &'a NamedExport, context: &mut Context<'a>)
         && node.start_line(context) == node.end_line(context);
 
     // parse
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(context.arena);
 
     items.push_str("export ");
     if node.type_only { items.push_str("type "); }
@@ -823,7 +844,7 @@ fn parse_export_named_decl<'a>(node: &'a NamedExport, context: &mut Context<'a>)
     }
 }
 
-fn parse_function_decl<'a>(node: &'a FnDecl, context: &mut Context<'a>) -> PrintItems {
+fn parse_function_decl<'a>(node: &'a FnDecl, context: &mut Context<'a>) -> PrintItems<'a> {
     parse_function_decl_or_expr(FunctionDeclOrExprNode {
         is_func_decl: true,
         ident: Some(&node.ident),
@@ -839,8 +860,8 @@ struct FunctionDeclOrExprNode<'a> {
     func: &'a Function,
 }
 
-fn parse_function_decl_or_expr<'a>(node: FunctionDeclOrExprNode<'a>, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_function_decl_or_expr<'a>(node: FunctionDeclOrExprNode<'a>, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     let start_header_info = Info::new("functionHeaderStart");
     let func = node.func;
     let space_after_function_keyword = !node.is_func_decl && context.config.function_expression_space_after_function_keyword;
@@ -910,14 +931,14 @@ fn parse_function_decl_or_expr<'a>(node: FunctionDeclOrExprNode<'a>, context: &m
     }
 }
 
-fn parse_param<'a>(node: &'a Param, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_param<'a>(node: &'a Param, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.extend(parse_decorators(&node.decorators, true, context));
     items.extend(parse_node((&node.pat).into(), context));
     items
 }
 
-fn parse_import_decl<'a>(node: &'a ImportDecl, context: &mut Context<'a>) -> PrintItems {
+fn parse_import_decl<'a>(node: &'a ImportDecl, context: &mut Context<'a>) -> PrintItems<'a> {
     // fill specifiers
     let mut default_import: Option<&ImportDefaultSpecifier> = None;
     let mut namespace_import: Option<&ImportStarAsSpecifier> = None;
@@ -943,7 +964,7 @@ fn parse_import_decl<'a>(node: &'a ImportDecl, context: &mut Context<'a>) -> Pri
         }
     };
     let has_from = default_import.is_some() || namespace_import.is_some() || has_named_imports;
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(context.arena);
 
     items.push_str("import ");
     if node.type_only { items.push_str("type "); }
@@ -981,8 +1002,8 @@ fn parse_import_decl<'a>(node: &'a ImportDecl, context: &mut Context<'a>) -> Pri
     }
 }
 
-fn parse_import_equals_decl<'a>(node: &'a TsImportEqualsDecl, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_import_equals_decl<'a>(node: &'a TsImportEqualsDecl, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     if node.is_export {
         items.push_str("export ");
     }
@@ -997,8 +1018,8 @@ fn parse_import_equals_decl<'a>(node: &'a TsImportEqualsDecl, context: &mut Cont
     return items;
 }
 
-fn parse_interface_decl<'a>(node: &'a TsInterfaceDecl, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_interface_decl<'a>(node: &'a TsInterfaceDecl, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     let start_header_info = Info::new("startHeader");
     items.push_info(start_header_info);
     context.store_info_for_node(node, start_header_info);
@@ -1018,7 +1039,7 @@ fn parse_interface_decl<'a>(node: &'a TsInterfaceDecl, context: &mut Context<'a>
     return items;
 }
 
-fn parse_module_decl<'a>(node: &'a TsModuleDecl, context: &mut Context<'a>) -> PrintItems {
+fn parse_module_decl<'a>(node: &'a TsModuleDecl, context: &mut Context<'a>) -> PrintItems<'a> {
     parse_module_or_namespace_decl(ModuleOrNamespaceDecl {
         span_data: node.span.data(),
         declare: node.declare,
@@ -1028,7 +1049,7 @@ fn parse_module_decl<'a>(node: &'a TsModuleDecl, context: &mut Context<'a>) -> P
     }, context)
 }
 
-fn parse_namespace_decl<'a>(node: &'a TsNamespaceDecl, context: &mut Context<'a>) -> PrintItems {
+fn parse_namespace_decl<'a>(node: &'a TsNamespaceDecl, context: &mut Context<'a>) -> PrintItems<'a> {
     parse_module_or_namespace_decl(ModuleOrNamespaceDecl {
         span_data: node.span.data(),
         declare: node.declare,
@@ -1046,8 +1067,8 @@ struct ModuleOrNamespaceDecl<'a> {
     pub body: Option<&'a TsNamespaceBody>,
 }
 
-fn parse_module_or_namespace_decl<'a>(node: ModuleOrNamespaceDecl<'a>, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_module_or_namespace_decl<'a>(node: ModuleOrNamespaceDecl<'a>, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
 
     let start_header_info = Info::new("startHeader");
     items.push_info(start_header_info);
@@ -1064,8 +1085,8 @@ fn parse_module_or_namespace_decl<'a>(node: ModuleOrNamespaceDecl<'a>, context:
 
     return items;
 
-    fn parse_body<'a>(body: Option<&'a TsNamespaceBody>, start_header_info: Info, context: &mut Context<'a>) -> PrintItems {
-        let mut items = PrintItems::new();
+    fn parse_body<'a>(body: Option<&'a TsNamespaceBody>, start_header_info: Info, context: &mut Context<'a>) -> PrintItems<'a> {
+        let mut items = PrintItems::new(context.arena);
         if let Some(body) = &body {
             match body {
                 TsNamespaceBody::TsModuleBlock(block) => {
@@ -1096,8 +1117,8 @@ fn parse_module_or_namespace_decl<'a>(node: ModuleOrNamespaceDecl<'a>, context:
     }
 }
 
-fn parse_type_alias<'a>(node: &'a TsTypeAliasDecl, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_type_alias<'a>(node: &'a TsTypeAliasDecl, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     if node.declare { items.push_str("declare "); }
     items.push_str("type ");
     items.extend(parse_node((&node.id).into(), context));
@@ -1114,7 +1135,7 @@ fn parse_type_alias<'a>(node: &'a TsTypeAliasDecl, context: &mut Context<'a>) ->
 
 /* exports */
 
-fn parse_named_import_or_export_specifiers<'a>(parent: &Node<'a>, specifiers: Vec<Node<'a>>, context: &mut Context<'a>) -> PrintItems {
+fn parse_named_import_or_export_specifiers<'a>(parent: &Node<'a>, specifiers: Vec<Node<'a>>, context: &mut Context<'a>) -> PrintItems<'a> {
     return parse_object_like_node(ParseObjectLikeNodeOptions {
         node_span_data: parent.span_data(),
         members: specifiers,
@@ -1123,6 +1144,7 @@ fn parse_named_import_or_export_specifiers<'a>(parent: &Node<'a>, specifiers: Ve
         prefer_hanging: get_prefer_hanging(parent, context),
         prefer_single_line: get_prefer_single_line(parent, context),
         surround_single_line_with_spaces: get_use_space(parent, context),
+        align_values: false,
     }, context);
 
     fn get_trailing_commas(parent_decl: &Node, context: &Context) -> TrailingCommas {
@@ -1160,18 +1182,45 @@ fn parse_named_import_or_export_specifiers<'a>(parent: &Node<'a>, specifiers: Ve
 
 /* expressions */
 
-fn parse_array_expr<'a>(node: &'a ArrayLit, context: &mut Context<'a>) -> PrintItems {
+fn parse_array_expr<'a>(node: &'a ArrayLit, context: &mut Context<'a>) -> PrintItems<'a> {
     parse_array_like_nodes(ParseArrayLikeNodesOptions {
         parent_span_data: node.span.data(),
         nodes: node.elems.iter().map(|x| x.as_ref().map(|elem| elem.into())).collect(),
         prefer_hanging: context.config.array_expression_prefer_hanging,
         prefer_single_line: context.config.array_expression_prefer_single_line,
         trailing_commas: context.config.array_expression_trailing_commas,
+        fill: context.config.array_expression_fill && node.elems.iter().all(is_fill_element),
     }, context)
 }
 
-fn parse_arrow_func_expr<'a>(node: &'a ArrowExpr, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+/// Gets if the array element is a number or short literal, which are packed on each line with `arrayExpression.fill`.
+fn is_fill_element(element: &Option<ExprOrSpread>) -> bool {
+    const MAX_SHORT_LITERAL_WIDTH: u32 = 12;
+    let element = match element {
+        Some(element) if element.spread.is_none() => element,
+        _ => return false,
+    };
+
+    return match &*element.expr {
+        Expr::Lit(Lit::Num(_)) | Expr::Lit(Lit::BigInt(_)) => true,
+        Expr::Unary(UnaryExpr { op: UnaryOp::Minus, arg, .. }) => is_number(arg),
+        Expr::Lit(Lit::Str(_)) | Expr::Lit(Lit::Bool(_)) | Expr::Lit(Lit::Null(_)) => {
+            let span = element.expr.span();
+            span.hi().0 - span.lo().0 <= MAX_SHORT_LITERAL_WIDTH
+        },
+        _ => false,
+    };
+
+    fn is_number(expr: &Expr) -> bool {
+        match expr {
+            Expr::Lit(Lit::Num(_)) | Expr::Lit(Lit::BigInt(_)) => true,
+            _ => false,
+        }
+    }
+}
+
+fn parse_arrow_func_expr<'a>(node: &'a ArrowExpr, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     let header_start_info = Info::new("arrowFunctionExpressionHeaderStart");
     let should_use_parens = get_should_use_parens(&node, context);
 
@@ -1220,7 +1269,7 @@ fn parse_arrow_func_expr<'a>(node: &'a ArrowExpr, context: &mut Context<'a>) ->
             start_header_info: Some(header_start_info),
         }, context));
 
-        items.extend(parsed_body.into());
+        items.extend(context.arena.items(parsed_body));
     } else {
         let start_body_info = Info::new("startBody");
         let end_body_info = Info::new("endBody");
@@ -1231,14 +1280,14 @@ fn parse_arrow_func_expr<'a>(node: &'a ArrowExpr, context: &mut Context<'a>) ->
         } else {
             items.push_condition(conditions::if_above_width_or(
                 context.config.indent_width,
-                if_true_or("newlineOrSpace", move |context| {
+                context.arena.items(if_true_or("newlineOrSpace", move |context| {
                     condition_resolvers::is_multiple_lines(context, &start_body_info, &end_body_info)
-                }, Signal::NewLine.into(), Signal::SpaceOrNewLine.into()).into(),
-                " ".into()
+                }, context.arena.items(Signal::NewLine), context.arena.items(Signal::SpaceOrNewLine))),
+                context.arena.items(" ")
             ));
         }
 
-        items.push_condition(conditions::indent_if_start_of_line(parsed_body.into()));
+        items.push_condition(conditions::indent_if_start_of_line(context.arena.items(parsed_body)));
         items.push_info(end_body_info);
     }
 
@@ -1290,8 +1339,8 @@ fn parse_arrow_func_expr<'a>(node: &'a ArrowExpr, context: &mut Context<'a>) ->
     }
 }
 
-fn parse_as_expr<'a>(node: &'a TsAsExpr, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_as_expr<'a>(node: &'a TsAsExpr, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.extend(parse_node((&node.expr).into(), context));
     items.push_str(" as");
     items.push_signal(Signal::SpaceIfNotTrailing);
@@ -1299,30 +1348,30 @@ fn parse_as_expr<'a>(node: &'a TsAsExpr, context: &mut Context<'a>) -> PrintItem
     items
 }
 
-fn parse_const_assertion<'a>(node: &'a TsConstAssertion, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_const_assertion<'a>(node: &'a TsConstAssertion, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.extend(parse_node((&node.expr).into(), context));
     items.push_str(" as const");
     items
 }
 
-fn parse_assignment_expr<'a>(node: &'a AssignExpr, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_assignment_expr<'a>(node: &'a AssignExpr, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.extend(parse_node((&node.left).into(), context));
     items.extend(parse_assignment((&node.right).into(), node.op.as_str(), context));
     items
 }
 
-fn parse_await_expr<'a>(node: &'a AwaitExpr, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_await_expr<'a>(node: &'a AwaitExpr, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.push_str("await ");
     items.extend(parse_node((&node.arg).into(), context));
     items
 }
 
-fn parse_binary_expr<'a>(node: &'a BinExpr, context: &mut Context<'a>) -> PrintItems {
+fn parse_binary_expr<'a>(node: &'a BinExpr, context: &mut Context<'a>) -> PrintItems<'a> {
     // todo: use a simplified version for nodes that don't need the complexity (for performance reasons)
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(context.arena);
     let flattened_binary_expr = get_flattened_bin_expr(node, context);
     // println!("Bin expr: {:?}", flattened_binary_expr.iter().map(|x| x.expr.text(context)).collect::<Vec<_>>());
     let line_per_expression = context.config.binary_expression_line_per_expression;
@@ -1357,6 +1406,7 @@ fn parse_binary_expr<'a>(node: &'a BinExpr, context: &mut Context<'a>) -> PrintI
 
     items.push_info(binary_expr_start_info);
 
+    let single_line_separator = if use_space_surrounding_operator { context.arena.items(Signal::SpaceOrNewLine) } else { PrintItems::new(context.arena) };
     items.extend(parser_helpers::parse_separated_values(|_| {
         let mut parsed_nodes = Vec::new();
         for bin_expr_item in flattened_binary_expr.into_iter() {
@@ -1364,7 +1414,7 @@ fn parse_binary_expr<'a>(node: &'a BinExpr, context: &mut Context<'a>) -> PrintI
                 start_line: bin_expr_item.expr.span_data().start_line(context),
                 end_line: bin_expr_item.expr.span_data().end_line(context)
             });
-            let mut items = PrintItems::new();
+            let mut items = PrintItems::new(context.arena);
 
             let pre_op = bin_expr_item.pre_op;
             let post_op = bin_expr_item.post_op;
@@ -1381,10 +1431,10 @@ fn parse_binary_expr<'a>(node: &'a BinExpr, context: &mut Context<'a>) -> PrintI
                     ).collect(),
                     context
                 ))
-            } else { (PrintItems::new(), PrintItems::new()) };
+            } else { (PrintItems::new(context.arena), PrintItems::new(context.arena)) };
             let is_inner_binary_expression = bin_expr_item.expr.kind() == NodeKind::BinExpr;
             items.extend(parse_node_with_inner_parse(bin_expr_item.expr, context, |node_items, context| {
-                let mut items = PrintItems::new();
+                let mut items = PrintItems::new(context.arena);
                 if let Some(op) = pre_op {
                     if !leading_pre_op_comments.is_empty() {
                         items.extend(leading_pre_op_comments);
@@ -1406,7 +1456,7 @@ fn parse_binary_expr<'a>(node: &'a BinExpr, context: &mut Context<'a>) -> PrintI
                     let node_items = node_items.into_rc_path();
                     with_queued_indent(
                         // indent again if it hasn't done the current binary expression's hanging indent
-                        if_true_or(
+                        context.arena.items(if_true_or(
                             "indentIfNecessary",
                             move |context| {
                                 let binary_expr_start_info = context.get_resolved_info(&binary_expr_start_info)?;
@@ -1414,9 +1464,9 @@ fn parse_binary_expr<'a>(node: &'a BinExpr, context: &mut Context<'a>) -> PrintI
                                 let is_hanging = binary_expr_start_info.indent_level < context.writer_info.indent_level;
                                 Some(!is_hanging)
                             },
-                            with_queued_indent(node_items.clone().into()),
-                            node_items.into(),
-                        ).into()
+                            with_queued_indent(context.arena.items(node_items)),
+                            context.arena.items(node_items),
+                        ))
                     )
                 } else {
                     node_items
@@ -1470,7 +1520,7 @@ fn parse_binary_expr<'a>(node: &'a BinExpr, context: &mut Context<'a>) -> PrintI
         allow_blank_lines: false,
         single_line_space_at_start: false,
         single_line_space_at_end: false,
-        single_line_separator: if use_space_surrounding_operator { Signal::SpaceOrNewLine.into() } else { PrintItems::new() },
+        single_line_separator,
         indent_width,
         multi_line_options,
         force_possible_newline_at_start: false,
@@ -1505,8 +1555,8 @@ fn parse_binary_expr<'a>(node: &'a BinExpr, context: &mut Context<'a>) -> PrintI
         }
     }
 
-    fn parse_op_comments(comments: Vec<&Comment>, context: &mut Context) -> PrintItems {
-        let mut items = PrintItems::new();
+    fn parse_op_comments<'a>(comments: Vec<&Comment>, context: &mut Context<'a>) -> PrintItems<'a> {
+        let mut items = PrintItems::new(context.arena);
         let mut had_comment_last = false;
         for comment in comments {
             if had_comment_last { items.push_str(" "); }
@@ -1529,15 +1579,15 @@ fn parse_binary_expr<'a>(node: &'a BinExpr, context: &mut Context<'a>) -> PrintI
     }
 }
 
-fn parse_call_expr<'a>(node: &'a CallExpr, context: &mut Context<'a>) -> PrintItems {
+fn parse_call_expr<'a>(node: &'a CallExpr, context: &mut Context<'a>) -> PrintItems<'a> {
     return if is_test_library_call_expr(&node, context) {
         parse_test_library_call_expr(node, context)
     } else {
         inner_parse(node, context)
     };
 
-    fn inner_parse<'a>(node: &'a CallExpr, context: &mut Context<'a>) -> PrintItems {
-        let mut items = PrintItems::new();
+    fn inner_parse<'a>(node: &'a CallExpr, context: &mut Context<'a>) -> PrintItems<'a> {
+        let mut items = PrintItems::new(context.arena);
 
         items.extend(parse_node((&node.callee).into(), context));
 
@@ -1559,19 +1609,19 @@ fn parse_call_expr<'a>(node: &'a CallExpr, context: &mut Context<'a>) -> PrintIt
         items
     }
 
-    fn parse_test_library_call_expr<'a>(node: &'a CallExpr, context: &mut Context<'a>) -> PrintItems {
-        let mut items = PrintItems::new();
+    fn parse_test_library_call_expr<'a>(node: &'a CallExpr, context: &mut Context<'a>) -> PrintItems<'a> {
+        let mut items = PrintItems::new(context.arena);
         items.extend(parse_test_library_callee(&node.callee, context));
         items.extend(parse_test_library_arguments(&node.args, context));
         return items;
 
-        fn parse_test_library_callee<'a>(callee: &'a ExprOrSuper, context: &mut Context<'a>) -> PrintItems {
+        fn parse_test_library_callee<'a>(callee: &'a ExprOrSuper, context: &mut Context<'a>) -> PrintItems<'a> {
             match callee {
                 ExprOrSuper::Expr(expr) => {
                     let expr = &**expr;
                     match expr {
                         Expr::Member(member_expr) => {
-                            let mut items = PrintItems::new();
+                            let mut items = PrintItems::new(context.arena);
                             items.extend(parse_node((&member_expr.obj).into(), context));
                             items.push_str(".");
                             items.extend(parse_node((&member_expr.prop).into(), context));
@@ -1584,8 +1634,8 @@ fn parse_call_expr<'a>(node: &'a CallExpr, context: &mut Context<'a>) -> PrintIt
             }
         }
 
-        fn parse_test_library_arguments<'a>(args: &'a Vec<ExprOrSpread>, context: &mut Context<'a>) -> PrintItems {
-            let mut items = PrintItems::new();
+        fn parse_test_library_arguments<'a>(args: &'a Vec<ExprOrSpread>, context: &mut Context<'a>) -> PrintItems<'a> {
+            let mut items = PrintItems::new(context.arena);
             items.push_str("(");
             items.extend(parse_node_with_inner_parse((&args[0]).into(), context, |items, _| {
                 let mut new_items = parser_helpers::with_no_new_lines(items);
@@ -1662,7 +1712,7 @@ fn parse_call_expr<'a>(node: &'a CallExpr, context: &mut Context<'a>) -> PrintIt
     }
 }
 
-fn parse_class_expr<'a>(node: &'a ClassExpr, context: &mut Context<'a>) -> PrintItems {
+fn parse_class_expr<'a>(node: &'a ClassExpr, context: &mut Context<'a>) -> PrintItems<'a> {
     parse_class_decl_or_expr(ClassDeclOrExpr {
         span_data: node.class.span.data(),
         decorators: &node.class.decorators,
@@ -1679,7 +1729,7 @@ fn parse_class_expr<'a>(node: &'a ClassExpr, context: &mut Context<'a>) -> Print
     }, context)
 }
 
-fn parse_conditional_expr<'a>(node: &'a CondExpr, context: &mut Context<'a>) -> PrintItems {
+fn parse_conditional_expr<'a>(node: &'a CondExpr, context: &mut Context<'a>) -> PrintItems<'a> {
     let operator_token = context.token_finder.get_first_operator_after(&*node.test, "?").unwrap();
     let force_new_lines = !context.config.conditional_expression_prefer_single_line && (
         node_helpers::get_use_new_lines_for_nodes(&*node.test, &*node.cons, context)
@@ -1689,7 +1739,7 @@ fn parse_conditional_expr<'a>(node: &'a CondExpr, context: &mut Context<'a>) ->
     let top_most_data = get_top_most_data(node, context);
     let before_alternate_info = Info::new("beforeAlternateInfo");
     let end_info = Info::new("endConditionalExpression");
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(context.arena);
 
     if top_most_data.is_top_most {
         items.push_info(top_most_data.top_most_info);
@@ -1710,21 +1760,21 @@ fn parse_conditional_expr<'a>(node: &'a CondExpr, context: &mut Context<'a>) ->
     if force_new_lines {
         items.push_signal(Signal::NewLine);
     } else {
-        items.push_condition(conditions::new_line_if_multiple_lines_space_or_new_line_otherwise(top_most_data.top_most_info, Some(before_alternate_info)));
+        items.push_condition(conditions::new_line_if_multiple_lines_space_or_new_line_otherwise(context.arena, top_most_data.top_most_info, Some(before_alternate_info)));
     }
 
     let cons_and_alt_items = {
-        let mut items = PrintItems::new();
+        let mut items = PrintItems::new(context.arena);
         if operator_position == OperatorPosition::NextLine {
             items.push_str("? ");
         }
         items.extend(parser_helpers::new_line_group(parse_node_with_inner_parse((&node.cons).into(), context, {
-            move |mut items, _| {
+            move |mut items, context| {
                 if operator_position == OperatorPosition::SameLine {
                     items.push_str(" :");
                     items
                 } else {
-                    conditions::indent_if_start_of_line(items).into()
+                    context.arena.items(conditions::indent_if_start_of_line(items))
                 }
             }
         })));
@@ -1732,16 +1782,16 @@ fn parse_conditional_expr<'a>(node: &'a CondExpr, context: &mut Context<'a>) ->
         if force_new_lines {
             items.push_signal(Signal::NewLine);
         } else {
-            items.push_condition(conditions::new_line_if_multiple_lines_space_or_new_line_otherwise(top_most_data.top_most_info, Some(before_alternate_info)));
+            items.push_condition(conditions::new_line_if_multiple_lines_space_or_new_line_otherwise(context.arena, top_most_data.top_most_info, Some(before_alternate_info)));
         }
 
         if operator_position == OperatorPosition::NextLine {
             items.push_str(": ");
         }
         items.push_info(before_alternate_info);
-        items.extend(parser_helpers::new_line_group(parse_node_with_inner_parse((&node.alt).into(), context, |items, _| {
+        items.extend(parser_helpers::new_line_group(parse_node_with_inner_parse((&node.alt).into(), context, |items, context| {
             if operator_position == OperatorPosition::NextLine {
-                conditions::indent_if_start_of_line(items).into()
+                context.arena.items(conditions::indent_if_start_of_line(items))
             } else {
                 items
             }
@@ -1815,15 +1865,15 @@ fn parse_conditional_expr<'a>(node: &'a CondExpr, context: &mut Context<'a>) ->
     }
 }
 
-fn parse_expr_or_spread<'a>(node: &'a ExprOrSpread, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_expr_or_spread<'a>(node: &'a ExprOrSpread, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     if node.spread.is_some() { items.push_str("..."); }
     items.extend(parse_node((&node.expr).into(), context));
     items
 }
 
-fn parse_expr_with_type_args<'a>(node: &'a TsExprWithTypeArgs, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_expr_with_type_args<'a>(node: &'a TsExprWithTypeArgs, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.extend(parse_node((&node.expr).into(), context));
     if let Some(type_args) = &node.type_args {
         items.extend(parse_node(type_args.into(), context));
@@ -1831,7 +1881,7 @@ fn parse_expr_with_type_args<'a>(node: &'a TsExprWithTypeArgs, context: &mut Con
     return items;
 }
 
-fn parse_fn_expr<'a>(node: &'a FnExpr, context: &mut Context<'a>) -> PrintItems {
+fn parse_fn_expr<'a>(node: &'a FnExpr, context: &mut Context<'a>) -> PrintItems<'a> {
     parse_function_decl_or_expr(FunctionDeclOrExprNode {
         is_func_decl: false,
         ident: node.ident.as_ref(),
@@ -1840,7 +1890,7 @@ fn parse_fn_expr<'a>(node: &'a FnExpr, context: &mut Context<'a>) -> PrintItems
     }, context)
 }
 
-fn parse_getter_prop<'a>(node: &'a GetterProp, context: &mut Context<'a>) -> PrintItems {
+fn parse_getter_prop<'a>(node: &'a GetterProp, context: &mut Context<'a>) -> PrintItems<'a> {
     return parse_class_or_object_method(ClassOrObjectMethod {
         parameters_span_data: node.get_parameters_span_data(context),
         decorators: None,
@@ -1859,14 +1909,14 @@ fn parse_getter_prop<'a>(node: &'a GetterProp, context: &mut Context<'a>) -> Pri
     }, context);
 }
 
-fn parse_key_value_prop<'a>(node: &'a KeyValueProp, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_key_value_prop<'a>(node: &'a KeyValueProp, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.extend(parse_node((&node.key).into(), context));
     items.extend(parse_assignment((&node.value).into(), ":", context));
     return items;
 }
 
-fn parse_member_expr<'a>(node: &'a MemberExpr, context: &mut Context<'a>) -> PrintItems {
+fn parse_member_expr<'a>(node: &'a MemberExpr, context: &mut Context<'a>) -> PrintItems<'a> {
     parse_for_member_like_expr(MemberLikeExpr {
         left_node: (&node.obj).into(),
         right_node: (&node.prop).into(),
@@ -1874,7 +1924,7 @@ fn parse_member_expr<'a>(node: &'a MemberExpr, context: &mut Context<'a>) -> Pri
     }, context)
 }
 
-fn parse_meta_prop_expr<'a>(node: &'a MetaPropExpr, context: &mut Context<'a>) -> PrintItems {
+fn parse_meta_prop_expr<'a>(node: &'a MetaPropExpr, context: &mut Context<'a>) -> PrintItems<'a> {
     parse_for_member_like_expr(MemberLikeExpr {
         left_node: (&node.meta).into(),
         right_node: (&node.prop).into(),
@@ -1882,8 +1932,8 @@ fn parse_meta_prop_expr<'a>(node: &'a MetaPropExpr, context: &mut Context<'a>) -
     }, context)
 }
 
-fn parse_new_expr<'a>(node: &'a NewExpr, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_new_expr<'a>(node: &'a NewExpr, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.push_str("new ");
     items.extend(parse_node((&node.callee).into(), context));
     if let Some(type_args) = &node.type_args { items.extend(parse_node(type_args.into(), context)); }
@@ -1900,14 +1950,14 @@ fn parse_new_expr<'a>(node: &'a NewExpr, context: &mut Context<'a>) -> PrintItem
     items
 }
 
-fn parse_non_null_expr<'a>(node: &'a TsNonNullExpr, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_non_null_expr<'a>(node: &'a TsNonNullExpr, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.extend(parse_node((&node.expr).into(), context));
     items.push_str("!");
     items
 }
 
-fn parse_object_lit<'a>(node: &'a ObjectLit, context: &mut Context<'a>) -> PrintItems {
+fn parse_object_lit<'a>(node: &'a ObjectLit, context: &mut Context<'a>) -> PrintItems<'a> {
     parse_object_like_node(ParseObjectLikeNodeOptions {
         node_span_data: node.span.data(),
         members: node.props.iter().map(|x| x.into()).collect(),
@@ -1916,11 +1966,12 @@ fn parse_object_lit<'a>(node: &'a ObjectLit, context: &mut Context<'a>) -> Print
         prefer_hanging: context.config.object_expression_prefer_hanging,
         prefer_single_line: context.config.object_expression_prefer_single_line,
         surround_single_line_with_spaces: true,
+        align_values: context.config.object_expression_align_values,
     }, context)
 }
 
-fn parse_paren_expr<'a>(node: &'a ParenExpr, context: &mut Context<'a>) -> PrintItems {
-    let parsed_items = conditions::with_indent_if_start_of_line_indented(parse_node_in_parens(
+fn parse_paren_expr<'a>(node: &'a ParenExpr, context: &mut Context<'a>) -> PrintItems<'a> {
+    let parsed_items = context.arena.items(conditions::with_indent_if_start_of_line_indented(parse_node_in_parens(
         |context| parse_node((&node.expr).into(), context),
         ParseNodeInParensOptions {
             inner_span: node.expr.span_data(),
@@ -1928,7 +1979,7 @@ fn parse_paren_expr<'a>(node: &'a ParenExpr, context: &mut Context<'a>) -> Print
             allow_open_paren_trailing_comments: true,
         },
         context
-    )).into();
+    )));
 
     return if get_use_new_line_group(node, context) {
         new_line_group(parsed_items)
@@ -1946,7 +1997,7 @@ fn parse_paren_expr<'a>(node: &'a ParenExpr, context: &mut Context<'a>) -> Print
     }
 }
 
-fn parse_sequence_expr<'a>(node: &'a SeqExpr, context: &mut Context<'a>) -> PrintItems {
+fn parse_sequence_expr<'a>(node: &'a SeqExpr, context: &mut Context<'a>) -> PrintItems<'a> {
     parse_separated_values(ParseSeparatedValuesOptions {
         nodes: node.exprs.iter().map(|x| Some(x.into())).collect(),
         prefer_hanging: context.config.sequence_expression_prefer_hanging,
@@ -1962,7 +2013,7 @@ fn parse_sequence_expr<'a>(node: &'a SeqExpr, context: &mut Context<'a>) -> Prin
     }, context)
 }
 
-fn parse_setter_prop<'a>(node: &'a SetterProp, context: &mut Context<'a>) -> PrintItems {
+fn parse_setter_prop<'a>(node: &'a SetterProp, context: &mut Context<'a>) -> PrintItems<'a> {
     parse_class_or_object_method(ClassOrObjectMethod {
         parameters_span_data: node.get_parameters_span_data(context),
         decorators: None,
@@ -1981,8 +2032,8 @@ fn parse_setter_prop<'a>(node: &'a SetterProp, context: &mut Context<'a>) -> Pri
     }, context)
 }
 
-fn parse_spread_element<'a>(node: &'a SpreadElement, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_spread_element<'a>(node: &'a SpreadElement, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.push_str("...");
     items.extend(parse_node((&node.expr).into(), context));
 
@@ -1993,31 +2044,201 @@ fn parse_spread_element<'a>(node: &'a SpreadElement, context: &mut Context<'a>)
     }
 }
 
-fn parse_tagged_tpl<'a>(node: &'a TaggedTpl, context: &mut Context<'a>) -> PrintItems {
+fn parse_tagged_tpl<'a>(node: &'a TaggedTpl, context: &mut Context<'a>) -> PrintItems<'a> {
     let use_space = context.config.tagged_template_space_before_literal;
     let mut items = parse_node((&node.tag).into(), context);
     if let Some(type_params) = &node.type_params { items.extend(parse_node(type_params.into(), context)); }
 
     items.push_condition(conditions::if_above_width_or(
         context.config.indent_width,
-        if use_space { Signal::SpaceOrNewLine } else { Signal::PossibleNewLine }.into(),
-        if use_space { " ".into() } else { PrintItems::new() }
+        context.arena.items(if use_space { Signal::SpaceOrNewLine } else { Signal::PossibleNewLine }),
+        if use_space { context.arena.items(" ") } else { PrintItems::new(context.arena) }
     ));
 
-    items.push_condition(conditions::indent_if_start_of_line(parse_template_literal(&node.quasis, &node.exprs.iter().map(|x| &**x).collect(), context)));
+    let exprs = node.exprs.iter().map(|x| &**x).collect();
+    let literal = match parse_embedded_template_literal(node, &exprs, context) {
+        Some(literal) => literal,
+        None => parse_template_literal(&node.quasis, &exprs, context),
+    };
+    items.push_condition(conditions::indent_if_start_of_line(literal));
     items
 }
 
-fn parse_tpl<'a>(node: &'a Tpl, context: &mut Context<'a>) -> PrintItems {
+/// Parses the template literal of a tagged template whose tag is configured as an embedded
+/// language (ex. css`...`) by formatting its text with the `${}` expressions substituted for
+/// placeholders. Returns `None` to print the literal as-is.
+fn parse_embedded_template_literal<'a>(node: &'a TaggedTpl, exprs: &Vec<&'a Expr>, context: &mut Context<'a>) -> Option<PrintItems<'a>> {
+    let tag_text = String::from(node.tag.text(context));
+    let extension = get_embedded_language_extension(&tag_text, context.config)?;
+    let quasi_texts = node.quasis.iter().map(|quasi| String::from(quasi.text(context))).collect::<Vec<_>>();
+
+    let placeholders = (0..exprs.len()).map(|i| format!("dprint_placeholder_{}_", i)).collect::<Vec<_>>();
+    let mut text = String::new();
+    for (i, quasi_text) in quasi_texts.iter().enumerate() {
+        if placeholders.iter().any(|placeholder| quasi_text.contains(placeholder.as_str())) {
+            return None;
+        }
+        text.push_str(quasi_text);
+        if let Some(placeholder) = placeholders.get(i) {
+            text.push_str(placeholder);
+        }
+    }
+    if text.trim().is_empty() {
+        return None;
+    }
+    // an expression on a line of its own (ex. a mixin without a semi-colon) isn't valid in the
+    // embedded language once substituted, so it would be merged with the following line
+    for i in 0..exprs.len() {
+        let text_before = quasi_texts[i].rsplit('\n').next().unwrap();
+        let text_after = quasi_texts[i + 1].split('\n').next().unwrap();
+        if text_before.trim().is_empty() && text_after.trim().is_empty() && quasi_texts[i + 1].contains('\n') {
+            return None;
+        }
+    }
+
+    // the text is printed indented once from the template
+    let indent_width = match context.embedded_indents.get_printed_width(node.lo()) {
+        Some(indent_width) => indent_width,
+        None => get_line_indent_width(node.lo(), context) + context.config.indent_width as u32,
+    };
+    let line_width = context.config.line_width.saturating_sub(indent_width);
+    let formatted_text = match (context.format_embedded_text)(extension, &text, line_width) {
+        Ok(Some(formatted_text)) => formatted_text,
+        // keep the original text when there is no formatter or it failed to format
+        Ok(None) | Err(_) => return None,
+    };
+    let formatted_text = formatted_text.trim_end();
+
+    // the raw text is formatted, so escapes (ex. \` or \\) must be kept as-is
+    if formatted_text.matches('\\').count() != text.matches('\\').count() || formatted_text.matches('`').count() != text.matches('`').count() {
+        return None;
+    }
+
+    // each expression must still appear once and in the same order
+    let mut last_index = 0;
+    for placeholder in placeholders.iter() {
+        if formatted_text.matches(placeholder.as_str()).count() != 1 {
+            return None;
+        }
+        let index = formatted_text.find(placeholder.as_str()).unwrap();
+        if index < last_index {
+            return None;
+        }
+        last_index = index;
+    }
+
+    let lines = formatted_text.lines().skip_while(|line| line.trim().is_empty()).collect::<Vec<_>>();
+    let is_single_line = lines.len() == 1 && !text.contains('\n');
+    let mut expr_index = 0;
+    let mut text_items = PrintItems::new(context.arena);
+    for line in lines.iter() {
+        if !is_single_line {
+            text_items.push_signal(Signal::NewLine);
+        }
+
+        // the indentation is printed with the file's indentation (ex. the embedded text may use tabs)
+        let mut line = line.trim_end();
+        let (indent_times, space_count) = get_embedded_line_indentation(line, std::cmp::max(context.config.indent_width as usize, 1));
+        line = line.trim_start();
+        let mut line_items = PrintItems::new(context.arena);
+        if space_count > 0 {
+            line_items.push_str(&" ".repeat(space_count));
+        }
+        while let Some(index) = placeholders.get(expr_index).and_then(|placeholder| line.find(placeholder.as_str())) {
+            if index > 0 {
+                line_items.push_str(&line[..index]);
+            }
+            line_items.push_str("${");
+            line_items.extend(parse_node(exprs[expr_index].into(), context));
+            line_items.push_str("}");
+            line = &line[index + placeholders[expr_index].len()..];
+            expr_index += 1;
+        }
+        if !line.is_empty() {
+            line_items.push_str(line);
+        }
+        text_items.extend(with_indent_times(line_items, indent_times));
+    }
+
+    // the text was formatted with an estimated indentation, so record the printed one
+    let printed_indent_width = context.embedded_indents.add_used_width(node.lo(), indent_width);
+    let indent_width_per_level = context.config.indent_width as u32;
+    let mut items = PrintItems::new(context.arena);
+    items.push_condition(Condition::new("embeddedIndentWidth", ConditionProperties {
+        condition: Rc::new(Box::new(move |condition_context| {
+            printed_indent_width.set(Some((condition_context.writer_info.indent_level as u32 + 1) * indent_width_per_level));
+            Some(false)
+        })),
+        true_path: None,
+        false_path: None,
+    }));
+    items.push_str("`");
+    if is_single_line {
+        items.extend(text_items);
+    } else {
+        items.extend(with_indent(text_items));
+        items.push_signal(Signal::NewLine);
+    }
+    items.push_str("`");
+    Some(items)
+}
+
+/// Gets the number of indents and remaining spaces of a line of formatted embedded text.
+///
+/// A tab is one indent and the spaces are counted as indents of the file's indent width.
+fn get_embedded_line_indentation(line: &str, indent_width: usize) -> (u32, usize) {
+    let indent_text = &line[..line.len() - line.trim_start().len()];
+    let tab_count = indent_text.matches('\t').count();
+    let space_count = indent_text.len() - tab_count;
+    ((tab_count + space_count / indent_width) as u32, space_count % indent_width)
+}
+
+/// Gets the width of the indentation of the line that the position is on.
+///
+/// This estimates the printed indentation of an embedded template before the file is printed.
+fn get_line_indent_width(pos: BytePos, context: &Context) -> u32 {
+    let line_start = context.file_bytes[..pos.0 as usize].iter().rposition(|c| *c == b'\n').map(|index| index + 1).unwrap_or(0);
+    context.file_bytes[line_start..].iter().take_while(|c| **c == b' ' || **c == b'\t').map(|c| {
+        if *c == b'\t' { context.config.indent_width as u32 } else { 1 }
+    }).sum()
+}
+
+/// Gets the file extension of the embedded language for the tag of a tagged template.
+fn get_embedded_language_extension(tag_text: &str, config: &Configuration) -> Option<&'static str> {
+    if matches_tag_names(tag_text, &config.tagged_template_css_tags) {
+        Some("css")
+    } else if matches_tag_names(tag_text, &config.tagged_template_graphql_tags) {
+        Some("graphql")
+    } else if matches_tag_names(tag_text, &config.tagged_template_html_tags) {
+        Some("html")
+    } else {
+        None
+    }
+}
+
+/// Gets if the tag text matches one of the comma separated tag names. A name ending
+/// in `.*` matches any member or call of that name (ex. `styled.*` matches `styled.div`).
+fn matches_tag_names(tag_text: &str, tag_names: &str) -> bool {
+    tag_names.split(',').map(|name| name.trim()).filter(|name| !name.is_empty()).any(|name| {
+        if name.ends_with(".*") {
+            let prefix = &name[..name.len() - 2];
+            tag_text.starts_with(prefix) && (tag_text[prefix.len()..].starts_with('.') || tag_text[prefix.len()..].starts_with('('))
+        } else {
+            tag_text == name
+        }
+    })
+}
+
+fn parse_tpl<'a>(node: &'a Tpl, context: &mut Context<'a>) -> PrintItems<'a> {
     parse_template_literal(&node.quasis, &node.exprs.iter().map(|x| &**x).collect(), context)
 }
 
-fn parse_tpl_element<'a>(node: &'a TplElement, context: &mut Context<'a>) -> PrintItems {
-    parse_raw_string(node.text(context).into())
+fn parse_tpl_element<'a>(node: &'a TplElement, context: &mut Context<'a>) -> PrintItems<'a> {
+    parse_raw_string(context.arena, node.text(context).into())
 }
 
-fn parse_template_literal<'a>(quasis: &'a Vec<TplElement>, exprs: &Vec<&'a Expr>, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_template_literal<'a>(quasis: &'a Vec<TplElement>, exprs: &Vec<&'a Expr>, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.push_str("`");
     items.push_signal(Signal::StartIgnoringIndent);
     for node in get_nodes(quasis, exprs) {
@@ -2111,8 +2332,8 @@ fn parse_template_literal<'a>(quasis: &'a Vec<TplElement>, exprs: &Vec<&'a Expr>
     }
 }
 
-fn parse_type_assertion<'a>(node: &'a TsTypeAssertion, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_type_assertion<'a>(node: &'a TsTypeAssertion, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.push_str("<");
     items.extend(parse_node((&node.type_ann).into(), context));
     items.push_str(">");
@@ -2121,8 +2342,8 @@ fn parse_type_assertion<'a>(node: &'a TsTypeAssertion, context: &mut Context<'a>
     items
 }
 
-fn parse_unary_expr<'a>(node: &'a UnaryExpr, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_unary_expr<'a>(node: &'a UnaryExpr, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.push_str(get_operator_text(node.op));
     items.extend(parse_node((&node.arg).into(), context));
     return items;
@@ -2140,8 +2361,8 @@ fn parse_unary_expr<'a>(node: &'a UnaryExpr, context: &mut Context<'a>) -> Print
     }
 }
 
-fn parse_update_expr<'a>(node: &'a UpdateExpr, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_update_expr<'a>(node: &'a UpdateExpr, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     let operator_text = get_operator_text(node.op);
     if node.prefix {
         items.push_str(operator_text);
@@ -2160,8 +2381,8 @@ fn parse_update_expr<'a>(node: &'a UpdateExpr, context: &mut Context<'a>) -> Pri
     }
 }
 
-fn parse_yield_expr<'a>(node: &'a YieldExpr, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_yield_expr<'a>(node: &'a YieldExpr, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.push_str("yield");
     if node.delegate { items.push_str("*"); }
     if let Some(arg) = &node.arg {
@@ -2173,14 +2394,14 @@ fn parse_yield_expr<'a>(node: &'a YieldExpr, context: &mut Context<'a>) -> Print
 
 /* exports */
 
-fn parse_export_named_specifier<'a>(node: &'a ExportNamedSpecifier, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_export_named_specifier<'a>(node: &'a ExportNamedSpecifier, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
 
     items.extend(parse_node((&node.orig).into(), context));
     if let Some(exported) = &node.exported {
         items.push_signal(Signal::SpaceOrNewLine);
         items.push_condition(conditions::indent_if_start_of_line({
-            let mut items = PrintItems::new();
+            let mut items = PrintItems::new(context.arena);
             items.push_str("as ");
             items.extend(parse_node(exported.into(), context));
             items
@@ -2190,8 +2411,8 @@ fn parse_export_named_specifier<'a>(node: &'a ExportNamedSpecifier, context: &mu
     items
 }
 
-fn parse_namespace_export_specifier<'a>(node: &'a ExportNamespaceSpecifier, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_namespace_export_specifier<'a>(node: &'a ExportNamespaceSpecifier, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.push_str("* as ");
     items.extend(parse_node((&node.name).into(), context));
     items
@@ -2199,14 +2420,14 @@ fn parse_namespace_export_specifier<'a>(node: &'a ExportNamespaceSpecifier, cont
 
 /* imports */
 
-fn parse_import_named_specifier<'a>(node: &'a ImportNamedSpecifier, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_import_named_specifier<'a>(node: &'a ImportNamedSpecifier, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
 
     if let Some(imported) = &node.imported {
         items.extend(parse_node(imported.into(), context));
         items.push_signal(Signal::SpaceOrNewLine);
         items.push_condition(conditions::indent_if_start_of_line({
-            let mut items = PrintItems::new();
+            let mut items = PrintItems::new(context.arena);
             items.push_str("as ");
             items.extend(parse_node((&node.local).into(), context));
             items
@@ -2218,16 +2439,16 @@ fn parse_import_named_specifier<'a>(node: &'a ImportNamedSpecifier, context: &mu
     items
 }
 
-fn parse_import_namespace_specifier<'a>(node: &'a ImportStarAsSpecifier, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_import_namespace_specifier<'a>(node: &'a ImportStarAsSpecifier, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.push_str("* as ");
     items.extend(parse_node((&node.local).into(), context));
     return items;
 }
 
-fn parse_external_module_ref<'a>(node: &'a TsExternalModuleRef, context: &mut Context<'a>) -> PrintItems {
+fn parse_external_module_ref<'a>(node: &'a TsExternalModuleRef, context: &mut Context<'a>) -> PrintItems<'a> {
     // force everything on a single line
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(context.arena);
     items.push_str("require(");
     items.extend(parse_node((&node.expr).into(), context));
     items.push_str(")");
@@ -2236,8 +2457,8 @@ fn parse_external_module_ref<'a>(node: &'a TsExternalModuleRef, context: &mut Co
 
 /* interface / type element */
 
-fn parse_call_signature_decl<'a>(node: &'a TsCallSignatureDecl, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_call_signature_decl<'a>(node: &'a TsCallSignatureDecl, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     let start_info = Info::new("startCallSignature");
 
     items.push_info(start_info);
@@ -2257,8 +2478,8 @@ fn parse_call_signature_decl<'a>(node: &'a TsCallSignatureDecl, context: &mut Co
     return items;
 }
 
-fn parse_construct_signature_decl<'a>(node: &'a TsConstructSignatureDecl, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_construct_signature_decl<'a>(node: &'a TsConstructSignatureDecl, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     let start_info = Info::new("startConstructSignature");
 
     items.push_info(start_info);
@@ -2280,8 +2501,8 @@ fn parse_construct_signature_decl<'a>(node: &'a TsConstructSignatureDecl, contex
     return items;
 }
 
-fn parse_index_signature<'a>(node: &'a TsIndexSignature, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_index_signature<'a>(node: &'a TsIndexSignature, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
 
     if node.readonly { items.push_str("readonly "); }
 
@@ -2295,8 +2516,8 @@ fn parse_index_signature<'a>(node: &'a TsIndexSignature, context: &mut Context<'
     return items;
 }
 
-fn parse_method_signature<'a>(node: &'a TsMethodSignature, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_method_signature<'a>(node: &'a TsMethodSignature, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     let start_info = Info::new("startMethodSignature");
     items.push_info(start_info);
 
@@ -2328,8 +2549,8 @@ fn parse_method_signature<'a>(node: &'a TsMethodSignature, context: &mut Context
     return items;
 }
 
-fn parse_property_signature<'a>(node: &'a TsPropertySignature, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_property_signature<'a>(node: &'a TsPropertySignature, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     if node.readonly { items.push_str("readonly "); }
 
     let key_items = parse_node((&node.key).into(), context);
@@ -2352,7 +2573,7 @@ fn parse_property_signature<'a>(node: &'a TsPropertySignature, context: &mut Con
     return items;
 }
 
-fn parse_interface_body<'a>(node: &'a TsInterfaceBody, context: &mut Context<'a>) -> PrintItems {
+fn parse_interface_body<'a>(node: &'a TsInterfaceBody, context: &mut Context<'a>) -> PrintItems<'a> {
     let start_header_info = get_parent_info(context);
 
     return parse_membered_body(ParseMemberedBodyOptions {
@@ -2377,7 +2598,7 @@ fn parse_interface_body<'a>(node: &'a TsInterfaceBody, context: &mut Context<'a>
     }
 }
 
-fn parse_type_lit<'a>(node: &'a TsTypeLit, context: &mut Context<'a>) -> PrintItems {
+fn parse_type_lit<'a>(node: &'a TsTypeLit, context: &mut Context<'a>) -> PrintItems<'a> {
     parse_object_like_node(ParseObjectLikeNodeOptions {
         node_span_data: node.span.data(),
         members: node.members.iter().map(|m| m.into()).collect(),
@@ -2386,13 +2607,14 @@ fn parse_type_lit<'a>(node: &'a TsTypeLit, context: &mut Context<'a>) -> PrintIt
         prefer_hanging: context.config.type_literal_prefer_hanging,
         prefer_single_line: context.config.type_literal_prefer_single_line,
         surround_single_line_with_spaces: true,
+        align_values: false,
     }, context)
 }
 
 /* jsx */
 
-fn parse_jsx_attribute<'a>(node: &'a JSXAttr, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_jsx_attribute<'a>(node: &'a JSXAttr, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.extend(parse_node((&node.name).into(), context));
     if let Some(value) = &node.value {
         items.push_str("=");
@@ -2407,19 +2629,19 @@ fn parse_jsx_attribute<'a>(node: &'a JSXAttr, context: &mut Context<'a>) -> Prin
     items
 }
 
-fn parse_jsx_closing_element<'a>(node: &'a JSXClosingElement, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_jsx_closing_element<'a>(node: &'a JSXClosingElement, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.push_str("</");
     items.extend(parse_node((&node.name).into(), context));
     items.push_str(">");
     items
 }
 
-fn parse_jsx_closing_fragment<'a>(_: &'a JSXClosingFragment, _: &mut Context<'a>) -> PrintItems {
-    "</>".into()
+fn parse_jsx_closing_fragment<'a>(_: &'a JSXClosingFragment, context: &mut Context<'a>) -> PrintItems<'a> {
+    context.arena.items("</>")
 }
 
-fn parse_jsx_element<'a>(node: &'a JSXElement, context: &mut Context<'a>) -> PrintItems {
+fn parse_jsx_element<'a>(node: &'a JSXElement, context: &mut Context<'a>) -> PrintItems<'a> {
     if let Some(closing) = &node.closing {
         parse_jsx_with_opening_and_closing(ParseJsxWithOpeningAndClosingOptions {
             opening_element: (&node.opening).into(),
@@ -2431,11 +2653,11 @@ fn parse_jsx_element<'a>(node: &'a JSXElement, context: &mut Context<'a>) -> Pri
     }
 }
 
-fn parse_jsx_empty_expr<'a>(node: &'a JSXEmptyExpr, context: &mut Context<'a>) -> PrintItems {
+fn parse_jsx_empty_expr<'a>(node: &'a JSXEmptyExpr, context: &mut Context<'a>) -> PrintItems<'a> {
     parse_comment_collection(get_jsx_empty_expr_comments(node, context), None, None, context)
 }
 
-fn parse_jsx_expr_container<'a>(node: &'a JSXExprContainer, context: &mut Context<'a>) -> PrintItems {
+fn parse_jsx_expr_container<'a>(node: &'a JSXExprContainer, context: &mut Context<'a>) -> PrintItems<'a> {
     // Don't send JSX empty expressions to parse_node because it will not handle comments
     // the way they should be specifically handled for empty expressions.
     let expr_items = match &node.expr {
@@ -2446,9 +2668,9 @@ fn parse_jsx_expr_container<'a>(node: &'a JSXExprContainer, context: &mut Contex
     parse_as_jsx_expr_container(expr_items, context)
 }
 
-fn parse_as_jsx_expr_container(parsed_node: PrintItems, context: &mut Context) -> PrintItems {
+fn parse_as_jsx_expr_container<'a>(parsed_node: PrintItems<'a>, context: &mut Context<'a>) -> PrintItems<'a> {
     let surround_with_space = context.config.jsx_expression_container_space_surrounding_expression;
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(context.arena);
 
     items.push_str("{");
     if surround_with_space { items.push_str(" "); }
@@ -2459,7 +2681,7 @@ fn parse_as_jsx_expr_container(parsed_node: PrintItems, context: &mut Context) -
     items
 }
 
-fn parse_jsx_fragment<'a>(node: &'a JSXFragment, context: &mut Context<'a>) -> PrintItems {
+fn parse_jsx_fragment<'a>(node: &'a JSXFragment, context: &mut Context<'a>) -> PrintItems<'a> {
     parse_jsx_with_opening_and_closing(ParseJsxWithOpeningAndClosingOptions {
         opening_element: (&node.opening).into(),
         closing_element: (&node.closing).into(),
@@ -2467,26 +2689,26 @@ fn parse_jsx_fragment<'a>(node: &'a JSXFragment, context: &mut Context<'a>) -> P
     }, context)
 }
 
-fn parse_jsx_member_expr<'a>(node: &'a JSXMemberExpr, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_jsx_member_expr<'a>(node: &'a JSXMemberExpr, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.extend(parse_node((&node.obj).into(), context));
     items.push_str(".");
     items.extend(parse_node((&node.prop).into(), context));
     items
 }
 
-fn parse_jsx_namespaced_name<'a>(node: &'a JSXNamespacedName, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_jsx_namespaced_name<'a>(node: &'a JSXNamespacedName, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.extend(parse_node((&node.ns).into(), context));
     items.push_str(":");
     items.extend(parse_node((&node.name).into(), context));
     items
 }
 
-fn parse_jsx_opening_element<'a>(node: &'a JSXOpeningElement, context: &mut Context<'a>) -> PrintItems {
+fn parse_jsx_opening_element<'a>(node: &'a JSXOpeningElement, context: &mut Context<'a>) -> PrintItems<'a> {
     let force_use_new_lines = get_force_is_multi_line(node, context);
     let start_info = Info::new("openingElementStartInfo");
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(context.arena);
 
     items.push_info(start_info);
     items.push_str("<");
@@ -2519,7 +2741,7 @@ fn parse_jsx_opening_element<'a>(node: &'a JSXOpeningElement, context: &mut Cont
         items.push_str("/");
     } else {
         if context.config.jsx_attributes_prefer_hanging {
-            items.push_condition(conditions::new_line_if_hanging(start_info, None));
+            items.push_condition(conditions::new_line_if_hanging(context.arena, start_info, None));
         }
     }
     items.push_str(">");
@@ -2537,23 +2759,23 @@ fn parse_jsx_opening_element<'a>(node: &'a JSXOpeningElement, context: &mut Cont
     }
 }
 
-fn parse_jsx_opening_fragment<'a>(_: &'a JSXOpeningFragment, _: &mut Context<'a>) -> PrintItems {
-    "<>".into()
+fn parse_jsx_opening_fragment<'a>(_: &'a JSXOpeningFragment, context: &mut Context<'a>) -> PrintItems<'a> {
+    context.arena.items("<>")
 }
 
-fn parse_jsx_spread_child<'a>(node: &'a JSXSpreadChild, context: &mut Context<'a>) -> PrintItems {
+fn parse_jsx_spread_child<'a>(node: &'a JSXSpreadChild, context: &mut Context<'a>) -> PrintItems<'a> {
     parse_as_jsx_expr_container({
-        let mut items = PrintItems::new();
+        let mut items = PrintItems::new(context.arena);
         items.push_str("...");
         items.extend(parse_node((&node.expr).into(), context));
         items
     }, context)
 }
 
-fn parse_jsx_text<'a>(node: &'a JSXText, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_jsx_text<'a>(node: &'a JSXText, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
 
-    for (i, line) in get_lines(node.text(context)).into_iter().enumerate() {
+    for (i, line) in get_jsx_text_lines(node.text(context)).into_iter().enumerate() {
         if i > 0 {
             items.push_signal(Signal::NewLine);
             items.push_signal(Signal::NewLine);
@@ -2573,59 +2795,60 @@ fn parse_jsx_text<'a>(node: &'a JSXText, context: &mut Context<'a>) -> PrintItem
     }
 
     return parser_helpers::new_line_group(items);
+}
 
-    fn get_lines(node_text: &str) -> Vec<String> {
-        let mut past_line: Option<&str> = None;
-        let lines = node_text.trim().lines().map(|line| line.trim());
-        let mut result = Vec::new();
-        let mut current_line = String::new();
-
-        for line in lines {
-            if let Some(past_line) = past_line {
-                if !line.is_empty() && past_line.is_empty() && !current_line.is_empty() {
-                    result.push(current_line);
-                    current_line = String::new();
-                }
-            }
+/// Gets the lines of the JSX text where single line breaks are joined with a space and blank lines separate the lines.
+fn get_jsx_text_lines(node_text: &str) -> Vec<String> {
+    let mut past_line: Option<&str> = None;
+    let lines = node_text.trim().lines().map(|line| line.trim());
+    let mut result = Vec::new();
+    let mut current_line = String::new();
 
-            if !line.is_empty() {
-                if !current_line.is_empty() {
-                    current_line.push_str(" ");
-                }
-                current_line.push_str(line);
+    for line in lines {
+        if let Some(past_line) = past_line {
+            if !line.is_empty() && past_line.is_empty() && !current_line.is_empty() {
+                result.push(current_line);
+                current_line = String::new();
             }
-
-            past_line.replace(line);
         }
 
-        if !current_line.is_empty() {
-            result.push(current_line);
+        if !line.is_empty() {
+            if !current_line.is_empty() {
+                current_line.push_str(" ");
+            }
+            current_line.push_str(line);
         }
 
-        result
+        past_line.replace(line);
     }
+
+    if !current_line.is_empty() {
+        result.push(current_line);
+    }
+
+    result
 }
 
 /* literals */
 
-fn parse_big_int_literal<'a>(node: &'a BigInt, context: &mut Context<'a>) -> PrintItems {
-    node.text(context).into()
+fn parse_big_int_literal<'a>(node: &'a BigInt, context: &mut Context<'a>) -> PrintItems<'a> {
+    context.arena.items(node.text(context))
 }
 
-fn parse_bool_literal(node: &Bool) -> PrintItems {
-    match node.value {
+fn parse_bool_literal<'a>(node: &Bool, context: &mut Context<'a>) -> PrintItems<'a> {
+    context.arena.items(match node.value {
         true => "true",
         false => "false",
-    }.into()
+    })
 }
 
-fn parse_num_literal<'a>(node: &'a Number, context: &mut Context<'a>) -> PrintItems {
-    node.text(context).into()
+fn parse_num_literal<'a>(node: &'a Number, context: &mut Context<'a>) -> PrintItems<'a> {
+    context.arena.items(node.text(context))
 }
 
-fn parse_reg_exp_literal(node: &Regex, _: &mut Context) -> PrintItems {
+fn parse_reg_exp_literal<'a>(node: &Regex, context: &mut Context<'a>) -> PrintItems<'a> {
     // the exp and flags should not be nodes so just ignore that (swc issue #511)
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(context.arena);
     items.push_str("/");
     items.push_str(&node.exp as &str);
     items.push_str("/");
@@ -2633,8 +2856,8 @@ fn parse_reg_exp_literal(node: &Regex, _: &mut Context) -> PrintItems {
     items
 }
 
-fn parse_string_literal<'a>(node: &'a Str, context: &mut Context<'a>) -> PrintItems {
-    return parse_raw_string(&get_string_literal_text(get_string_value(&node, context), context));
+fn parse_string_literal<'a>(node: &'a Str, context: &mut Context<'a>) -> PrintItems<'a> {
+    return parse_raw_string(context.arena, &get_string_literal_text(get_string_value(&node, context), context));
 
     fn get_string_literal_text(string_value: String, context: &mut Context) -> String {
         return match context.config.quote_style {
@@ -2689,8 +2912,8 @@ fn parse_string_literal<'a>(node: &'a Str, context: &mut Context<'a>) -> PrintIt
 
 /* module */
 
-fn parse_module<'a>(node: &'a Module, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_module<'a>(node: &'a Module, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     if let Some(shebang) = &node.shebang {
         items.push_str("#!");
         items.push_str(&shebang as &str);
@@ -2709,32 +2932,33 @@ fn parse_module<'a>(node: &'a Module, context: &mut Context<'a>) -> PrintItems {
 
 /* patterns */
 
-fn parse_array_pat<'a>(node: &'a ArrayPat, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_array_pat<'a>(node: &'a ArrayPat, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.extend(parse_array_like_nodes(ParseArrayLikeNodesOptions {
         parent_span_data: node.span.data(),
         nodes: node.elems.iter().map(|x| x.as_ref().map(|elem| elem.into())).collect(),
         prefer_hanging: context.config.array_pattern_prefer_hanging,
         prefer_single_line: context.config.array_pattern_prefer_single_line,
         trailing_commas: context.config.array_pattern_trailing_commas,
+        fill: false,
     }, context));
     if node.optional { items.push_str("?"); }
     items.extend(parse_type_ann_with_colon_if_exists(&node.type_ann, context));
     items
 }
 
-fn parse_assign_pat<'a>(node: &'a AssignPat, context: &mut Context<'a>) -> PrintItems {
+fn parse_assign_pat<'a>(node: &'a AssignPat, context: &mut Context<'a>) -> PrintItems<'a> {
     parser_helpers::new_line_group({
-        let mut items = PrintItems::new();
+        let mut items = PrintItems::new(context.arena);
         items.extend(parse_node((&node.left).into(), context));
         items.extend(parse_assignment((&node.right).into(), "=", context));
         items
     })
 }
 
-fn parse_assign_pat_prop<'a>(node: &'a AssignPatProp, context: &mut Context<'a>) -> PrintItems {
+fn parse_assign_pat_prop<'a>(node: &'a AssignPatProp, context: &mut Context<'a>) -> PrintItems<'a> {
     parser_helpers::new_line_group({
-        let mut items = PrintItems::new();
+        let mut items = PrintItems::new(context.arena);
         items.extend(parse_node((&node.key).into(), context));
         if let Some(value) = &node.value {
             items.extend(parse_assignment(value.into(), "=", context));
@@ -2743,16 +2967,16 @@ fn parse_assign_pat_prop<'a>(node: &'a AssignPatProp, context: &mut Context<'a>)
     })
 }
 
-fn parse_rest_pat<'a>(node: &'a RestPat, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_rest_pat<'a>(node: &'a RestPat, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.push_str("...");
     items.extend(parse_node((&node.arg).into(), context));
     items.extend(parse_type_ann_with_colon_if_exists(&node.type_ann, context));
     items
 }
 
-fn parse_object_pat<'a>(node: &'a ObjectPat, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_object_pat<'a>(node: &'a ObjectPat, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.extend(parse_object_like_node(ParseObjectLikeNodeOptions {
         node_span_data: node.span.data(),
         members: node.props.iter().map(|x| x.into()).collect(),
@@ -2761,6 +2985,7 @@ fn parse_object_pat<'a>(node: &'a ObjectPat, context: &mut Context<'a>) -> Print
         prefer_hanging: context.config.object_pattern_prefer_hanging,
         prefer_single_line: context.config.object_pattern_prefer_single_line,
         surround_single_line_with_spaces: true,
+        align_values: false,
     }, context));
     if node.optional { items.push_str("?"); }
     items.extend(parse_type_ann_with_colon_if_exists(&node.type_ann, context));
@@ -2778,7 +3003,7 @@ fn parse_object_pat<'a>(node: &'a ObjectPat, context: &mut Context<'a>) -> Print
 
 /* properties */
 
-fn parse_method_prop<'a>(node: &'a MethodProp, context: &mut Context<'a>) -> PrintItems {
+fn parse_method_prop<'a>(node: &'a MethodProp, context: &mut Context<'a>) -> PrintItems<'a> {
     return parse_class_or_object_method(ClassOrObjectMethod {
         parameters_span_data: node.get_parameters_span_data(context),
         decorators: None,
@@ -2831,8 +3056,8 @@ impl From<MethodKind> for ClassOrObjectMethodKind {
     }
 }
 
-fn parse_class_or_object_method<'a>(node: ClassOrObjectMethod<'a>, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_class_or_object_method<'a>(node: ClassOrObjectMethod<'a>, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     if let Some(decorators) = node.decorators.as_ref() {
         items.extend(parse_decorators(decorators, false, context));
     }
@@ -2918,7 +3143,7 @@ fn accessibility_to_str(accessibility: &Accessibility) -> &str {
 
 /* statements */
 
-fn parse_block_stmt<'a>(node: &'a BlockStmt, context: &mut Context<'a>) -> PrintItems {
+fn parse_block_stmt<'a>(node: &'a BlockStmt, context: &mut Context<'a>) -> PrintItems<'a> {
     parse_block(|stmts, context| {
         parse_statements(
             node.get_inner_span_data(context),
@@ -2931,8 +3156,8 @@ fn parse_block_stmt<'a>(node: &'a BlockStmt, context: &mut Context<'a>) -> Print
     }, context)
 }
 
-fn parse_break_stmt<'a>(node: &'a BreakStmt, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_break_stmt<'a>(node: &'a BreakStmt, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
 
     items.push_str("break");
     if let Some(label) = &node.label {
@@ -2946,8 +3171,8 @@ fn parse_break_stmt<'a>(node: &'a BreakStmt, context: &mut Context<'a>) -> Print
     items
 }
 
-fn parse_continue_stmt<'a>(node: &'a ContinueStmt, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_continue_stmt<'a>(node: &'a ContinueStmt, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
 
     items.push_str("continue");
     if let Some(label) = &node.label {
@@ -2961,8 +3186,8 @@ fn parse_continue_stmt<'a>(node: &'a ContinueStmt, context: &mut Context<'a>) ->
     items
 }
 
-fn parse_debugger_stmt<'a>(_: &'a DebuggerStmt, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_debugger_stmt<'a>(_: &'a DebuggerStmt, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
 
     items.push_str("debugger");
     if context.config.semi_colons.is_true() {
@@ -2972,9 +3197,9 @@ fn parse_debugger_stmt<'a>(_: &'a DebuggerStmt, context: &mut Context<'a>) -> Pr
     items
 }
 
-fn parse_do_while_stmt<'a>(node: &'a DoWhileStmt, context: &mut Context<'a>) -> PrintItems {
+fn parse_do_while_stmt<'a>(node: &'a DoWhileStmt, context: &mut Context<'a>) -> PrintItems<'a> {
     // the braces are technically optional on do while statements
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(context.arena);
     items.push_str("do");
     items.extend(parse_brace_separator(ParseBraceSeparatorOptions {
         brace_position: context.config.do_while_statement_brace_position,
@@ -3001,8 +3226,8 @@ fn parse_do_while_stmt<'a>(node: &'a DoWhileStmt, context: &mut Context<'a>) ->
     return items;
 }
 
-fn parse_export_all<'a>(node: &'a ExportAll, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_export_all<'a>(node: &'a ExportAll, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.push_str("export * from ");
     items.extend(parse_node((&node.src).into(), context));
 
@@ -3013,12 +3238,12 @@ fn parse_export_all<'a>(node: &'a ExportAll, context: &mut Context<'a>) -> Print
     items
 }
 
-fn parse_empty_stmt(_: &EmptyStmt, _: &mut Context) -> PrintItems {
-    ";".into()
+fn parse_empty_stmt<'a>(_: &EmptyStmt, context: &mut Context<'a>) -> PrintItems<'a> {
+    context.arena.items(";")
 }
 
-fn parse_export_assignment<'a>(node: &'a TsExportAssignment, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_export_assignment<'a>(node: &'a TsExportAssignment, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
 
     items.push_str("export");
     items.extend(parse_assignment((&node.expr).into(), "=", context));
@@ -3029,8 +3254,8 @@ fn parse_export_assignment<'a>(node: &'a TsExportAssignment, context: &mut Conte
     items
 }
 
-fn parse_namespace_export<'a>(node: &'a TsNamespaceExportDecl, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_namespace_export<'a>(node: &'a TsNamespaceExportDecl, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.push_str("export as namespace ");
     items.extend(parse_node((&node.id).into(), context));
 
@@ -3041,15 +3266,15 @@ fn parse_namespace_export<'a>(node: &'a TsNamespaceExportDecl, context: &mut Con
     items
 }
 
-fn parse_expr_stmt<'a>(stmt: &'a ExprStmt, context: &mut Context<'a>) -> PrintItems {
+fn parse_expr_stmt<'a>(stmt: &'a ExprStmt, context: &mut Context<'a>) -> PrintItems<'a> {
     if context.config.semi_colons.is_true() {
         return parse_inner(&stmt, context);
     } else {
         return parse_for_prefix_semi_colon_insertion(&stmt, context);
     }
 
-    fn parse_inner<'a>(stmt: &'a ExprStmt, context: &mut Context<'a>) -> PrintItems {
-        let mut items = PrintItems::new();
+    fn parse_inner<'a>(stmt: &'a ExprStmt, context: &mut Context<'a>) -> PrintItems<'a> {
+        let mut items = PrintItems::new(context.arena);
         items.extend(parse_node((&stmt.expr).into(), context));
         if context.config.semi_colons.is_true() {
             items.push_str(";");
@@ -3057,22 +3282,22 @@ fn parse_expr_stmt<'a>(stmt: &'a ExprStmt, context: &mut Context<'a>) -> PrintIt
         return items;
     }
 
-    fn parse_for_prefix_semi_colon_insertion<'a>(stmt: &'a ExprStmt, context: &mut Context<'a>) -> PrintItems {
+    fn parse_for_prefix_semi_colon_insertion<'a>(stmt: &'a ExprStmt, context: &mut Context<'a>) -> PrintItems<'a> {
         let parsed_node = parse_inner(&stmt, context);
         let parsed_node = parsed_node.into_rc_path();
         return if should_add_semi_colon(&parsed_node).unwrap_or(false) {
-            let mut items = PrintItems::new();
+            let mut items = PrintItems::new(context.arena);
             items.push_str(";");
-            items.extend(parsed_node.into());
+            items.extend(context.arena.items(parsed_node));
             items
         } else {
-            parsed_node.into()
+            context.arena.items(parsed_node)
         };
 
         fn should_add_semi_colon(path: &Option<PrintItemPath>) -> Option<bool> {
             // todo: this needs to be improved
             if let Some(path) = path {
-                for item in PrintItemsIterator::new(path.clone()) {
+                for item in PrintItemsIterator::new(*path) {
                     match item {
                         PrintItem::String(value) => {
                             if let Some(c) = value.text.chars().next() {
@@ -3106,7 +3331,7 @@ fn parse_expr_stmt<'a>(stmt: &'a ExprStmt, context: &mut Context<'a>) -> PrintIt
     }
 }
 
-fn parse_for_stmt<'a>(node: &'a ForStmt, context: &mut Context<'a>) -> PrintItems {
+fn parse_for_stmt<'a>(node: &'a ForStmt, context: &mut Context<'a>) -> PrintItems<'a> {
     let start_header_info = Info::new("startHeader");
     let end_header_info = Info::new("endHeader");
     let first_inner_node = {
@@ -3129,7 +3354,7 @@ fn parse_for_stmt<'a>(node: &'a ForStmt, context: &mut Context<'a>) -> PrintItem
         }
     };
     let force_use_new_lines = get_use_new_lines(&first_inner_node, context);
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(context.arena);
     items.push_info(start_header_info);
     items.push_str("for");
     if context.config.for_statement_space_after_for_keyword {
@@ -3138,7 +3363,7 @@ fn parse_for_stmt<'a>(node: &'a ForStmt, context: &mut Context<'a>) -> PrintItem
 
     let separator_after_semi_colons = if context.config.for_statement_space_after_semi_colons { Signal::SpaceOrNewLine } else { Signal::PossibleNewLine };
     let parsed_init = parser_helpers::new_line_group({
-        let mut items = PrintItems::new();
+        let mut items = PrintItems::new(context.arena);
         if let Some(init) = &node.init {
             items.extend(parse_node(init.into(), context));
         }
@@ -3148,7 +3373,7 @@ fn parse_for_stmt<'a>(node: &'a ForStmt, context: &mut Context<'a>) -> PrintItem
     });
     let parsed_test = if let Some(test) = &node.test {
         Some(parser_helpers::new_line_group({
-            let mut items = PrintItems::new();
+            let mut items = PrintItems::new(context.arena);
             items.extend(parse_node(test.into(), context));
             items.push_str(";");
             items
@@ -3176,7 +3401,7 @@ fn parse_for_stmt<'a>(node: &'a ForStmt, context: &mut Context<'a>) -> PrintItem
                 allow_blank_lines: false,
                 single_line_space_at_start: false,
                 single_line_space_at_end: false,
-                single_line_separator: separator_after_semi_colons.into(),
+                single_line_separator: context.arena.items(separator_after_semi_colons),
                 indent_width: context.config.indent_width,
                 multi_line_options: parser_helpers::MultiLineOptions::same_line_no_indent(),
                 force_possible_newline_at_start: false,
@@ -3220,10 +3445,10 @@ fn parse_for_stmt<'a>(node: &'a ForStmt, context: &mut Context<'a>) -> PrintItem
     }
 }
 
-fn parse_for_in_stmt<'a>(node: &'a ForInStmt, context: &mut Context<'a>) -> PrintItems {
+fn parse_for_in_stmt<'a>(node: &'a ForInStmt, context: &mut Context<'a>) -> PrintItems<'a> {
     let start_header_info = Info::new("startHeader");
     let end_header_info = Info::new("endHeader");
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(context.arena);
     items.push_info(start_header_info);
     items.push_str("for");
     if context.config.for_in_statement_space_after_for_keyword {
@@ -3232,11 +3457,11 @@ fn parse_for_in_stmt<'a>(node: &'a ForInStmt, context: &mut Context<'a>) -> Prin
     let inner_header_span = create_span_data(node.left.lo(), node.right.hi());
     items.extend(parse_node_in_parens(
         |context| {
-            let mut items = PrintItems::new();
+            let mut items = PrintItems::new(context.arena);
             items.extend(parse_node((&node.left).into(), context));
             items.push_signal(Signal::SpaceOrNewLine);
             items.push_condition(conditions::indent_if_start_of_line({
-                let mut items = PrintItems::new();
+                let mut items = PrintItems::new(context.arena);
                 items.push_str("in ");
                 items.extend(parse_node((&node.right).into(), context));
                 items
@@ -3267,10 +3492,10 @@ fn parse_for_in_stmt<'a>(node: &'a ForInStmt, context: &mut Context<'a>) -> Prin
     return items;
 }
 
-fn parse_for_of_stmt<'a>(node: &'a ForOfStmt, context: &mut Context<'a>) -> PrintItems {
+fn parse_for_of_stmt<'a>(node: &'a ForOfStmt, context: &mut Context<'a>) -> PrintItems<'a> {
     let start_header_info = Info::new("startHeader");
     let end_header_info = Info::new("endHeader");
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(context.arena);
     items.push_info(start_header_info);
     items.push_str("for");
     if context.config.for_of_statement_space_after_for_keyword {
@@ -3283,11 +3508,11 @@ fn parse_for_of_stmt<'a>(node: &'a ForOfStmt, context: &mut Context<'a>) -> Prin
     let inner_header_span = create_span_data(node.left.lo(), node.right.hi());
     items.extend(parse_node_in_parens(
         |context| {
-            let mut items = PrintItems::new();
+            let mut items = PrintItems::new(context.arena);
             items.extend(parse_node((&node.left).into(), context));
             items.push_signal(Signal::SpaceOrNewLine);
             items.push_condition(conditions::indent_if_start_of_line({
-                let mut items = PrintItems::new();
+                let mut items = PrintItems::new(context.arena);
                 items.push_str("of ");
                 items.extend(parse_node((&node.right).into(), context));
                 items
@@ -3318,15 +3543,15 @@ fn parse_for_of_stmt<'a>(node: &'a ForOfStmt, context: &mut Context<'a>) -> Prin
     return items;
 }
 
-fn parse_if_stmt<'a>(node: &'a IfStmt, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_if_stmt<'a>(node: &'a IfStmt, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     let cons = &*node.cons;
     let cons_span_data = cons.span_data();
     let result = parse_header_with_conditional_brace_body(ParseHeaderWithConditionalBraceBodyOptions {
         parent: node.span.data(),
         body_node: cons.into(),
         parsed_header: {
-            let mut items = PrintItems::new();
+            let mut items = PrintItems::new(context.arena);
             items.push_str("if");
             if context.config.if_statement_space_after_if_keyword { items.push_str(" "); }
             let test = &*node.test;
@@ -3398,8 +3623,8 @@ fn parse_if_stmt<'a>(node: &'a IfStmt, context: &mut Context<'a>) -> PrintItems
     return items;
 }
 
-fn parse_labeled_stmt<'a>(node: &'a LabeledStmt, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_labeled_stmt<'a>(node: &'a LabeledStmt, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.extend(parse_node((&node.label).into(), context));
     items.push_str(":");
 
@@ -3415,8 +3640,8 @@ fn parse_labeled_stmt<'a>(node: &'a LabeledStmt, context: &mut Context<'a>) -> P
     return items;
 }
 
-fn parse_return_stmt<'a>(node: &'a ReturnStmt, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_return_stmt<'a>(node: &'a ReturnStmt, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.push_str("return");
     if let Some(arg) = &node.arg {
         items.push_str(" ");
@@ -3426,9 +3651,9 @@ fn parse_return_stmt<'a>(node: &'a ReturnStmt, context: &mut Context<'a>) -> Pri
     return items;
 }
 
-fn parse_switch_stmt<'a>(node: &'a SwitchStmt, context: &mut Context<'a>) -> PrintItems {
+fn parse_switch_stmt<'a>(node: &'a SwitchStmt, context: &mut Context<'a>) -> PrintItems<'a> {
     let start_header_info = Info::new("startHeader");
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(context.arena);
     items.push_info(start_header_info);
     items.push_str("switch ");
     items.extend(parse_node_in_parens(
@@ -3460,10 +3685,10 @@ fn parse_switch_stmt<'a>(node: &'a SwitchStmt, context: &mut Context<'a>) -> Pri
     return items;
 }
 
-fn parse_switch_case<'a>(node: &'a SwitchCase, context: &mut Context<'a>) -> PrintItems {
+fn parse_switch_case<'a>(node: &'a SwitchCase, context: &mut Context<'a>) -> PrintItems<'a> {
     let block_stmt_body = get_block_stmt_body(&node);
     let start_header_info = Info::new("switchCaseStartHeader");
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(context.arena);
     let colon_token = context.token_finder.get_first_colon_token_after(&if let Some(test) = &node.test {
         test.span().hi()
     } else {
@@ -3518,8 +3743,8 @@ fn parse_switch_case<'a>(node: &'a SwitchCase, context: &mut Context<'a>) -> Pri
         return None;
     }
 
-    fn parse_trailing_comments_for_case<'a>(node_span_data: SpanData, block_stmt_body: &Option<Span>, context: &mut Context<'a>) -> PrintItems {
-        let mut items = PrintItems::new();
+    fn parse_trailing_comments_for_case<'a>(node_span_data: SpanData, block_stmt_body: &Option<Span>, context: &mut Context<'a>) -> PrintItems<'a> {
+        let mut items = PrintItems::new(context.arena);
         // parse the trailing comments as statements
         let trailing_comments = get_trailing_comments_as_statements(&node_span_data, context);
         if !trailing_comments.is_empty() {
@@ -3548,16 +3773,16 @@ fn parse_switch_case<'a>(node: &'a SwitchCase, context: &mut Context<'a>) -> Pri
     }
 }
 
-fn parse_throw_stmt<'a>(node: &'a ThrowStmt, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_throw_stmt<'a>(node: &'a ThrowStmt, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.push_str("throw ");
     items.extend(parse_node((&node.arg).into(), context));
     if context.config.semi_colons.is_true() { items.push_str(";"); }
     return items;
 }
 
-fn parse_try_stmt<'a>(node: &'a TryStmt, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_try_stmt<'a>(node: &'a TryStmt, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     let brace_position = context.config.try_statement_brace_position;
     let next_control_flow_position = context.config.try_statement_next_control_flow_position;
     let mut last_block_span_data = node.block.span.data();
@@ -3622,8 +3847,8 @@ fn parse_try_stmt<'a>(node: &'a TryStmt, context: &mut Context<'a>) -> PrintItem
     return items;
 }
 
-fn parse_var_decl<'a>(node: &'a VarDecl, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_var_decl<'a>(node: &'a VarDecl, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     let force_use_new_lines = get_use_new_lines(&node.decls, context);
     if node.declare { items.push_str("declare "); }
     items.push_str(match node.kind {
@@ -3671,8 +3896,8 @@ fn parse_var_decl<'a>(node: &'a VarDecl, context: &mut Context<'a>) -> PrintItem
     }
 }
 
-fn parse_var_declarator<'a>(node: &'a VarDeclarator, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_var_declarator<'a>(node: &'a VarDeclarator, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
 
     items.extend(parse_node((&node.name).into(), context));
 
@@ -3686,12 +3911,12 @@ fn parse_var_declarator<'a>(node: &'a VarDeclarator, context: &mut Context<'a>)
     if let Node::VarDecl(var_dec) = context.parent() {
         if var_dec.decls.len() > 1 && &var_dec.decls[0] == node {
             let items = items.into_rc_path();
-            if_true_or(
+            context.arena.items(if_true_or(
                 "indentIfNotStartOfLine",
                 |context| Some(!condition_resolvers::is_start_of_line(context)),
-                with_indent(items.clone().into()),
-                items.into(),
-            ).into()
+                with_indent(context.arena.items(items)),
+                context.arena.items(items),
+            ))
         } else {
             items
         }
@@ -3700,10 +3925,10 @@ fn parse_var_declarator<'a>(node: &'a VarDeclarator, context: &mut Context<'a>)
     }
 }
 
-fn parse_while_stmt<'a>(node: &'a WhileStmt, context: &mut Context<'a>) -> PrintItems {
+fn parse_while_stmt<'a>(node: &'a WhileStmt, context: &mut Context<'a>) -> PrintItems<'a> {
     let start_header_info = Info::new("startHeader");
     let end_header_info = Info::new("endHeader");
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(context.arena);
     items.push_info(start_header_info);
     items.push_str("while");
     if context.config.while_statement_space_after_while_keyword {
@@ -3735,19 +3960,19 @@ fn parse_while_stmt<'a>(node: &'a WhileStmt, context: &mut Context<'a>) -> Print
 
 /* types */
 
-fn parse_array_type<'a>(node: &'a TsArrayType, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_array_type<'a>(node: &'a TsArrayType, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.extend(parse_node((&node.elem_type).into(), context));
     items.push_str("[]");
     return items;
 }
 
-fn parse_conditional_type<'a>(node: &'a TsConditionalType, context: &mut Context<'a>) -> PrintItems {
+fn parse_conditional_type<'a>(node: &'a TsConditionalType, context: &mut Context<'a>) -> PrintItems<'a> {
     let use_new_lines = !context.config.conditional_type_prefer_single_line
         && node_helpers::get_use_new_lines_for_nodes(&*node.true_type, &*node.false_type, context);
     let top_most_data = get_top_most_data(node, context);
     let is_parent_conditional_type = context.parent().kind() == NodeKind::TsConditionalType;
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(context.arena);
     let before_false_info = Info::new("beforeFalse");
 
     // main area
@@ -3762,7 +3987,7 @@ fn parse_conditional_type<'a>(node: &'a TsConditionalType, context: &mut Context
     items.push_condition(conditions::indent_if_start_of_line(parser_helpers::new_line_group(parse_node((&node.extends_type).into(), context))));
     items.push_signal(Signal::SpaceOrNewLine);
     items.push_condition(conditions::indent_if_start_of_line({
-        let mut items = PrintItems::new();
+        let mut items = PrintItems::new(context.arena);
         items.push_str("? ");
         items.extend(parser_helpers::new_line_group(parse_node((&node.true_type).into(), context)));
         items
@@ -3772,11 +3997,11 @@ fn parse_conditional_type<'a>(node: &'a TsConditionalType, context: &mut Context
     if use_new_lines {
         items.push_signal(Signal::NewLine);
     } else {
-        items.push_condition(conditions::new_line_if_multiple_lines_space_or_new_line_otherwise(top_most_data.top_most_info, Some(before_false_info)));
+        items.push_condition(conditions::new_line_if_multiple_lines_space_or_new_line_otherwise(context.arena, top_most_data.top_most_info, Some(before_false_info)));
     }
 
     let false_type_parsed = {
-        let mut items = PrintItems::new();
+        let mut items = PrintItems::new(context.arena);
         items.push_info(before_false_info);
         items.push_str(": ");
         items.extend(parser_helpers::new_line_group(parse_node((&node.false_type).into(), context)));
@@ -3834,9 +4059,9 @@ fn parse_conditional_type<'a>(node: &'a TsConditionalType, context: &mut Context
     }
 }
 
-fn parse_constructor_type<'a>(node: &'a TsConstructorType, context: &mut Context<'a>) -> PrintItems {
+fn parse_constructor_type<'a>(node: &'a TsConstructorType, context: &mut Context<'a>) -> PrintItems<'a> {
     let start_info = Info::new("startConstructorType");
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(context.arena);
     items.push_info(start_info);
     items.push_str("new");
     if context.config.constructor_type_space_after_new_keyword { items.push_str(" "); }
@@ -3851,7 +4076,7 @@ fn parse_constructor_type<'a>(node: &'a TsConstructorType, context: &mut Context
             start_info,
             type_node: Some((&node.type_ann).into()),
             type_node_separator: Some({
-                let mut items = PrintItems::new();
+                let mut items = PrintItems::new(context.arena);
                 items.push_str(" =>");
                 items.push_signal(Signal::SpaceIfNotTrailing);
                 items.push_signal(Signal::PossibleNewLine);
@@ -3865,13 +4090,13 @@ fn parse_constructor_type<'a>(node: &'a TsConstructorType, context: &mut Context
     items
 }
 
-fn parse_function_type<'a>(node: &'a TsFnType, context: &mut Context<'a>) -> PrintItems {
+fn parse_function_type<'a>(node: &'a TsFnType, context: &mut Context<'a>) -> PrintItems<'a> {
     let start_info = Info::new("startFunctionType");
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(context.arena);
     let mut indent_after_arrow_condition = if_true(
         "indentIfIsStartOfLineAfterArrow",
         |context| Some(condition_resolvers::is_start_of_line(&context)),
-        Signal::StartIndent.into()
+        context.arena.items(Signal::StartIndent)
     );
     let indent_after_arrow_condition_ref = indent_after_arrow_condition.get_reference();
 
@@ -3886,7 +4111,7 @@ fn parse_function_type<'a>(node: &'a TsFnType, context: &mut Context<'a>) -> Pri
             start_info,
             type_node: Some((&node.type_ann).into()),
             type_node_separator: {
-                let mut items = PrintItems::new();
+                let mut items = PrintItems::new(context.arena);
                 items.push_str(" =>");
                 items.push_signal(Signal::SpaceIfNotTrailing);
                 items.push_signal(Signal::PossibleNewLine);
@@ -3901,14 +4126,14 @@ fn parse_function_type<'a>(node: &'a TsFnType, context: &mut Context<'a>) -> Pri
     items.push_condition(if_true(
         "shouldFinishIndent",
         move |context| context.get_resolved_condition(&indent_after_arrow_condition_ref),
-        Signal::FinishIndent.into()
+        context.arena.items(Signal::FinishIndent)
     ));
 
     return items;
 }
 
-fn parse_import_type<'a>(node: &'a TsImportType, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_import_type<'a>(node: &'a TsImportType, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.push_str("import(");
     items.extend(parse_node((&node.arg).into(), context));
     items.push_str(")");
@@ -3924,8 +4149,8 @@ fn parse_import_type<'a>(node: &'a TsImportType, context: &mut Context<'a>) -> P
     return items;
 }
 
-fn parse_indexed_access_type<'a>(node: &'a TsIndexedAccessType, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_indexed_access_type<'a>(node: &'a TsIndexedAccessType, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.extend(parse_node((&node.obj_type).into(), context));
     items.extend(parse_computed_prop_like(ParseComputedPropLikeOptions {
         inner_node_span_data: node.index_type.span_data(),
@@ -3934,14 +4159,14 @@ fn parse_indexed_access_type<'a>(node: &'a TsIndexedAccessType, context: &mut Co
     return items;
 }
 
-fn parse_infer_type<'a>(node: &'a TsInferType, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_infer_type<'a>(node: &'a TsInferType, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.push_str("infer ");
     items.extend(parse_node((&node.type_param).into(), context));
     return items;
 }
 
-fn parse_intersection_type<'a>(node: &'a TsIntersectionType, context: &mut Context<'a>) -> PrintItems {
+fn parse_intersection_type<'a>(node: &'a TsIntersectionType, context: &mut Context<'a>) -> PrintItems<'a> {
     parse_union_or_intersection_type(UnionOrIntersectionType {
         span_data: node.span.data(),
         types: &node.types,
@@ -3949,16 +4174,16 @@ fn parse_intersection_type<'a>(node: &'a TsIntersectionType, context: &mut Conte
     }, context)
 }
 
-fn parse_lit_type<'a>(node: &'a TsLitType, context: &mut Context<'a>) -> PrintItems {
+fn parse_lit_type<'a>(node: &'a TsLitType, context: &mut Context<'a>) -> PrintItems<'a> {
     match &node.lit {
         // need to do this in order to support negative numbers
-        TsLit::Number(_) => node.text(context).into(),
+        TsLit::Number(_) => context.arena.items(node.text(context)),
         _ => parse_node((&node.lit).into(), context)
     }
 }
 
-fn parse_mapped_type<'a>(node: &'a TsMappedType, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_mapped_type<'a>(node: &'a TsMappedType, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     let start_info = Info::new("startMappedType");
     let end_info = Info::new("endMappedType");
     let open_brace_token = context.token_finder.get_first_open_brace_token_within(node).expect("Expected to find an open brace token in the mapped type.");
@@ -3972,15 +4197,15 @@ fn parse_mapped_type<'a>(node: &'a TsMappedType, context: &mut Context<'a>) -> P
                 condition_resolvers::is_multiple_lines(context, &start_info, &end_info)
             }
         },
-        Signal::NewLine.into(),
-        Signal::SpaceOrNewLine.into(),
+        context.arena.items(Signal::NewLine),
+        context.arena.items(Signal::SpaceOrNewLine),
     );
     let is_multiple_lines = is_multiple_lines_condition.get_reference().create_resolver();
     items.push_info(start_info);
     items.push_str("{");
     items.push_condition(is_multiple_lines_condition.clone());
     items.push_condition(conditions::indent_if_start_of_line(parser_helpers::new_line_group({
-        let mut items = PrintItems::new();
+        let mut items = PrintItems::new(context.arena);
         if let Some(readonly) = node.readonly {
             items.push_str(match readonly {
                 TruePlusMinus::True => "readonly ",
@@ -4002,7 +4227,7 @@ fn parse_mapped_type<'a>(node: &'a TsMappedType, context: &mut Context<'a>) -> P
             });
         }
         items.extend(parse_type_ann_with_colon_if_exists_for_type(&node.type_ann, context));
-        items.extend(get_parsed_semi_colon(context.config.semi_colons, true, &is_multiple_lines));
+        items.extend(get_parsed_semi_colon(context.arena, context.config.semi_colons, true, &is_multiple_lines));
         items
     })));
     items.push_condition(is_multiple_lines_condition);
@@ -4011,23 +4236,23 @@ fn parse_mapped_type<'a>(node: &'a TsMappedType, context: &mut Context<'a>) -> P
     return items;
 }
 
-fn parse_optional_type<'a>(node: &'a TsOptionalType, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_optional_type<'a>(node: &'a TsOptionalType, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.extend(parse_node((&node.type_ann).into(), context));
     items.push_str("?");
     return items;
 }
 
-fn parse_qualified_name<'a>(node: &'a TsQualifiedName, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_qualified_name<'a>(node: &'a TsQualifiedName, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.extend(parse_node((&node.left).into(), context));
     items.push_str(".");
     items.extend(parse_node((&node.right).into(), context));
     return items;
 }
 
-fn parse_parenthesized_type<'a>(node: &'a TsParenthesizedType, context: &mut Context<'a>) -> PrintItems {
-    let parsed_type = conditions::with_indent_if_start_of_line_indented(parse_node_in_parens(
+fn parse_parenthesized_type<'a>(node: &'a TsParenthesizedType, context: &mut Context<'a>) -> PrintItems<'a> {
+    let parsed_type = context.arena.items(conditions::with_indent_if_start_of_line_indented(parse_node_in_parens(
         |context| parse_node((&node.type_ann).into(), context),
         ParseNodeInParensOptions {
             inner_span: node.type_ann.span_data(),
@@ -4035,7 +4260,7 @@ fn parse_parenthesized_type<'a>(node: &'a TsParenthesizedType, context: &mut Con
             allow_open_paren_trailing_comments: true,
         },
         context
-    )).into();
+    )));
 
     return if use_new_line_group(context) { new_line_group(parsed_type) } else { parsed_type };
 
@@ -4047,36 +4272,37 @@ fn parse_parenthesized_type<'a>(node: &'a TsParenthesizedType, context: &mut Con
     }
 }
 
-fn parse_rest_type<'a>(node: &'a TsRestType, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_rest_type<'a>(node: &'a TsRestType, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.push_str("...");
     items.extend(parse_node((&node.type_ann).into(), context));
     return items;
 }
 
-fn parse_tuple_type<'a>(node: &'a TsTupleType, context: &mut Context<'a>) -> PrintItems {
+fn parse_tuple_type<'a>(node: &'a TsTupleType, context: &mut Context<'a>) -> PrintItems<'a> {
     parse_array_like_nodes(ParseArrayLikeNodesOptions {
         parent_span_data: node.span.data(),
         nodes: node.elem_types.iter().map(|x| Some(x.into())).collect(),
         prefer_hanging: context.config.tuple_type_prefer_hanging,
         prefer_single_line: context.config.tuple_type_prefer_single_line,
         trailing_commas: context.config.tuple_type_trailing_commas,
+        fill: false,
     }, context)
 }
 
-fn parse_type_ann<'a>(node: &'a TsTypeAnn, context: &mut Context<'a>) -> PrintItems {
+fn parse_type_ann<'a>(node: &'a TsTypeAnn, context: &mut Context<'a>) -> PrintItems<'a> {
     parse_node((&node.type_ann).into(), context)
 }
 
-fn parse_type_param<'a>(node: &'a TsTypeParam, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_type_param<'a>(node: &'a TsTypeParam, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
 
     items.extend(parse_node((&node.name).into(), context));
 
     if let Some(constraint) = &node.constraint {
         items.push_signal(Signal::SpaceOrNewLine);
         items.push_condition(conditions::indent_if_start_of_line({
-            let mut items = PrintItems::new();
+            let mut items = PrintItems::new(context.arena);
             items.push_str(if context.parent().kind() == NodeKind::TsMappedType {
                 "in"
             } else {
@@ -4095,10 +4321,10 @@ fn parse_type_param<'a>(node: &'a TsTypeParam, context: &mut Context<'a>) -> Pri
     return items;
 }
 
-fn parse_type_parameters<'a>(node: TypeParamNode<'a>, context: &mut Context<'a>) -> PrintItems {
+fn parse_type_parameters<'a>(node: TypeParamNode<'a>, context: &mut Context<'a>) -> PrintItems<'a> {
     let params = node.params();
     let force_use_new_lines = get_use_new_lines(&node.span().data(), &params, context);
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(context.arena);
 
     items.push_str("<");
     items.extend(parse_separated_values(ParseSeparatedValuesOptions {
@@ -4145,8 +4371,8 @@ fn parse_type_parameters<'a>(node: TypeParamNode<'a>, context: &mut Context<'a>)
     }
 }
 
-fn parse_type_operator<'a>(node: &'a TsTypeOperator, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_type_operator<'a>(node: &'a TsTypeOperator, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.push_str(match node.op {
         TsTypeOperatorOp::KeyOf => "keyof",
         TsTypeOperatorOp::Unique => "unique",
@@ -4157,8 +4383,8 @@ fn parse_type_operator<'a>(node: &'a TsTypeOperator, context: &mut Context<'a>)
     return items;
 }
 
-fn parse_type_predicate<'a>(node: &'a TsTypePredicate, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_type_predicate<'a>(node: &'a TsTypePredicate, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     if node.asserts { items.push_str("asserts "); }
     items.extend(parse_node((&node.param_name).into(), context));
     if let Some(type_ann) = &node.type_ann {
@@ -4169,16 +4395,16 @@ fn parse_type_predicate<'a>(node: &'a TsTypePredicate, context: &mut Context<'a>
     return items;
 }
 
-fn parse_type_query<'a>(node: &'a TsTypeQuery, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_type_query<'a>(node: &'a TsTypeQuery, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.push_str("typeof");
     items.push_signal(Signal::SpaceIfNotTrailing);
     items.extend(parse_node((&node.expr_name).into(), context));
     return items;
 }
 
-fn parse_type_reference<'a>(node: &'a TsTypeRef, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_type_reference<'a>(node: &'a TsTypeRef, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     items.extend(parse_node((&node.type_name).into(), context));
     if let Some(type_params) = &node.type_params {
         items.extend(parse_node(type_params.into(), context));
@@ -4186,7 +4412,7 @@ fn parse_type_reference<'a>(node: &'a TsTypeRef, context: &mut Context<'a>) -> P
     return items;
 }
 
-fn parse_union_type<'a>(node: &'a TsUnionType, context: &mut Context<'a>) -> PrintItems {
+fn parse_union_type<'a>(node: &'a TsUnionType, context: &mut Context<'a>) -> PrintItems<'a> {
     parse_union_or_intersection_type(UnionOrIntersectionType {
         span_data: node.span.data(),
         types: &node.types,
@@ -4200,9 +4426,9 @@ struct UnionOrIntersectionType<'a> {
     pub is_union: bool,
 }
 
-fn parse_union_or_intersection_type<'a>(node: UnionOrIntersectionType<'a>, context: &mut Context<'a>) -> PrintItems {
+fn parse_union_or_intersection_type<'a>(node: UnionOrIntersectionType<'a>, context: &mut Context<'a>) -> PrintItems<'a> {
     // todo: configuration for operator position
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(context.arena);
     let force_use_new_lines = get_use_new_lines_for_nodes(&node.types, context.config.union_and_intersection_type_prefer_single_line, context);
     let separator = if node.is_union { "|" } else { "&" };
 
@@ -4228,6 +4454,7 @@ fn parse_union_or_intersection_type<'a>(node: UnionOrIntersectionType<'a>, conte
     } else {
         parser_helpers::MultiLineOptions::same_line_start_hanging_indent()
     };
+    let single_line_separator = context.arena.items(Signal::SpaceOrNewLine);
     let parse_result = parser_helpers::parse_separated_values(|is_multi_line_or_hanging_ref| {
         let is_multi_line_or_hanging = is_multi_line_or_hanging_ref.create_resolver();
         let types_count = node.types.len();
@@ -4240,7 +4467,7 @@ fn parse_union_or_intersection_type<'a>(node: UnionOrIntersectionType<'a>, conte
             let separator_token = context.token_finder.get_previous_token_if_operator(&type_node.span().data(), separator);
             let start_info = Info::new("startInfo");
             let after_separator_info = Info::new("afterSeparatorInfo");
-            let mut items = PrintItems::new();
+            let mut items = PrintItems::new(context.arena);
             items.push_info(start_info);
             if let Some(separator_token) = separator_token {
                 items.extend(parse_leading_comments(separator_token, context));
@@ -4249,7 +4476,7 @@ fn parse_union_or_intersection_type<'a>(node: UnionOrIntersectionType<'a>, conte
                 items.push_condition(if_true(
                     "separatorIfMultiLine",
                     is_multi_line_or_hanging.clone(),
-                    separator.into(),
+                    context.arena.items(separator),
                 ));
             } else if i > 0 {
                 items.push_str(separator);
@@ -4267,7 +4494,7 @@ fn parse_union_or_intersection_type<'a>(node: UnionOrIntersectionType<'a>, conte
                     let is_at_same_position = condition_resolvers::is_at_same_position(condition_context, &start_info)?;
                     return Some(is_on_same_line && !is_at_same_position);
                 },
-                Signal::SpaceIfNotTrailing.into(),
+                context.arena.items(Signal::SpaceIfNotTrailing),
             ));
             items.extend(parse_node(type_node.into(), context));
 
@@ -4286,7 +4513,7 @@ fn parse_union_or_intersection_type<'a>(node: UnionOrIntersectionType<'a>, conte
         allow_blank_lines: false,
         single_line_space_at_start: false,
         single_line_space_at_end: false,
-        single_line_separator: Signal::SpaceOrNewLine.into(),
+        single_line_separator,
         indent_width,
         multi_line_options,
         force_possible_newline_at_start: false,
@@ -4306,13 +4533,13 @@ fn parse_union_or_intersection_type<'a>(node: UnionOrIntersectionType<'a>, conte
 
 /* comments */
 
-fn parse_leading_comments<'a>(node: &dyn SpanDataContainer, context: &mut Context<'a>) -> PrintItems {
+fn parse_leading_comments<'a>(node: &dyn SpanDataContainer, context: &mut Context<'a>) -> PrintItems<'a> {
     let leading_comments = node.leading_comments(context);
     parse_comments_as_leading(node, leading_comments, context)
 }
 
-fn parse_comments_as_leading<'a>(node: &dyn SpanDataContainer, comments: CommentsIterator<'a>, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_comments_as_leading<'a>(node: &dyn SpanDataContainer, comments: CommentsIterator<'a>, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     if let Some(last_comment) = comments.get_last_comment() {
         let last_comment_previously_handled = context.has_handled_comment(&last_comment);
 
@@ -4338,7 +4565,7 @@ fn parse_comments_as_leading<'a>(node: &dyn SpanDataContainer, comments: Comment
     items
 }
 
-fn parse_trailing_comments_as_statements<'a>(node: &dyn SpanDataContainer, context: &mut Context<'a>) -> PrintItems {
+fn parse_trailing_comments_as_statements<'a>(node: &dyn SpanDataContainer, context: &mut Context<'a>) -> PrintItems<'a> {
     let unhandled_comments = get_trailing_comments_as_statements(node, context);
     parse_comments_as_statements(unhandled_comments.into_iter(), Some(node), context)
 }
@@ -4354,9 +4581,9 @@ fn get_trailing_comments_as_statements<'a>(node: &dyn SpanDataContainer, context
     comments
 }
 
-fn parse_comments_as_statements<'a>(comments: impl Iterator<Item=&'a Comment>, last_node: Option<&dyn SpanDataContainer>, context: &mut Context<'a>) -> PrintItems {
+fn parse_comments_as_statements<'a>(comments: impl Iterator<Item=&'a Comment>, last_node: Option<&dyn SpanDataContainer>, context: &mut Context<'a>) -> PrintItems<'a> {
     let mut last_node = last_node;
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(context.arena);
     for comment in comments {
         if !context.has_handled_comment(comment) {
             items.extend(parse_comment_based_on_last_node(comment, &last_node, ParseCommentBasedOnLastNodeOptions {
@@ -4368,9 +4595,9 @@ fn parse_comments_as_statements<'a>(comments: impl Iterator<Item=&'a Comment>, l
     items
 }
 
-fn parse_comment_collection<'a>(comments: impl Iterator<Item=&'a Comment>, last_node: Option<&dyn SpanDataContainer>, next_node: Option<&dyn SpanDataContainer>, context: &mut Context<'a>) -> PrintItems {
+fn parse_comment_collection<'a>(comments: impl Iterator<Item=&'a Comment>, last_node: Option<&dyn SpanDataContainer>, next_node: Option<&dyn SpanDataContainer>, context: &mut Context<'a>) -> PrintItems<'a> {
     let mut last_node = last_node;
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(context.arena);
     let next_node_start_line = next_node.map(|n| n.start_line(context));
     for comment in comments {
         if !context.has_handled_comment(comment) {
@@ -4391,8 +4618,8 @@ struct ParseCommentBasedOnLastNodeOptions {
     separate_with_newlines: bool,
 }
 
-fn parse_comment_based_on_last_node(comment: &Comment, last_node: &Option<&dyn SpanDataContainer>, opts: ParseCommentBasedOnLastNodeOptions, context: &mut Context) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_comment_based_on_last_node<'a>(comment: &Comment, last_node: &Option<&dyn SpanDataContainer>, opts: ParseCommentBasedOnLastNodeOptions, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     let mut pushed_ignore_new_lines = false;
 
     if let Some(last_node) = last_node {
@@ -4407,6 +4634,9 @@ fn parse_comment_based_on_last_node(comment: &Comment, last_node: &Option<&dyn S
             }
         } else if comment.kind == CommentKind::Line {
             items.push_signal(Signal::StartForceNoNewLines);
+            if let Some(alignment_group) = context.take_alignment_group_for_node(comment) {
+                items.push_alignment(&alignment_group);
+            }
             items.push_str(" ");
             pushed_ignore_new_lines = true;
         } else if last_node.text(context).starts_with("/*") {
@@ -4425,7 +4655,7 @@ fn parse_comment_based_on_last_node(comment: &Comment, last_node: &Option<&dyn S
     return items;
 }
 
-fn parse_comment(comment: &Comment, context: &mut Context) -> Option<PrintItems> {
+fn parse_comment<'a>(comment: &Comment, context: &mut Context<'a>) -> Option<PrintItems<'a>> {
     // only parse if handled
     if context.has_handled_comment(comment) {
         return None;
@@ -4434,21 +4664,17 @@ fn parse_comment(comment: &Comment, context: &mut Context) -> Option<PrintItems>
     // mark handled and parse
     context.mark_comment_handled(comment);
     return Some(match comment.kind {
-        CommentKind::Block => parse_comment_block(comment),
-        CommentKind::Line => parser_helpers::parse_js_like_comment_line(&comment.text, context.config.comment_line_force_space_after_slashes),
+        CommentKind::Block => parser_helpers::parse_js_like_comment_block(context.arena, &comment.text, parser_helpers::CommentBlockOptions {
+            align_gutters: context.config.comment_block_align_gutters,
+            wrap_width: if context.config.comment_block_reflow { Some(context.config.line_width) } else { None },
+            unicode_width: context.config.unicode_width,
+        }),
+        CommentKind::Line => parser_helpers::parse_js_like_comment_line(context.arena, &comment.text, context.config.comment_line_force_space_after_slashes),
     });
-
-    fn parse_comment_block(comment: &Comment) -> PrintItems {
-        let mut items = PrintItems::new();
-        items.push_str("/*");
-        items.extend(parse_raw_string(&comment.text));
-        items.push_str("*/");
-        items
-    }
 }
 
-fn parse_first_line_trailing_comments<'a>(node: &dyn SpanDataContainer, first_member: Option<SpanData>, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_first_line_trailing_comments<'a>(node: &dyn SpanDataContainer, first_member: Option<SpanData>, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     let node_start_line = node.start_line(context);
 
     for comment in get_comments(&node, &first_member, context) {
@@ -4474,19 +4700,19 @@ fn parse_first_line_trailing_comments<'a>(node: &dyn SpanDataContainer, first_me
     }
 }
 
-fn parse_trailing_comments<'a>(node: &dyn SpanDataContainer, context: &mut Context<'a>) -> PrintItems {
+fn parse_trailing_comments<'a>(node: &dyn SpanDataContainer, context: &mut Context<'a>) -> PrintItems<'a> {
     let trailing_comments = node.trailing_comments(context);
     parse_comments_as_trailing(node, trailing_comments, context)
 }
 
-fn parse_comments_as_trailing<'a>(node: &dyn SpanDataContainer, trailing_comments: CommentsIterator<'a>, context: &mut Context<'a>) -> PrintItems {
+fn parse_comments_as_trailing<'a>(node: &dyn SpanDataContainer, trailing_comments: CommentsIterator<'a>, context: &mut Context<'a>) -> PrintItems<'a> {
     // use the roslyn definition of trailing comments
     let node_end_line = node.end_line(context);
     let trailing_comments_on_same_line = trailing_comments.into_iter()
         .filter(|c|c.start_line(context) <= node_end_line) // less than or equal instead of just equal in order to include "forgotten" comments
         .collect::<Vec<_>>();
     let first_unhandled_comment = trailing_comments_on_same_line.iter().filter(|c| !context.has_handled_comment(&c)).next();
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(context.arena);
 
     if let Some(first_unhandled_comment) = first_unhandled_comment {
         if first_unhandled_comment.kind == CommentKind::Block {
@@ -4511,15 +4737,18 @@ struct ParseArrayLikeNodesOptions<'a> {
     prefer_hanging: bool,
     prefer_single_line: bool,
     trailing_commas: TrailingCommas,
+    /// Packs as many elements on each line as fit when multi-line.
+    fill: bool,
 }
 
-fn parse_array_like_nodes<'a>(opts: ParseArrayLikeNodesOptions<'a>, context: &mut Context<'a>) -> PrintItems {
+fn parse_array_like_nodes<'a>(opts: ParseArrayLikeNodesOptions<'a>, context: &mut Context<'a>) -> PrintItems<'a> {
     let parent_span_data = opts.parent_span_data;
     let nodes = opts.nodes;
     let trailing_commas = if allow_trailing_commas(&nodes) { opts.trailing_commas } else { TrailingCommas::Never };
     let prefer_hanging = opts.prefer_hanging;
+    let fill = opts.fill;
     let force_use_new_lines = get_force_use_new_lines(&parent_span_data, &nodes, opts.prefer_single_line, context);
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(context.arena);
     let mut first_member = nodes.get(0).map(|x| x.as_ref().map(|y| y.span_data())).flatten();
 
     if first_member.is_none() {
@@ -4539,7 +4768,10 @@ fn parse_array_like_nodes<'a>(opts: ParseArrayLikeNodesOptions<'a>, context: &mu
             single_line_space_at_start: false,
             single_line_space_at_end: false,
             custom_single_line_separator: None,
-            multi_line_options: parser_helpers::MultiLineOptions::surround_newlines_indented(),
+            multi_line_options: parser_helpers::MultiLineOptions {
+                fill,
+                ..parser_helpers::MultiLineOptions::surround_newlines_indented()
+            },
             force_possible_newline_at_start: false,
         }, context)
     }, |_| None, ParseSurroundedByTokensOptions {
@@ -4596,10 +4828,10 @@ struct ParseMemberedBodyOptions<'a, FShouldUseBlankLine> where FShouldUseBlankLi
 fn parse_membered_body<'a, FShouldUseBlankLine>(
     opts: ParseMemberedBodyOptions<'a, FShouldUseBlankLine>,
     context: &mut Context<'a>
-) -> PrintItems
+) -> PrintItems<'a>
     where FShouldUseBlankLine : Fn(&Node, &Node, &mut Context) -> bool
 {
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(context.arena);
     // todo: no expect here
     let open_brace_token = context.token_finder.get_first_open_brace_token_before(&if opts.members.is_empty() { opts.span_data.hi } else { opts.members[0].lo() })
         .expect("Expected to find an open brace token.");
@@ -4633,7 +4865,7 @@ fn parse_membered_body<'a, FShouldUseBlankLine>(
     items
 }
 
-fn parse_statements<'a>(inner_span_data: SpanData, stmts: impl Iterator<Item=Node<'a>>, context: &mut Context<'a>) -> PrintItems {
+fn parse_statements<'a>(inner_span_data: SpanData, stmts: impl Iterator<Item=Node<'a>>, context: &mut Context<'a>) -> PrintItems<'a> {
     parse_statements_or_members(ParseStatementsOrMembersOptions {
         inner_span_data,
         items: stmts.map(|stmt| (stmt, None)).collect(),
@@ -4647,7 +4879,7 @@ fn parse_statements<'a>(inner_span_data: SpanData, stmts: impl Iterator<Item=Nod
 
 struct ParseStatementsOrMembersOptions<'a, FShouldUseBlankLine> where FShouldUseBlankLine : Fn(&Node, &Node, &mut Context) -> bool {
     inner_span_data: SpanData,
-    items: Vec<(Node<'a>, Option<PrintItems>)>,
+    items: Vec<(Node<'a>, Option<PrintItems<'a>>)>,
     should_use_space: Option<Box<dyn Fn(&Node, &Node, &mut Context) -> bool>>, // todo: Remove putting functions on heap by using type parameters?
     should_use_new_line: Option<Box<dyn Fn(&Node, &Node, &mut Context) -> bool>>,
     should_use_blank_line: FShouldUseBlankLine,
@@ -4658,12 +4890,28 @@ struct ParseStatementsOrMembersOptions<'a, FShouldUseBlankLine> where FShouldUse
 fn parse_statements_or_members<'a, FShouldUseBlankLine>(
     opts: ParseStatementsOrMembersOptions<'a, FShouldUseBlankLine>,
     context: &mut Context<'a>
-) -> PrintItems where FShouldUseBlankLine : Fn(&Node, &Node, &mut Context) -> bool
+) -> PrintItems<'a> where FShouldUseBlankLine : Fn(&Node, &Node, &mut Context) -> bool
 {
     let mut last_node: Option<Node> = None;
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(context.arena);
     let children_len = opts.items.len();
 
+    // items that were already parsed can't be aligned
+    if opts.items.iter().all(|(_, print_items)| print_items.is_none()) {
+        let nodes = opts.items.iter().map(|(node, _)| node.clone()).collect::<Vec<_>>();
+        if context.config.variable_statement_align_assignments {
+            store_alignment_groups("variableStatementAssignments", &nodes, |node, context| match node {
+                Node::VarDecl(var_decl) if var_decl.decls.len() == 1 => get_assignment_op_span_data(var_decl.decls[0].init.as_ref()?, context),
+                _ => None,
+            }, context);
+        }
+        if context.config.comment_line_align_trailing {
+            store_alignment_groups("trailingComments", &nodes, |node, context| {
+                get_same_line_trailing_line_comment(node, context).map(|comment| comment.span_data())
+            }, context);
+        }
+    }
+
     for (i, (node, optional_print_items)) in opts.items.into_iter().enumerate() {
         let is_empty_stmt = match node { Node::EmptyStmt(_) => true, _ => false };
         if !is_empty_stmt {
@@ -4688,10 +4936,10 @@ fn parse_statements_or_members<'a, FShouldUseBlankLine>(
                 print_items
             } else {
                 if let Some(trailing_commas) = opts.trailing_commas {
-                    let parsed_comma = get_parsed_trailing_comma(trailing_commas, i == children_len - 1, &|_| Some(true));
+                    let parsed_comma = get_parsed_trailing_comma(context.arena, trailing_commas, i == children_len - 1, &|_| Some(true));
                     parse_comma_separated_value(Some(node.clone()), parsed_comma, context)
                 } else if let Some(semi_colons) = opts.semi_colons {
-                    let parsed_semi_colon = get_parsed_semi_colon(semi_colons, i == children_len - 1, &|_| Some(true));
+                    let parsed_semi_colon = get_parsed_semi_colon(context.arena, semi_colons, i == children_len - 1, &|_| Some(true));
                     parse_node_with_semi_colon(Some(node.clone()), parsed_semi_colon, context)
                 } else {
                     parse_node(node.clone(), context)
@@ -4735,14 +4983,14 @@ fn parse_statements_or_members<'a, FShouldUseBlankLine>(
     }
 }
 
-struct ParseParametersOrArgumentsOptions<'a, F> where F : FnOnce(&mut Context<'a>) -> Option<PrintItems> {
+struct ParseParametersOrArgumentsOptions<'a, F> where F : FnOnce(&mut Context<'a>) -> Option<PrintItems<'a>> {
     span_data: Option<SpanData>,
     nodes: Vec<Node<'a>>,
     custom_close_paren: F,
     is_parameters: bool,
 }
 
-fn parse_parameters_or_arguments<'a, F>(opts: ParseParametersOrArgumentsOptions<'a, F>, context: &mut Context<'a>) -> PrintItems where F : FnOnce(&mut Context<'a>) -> Option<PrintItems> {
+fn parse_parameters_or_arguments<'a, F>(opts: ParseParametersOrArgumentsOptions<'a, F>, context: &mut Context<'a>) -> PrintItems<'a> where F : FnOnce(&mut Context<'a>) -> Option<PrintItems<'a>> {
     let is_parameters = opts.is_parameters;
     let prefer_single_line = is_parameters && context.config.parameters_prefer_single_line || !is_parameters && context.config.arguments_prefer_single_line;
     let force_use_new_lines = get_use_new_lines_for_nodes_with_preceeding_token("(", &opts.nodes, prefer_single_line, context);
@@ -4754,7 +5002,7 @@ fn parse_parameters_or_arguments<'a, F>(opts: ParseParametersOrArgumentsOptions<
     let trailing_commas = get_trailing_commas(&nodes, is_parameters, context);
 
     return parse_surrounded_by_tokens(|context| {
-        let mut items = PrintItems::new();
+        let mut items = PrintItems::new(context.arena);
 
         if !force_use_new_lines && nodes.len() == 1 && is_arrow_function_with_expr_body(&nodes[0]) {
             let start_info = Info::new("startArrow");
@@ -4771,7 +5019,7 @@ fn parse_parameters_or_arguments<'a, F>(opts: ParseParametersOrArgumentsOptions<
                     let is_different_start_line_indentation = start_info.line_start_indent_level != context.writer_info.line_start_indent_level;
                     Some(is_different_line && is_different_start_line_indentation)
                 },
-                Signal::NewLine.into()
+                context.arena.items(Signal::NewLine)
             ));
         } else {
             items.extend(parse_separated_values(ParseSeparatedValuesOptions {
@@ -4843,18 +5091,18 @@ fn parse_parameters_or_arguments<'a, F>(opts: ParseParametersOrArgumentsOptions<
 struct ParseCloseParenWithTypeOptions<'a> {
     start_info: Info,
     type_node: Option<Node<'a>>,
-    type_node_separator: Option<PrintItems>,
+    type_node_separator: Option<PrintItems<'a>>,
     param_count: usize,
 }
 
-fn parse_close_paren_with_type<'a>(opts: ParseCloseParenWithTypeOptions<'a>, context: &mut Context<'a>) -> PrintItems {
+fn parse_close_paren_with_type<'a>(opts: ParseCloseParenWithTypeOptions<'a>, context: &mut Context<'a>) -> PrintItems<'a> {
     // todo: clean this up a bit
     let type_node_start_info = Info::new("typeNodeStart");
     let has_type_node = opts.type_node.is_some();
     let type_node_end_info = Info::new("typeNodeEnd");
     let start_info = opts.start_info;
     let parsed_type_node = parse_type_node(opts.type_node, opts.type_node_separator, type_node_start_info, type_node_end_info, opts.param_count, context);
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(context.arena);
 
     items.push_condition(if_true(
         "newLineIfHeaderHangingAndTypeNodeMultipleLines",
@@ -4868,7 +5116,7 @@ fn parse_close_paren_with_type<'a>(opts: ParseCloseParenWithTypeOptions<'a>, con
             }
             return None;
         },
-        Signal::NewLine.into(),
+        context.arena.items(Signal::NewLine),
     ));
     items.push_str(")");
     items.extend(parsed_type_node);
@@ -4876,13 +5124,13 @@ fn parse_close_paren_with_type<'a>(opts: ParseCloseParenWithTypeOptions<'a>, con
 
     fn parse_type_node<'a>(
         type_node: Option<Node<'a>>,
-        type_node_separator: Option<PrintItems>,
+        type_node_separator: Option<PrintItems<'a>>,
         type_node_start_info: Info,
         type_node_end_info: Info,
         param_count: usize,
         context: &mut Context<'a>
-    ) -> PrintItems {
-        let mut items = PrintItems::new();
+    ) -> PrintItems<'a> {
+        let mut items = PrintItems::new(context.arena);
         return if let Some(type_node) = type_node {
             let use_new_line_group = get_use_new_line_group(param_count, &type_node, context);
             items.push_info(type_node_start_info);
@@ -4934,28 +5182,96 @@ struct ParseSeparatedValuesOptions<'a> {
     semi_colons: Option<SemiColons>,
     single_line_space_at_start: bool,
     single_line_space_at_end: bool,
-    custom_single_line_separator: Option<PrintItems>,
+    custom_single_line_separator: Option<PrintItems<'a>>,
     multi_line_options: parser_helpers::MultiLineOptions,
     force_possible_newline_at_start: bool,
 }
 
 #[inline]
+/// Stores an alignment group for each run of consecutive nodes that aren't separated by a blank line.
+///
+/// Each group is stored for the spans returned by `get_aligned_span_data` and a node it
+/// returns `None` for ends the run.
+fn store_alignment_groups<'a>(
+    name: &'static str,
+    nodes: &[Node<'a>],
+    get_aligned_span_data: impl Fn(&Node<'a>, &mut Context<'a>) -> Option<SpanData>,
+    context: &mut Context<'a>,
+) {
+    let mut span_datas = Vec::new();
+    let mut last_node: Option<&Node<'a>> = None;
+
+    for node in nodes {
+        let aligned_span_data = get_aligned_span_data(node, context);
+        let continues_run = aligned_span_data.is_some() && !span_datas.is_empty() && match last_node {
+            Some(last_node) => !node_helpers::has_separating_blank_line(last_node, node, context),
+            None => false,
+        };
+        if !continues_run {
+            store_group(name, std::mem::replace(&mut span_datas, Vec::new()), context);
+        }
+        if let Some(aligned_span_data) = aligned_span_data {
+            span_datas.push(aligned_span_data);
+        }
+        last_node = Some(node);
+    }
+
+    store_group(name, span_datas, context);
+
+    fn store_group(name: &'static str, span_datas: Vec<SpanData>, context: &mut Context) {
+        // nothing to align with
+        if span_datas.len() < 2 {
+            return;
+        }
+
+        let group = AlignmentGroup::new(name);
+        for span_data in span_datas {
+            context.store_alignment_group_for_node(&span_data, group.clone());
+        }
+    }
+}
+
+/// Gets the span data of the operator token before an assigned expression.
+fn get_assignment_op_span_data(expr: &Expr, context: &mut Context) -> Option<SpanData> {
+    context.token_finder.get_previous_token(expr).map(|token| token.span_data())
+}
+
+/// Gets the line comment trailing a node that's on a single line, skipping over a separating comma.
+fn get_same_line_trailing_line_comment<'a>(node: &Node<'a>, context: &mut Context<'a>) -> Option<&'a Comment> {
+    let node_end_line = node.end_line(context);
+    if node.start_line(context) != node_end_line {
+        return None;
+    }
+
+    let mut trailing_comments = match context.token_finder.get_next_token_if_comma(node) {
+        Some(comma_token) => comma_token.trailing_comments(context),
+        None => node.trailing_comments(context),
+    };
+    let first_comment = trailing_comments.next()?;
+    if first_comment.kind == CommentKind::Line && first_comment.start_line(context) == node_end_line {
+        Some(first_comment)
+    } else {
+        None
+    }
+}
+
 fn parse_separated_values<'a>(
     opts: ParseSeparatedValuesOptions<'a>,
     context: &mut Context<'a>
-) -> PrintItems {
+) -> PrintItems<'a> {
     parse_separated_values_with_result(opts, context).items
 }
 
 fn parse_separated_values_with_result<'a>(
     opts: ParseSeparatedValuesOptions<'a>,
     context: &mut Context<'a>
-) -> ParseSeparatedValuesResult {
+) -> ParseSeparatedValuesResult<'a> {
     let nodes = opts.nodes;
     let semi_colons = opts.semi_colons;
     let trailing_commas = opts.trailing_commas;
     let indent_width = context.config.indent_width;
     let compute_lines_span = opts.allow_blank_lines; // save time otherwise
+    let single_line_separator = opts.custom_single_line_separator.unwrap_or_else(|| context.arena.items(Signal::SpaceOrNewLine));
     parser_helpers::parse_separated_values(|is_multi_line_or_hanging_ref| {
         let is_multi_line_or_hanging = is_multi_line_or_hanging_ref.create_resolver();
         let mut parsed_nodes = Vec::new();
@@ -4972,16 +5288,16 @@ fn parse_separated_values_with_result<'a>(
                 })
             } else { None };
             let items = parser_helpers::new_line_group(if let Some(trailing_commas) = trailing_commas {
-                let parsed_comma = get_parsed_trailing_comma(trailing_commas, i == nodes_count - 1, &is_multi_line_or_hanging);
+                let parsed_comma = get_parsed_trailing_comma(context.arena, trailing_commas, i == nodes_count - 1, &is_multi_line_or_hanging);
                 parse_comma_separated_value(value, parsed_comma, context)
             } else if let Some(semi_colons) = semi_colons {
-                let parsed_semi_colon = get_parsed_semi_colon(semi_colons, i == nodes_count - 1, &is_multi_line_or_hanging);
+                let parsed_semi_colon = get_parsed_semi_colon(context.arena, semi_colons, i == nodes_count - 1, &is_multi_line_or_hanging);
                 parse_node_with_semi_colon(value, parsed_semi_colon, context)
             } else {
                 if let Some(value) = value {
                     parse_node(value, context)
                 } else {
-                    PrintItems::new()
+                    PrintItems::new(context.arena)
                 }
             });
             parsed_nodes.push(parser_helpers::ParsedValue {
@@ -4999,22 +5315,22 @@ fn parse_separated_values_with_result<'a>(
         allow_blank_lines: opts.allow_blank_lines,
         single_line_space_at_start: opts.single_line_space_at_start,
         single_line_space_at_end: opts.single_line_space_at_end,
-        single_line_separator: opts.custom_single_line_separator.unwrap_or(Signal::SpaceOrNewLine.into()),
+        single_line_separator,
         indent_width,
         multi_line_options: opts.multi_line_options,
         force_possible_newline_at_start: opts.force_possible_newline_at_start,
     })
 }
 
-fn parse_comma_separated_value<'a>(value: Option<Node<'a>>, parsed_comma: PrintItems, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_comma_separated_value<'a>(value: Option<Node<'a>>, parsed_comma: PrintItems<'a>, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     let comma_token = get_comma_token(&value, context);
 
     if let Some(element) = value {
         let parsed_comma = parsed_comma.into_rc_path();
         items.extend(parse_node_with_inner_parse(element, context, move |mut items, _| {
             // this Rc clone is necessary because we can't move the captured parsed_comma out of this closure
-            items.push_optional_path(parsed_comma.clone());
+            items.push_optional_path(parsed_comma);
             items
         }));
     } else {
@@ -5038,12 +5354,12 @@ fn parse_comma_separated_value<'a>(value: Option<Node<'a>>, parsed_comma: PrintI
     }
 }
 
-fn parse_node_with_semi_colon<'a>(value: Option<Node<'a>>, parsed_semi_colon: PrintItems, context: &mut Context<'a>) -> PrintItems {
+fn parse_node_with_semi_colon<'a>(value: Option<Node<'a>>, parsed_semi_colon: PrintItems<'a>, context: &mut Context<'a>) -> PrintItems<'a> {
     if let Some(element) = value {
         let parsed_semi_colon = parsed_semi_colon.into_rc_path();
         parse_node_with_inner_parse(element, context, move |mut items, _| {
             // this Rc clone is necessary because we can't move the captured parsed_semi_colon out of this closure
-            items.push_optional_path(parsed_semi_colon.clone());
+            items.push_optional_path(parsed_semi_colon);
             items
         })
     } else {
@@ -5052,8 +5368,8 @@ fn parse_node_with_semi_colon<'a>(value: Option<Node<'a>>, parsed_semi_colon: Pr
 }
 
 /// For some reason, some nodes don't have a TsTypeAnn, but instead of a Box<TsType>
-fn parse_type_ann_with_colon_if_exists_for_type<'a>(type_ann: &'a Option<Box<TsType>>, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_type_ann_with_colon_if_exists_for_type<'a>(type_ann: &'a Option<Box<TsType>>, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     if let Some(type_ann) = type_ann {
         if context.config.type_annotation_space_before_colon {
             items.push_str(" ");
@@ -5066,8 +5382,8 @@ fn parse_type_ann_with_colon_if_exists_for_type<'a>(type_ann: &'a Option<Box<TsT
     items
 }
 
-fn parse_type_ann_with_colon_if_exists<'a>(type_ann: &'a Option<TsTypeAnn>, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_type_ann_with_colon_if_exists<'a>(type_ann: &'a Option<TsTypeAnn>, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     if let Some(type_ann) = type_ann {
         if context.config.type_annotation_space_before_colon {
             items.push_str(" ");
@@ -5080,7 +5396,7 @@ fn parse_type_ann_with_colon_if_exists<'a>(type_ann: &'a Option<TsTypeAnn>, cont
     items
 }
 
-fn parse_type_ann_with_colon<'a>(type_ann: Node<'a>, colon_token: Option<&TokenAndSpan>, context: &mut Context<'a>) -> PrintItems {
+fn parse_type_ann_with_colon<'a>(type_ann: Node<'a>, colon_token: Option<&TokenAndSpan>, context: &mut Context<'a>) -> PrintItems<'a> {
     parse_assignment_like_with_token(type_ann, ":", colon_token, context)
 }
 
@@ -5090,44 +5406,44 @@ struct ParseBraceSeparatorOptions<'a> {
     start_header_info: Option<Info>,
 }
 
-fn parse_brace_separator<'a>(opts: ParseBraceSeparatorOptions<'a>, context: &mut Context) -> PrintItems {
+fn parse_brace_separator<'a>(opts: ParseBraceSeparatorOptions<'a>, context: &mut Context<'a>) -> PrintItems<'a> {
     return match opts.brace_position {
         BracePosition::NextLineIfHanging => {
             if let Some(start_header_info) = opts.start_header_info {
-                conditions::new_line_if_hanging_space_otherwise(conditions::NewLineIfHangingSpaceOtherwiseOptions {
+                context.arena.items(conditions::new_line_if_hanging_space_otherwise(context.arena, conditions::NewLineIfHangingSpaceOtherwiseOptions {
                     start_info: start_header_info,
                     end_info: None,
-                    space_char: Some(space_if_not_start_line()),
-                }).into()
+                    space_char: Some(space_if_not_start_line(context.arena)),
+                }))
             } else {
-                space_if_not_start_line()
+                space_if_not_start_line(context.arena)
             }
         },
         BracePosition::SameLine => {
-            space_if_not_start_line()
+            space_if_not_start_line(context.arena)
         },
         BracePosition::NextLine => {
-            Signal::NewLine.into()
+            context.arena.items(Signal::NewLine)
         },
         BracePosition::Maintain => {
             if let Some(open_brace_token) = opts.open_brace_token {
                 if node_helpers::is_first_node_on_line(open_brace_token, context) {
-                    Signal::NewLine.into()
+                    context.arena.items(Signal::NewLine)
                 } else {
-                    space_if_not_start_line()
+                    space_if_not_start_line(context.arena)
                 }
             } else {
-                space_if_not_start_line()
+                space_if_not_start_line(context.arena)
             }
         },
     };
 
-    fn space_if_not_start_line() -> PrintItems {
-        if_true(
+    fn space_if_not_start_line<'a>(arena: &'a PrintItemsArena<'a>) -> PrintItems<'a> {
+        arena.items(if_true(
             "spaceIfNotStartLine",
             |context| Some(!context.writer_info.is_start_of_line()),
-            " ".into()
-        ).into()
+            arena.items(" ")
+        ))
     }
 }
 
@@ -5138,10 +5454,10 @@ struct ParseNodeInParensOptions {
 }
 
 fn parse_node_in_parens<'a>(
-    parse_node: impl FnOnce(&mut Context<'a>) -> PrintItems,
+    parse_node: impl FnOnce(&mut Context<'a>) -> PrintItems<'a>,
     opts: ParseNodeInParensOptions,
     context: &mut Context<'a>
-) -> PrintItems {
+) -> PrintItems<'a> {
     let inner_span = opts.inner_span;
     let paren_span = get_paren_span(&inner_span, context);
     let force_use_new_lines = get_force_use_new_lines(inner_span, &paren_span, context);
@@ -5197,21 +5513,21 @@ struct ParseExtendsOrImplementsOptions<'a> {
     prefer_hanging: bool,
 }
 
-fn parse_extends_or_implements<'a>(opts: ParseExtendsOrImplementsOptions<'a>, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_extends_or_implements<'a>(opts: ParseExtendsOrImplementsOptions<'a>, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
 
     if opts.type_items.is_empty() {
         return items;
     }
 
-    items.push_condition(conditions::new_line_if_hanging_space_otherwise(conditions::NewLineIfHangingSpaceOtherwiseOptions {
+    items.push_condition(conditions::new_line_if_hanging_space_otherwise(context.arena, conditions::NewLineIfHangingSpaceOtherwiseOptions {
         start_info: opts.start_header_info,
         end_info: None,
-        space_char: Some(conditions::if_above_width_or(context.config.indent_width, Signal::SpaceOrNewLine.into(), " ".into()).into()),
+        space_char: Some(context.arena.items(conditions::if_above_width_or(context.config.indent_width, context.arena.items(Signal::SpaceOrNewLine), context.arena.items(" ")))),
     }));
     // the newline group will force it to put the extends or implements on a new line
     items.push_condition(conditions::indent_if_start_of_line(parser_helpers::new_line_group({
-        let mut items = PrintItems::new();
+        let mut items = PrintItems::new(context.arena);
         items.push_str(opts.text);
         items.extend(parse_separated_values(ParseSeparatedValuesOptions {
             nodes: opts.type_items.into_iter().map(|x| Some(x)).collect(),
@@ -5240,15 +5556,25 @@ struct ParseObjectLikeNodeOptions<'a> {
     prefer_hanging: bool,
     prefer_single_line: bool,
     surround_single_line_with_spaces: bool,
+    /// Aligns the values of consecutive key value properties when multi-line.
+    align_values: bool,
 }
 
-fn parse_object_like_node<'a>(opts: ParseObjectLikeNodeOptions<'a>, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_object_like_node<'a>(opts: ParseObjectLikeNodeOptions<'a>, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
 
     let open_brace_token = context.token_finder.get_first_open_brace_token_within(&opts.node_span_data);
     let close_brace_token = context.token_finder.get_last_close_brace_token_within(&opts.node_span_data);
     let force_multi_line = get_use_new_lines_for_nodes_with_preceeding_token("{", &opts.members, opts.prefer_single_line, context);
 
+    // only align when it's known each member will be on its own line
+    if opts.align_values && force_multi_line {
+        store_alignment_groups("objectValues", &opts.members, |member, context| match member {
+            Node::KeyValueProp(prop) => get_assignment_op_span_data(&prop.value, context),
+            _ => None,
+        }, context);
+    }
+
     let first_member_span_data = opts.members.get(0).map(|x| x.span_data());
     let obj_span_data = if let (Some(open_brace_token), Some(close_brace_token)) = (open_brace_token, close_brace_token) {
         Some(create_span_data(open_brace_token.lo(), close_brace_token.hi()))
@@ -5257,7 +5583,7 @@ fn parse_object_like_node<'a>(opts: ParseObjectLikeNodeOptions<'a>, context: &mu
     };
 
     items.extend(parse_surrounded_by_tokens(|context| {
-        let mut items = PrintItems::new();
+        let mut items = PrintItems::new(context.arena);
         if !opts.members.is_empty() {
             items.extend(parse_separated_values(ParseSeparatedValuesOptions {
                 nodes: opts.members.into_iter().map(|x| Some(x)).collect(),
@@ -5292,8 +5618,8 @@ struct MemberLikeExpr<'a> {
     is_computed: bool,
 }
 
-fn parse_for_member_like_expr<'a>(node: MemberLikeExpr<'a>, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_for_member_like_expr<'a>(node: MemberLikeExpr<'a>, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     let force_use_new_line = !context.config.member_expression_prefer_single_line
         && node_helpers::get_use_new_lines_for_nodes(&node.left_node, &node.right_node, context);
     let is_optional = context.parent().kind() == NodeKind::OptChainExpr;
@@ -5311,7 +5637,7 @@ fn parse_for_member_like_expr<'a>(node: MemberLikeExpr<'a>, context: &mut Contex
         } else if !context.config.member_expression_line_per_expression {
             items.push_condition(conditions::if_above_width(
                 context.config.indent_width,
-                Signal::PossibleNewLine.into()
+                context.arena.items(Signal::PossibleNewLine)
             ));
         } else {
             let top_most_start_info = top_most_data.top_most_start_info;
@@ -5319,8 +5645,8 @@ fn parse_for_member_like_expr<'a>(node: MemberLikeExpr<'a>, context: &mut Contex
             items.push_condition(if_true_or(
                 "isMultipleLines",
                 move |context| condition_resolvers::is_multiple_lines(context, &top_most_start_info, &top_most_end_info),
-                Signal::NewLine.into(),
-                Signal::PossibleNewLine.into(),
+                context.arena.items(Signal::NewLine),
+                context.arena.items(Signal::PossibleNewLine),
             ));
         }
     }
@@ -5331,12 +5657,12 @@ fn parse_for_member_like_expr<'a>(node: MemberLikeExpr<'a>, context: &mut Contex
     }
 
     items.push_condition(conditions::indent_if_start_of_line({
-        let mut items = PrintItems::new();
+        let mut items = PrintItems::new(context.arena);
         let is_computed = node.is_computed;
         let right_node_span_data = node.right_node.span_data();
 
         items.extend(parse_node_with_inner_parse(node.right_node, context, |node_items, context| {
-            let mut items = PrintItems::new();
+            let mut items = PrintItems::new(context.arena);
             if is_optional {
                 items.push_str("?");
                 if is_computed { items.push_str("."); }
@@ -5407,12 +5733,12 @@ fn parse_for_member_like_expr<'a>(node: MemberLikeExpr<'a>, context: &mut Contex
     }
 }
 
-struct ParseComputedPropLikeOptions {
+struct ParseComputedPropLikeOptions<'a> {
     inner_node_span_data: SpanData,
-    inner_items: PrintItems,
+    inner_items: PrintItems<'a>,
 }
 
-fn parse_computed_prop_like<'a>(opts: ParseComputedPropLikeOptions, context: &mut Context<'a>) -> PrintItems {
+fn parse_computed_prop_like<'a>(opts: ParseComputedPropLikeOptions<'a>, context: &mut Context<'a>) -> PrintItems<'a> {
     let inner_node_span_data = opts.inner_node_span_data;
     let inner_items = opts.inner_items;
     let span_data = get_bracket_span(&inner_node_span_data, context);
@@ -5451,8 +5777,8 @@ fn parse_computed_prop_like<'a>(opts: ParseComputedPropLikeOptions, context: &mu
     }
 }
 
-fn parse_decorators<'a>(decorators: &'a Vec<Decorator>, is_inline: bool, context: &mut Context<'a>) -> PrintItems {
-    let mut items = PrintItems::new();
+fn parse_decorators<'a>(decorators: &'a Vec<Decorator>, is_inline: bool, context: &mut Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     if decorators.is_empty() {
         return items;
     }
@@ -5479,7 +5805,7 @@ fn parse_decorators<'a>(decorators: &'a Vec<Decorator>, is_inline: bool, context
 
     if is_inline {
         let is_multi_line = separated_values_result.is_multi_line_condition_ref.create_resolver();
-        items.push_condition(if_true("inlineMultiLineSpace", is_multi_line, Signal::NewLine.into()));
+        items.push_condition(if_true("inlineMultiLineSpace", is_multi_line, context.arena.items(Signal::NewLine)));
     } else {
         items.push_signal(Signal::NewLine);
     }
@@ -5493,15 +5819,15 @@ fn parse_decorators<'a>(decorators: &'a Vec<Decorator>, is_inline: bool, context
     return items;
 }
 
-fn parse_control_flow_separator(
+fn parse_control_flow_separator<'a>(
     next_control_flow_position: NextControlFlowPosition,
     previous_node_block: &SpanData,
     token_text: &str,
     previous_start_info: Info,
     previous_close_brace_condition_ref: Option<ConditionReference>,
-    context: &mut Context
-) -> PrintItems {
-    let mut items = PrintItems::new();
+    context: &mut Context<'a>
+) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     match next_control_flow_position {
         NextControlFlowPosition::SameLine => {
             items.push_condition(if_true_or(
@@ -5521,8 +5847,8 @@ fn parse_control_flow_separator(
 
                     Some(false)
                 },
-                Signal::NewLine.into(),
-                " ".into(),
+                context.arena.items(Signal::NewLine),
+                context.arena.items(" "),
             ));
         },
         NextControlFlowPosition::NextLine => items.push_signal(Signal::NewLine),
@@ -5542,23 +5868,23 @@ fn parse_control_flow_separator(
 struct ParseHeaderWithConditionalBraceBodyOptions<'a> {
     parent: SpanData,
     body_node: Node<'a>,
-    parsed_header: PrintItems,
+    parsed_header: PrintItems<'a>,
     use_braces: UseBraces,
     brace_position: BracePosition,
     single_body_position: Option<SingleBodyPosition>,
     requires_braces_condition_ref: Option<ConditionReference>,
 }
 
-struct ParseHeaderWithConditionalBraceBodyResult {
-    parsed_node: PrintItems,
+struct ParseHeaderWithConditionalBraceBodyResult<'a> {
+    parsed_node: PrintItems<'a>,
     open_brace_condition_ref: ConditionReference,
     close_brace_condition_ref: ConditionReference,
 }
 
-fn parse_header_with_conditional_brace_body<'a>(opts: ParseHeaderWithConditionalBraceBodyOptions<'a>, context: &mut Context<'a>) -> ParseHeaderWithConditionalBraceBodyResult {
+fn parse_header_with_conditional_brace_body<'a>(opts: ParseHeaderWithConditionalBraceBodyOptions<'a>, context: &mut Context<'a>) -> ParseHeaderWithConditionalBraceBodyResult<'a> {
     let start_header_info = Info::new("startHeader");
     let end_header_info = Info::new("endHeader");
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(context.arena);
 
     items.push_info(start_header_info);
     items.extend(opts.parsed_header);
@@ -5595,13 +5921,13 @@ struct ParseConditionalBraceBodyOptions<'a> {
     end_header_info: Option<Info>,
 }
 
-struct ParseConditionalBraceBodyResult {
-    parsed_node: PrintItems,
+struct ParseConditionalBraceBodyResult<'a> {
+    parsed_node: PrintItems<'a>,
     open_brace_condition_ref: ConditionReference,
     close_brace_condition_ref: ConditionReference,
 }
 
-fn parse_conditional_brace_body<'a>(opts: ParseConditionalBraceBodyOptions<'a>, context: &mut Context<'a>) -> ParseConditionalBraceBodyResult {
+fn parse_conditional_brace_body<'a>(opts: ParseConditionalBraceBodyOptions<'a>, context: &mut Context<'a>) -> ParseConditionalBraceBodyResult<'a> {
     // todo: reorganize...
     let start_info = Info::new("startInfo");
     let end_info = Info::new("endInfo");
@@ -5643,7 +5969,7 @@ fn parse_conditional_brace_body<'a>(opts: ParseConditionalBraceBodyOptions<'a>,
             }
             return Some(start_inner_text_info.column_number < end_statements_info.column_number);
         },
-        Signal::SpaceOrNewLine.into(),
+        context.arena.items(Signal::SpaceOrNewLine),
     );
     let space_condition_ref = space_condition.get_reference();
     let mut newline_condition = if_true(
@@ -5662,7 +5988,7 @@ fn parse_conditional_brace_body<'a>(opts: ParseConditionalBraceBodyOptions<'a>,
             let resolved_end_statements_info = condition_context.get_resolved_info(&end_statements_info)?;
             return Some(resolved_end_statements_info.line_number > resolved_start_info.line_number);
         },
-        Signal::NewLine.into(),
+        context.arena.items(Signal::NewLine),
     );
     let newline_condition_ref = newline_condition.get_reference();
     let force_braces = get_force_braces(&opts.body_node);
@@ -5718,7 +6044,7 @@ fn parse_conditional_brace_body<'a>(opts: ParseConditionalBraceBodyOptions<'a>,
             }))
         },
         true_path: {
-            let mut items = PrintItems::new();
+            let mut items = PrintItems::new(context.arena);
             items.extend(parse_brace_separator(ParseBraceSeparatorOptions {
                 brace_position: opts.brace_position,
                 open_brace_token: open_brace_token,
@@ -5732,7 +6058,7 @@ fn parse_conditional_brace_body<'a>(opts: ParseConditionalBraceBodyOptions<'a>,
     let open_brace_condition_ref = open_brace_condition.get_reference();
 
     // parse body
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(context.arena);
     items.push_info(start_info);
     items.push_condition(open_brace_condition);
     items.push_condition(space_condition);
@@ -5747,7 +6073,7 @@ fn parse_conditional_brace_body<'a>(opts: ParseConditionalBraceBodyOptions<'a>,
 
     if let Node::BlockStmt(body_node) = opts.body_node {
         items.extend(parser_helpers::with_indent({
-            let mut items = PrintItems::new();
+            let mut items = PrintItems::new(context.arena);
             // parse the remaining trailing comments inside because some of them are parsed already
             // by parsing the header trailing comments
             items.extend(parse_leading_comments(body_node, context));
@@ -5756,7 +6082,7 @@ fn parse_conditional_brace_body<'a>(opts: ParseConditionalBraceBodyOptions<'a>,
         }));
     } else {
         items.extend(parser_helpers::with_indent({
-            let mut items = PrintItems::new();
+            let mut items = PrintItems::new(context.arena);
             let body_node_span_data = opts.body_node.span_data();
             items.extend(parse_node(opts.body_node, context));
             items.extend(parse_trailing_comments(&body_node_span_data, context));
@@ -5769,7 +6095,7 @@ fn parse_conditional_brace_body<'a>(opts: ParseConditionalBraceBodyOptions<'a>,
         "closeBrace",
         move |condition_context| condition_context.get_resolved_condition(&open_brace_condition_ref),
         {
-            let mut items = PrintItems::new();
+            let mut items = PrintItems::new(context.arena);
             items.push_condition(if_true_or(
                 "closeBraceNewLine",
                 move |condition_context| {
@@ -5778,8 +6104,8 @@ fn parse_conditional_brace_body<'a>(opts: ParseConditionalBraceBodyOptions<'a>,
                     let has_statement_text = condition_resolvers::are_infos_not_equal(condition_context, &start_statements_info, &end_statements_info)?;
                     return Some(has_statement_text);
                 },
-                Signal::NewLine.into(),
-                if_true(
+                context.arena.items(Signal::NewLine),
+                context.arena.items(if_true(
                     "closeBraceSpace",
                     move |condition_context| {
                         if condition_resolvers::is_at_same_position(condition_context, &start_inner_text_info)? {
@@ -5788,8 +6114,8 @@ fn parse_conditional_brace_body<'a>(opts: ParseConditionalBraceBodyOptions<'a>,
                         let had_space = condition_context.get_resolved_condition(&space_condition_ref)?;
                         return Some(had_space);
                     },
-                    " ".into(),
-                ).into()
+                    context.arena.items(" "),
+                ))
             ));
             items.push_str("}");
             items
@@ -5924,7 +6250,7 @@ struct ParseJsxWithOpeningAndClosingOptions<'a> {
     children: Vec<Node<'a>>,
 }
 
-fn parse_jsx_with_opening_and_closing<'a>(opts: ParseJsxWithOpeningAndClosingOptions<'a>, context: &mut Context<'a>) -> PrintItems {
+fn parse_jsx_with_opening_and_closing<'a>(opts: ParseJsxWithOpeningAndClosingOptions<'a>, context: &mut Context<'a>) -> PrintItems<'a> {
     let force_use_multi_lines = get_force_use_multi_lines(&opts.opening_element, &opts.children, context);
     let children = opts.children.into_iter().filter(|c| match c {
         Node::JSXText(c) => !c.text(context).trim().is_empty(),
@@ -5932,7 +6258,7 @@ fn parse_jsx_with_opening_and_closing<'a>(opts: ParseJsxWithOpeningAndClosingOpt
     }).collect();
     let start_info = Info::new("startInfo");
     let end_info = Info::new("endInfo");
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(context.arena);
     let inner_span_data = create_span_data(opts.opening_element.span_data().hi, opts.closing_element.span_data().lo);
 
     items.push_info(start_info);
@@ -5974,7 +6300,7 @@ struct ParseJsxChildrenOptions<'a> {
     force_use_multi_lines: bool,
 }
 
-fn parse_jsx_children<'a>(opts: ParseJsxChildrenOptions<'a>, context: &mut Context<'a>) -> PrintItems {
+fn parse_jsx_children<'a>(opts: ParseJsxChildrenOptions<'a>, context: &mut Context<'a>) -> PrintItems<'a> {
     // Need to parse the children here so they only get parsed once.
     // Nodes need to be only parsed once so that their comments don't end up in
     // the handled comments collection and the second time they won't be parsed out.
@@ -5987,7 +6313,7 @@ fn parse_jsx_children<'a>(opts: ParseJsxChildrenOptions<'a>, context: &mut Conte
     }
     else {
         // decide whether newlines should be used or not
-        return if_true_or(
+        return context.arena.items(if_true_or(
             "jsxChildrenNewLinesOrNot",
             move |condition_context| {
                 // use newlines if the header is multiple lines
@@ -6001,35 +6327,23 @@ fn parse_jsx_children<'a>(opts: ParseJsxChildrenOptions<'a>, context: &mut Conte
             },
             parse_for_new_lines(children.clone(), opts.inner_span_data, context),
             parse_for_single_line(children, context),
-        ).into();
+        ));
     }
 
-    fn parse_for_new_lines<'a>(children: Vec<(Node<'a>, Option<PrintItemPath>)>, inner_span_data: SpanData, context: &mut Context<'a>) -> PrintItems {
-        let mut items = PrintItems::new();
+    fn parse_for_new_lines<'a>(children: Vec<(Node<'a>, Option<PrintItemPath<'a>>)>, inner_span_data: SpanData, context: &mut Context<'a>) -> PrintItems<'a> {
+        if context.config.jsx_element_fill_text && children.iter().any(|(child, _)| is_jsx_text(child)) {
+            return parse_for_fill(children, context);
+        }
+
+        let mut items = PrintItems::new(context.arena);
         let has_children = !children.is_empty();
         items.push_signal(Signal::NewLine);
         items.extend(parser_helpers::with_indent(parse_statements_or_members(ParseStatementsOrMembersOptions {
             inner_span_data,
-            items: children.into_iter().map(|(a, b)| (a, Some(b.into()))).collect(),
+            items: children.into_iter().map(|(a, b)| (a, Some(context.arena.items(b)))).collect(),
             should_use_space: Some(Box::new(|previous, next, context| should_use_space(previous, next, context))),
-            should_use_new_line: Some(Box::new(|previous, next, context| {
-                if let Node::JSXText(next) = next {
-                    return !utils::has_no_new_lines_in_leading_whitespace(next.text(context));
-                }
-                if let Node::JSXText(previous) = previous {
-                    return !utils::has_no_new_lines_in_trailing_whitespace(previous.text(context));
-                }
-                return true;
-            })),
-            should_use_blank_line: |previous, next, context| {
-                if let Node::JSXText(previous) = previous {
-                    return utils::has_new_line_occurrences_in_trailing_whitespace(previous.text(context), 2);
-                }
-                if let Node::JSXText(next) = next {
-                    return utils::has_new_line_occurrences_in_leading_whitespace(next.text(context), 2);
-                }
-                return node_helpers::has_separating_blank_line(previous, next, context);
-            },
+            should_use_new_line: Some(Box::new(|previous, next, context| has_new_line_between(previous, next, context))),
+            should_use_blank_line: |previous, next, context| has_blank_line_between(previous, next, context),
             trailing_commas: None,
             semi_colons: None,
         }, context)));
@@ -6041,8 +6355,68 @@ fn parse_jsx_children<'a>(opts: ParseJsxChildrenOptions<'a>, context: &mut Conte
         items
     }
 
-    fn parse_for_single_line<'a>(children: Vec<(Node<'a>, Option<PrintItemPath>)>, context: &mut Context<'a>) -> PrintItems {
-        let mut items = PrintItems::new();
+    /// Packs as many words and elements on each line as fit, keeping the line breaks next to elements.
+    fn parse_for_fill<'a>(children: Vec<(Node<'a>, Option<PrintItemPath<'a>>)>, context: &mut Context<'a>) -> PrintItems<'a> {
+        let mut inner_items = PrintItems::new(context.arena);
+        let mut values = Vec::new();
+        let mut current_value = PrintItems::new(context.arena);
+        let mut previous_child: Option<Node<'a>> = None;
+
+        for (child, parsed_child) in children.into_iter() {
+            if let Some(previous_child) = previous_child {
+                if has_new_line_between(&previous_child, &child, context) {
+                    finish_line(&mut inner_items, &mut values, &mut current_value);
+                    inner_items.push_signal(Signal::NewLine);
+                    if has_blank_line_between(&previous_child, &child, context) {
+                        inner_items.push_signal(Signal::NewLine);
+                    }
+                } else if should_use_space(&previous_child, &child, context) {
+                    values.push(std::mem::replace(&mut current_value, PrintItems::new(context.arena)));
+                } else {
+                    // there is no significant whitespace between the text and the element, so they can't be separated by a space
+                    current_value.push_signal(Signal::PossibleNewLine);
+                }
+            }
+
+            if let Node::JSXText(text) = &child {
+                // fill the words of the text rather than the text as a whole
+                for (i, line) in get_jsx_text_lines(text.text(context)).into_iter().enumerate() {
+                    if i > 0 {
+                        finish_line(&mut inner_items, &mut values, &mut current_value);
+                        inner_items.push_signal(Signal::NewLine);
+                        inner_items.push_signal(Signal::NewLine);
+                    }
+                    for (j, word) in line.split(' ').filter(|word| !word.is_empty()).enumerate() {
+                        if j > 0 {
+                            values.push(std::mem::replace(&mut current_value, PrintItems::new(context.arena)));
+                        }
+                        current_value.push_str(word);
+                    }
+                }
+            } else {
+                current_value.extend(context.arena.items(parsed_child));
+            }
+
+            previous_child = Some(child);
+        }
+
+        finish_line(&mut inner_items, &mut values, &mut current_value);
+
+        let mut items = PrintItems::new(context.arena);
+        items.push_signal(Signal::NewLine);
+        items.extend(parser_helpers::with_indent(inner_items));
+        items.push_signal(Signal::NewLine);
+        return items;
+
+        fn finish_line<'a>(inner_items: &mut PrintItems<'a>, values: &mut Vec<PrintItems<'a>>, current_value: &mut PrintItems<'a>) {
+            let arena = inner_items.arena();
+            values.push(std::mem::replace(current_value, PrintItems::new(arena)));
+            inner_items.extend(parser_helpers::fill(std::mem::replace(values, Vec::new()), PrintItems::new(arena)));
+        }
+    }
+
+    fn parse_for_single_line<'a>(children: Vec<(Node<'a>, Option<PrintItemPath<'a>>)>, context: &mut Context<'a>) -> PrintItems<'a> {
+        let mut items = PrintItems::new(context.arena);
         if children.is_empty() {
             items.push_signal(Signal::PossibleNewLine);
         } else {
@@ -6054,7 +6428,7 @@ fn parse_jsx_children<'a>(opts: ParseJsxChildrenOptions<'a>, context: &mut Conte
                     }
                 }
 
-                items.extend(parsed_child.into());
+                items.extend(context.arena.items(parsed_child));
                 items.push_signal(Signal::PossibleNewLine);
                 previous_child = Some(child);
             }
@@ -6062,6 +6436,33 @@ fn parse_jsx_children<'a>(opts: ParseJsxChildrenOptions<'a>, context: &mut Conte
         items
     }
 
+    fn is_jsx_text(node: &Node) -> bool {
+        match node {
+            Node::JSXText(_) => true,
+            _ => false,
+        }
+    }
+
+    fn has_new_line_between(previous_element: &Node, next_element: &Node, context: &mut Context) -> bool {
+        if let Node::JSXText(element) = next_element {
+            !utils::has_no_new_lines_in_leading_whitespace(element.text(context))
+        } else if let Node::JSXText(element) = previous_element {
+            !utils::has_no_new_lines_in_trailing_whitespace(element.text(context))
+        } else {
+            true
+        }
+    }
+
+    fn has_blank_line_between(previous_element: &Node, next_element: &Node, context: &mut Context) -> bool {
+        if let Node::JSXText(element) = previous_element {
+            utils::has_new_line_occurrences_in_trailing_whitespace(element.text(context), 2)
+        } else if let Node::JSXText(element) = next_element {
+            utils::has_new_line_occurrences_in_leading_whitespace(element.text(context), 2)
+        } else {
+            node_helpers::has_separating_blank_line(previous_element, next_element, context)
+        }
+    }
+
     fn should_use_space(previous_element: &Node, next_element: &Node, context: &mut Context) -> bool {
         if let Node::JSXText(element) = previous_element {
             element.text(context).ends_with(" ")
@@ -6073,7 +6474,7 @@ fn parse_jsx_children<'a>(opts: ParseJsxChildrenOptions<'a>, context: &mut Conte
     }
 }
 
-fn parse_assignment<'a>(expr: Node<'a>, op: &str, context: &mut Context<'a>) -> PrintItems {
+fn parse_assignment<'a>(expr: Node<'a>, op: &str, context: &mut Context<'a>) -> PrintItems<'a> {
     let op_token = context.token_finder.get_previous_token(&expr);
     #[cfg(debug_assertions)]
     assert_has_op(op, op_token, context);
@@ -6081,18 +6482,26 @@ fn parse_assignment<'a>(expr: Node<'a>, op: &str, context: &mut Context<'a>) ->
     parse_assignment_like_with_token(expr, op, op_token, context)
 }
 
-fn parse_assignment_like_with_token<'a>(expr: Node<'a>, op: &str, op_token: Option<&TokenAndSpan>, context: &mut Context<'a>) -> PrintItems {
+fn parse_assignment_like_with_token<'a>(expr: Node<'a>, op: &str, op_token: Option<&TokenAndSpan>, context: &mut Context<'a>) -> PrintItems<'a> {
     let use_new_line_group = get_use_new_line_group(&expr);
-    let mut items = PrintItems::new();
+    let mut items = PrintItems::new(context.arena);
 
-    if op == ":" { items.push_str(op) } else { items.push_str(&format!(" {}", op)) }; // good enough for now...
+    // aligns the values after a colon and otherwise the operators themselves
+    let alignment_group = op_token.and_then(|token| context.take_alignment_group_for_node(token));
+    if op == ":" {
+        items.push_str(op);
+        if let Some(alignment_group) = &alignment_group { items.push_alignment(alignment_group); }
+    } else {
+        if let Some(alignment_group) = &alignment_group { items.push_alignment(alignment_group); }
+        items.push_str(&format!(" {}", op)); // good enough for now...
+    }
 
     let op_end = op_token.map(|x| x.hi()).unwrap_or_else(|| context.token_finder.get_previous_token_end_before(&expr));
     let op_trailing_comments = get_op_trailing_comments(op_end, context);
     let had_op_trailing_comments = !op_trailing_comments.is_empty();
     if !op_trailing_comments.is_empty() {
         items.extend(with_indent({
-            let mut items = PrintItems::new();
+            let mut items = PrintItems::new(context.arena);
             if let Some(first_comment) = op_trailing_comments.iter().next() {
                 if first_comment.kind == CommentKind::Block {
                     items.push_signal(Signal::SpaceIfNotTrailing);
@@ -6105,21 +6514,21 @@ fn parse_assignment_like_with_token<'a>(expr: Node<'a>, op: &str, op_token: Opti
     }
 
     let parsed_assignment = {
-        let mut items = PrintItems::new();
+        let mut items = PrintItems::new(context.arena);
         if !had_op_trailing_comments {
             items.push_condition(conditions::if_above_width_or(
                 context.config.indent_width,
                 {
-                    let mut items = PrintItems::new();
+                    let mut items = PrintItems::new(context.arena);
                     items.push_signal(Signal::SpaceIfNotTrailing);
                     items.push_signal(Signal::PossibleNewLine);
                     items
                 },
-                Signal::SpaceIfNotTrailing.into()
+                context.arena.items(Signal::SpaceIfNotTrailing)
             ).into());
         }
         let assignment = parse_node(expr, context);
-        let assignment = if had_op_trailing_comments { assignment } else { conditions::indent_if_start_of_line(assignment).into() };
+        let assignment = if had_op_trailing_comments { assignment } else { context.arena.items(conditions::indent_if_start_of_line(assignment)) };
         let assignment = if use_new_line_group { new_line_group(assignment) } else { assignment };
         items.extend(assignment);
         items
@@ -6128,8 +6537,8 @@ fn parse_assignment_like_with_token<'a>(expr: Node<'a>, op: &str, op_token: Opti
     items.push_condition(if_true_or(
         "indentIfStartOfLineIndentedOrTokenHadTrailingLineComment",
         move |context| Some(had_op_trailing_comments || condition_resolvers::is_start_of_line_indented(context)),
-        with_indent(parsed_assignment.clone().into()),
-        parsed_assignment.into()
+        with_indent(context.arena.items(parsed_assignment)),
+        context.arena.items(parsed_assignment)
     ));
 
     return items;
@@ -6164,17 +6573,17 @@ struct ParseBlockOptions<'a> {
 }
 
 fn parse_block<'a>(
-    parse_inner: impl FnOnce(Vec<Node<'a>>, &mut Context<'a>) -> PrintItems,
+    parse_inner: impl FnOnce(Vec<Node<'a>>, &mut Context<'a>) -> PrintItems<'a>,
     opts: ParseBlockOptions<'a>,
     context: &mut Context<'a>
-) -> PrintItems {
-    let mut items = PrintItems::new();
+) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     let before_open_token_info = Info::new("after_open_token_info");
     let first_member_span_data = opts.children.get(0).map(|x| x.span_data());
     let span_data = opts.span_data;
     items.push_info(before_open_token_info);
     items.extend(parse_surrounded_by_tokens(|context| {
-        let mut items = PrintItems::new();
+        let mut items = PrintItems::new(context.arena);
         let start_inner_info = Info::new("startStatementsInfo");
         let end_inner_info = Info::new("endStatementsInfo");
         let is_tokens_same_line_and_empty = if let Some(span_data) = &span_data {
@@ -6191,13 +6600,13 @@ fn parse_block<'a>(
             items.push_condition(if_true(
                 "newLineIfDifferentLine",
                 move |context| condition_resolvers::is_on_different_line(context, &before_open_token_info),
-                Signal::NewLine.into()
+                context.arena.items(Signal::NewLine)
             ));
         } else {
             items.push_condition(if_false(
                 "endNewline",
                 move |context| condition_resolvers::are_infos_equal(context, &start_inner_info, &end_inner_info),
-                Signal::NewLine.into(),
+                context.arena.items(Signal::NewLine),
             ));
         }
         items
@@ -6223,12 +6632,12 @@ struct ParseSurroundedByTokensOptions {
 }
 
 fn parse_surrounded_by_tokens<'a>(
-    parse_inner: impl FnOnce(&mut Context<'a>) -> PrintItems,
-    custom_close_token: impl FnOnce(&mut Context<'a>) -> Option<PrintItems>,
+    parse_inner: impl FnOnce(&mut Context<'a>) -> PrintItems<'a>,
+    custom_close_token: impl FnOnce(&mut Context<'a>) -> Option<PrintItems<'a>>,
     opts: ParseSurroundedByTokensOptions,
     context: &mut Context<'a>
-) -> PrintItems {
-    let mut items = PrintItems::new();
+) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
     if let Some(span_data) = opts.span_data {
         let open_token_end = BytePos(span_data.lo.0 + (opts.open_token.len() as u32));
         let close_token_start = BytePos(span_data.hi.0 - (opts.close_token.len() as u32));
@@ -6260,7 +6669,7 @@ fn parse_surrounded_by_tokens<'a>(
                     let had_comments = !condition_resolvers::is_at_same_position(context, &before_trailing_comments_info)?;
                     return Some(had_comments && !context.writer_info.is_start_of_line())
                 },
-                Signal::NewLine.into()
+                context.arena.items(Signal::NewLine)
             ));
         } else {
             let comments = open_token_end.trailing_comments(context);
@@ -6275,6 +6684,7 @@ fn parse_surrounded_by_tokens<'a>(
                 if comments.has_unhandled_comment(context) {
                     if is_single_line {
                         let indent_width = context.config.indent_width;
+                        let single_line_separator = context.arena.items(Signal::SpaceOrNewLine);
                         items.extend(parser_helpers::parse_separated_values(|_| {
                             let mut parsed_comments = Vec::new();
                             for c in comments {
@@ -6296,7 +6706,7 @@ fn parse_surrounded_by_tokens<'a>(
                             allow_blank_lines: true,
                             single_line_space_at_start: false,
                             single_line_space_at_end: false,
-                            single_line_separator: Signal::SpaceOrNewLine.into(),
+                            single_line_separator,
                             indent_width,
                             multi_line_options: parser_helpers::MultiLineOptions::surround_newlines_indented(),
                             force_possible_newline_at_start: false,
@@ -6322,13 +6732,17 @@ fn parse_surrounded_by_tokens<'a>(
     if let Some(parsed_close_token) = (custom_close_token)(context) {
         items.extend(parsed_close_token);
     } else {
-        items.push_str(opts.close_token);
+        let mut close_token_items: PrintItems<'a> = context.arena.items(opts.close_token);
+        if let Some(span_data) = opts.span_data {
+            close_token_items.set_source_position((span_data.hi.0 as usize) - opts.close_token.len());
+        }
+        items.extend(close_token_items);
     }
 
     return items;
 
-    fn parse_first_line_trailing_comment(open_token_start_line: usize, comments: CommentsIterator, context: &mut Context) -> PrintItems {
-        let mut items = PrintItems::new();
+    fn parse_first_line_trailing_comment<'a>(open_token_start_line: usize, comments: CommentsIterator, context: &mut Context<'a>) -> PrintItems<'a> {
+        let mut items = PrintItems::new(context.arena);
         let first_comment = comments.into_iter().next();
         if let Some(first_comment) = first_comment {
             if first_comment.kind == CommentKind::Line && first_comment.start_line(context) == open_token_start_line {
@@ -6522,35 +6936,35 @@ fn has_any_node_comment_on_different_line(nodes: &Vec<impl Ranged>, context: &mu
 
 /* config helpers */
 
-fn get_parsed_trailing_comma(option: TrailingCommas, is_trailing: bool, is_multi_line: &(impl Fn(&mut ConditionResolverContext) -> Option<bool> + Clone + 'static)) -> PrintItems {
-    if !is_trailing { return ",".into(); }
+fn get_parsed_trailing_comma<'a>(arena: &'a PrintItemsArena<'a>, option: TrailingCommas, is_trailing: bool, is_multi_line: &(impl Fn(&mut ConditionResolverContext) -> Option<bool> + Clone + 'static)) -> PrintItems<'a> {
+    if !is_trailing { return arena.items(","); }
 
     match option {
-        TrailingCommas::Always => ",".into(),
+        TrailingCommas::Always => arena.items(","),
         TrailingCommas::OnlyMultiLine => {
-            if_true("trailingCommaIfMultiLine", is_multi_line.clone(), ",".into()).into()
+            arena.items(if_true("trailingCommaIfMultiLine", is_multi_line.clone(), arena.items(",")))
         },
         TrailingCommas::Never => {
-            PrintItems::new()
+            PrintItems::new(arena)
         },
     }
 }
 
-fn get_parsed_semi_colon(option: SemiColons, is_trailing: bool, is_multi_line: &(impl Fn(&mut ConditionResolverContext) -> Option<bool> + Clone + 'static)) -> PrintItems {
+fn get_parsed_semi_colon<'a>(arena: &'a PrintItemsArena<'a>, option: SemiColons, is_trailing: bool, is_multi_line: &(impl Fn(&mut ConditionResolverContext) -> Option<bool> + Clone + 'static)) -> PrintItems<'a> {
     match option {
-        SemiColons::Always => ";".into(),
+        SemiColons::Always => arena.items(";"),
         SemiColons::Prefer => {
             if is_trailing {
-                if_true("semiColonIfMultiLine", is_multi_line.clone(), ";".into()).into()
+                arena.items(if_true("semiColonIfMultiLine", is_multi_line.clone(), arena.items(";")))
             } else {
-                ";".into()
+                arena.items(";")
             }
         },
         SemiColons::Asi => {
             if is_trailing {
-                PrintItems::new()
+                PrintItems::new(arena)
             } else {
-                if_false("semiColonIfSingleLine", is_multi_line.clone(), ";".into()).into()
+                arena.items(if_false("semiColonIfSingleLine", is_multi_line.clone(), arena.items(";")))
             }
         },
     }
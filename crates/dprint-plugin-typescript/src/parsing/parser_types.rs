@@ -1,6 +1,8 @@
 use std::str;
+use std::cell::{Cell, RefCell};
 use std::collections::{HashSet, HashMap};
-use dprint_core::{Info, ConditionReference};
+use std::rc::Rc;
+use dprint_core::{AlignmentGroup, Info, ConditionReference, FormatError, PrintItemsArena};
 use swc_common::{SpanData, BytePos, comments::{Comment, CommentKind}, SourceFile, Spanned, Span};
 use swc_ecma_ast::*;
 use swc_ecma_parser::{token::TokenAndSpan};
@@ -8,8 +10,67 @@ use super::*;
 use super::super::configuration::*;
 use super::super::utils::Stack;
 
+/// Formats the text of an embedded language given its file extension (ex. `css`) and the line
+/// width remaining after the indentation the formatted text will be printed at.
+///
+/// Returns `Ok(None)` when there is no formatter for the language.
+pub type FormatEmbeddedText<'a> = &'a dyn Fn(&str, &str, u32) -> Result<Option<String>, FormatError>;
+
+/// A cell the printer records the printed indent width of an embedded template in.
+pub type PrintedIndentWidth = Rc<Cell<Option<u32>>>;
+
+/// The indentation the text of the embedded language templates (ex. css`...`) is printed at.
+///
+/// The text of a template is formatted before the file is printed, so its indentation is first
+/// estimated from the indentation in the file text. The printer records the actual indentation
+/// so the file can be parsed again with it when an estimate was wrong.
+#[derive(Default)]
+pub struct EmbeddedIndents {
+    /// The printed indent widths from the previous print keyed by the template's position.
+    printed_widths: RefCell<HashMap<BytePos, u32>>,
+    /// The indent width used for each template along with the one recorded by the printer.
+    used_widths: RefCell<Vec<(BytePos, u32, PrintedIndentWidth)>>,
+}
+
+impl EmbeddedIndents {
+    /// Gets the indent width the template was printed at in the previous print.
+    pub fn get_printed_width(&self, pos: BytePos) -> Option<u32> {
+        self.printed_widths.borrow().get(&pos).copied()
+    }
+
+    /// Stores the indent width used to format a template and gets the cell the printer should
+    /// record the printed indent width in.
+    pub fn add_used_width(&self, pos: BytePos, width: u32) -> PrintedIndentWidth {
+        let printed_width = Rc::new(Cell::new(None));
+        self.used_widths.borrow_mut().push((pos, width, printed_width.clone()));
+        printed_width
+    }
+
+    /// Gets if any templates were formatted.
+    pub fn has_templates(&self) -> bool {
+        !self.used_widths.borrow().is_empty()
+    }
+
+    /// Takes the recorded printed indent widths to use for the next parse. Returns true when any
+    /// of them differ from the indent widths that were used.
+    pub fn take_printed_widths(&self) -> bool {
+        let mut has_changed = false;
+        let mut printed_widths = self.printed_widths.borrow_mut();
+        for (pos, used_width, printed_width) in self.used_widths.borrow_mut().drain(..) {
+            if let Some(printed_width) = printed_width.get() {
+                has_changed = has_changed || printed_width != used_width;
+                printed_widths.insert(pos, printed_width);
+            }
+        }
+        has_changed
+    }
+}
+
 pub struct Context<'a> {
+    pub arena: &'a PrintItemsArena<'a>,
     pub config: &'a Configuration,
+    pub format_embedded_text: FormatEmbeddedText<'a>,
+    pub embedded_indents: &'a EmbeddedIndents,
     pub comments: CommentCollection<'a>,
     pub token_finder: TokenFinder<'a>,
     pub file_bytes: &'a [u8],
@@ -19,6 +80,7 @@ pub struct Context<'a> {
     pub info: &'a SourceFile,
     stored_infos: HashMap<(BytePos, BytePos), Info>,
     stored_info_ranges: HashMap<(BytePos, BytePos), (Info, Info)>,
+    alignment_groups: HashMap<(BytePos, BytePos), AlignmentGroup>,
     pub end_statement_or_member_infos: Stack<Info>,
     before_comments_start_info_stack: Stack<(SpanData, Info)>,
     if_stmt_last_brace_condition_ref: Option<ConditionReference>,
@@ -29,7 +91,10 @@ pub struct Context<'a> {
 
 impl<'a> Context<'a> {
     pub fn new(
+        arena: &'a PrintItemsArena<'a>,
         config: &'a Configuration,
+        format_embedded_text: FormatEmbeddedText<'a>,
+        embedded_indents: &'a EmbeddedIndents,
         leading_comments: &'a HashMap<BytePos, Vec<Comment>>,
         trailing_comments: &'a HashMap<BytePos, Vec<Comment>>,
         tokens: &'a Vec<TokenAndSpan>,
@@ -38,7 +103,10 @@ impl<'a> Context<'a> {
         info: &'a SourceFile
     ) -> Context<'a> {
         Context {
+            arena,
             config,
+            format_embedded_text,
+            embedded_indents,
             comments: CommentCollection::new(leading_comments, trailing_comments, tokens, file_bytes),
             token_finder: TokenFinder::new(tokens, file_bytes),
             file_bytes,
@@ -48,6 +116,7 @@ impl<'a> Context<'a> {
             info,
             stored_infos: HashMap::new(),
             stored_info_ranges: HashMap::new(),
+            alignment_groups: HashMap::new(),
             end_statement_or_member_infos: Stack::new(),
             before_comments_start_info_stack: Stack::new(),
             if_stmt_last_brace_condition_ref: None,
@@ -89,6 +158,14 @@ impl<'a> Context<'a> {
         self.stored_info_ranges.get(&(node.lo(), node.hi())).map(|x| x.to_owned())
     }
 
+    pub fn store_alignment_group_for_node(&mut self, node: &dyn Ranged, group: AlignmentGroup) {
+        self.alignment_groups.insert((node.lo(), node.hi()), group);
+    }
+
+    pub fn take_alignment_group_for_node(&mut self, node: &dyn Ranged) -> Option<AlignmentGroup> {
+        self.alignment_groups.remove(&(node.lo(), node.hi()))
+    }
+
     pub fn store_if_stmt_last_brace_condition_ref(&mut self, condition_reference: ConditionReference) {
         self.if_stmt_last_brace_condition_ref = Some(condition_reference);
     }
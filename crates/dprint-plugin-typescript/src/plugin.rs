@@ -1,31 +1,53 @@
 use std::collections::HashMap;
-use dprint_core::configuration::{ConfigurationDiagnostic, ResolveConfigurationResult, GlobalConfiguration};
+use dprint_core::configuration::{ConfigurationDiagnostic, GlobalConfiguration, ResolvedConfigOptions, resolve_config_directive};
 use std::path::PathBuf;
 use dprint_core::plugins::*;
-use super::configuration::{Configuration, resolve_config};
+use dprint_core::{FormatError, LongLine};
+use super::configuration::{Configuration, resolve_config, resolve_config_options, CONFIG_OPTIONS};
 use super::formatter::Formatter;
 
 /// TypeScript Dprint CLI Plugin.
 pub struct TypeScriptPlugin {
-    resolve_config_result: Option<ResolveConfigurationResult<Configuration>>,
+    plugin_config: HashMap<String, String>,
+    global_config: Option<GlobalConfiguration>,
+    config: Option<Configuration>,
+    resolved_options: Option<ResolvedConfigOptions>,
     formatter: Option<Formatter>,
 }
 
 impl TypeScriptPlugin {
     pub fn new() -> TypeScriptPlugin {
         TypeScriptPlugin {
-            resolve_config_result: None,
+            plugin_config: HashMap::new(),
+            global_config: None,
+            config: None,
+            resolved_options: None,
             formatter: None,
         }
     }
 
-    fn get_resolved_config_result(&self) -> &ResolveConfigurationResult<Configuration> {
-        self.resolve_config_result.as_ref().expect("Plugin must be initialized.")
+    fn get_resolved_options(&self) -> &ResolvedConfigOptions {
+        self.resolved_options.as_ref().expect("Plugin must be initialized.")
     }
 
     fn get_formatter(&self) -> &Formatter {
         self.formatter.as_ref().expect("Plugin must be initialized.")
     }
+
+    /// Gets a formatter with the file's configuration when it has a `dprint-config:` directive
+    /// or the host provides a line width.
+    fn get_file_formatter(&self, file_text: &str, line_width: Option<u32>) -> Result<Option<Formatter>, FormatError> {
+        let global_config = self.global_config.as_ref().expect("Plugin must be initialized.");
+        let file_config = resolve_config_directive(file_text, &self.plugin_config, CONFIG_OPTIONS, |config| resolve_config(config, global_config))?;
+        let file_config = match line_width {
+            Some(line_width) => Some(Configuration {
+                line_width,
+                ..file_config.unwrap_or_else(|| self.config.as_ref().expect("Plugin must be initialized.").clone())
+            }),
+            None => file_config,
+        };
+        Ok(file_config.map(Formatter::new))
+    }
 }
 
 impl Plugin for TypeScriptPlugin {
@@ -34,15 +56,18 @@ impl Plugin for TypeScriptPlugin {
     fn config_keys(&self) -> Vec<String> { vec![String::from("typescript"), String::from("javascript")] }
 
     fn initialize(&mut self, plugin_config: HashMap<String, String>, global_config: &GlobalConfiguration) {
-        let config_result = resolve_config(plugin_config, &global_config);
-        self.formatter = Some(Formatter::new(config_result.config.clone()));
-        self.resolve_config_result = Some(config_result);
+        let (config, resolved_options) = resolve_config_options(plugin_config.clone(), &global_config);
+        self.formatter = Some(Formatter::new(config.clone()));
+        self.config = Some(config);
+        self.resolved_options = Some(resolved_options);
+        self.plugin_config = plugin_config;
+        self.global_config = Some(global_config.clone());
     }
 
     fn should_format_file(&self, file_path: &PathBuf, _: &str) -> bool {
         if let Some(ext) = file_path.extension().and_then(|e| e.to_str()) {
             match String::from(ext).to_lowercase().as_ref() {
-                "js" | "jsx" | "ts" | "tsx" => true,
+                "js" | "jsx" | "ts" | "tsx" | "vue" | "svelte" => true,
                 _ => false,
             }
         } else {
@@ -51,15 +76,34 @@ impl Plugin for TypeScriptPlugin {
     }
 
     fn get_resolved_config(&self) -> String {
-        let config = &self.get_resolved_config_result().config;
-        serde_json::to_string_pretty(config).unwrap()
+        self.get_resolved_options().to_json_string()
     }
 
     fn get_configuration_diagnostics(&self) -> &Vec<ConfigurationDiagnostic> {
-        &self.get_resolved_config_result().diagnostics
+        &self.get_resolved_options().diagnostics
+    }
+
+    fn format_text(&self, file_path: &PathBuf, file_text: &str, host: &FormatHost) -> Result<String, FormatError> {
+        let file_formatter = self.get_file_formatter(file_text, host.line_width())?;
+        let formatter = file_formatter.as_ref().unwrap_or_else(|| self.get_formatter());
+        formatter.format_text_with_embedded(file_path, file_text, |extension, text, line_width| host.format_text(extension, text, "", Some(line_width)))
+    }
+
+    fn format_text_equals(&self, file_path: &PathBuf, file_text: &str, host: &FormatHost) -> Result<bool, FormatError> {
+        let file_formatter = self.get_file_formatter(file_text, host.line_width())?;
+        let formatter = file_formatter.as_ref().unwrap_or_else(|| self.get_formatter());
+        formatter.format_text_equals(file_path, file_text, |extension, text, line_width| host.format_text(extension, text, "", Some(line_width)))
+    }
+
+    fn get_print_ir(&self, file_path: &PathBuf, file_text: &str, host: &FormatHost) -> Result<String, FormatError> {
+        let file_formatter = self.get_file_formatter(file_text, host.line_width())?;
+        let formatter = file_formatter.as_ref().unwrap_or_else(|| self.get_formatter());
+        formatter.get_print_ir(file_path, file_text)
     }
 
-    fn format_text(&self, file_path: &PathBuf, file_text: &str) -> Result<String, String> {
-        self.get_formatter().format_text(file_path, file_text)
+    fn format_text_with_long_lines(&self, file_path: &PathBuf, file_text: &str, host: &FormatHost) -> Result<(String, Vec<LongLine>), FormatError> {
+        let file_formatter = self.get_file_formatter(file_text, host.line_width())?;
+        let formatter = file_formatter.as_ref().unwrap_or_else(|| self.get_formatter());
+        formatter.format_text_with_long_lines(file_path, file_text, |extension, text, line_width| host.format_text(extension, text, "", Some(line_width)))
     }
 }
@@ -0,0 +1,165 @@
+use dprint_core::FormatError;
+use serde_json::Value;
+use swc_ecma_ast::Module;
+
+/// Gets if the two modules have the same AST when ignoring spans, parentheses,
+/// and trivia (comments and whitespace are not part of the AST).
+pub fn is_ast_equivalent(original: &Module, formatted: &Module) -> Result<bool, FormatError> {
+    let original = to_value(original)?;
+    let formatted = to_value(formatted)?;
+    Ok(normalize(original) == normalize(formatted))
+}
+
+fn to_value(module: &Module) -> Result<Value, FormatError> {
+    serde_json::to_value(module).map_err(|err| FormatError::internal(format!("Error serializing the AST to verify semantic equivalence. {}", err)))
+}
+
+fn normalize(value: Value) -> Value {
+    match value {
+        Value::Object(map) => {
+            let node_type = map.get("type").and_then(|t| t.as_str()).map(String::from);
+            let mut map = map.into_iter()
+                .filter(|(key, value)| !is_ignored_key(key) && !is_span(value))
+                .map(|(key, value)| (key, normalize(value)))
+                .collect::<serde_json::Map<String, Value>>();
+
+            match node_type.as_ref().map(|t| t.as_str()) {
+                // parentheses may be added or removed by the formatter, so only compare the inner node
+                Some("ParenthesisExpression") | Some("TsParenthesizedType") => {
+                    map.remove("type");
+                    if map.len() == 1 {
+                        return map.into_iter().next().unwrap().1;
+                    }
+                },
+                // whitespace in jsx text is collapsed by the formatter
+                Some("JSXText") => {
+                    for key in &["value", "raw"] {
+                        if let Some(Value::String(text)) = map.get(*key) {
+                            let collapsed = collapse_jsx_text_whitespace(text);
+                            map.insert(String::from(*key), Value::String(collapsed));
+                        }
+                    }
+                },
+                _ => {},
+            }
+
+            Value::Object(map)
+        },
+        Value::Array(elements) => {
+            Value::Array(elements.into_iter().map(normalize).filter(|e| !is_empty_jsx_text(e)).collect())
+        },
+        _ => value,
+    }
+}
+
+fn is_ignored_key(key: &str) -> bool {
+    match key {
+        "span" | "hasEscape" => true,
+        _ => false,
+    }
+}
+
+/// Gets if the value is a span stored under a key other than `span` (ex. the `...` of a rest element).
+fn is_span(value: &Value) -> bool {
+    if let Value::Object(map) = value {
+        map.len() == 3 && map.contains_key("start") && map.contains_key("end") && map.contains_key("ctxt")
+    } else {
+        false
+    }
+}
+
+fn is_empty_jsx_text(value: &Value) -> bool {
+    if let Value::Object(map) = value {
+        if map.get("type").and_then(|t| t.as_str()) == Some("JSXText") {
+            return map.get("value").and_then(|v| v.as_str()).map(|v| v.is_empty()).unwrap_or(false);
+        }
+    }
+    false
+}
+
+/// Collapses the whitespace of JSX text that the formatter may change without changing how the text renders.
+///
+/// Whitespace with a line break is removed at the start and end of the text and is
+/// otherwise a single space. Whitespace on the same line is significant, so it's kept
+/// as a single space even at the start and end of the text.
+fn collapse_jsx_text_whitespace(text: &str) -> String {
+    let mut result = String::new();
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if !c.is_whitespace() {
+            result.push(c);
+            continue;
+        }
+
+        let mut has_line_break = is_line_break(c);
+        while let Some(next_char) = chars.peek() {
+            if !next_char.is_whitespace() {
+                break;
+            }
+            has_line_break = has_line_break || is_line_break(*next_char);
+            chars.next();
+        }
+
+        let is_at_start_or_end = result.is_empty() || chars.peek().is_none();
+        if !has_line_break || !is_at_start_or_end {
+            result.push(' ');
+        }
+    }
+
+    return result;
+
+    fn is_line_break(c: char) -> bool {
+        c == '\n' || c == '\r'
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use super::super::parse_swc_ast;
+    use super::*;
+
+    #[test]
+    fn should_be_equivalent_when_only_trivia_and_parens_differ() {
+        assert_equivalent("const  t = (5) ; // test", "const t = 5;\n", true);
+    }
+
+    #[test]
+    fn should_not_be_equivalent_when_grouping_changes() {
+        assert_equivalent("const t = (a + b) * c;", "const t = a + b * c;", false);
+    }
+
+    #[test]
+    fn should_be_equivalent_when_quotes_change() {
+        assert_equivalent("const t = 'test\"';", "const t = \"test\\\"\";", true);
+    }
+
+    #[test]
+    fn should_be_equivalent_when_rest_element_spacing_changes() {
+        assert_equivalent("function test(  ...p : string[]) {}", "function test(...p: string[]) {}", true);
+    }
+
+    #[test]
+    fn should_be_equivalent_when_jsx_text_line_breaks_change() {
+        assert_equivalent("const t = <div>\n    testing   this\n    out\n</div>;", "const t = <div>testing this out</div>;", true);
+        assert_equivalent("const t = <div>a <b /></div>;", "const t = <div>\n    a <b />\n</div>;", true);
+        assert_equivalent("const t = <div>\n    <b />\n</div>;", "const t = <div><b /></div>;", true);
+    }
+
+    #[test]
+    fn should_not_be_equivalent_when_jsx_text_spaces_change() {
+        assert_equivalent("const t = <div> a <b /></div>;", "const t = <div>a<b /></div>;", false);
+        assert_equivalent("const t = <div><b /> a</div>;", "const t = <div><b />a</div>;", false);
+        assert_equivalent("const t = <div><a /> <b /></div>;", "const t = <div><a /><b /></div>;", false);
+        assert_equivalent("const t = <div>a <b /></div>;", "const t = <div>a<b /></div>;", false);
+        assert_equivalent("const t = <div>a <b /></div>;", "const t = <div>\n    a\n    <b />\n</div>;", false);
+    }
+
+    fn assert_equivalent(original: &str, formatted: &str, expected: bool) {
+        let file_path = PathBuf::from("./test.tsx");
+        let original = parse_swc_ast(&file_path, original).unwrap();
+        let formatted = parse_swc_ast(&file_path, formatted).unwrap();
+        assert_eq!(is_ast_equivalent(&original.module, &formatted.module).unwrap(), expected);
+    }
+}
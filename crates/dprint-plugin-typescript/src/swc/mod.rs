@@ -1,3 +1,5 @@
+mod ast_equivalence;
 mod parse_swc_ast;
 
+pub use ast_equivalence::*;
 pub use parse_swc_ast::*;
@@ -1,8 +1,10 @@
 use std::collections::{HashMap};
 use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use dprint_core::{FormatError, FormatErrorKind};
 use swc_common::{
     errors::{Handler, Emitter, DiagnosticBuilder},
-    FileName, comments::{Comment, Comments, CommentMap}, SourceFile, BytePos
+    FileName, comments::{Comment, Comments, CommentMap}, SourceFile, BytePos, Span
 };
 use swc_ecma_ast::{Module};
 use swc_ecma_parser::{Parser, Session, SourceFileInput, Syntax, lexer::Lexer, Capturing, JscTarget, token::{TokenAndSpan}};
@@ -16,7 +18,7 @@ pub struct ParsedSourceFile<'a> {
     pub trailing_comments: HashMap<BytePos, Vec<Comment>>,
 }
 
-pub fn parse_swc_ast<'a>(file_path: &PathBuf, file_text: &'a str) -> Result<ParsedSourceFile<'a>, String> {
+pub fn parse_swc_ast<'a>(file_path: &PathBuf, file_text: &'a str) -> Result<ParsedSourceFile<'a>, FormatError> {
     match parse_inner(file_path, file_text) {
         Ok(result) => Ok(result),
         Err(err) => {
@@ -34,8 +36,9 @@ pub fn parse_swc_ast<'a>(file_path: &PathBuf, file_text: &'a str) -> Result<Pars
     }
 }
 
-fn parse_inner<'a>(file_path: &PathBuf, file_text: &'a str) -> Result<ParsedSourceFile<'a>, String> {
-    let handler = Handler::with_emitter(false, false, Box::new(EmptyEmitter {}));
+fn parse_inner<'a>(file_path: &PathBuf, file_text: &'a str) -> Result<ParsedSourceFile<'a>, FormatError> {
+    let emitted_diagnostics = Arc::new(Mutex::new(Vec::new()));
+    let handler = Handler::with_emitter(false, false, Box::new(CollectingEmitter { diagnostics: emitted_diagnostics.clone() }));
     let session = Session { handler: &handler };
 
     let file_bytes = file_text.as_bytes();
@@ -69,8 +72,8 @@ fn parse_inner<'a>(file_path: &PathBuf, file_text: &'a str) -> Result<ParsedSour
             Err(mut error) => {
                 // mark the diagnostic as being handled (otherwise it will panic in its drop)
                 error.cancel();
-                // return the formatted diagnostic string
-                Err(format_diagnostic(&error, file_text))
+                let emitted_diagnostics = emitted_diagnostics.lock().unwrap();
+                Err(get_format_error(&error, &emitted_diagnostics, file_text))
             },
             Ok(module) => Ok((module, tokens))
         }
@@ -109,12 +112,23 @@ fn get_lowercase_extension(file_path: &PathBuf) -> Option<String> {
     file_path.extension().and_then(|e| e.to_str()).map(|f| f.to_lowercase())
 }
 
-pub struct EmptyEmitter {
+/// A diagnostic the parser reported before it failed (ex. an error it recovered from).
+struct EmittedDiagnostic {
+    message: String,
+    range: Option<(usize, usize)>,
 }
 
-impl Emitter for EmptyEmitter {
-    fn emit(&mut self, _: &DiagnosticBuilder<'_>) {
-        // for now, we don't care about diagnostics so do nothing
+/// Collects the diagnostics emitted while parsing so they can be included in the error.
+struct CollectingEmitter {
+    diagnostics: Arc<Mutex<Vec<EmittedDiagnostic>>>,
+}
+
+impl Emitter for CollectingEmitter {
+    fn emit(&mut self, diagnostic: &DiagnosticBuilder<'_>) {
+        self.diagnostics.lock().unwrap().push(EmittedDiagnostic {
+            message: diagnostic.message(),
+            range: diagnostic.span.primary_span().map(get_span_range),
+        });
     }
 
     fn should_show_explain(&self) -> bool {
@@ -122,13 +136,34 @@ impl Emitter for EmptyEmitter {
     }
 }
 
-fn format_diagnostic(error: &DiagnosticBuilder, file_text: &str) -> String {
-    // todo: handling sub diagnostics?
-    dprint_core::utils::string_utils::format_diagnostic(
-        error.span.primary_span().map(|span| (span.lo().0 as usize, span.hi().0 as usize)),
-        &error.message(),
-        file_text
-    )
+fn get_format_error(error: &DiagnosticBuilder, emitted_diagnostics: &[EmittedDiagnostic], file_text: &str) -> FormatError {
+    let mut format_error = match error.span.primary_span() {
+        Some(span) => FormatError::syntax(error.message(), get_span_range(span), file_text),
+        None => FormatError::new(FormatErrorKind::Syntax, error.message()),
+    };
+
+    // show the other labelled spans, sub diagnostics, and previously emitted diagnostics along with the error
+    for span_label in error.span.span_labels() {
+        if let (false, Some(label)) = (span_label.is_primary, span_label.label) {
+            format_error = format_error.with_label(get_span_range(span_label.span), label);
+        }
+    }
+    for child in error.children.iter() {
+        if let Some(span) = child.span.primary_span() {
+            format_error = format_error.with_label(get_span_range(span), child.message());
+        }
+    }
+    for diagnostic in emitted_diagnostics.iter() {
+        if let Some(range) = diagnostic.range {
+            format_error = format_error.with_label(range, diagnostic.message.clone());
+        }
+    }
+
+    format_error
+}
+
+fn get_span_range(span: Span) -> (usize, usize) {
+    (span.lo().0 as usize, span.hi().0 as usize)
 }
 
 #[cfg(test)]
@@ -137,14 +172,42 @@ mod tests {
 
     #[test]
     fn should_error_on_syntax_diagnostic() {
-        let message = parse_swc_ast(&PathBuf::from("./test.ts"), "test;\nas#;").err().unwrap();
+        let file_text = "test;\nas#;";
+        let err = parse_swc_ast(&PathBuf::from("./test.ts"), file_text).err().unwrap();
+        assert_eq!(err.kind, FormatErrorKind::Syntax);
         assert_eq!(
-            message,
+            err.get_diagnostic_text(file_text),
             concat!(
                 "Line 2, column 3: Expected ';', '}' or <eof>\n",
                 "\n",
-                "  as#;\n",
-                "    ~"
+                "  1 | test;\n",
+                "  2 | as#;\n",
+                "    | -- This is the expression part of an expression statement\n",
+                "    |   ~"
+            )
+        );
+    }
+
+    #[test]
+    fn should_include_emitted_diagnostics_as_labels() {
+        let emitted_diagnostics = vec![EmittedDiagnostic {
+            message: String::from("Unexpected token."),
+            range: Some((0, 1)),
+        }];
+        let handler = Handler::with_emitter(false, false, Box::new(CollectingEmitter { diagnostics: Default::default() }));
+        let mut error = handler.struct_span_err(Span::new(BytePos(7), BytePos(8), Default::default()), "Expected ';'.");
+        let file_text = "@;\ntest a;";
+        let err = get_format_error(&error, &emitted_diagnostics, file_text);
+        error.cancel();
+        assert_eq!(
+            err.get_diagnostic_text(file_text),
+            concat!(
+                "Line 2, column 5: Expected ';'.\n",
+                "\n",
+                "  1 | @;\n",
+                "    | - Unexpected token.\n",
+                "  2 | test a;\n",
+                "    |     ~"
             )
         );
     }
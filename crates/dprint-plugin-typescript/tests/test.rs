@@ -1,5 +1,6 @@
 extern crate dprint_plugin_typescript;
 extern crate dprint_development;
+extern crate dprint_plugin_css;
 
 //#[macro_use] extern crate debug_here;
 
@@ -45,6 +46,7 @@ fn test_performance() {
 fn test_specs() {
     //debug_here!();
     let global_config = resolve_global_config(HashMap::new()).config;
+    let css_config = dprint_plugin_css::configuration::resolve_config(HashMap::new(), &global_config).config;
 
     run_specs(
         &PathBuf::from("./tests/specs"),
@@ -54,8 +56,123 @@ fn test_specs() {
             let config_result = resolve_config(spec_config.clone(), &global_config);
             ensure_no_diagnostics(&config_result.diagnostics);
 
+            // the global indentation configuration is shared with the css plugin
+            let css_config = dprint_plugin_css::configuration::Configuration {
+                use_tabs: config_result.config.use_tabs,
+                indent_width: config_result.config.indent_width,
+                ..css_config.clone()
+            };
             let formatter = Formatter::new(config_result.config);
-            formatter.format_text(&file_name, &file_text)
+            formatter.format_text_with_embedded(&file_name, &file_text, |extension, text, line_width| {
+                match extension {
+                    "css" => {
+                        let css_config = dprint_plugin_css::configuration::Configuration { line_width, ..css_config.clone() };
+                        dprint_plugin_css::format_text(&PathBuf::from("embedded.css"), text, &css_config).map(Some)
+                    },
+                    _ => Ok(None),
+                }
+            })
         }
     )
 }
+
+#[test]
+fn test_format_text_with_mapping() {
+    let formatter = Formatter::new(ConfigurationBuilder::new().build());
+    let file_text = "const  t  =  {  a :   5 } ;";
+    let (result, position_map) = formatter.format_text_with_mapping(&PathBuf::from("file.ts"), file_text).unwrap();
+    assert_eq!(result, "const t = { a: 5 };\n");
+    assert_eq!(position_map.map_position(file_text.find("t ").unwrap()), result.find("t ").unwrap());
+    assert_eq!(position_map.map_position(file_text.find("a").unwrap()), result.find("a").unwrap());
+    assert_eq!(position_map.map_position(file_text.find("5").unwrap()), result.find("5").unwrap());
+    assert_eq!(position_map.map_position(file_text.find("}").unwrap()), result.find("}").unwrap());
+}
+
+#[test]
+fn test_embedded_text_keeps_original_when_expressions_change() {
+    let formatter = Formatter::new(ConfigurationBuilder::new().build());
+    let file_text = "const t = css`a:${b};c:${d}`;\n";
+    let expected = "const t = css `a:${b};c:${d}`;\n";
+    // an expression is dropped
+    let result = formatter.format_text_with_embedded(&PathBuf::from("file.ts"), file_text, |_, text, _| {
+        Ok(Some(text.replace("dprint_placeholder_1_", "")))
+    }).unwrap();
+    assert_eq!(result, expected);
+    // an expression is duplicated
+    let result = formatter.format_text_with_embedded(&PathBuf::from("file.ts"), file_text, |_, text, _| {
+        Ok(Some(format!("{}{}", text, text)))
+    }).unwrap();
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_embedded_text_graphql_and_html_tags() {
+    let formatter = Formatter::new(ConfigurationBuilder::new().tagged_template_graphql_tags("gql,myGql").build());
+    let file_text = "const a = gql`query{a}`;\nconst b = myGql`query{b}`;\nconst c = html`<div></div>`;\nconst d = graphql`query{d}`;\n";
+    let extensions = std::cell::RefCell::new(Vec::new());
+    let result = formatter.format_text_with_embedded(&PathBuf::from("file.ts"), file_text, |extension, text, _| {
+        extensions.borrow_mut().push(String::from(extension));
+        Ok(match extension {
+            "graphql" => Some(text.replace("{", " { ").replace("}", " }")),
+            "html" => Some(text.replace("></", "> </")),
+            _ => None,
+        })
+    }).unwrap();
+    assert_eq!(result, "const a = gql `query { a }`;\nconst b = myGql `query { b }`;\nconst c = html `<div> </div>`;\nconst d = graphql `query{d}`;\n");
+    assert_eq!(extensions.into_inner(), vec!["graphql", "graphql", "html"]);
+}
+
+#[test]
+fn test_format_text_with_long_lines_in_component_file() {
+    let formatter = Formatter::new(ConfigurationBuilder::new().line_width(40).build());
+    let string_text = format!("\"{}\"", "a".repeat(40));
+    let file_text = format!("<template>\n  <div />\n</template>\n<script>\nconst  t  =  {};\n</script>\n", string_text);
+    let (result, long_lines) = formatter.format_text_with_long_lines(&PathBuf::from("file.vue"), &file_text, |_, _, _| Ok(None)).unwrap();
+    assert_eq!(result, format!("<template>\n  <div />\n</template>\n<script>\nconst t =\n    {};\n</script>\n", string_text));
+    assert_eq!(long_lines.len(), 1);
+    assert_eq!(long_lines[0].line_number, 6);
+    assert_eq!(long_lines[0].column_number, 5);
+    assert_eq!(long_lines[0].width, 47);
+    assert_eq!(long_lines[0].text, Some(string_text.clone()));
+    assert_eq!(long_lines[0].source_position, Some(file_text.find(&string_text).unwrap()));
+}
+
+#[test]
+fn test_embedded_text_line_width_is_width_after_indentation() {
+    let formatter = Formatter::new(ConfigurationBuilder::new().line_width(80).build());
+    let line_widths = std::cell::RefCell::new(Vec::new());
+    let file_text = "const a = css`color:red`;\nfunction test() {\n    return css`color:red`;\n}\n";
+    formatter.format_text_with_embedded(&PathBuf::from("file.ts"), file_text, |_, _, line_width| {
+        line_widths.borrow_mut().push(line_width);
+        Ok(None)
+    }).unwrap();
+    assert_eq!(line_widths.into_inner(), vec![76, 72]);
+}
+
+#[test]
+fn test_get_print_ir_of_component_file() {
+    let formatter = Formatter::new(ConfigurationBuilder::new().build());
+    let file_text = "<template>\n  <div />\n</template>\n<script>\nconst a = 1;\n</script>\n<script context=\"module\">\nconst b = 2;\n</script>\n";
+    let print_ir = formatter.get_print_ir(&PathBuf::from("file.svelte"), file_text).unwrap();
+    let first_block_index = print_ir.find("<script> block on line 4:\n").unwrap();
+    let second_block_index = print_ir.find("<script> block on line 7:\n").unwrap();
+    assert_eq!(first_block_index, 0);
+    assert!(print_ir[..second_block_index].contains("`a`"));
+    assert!(print_ir[second_block_index..].contains("`b`"));
+}
+
+#[test]
+fn test_format_text_equals() {
+    let formatter = Formatter::new(ConfigurationBuilder::new().build());
+    let format_text_equals = |file_name: &str, file_text: &str| formatter.format_text_equals(&PathBuf::from(file_name), file_text, |_, _, _| Ok(None)).unwrap();
+    assert!(format_text_equals("file.ts", "const t = { a: 5 };\n"));
+    assert!(!format_text_equals("file.ts", "const t = { a: 5 };"));
+    assert!(!format_text_equals("file.ts", "const  t  =  5;\n"));
+    assert!(format_text_equals("file.ts", "// dprint-ignore-file\nconst  t  =  5;"));
+    assert!(format_text_equals("file.vue", "<script>\nconst t = 5;\n</script>\n"));
+    assert!(!format_text_equals("file.vue", "<script>\nconst  t  =  5;\n</script>\n"));
+
+    let formatter = Formatter::new(ConfigurationBuilder::new().verify_semantic_equivalence(true).build());
+    assert!(formatter.format_text_equals(&PathBuf::from("file.ts"), "const t = 5;\n", |_, _, _| Ok(None)).unwrap());
+    assert!(!formatter.format_text_equals(&PathBuf::from("file.ts"), "const  t = 5;\n", |_, _, _| Ok(None)).unwrap());
+}
@@ -0,0 +1,7 @@
+mod resolve_config;
+mod types;
+mod options;
+
+pub use resolve_config::*;
+pub use types::*;
+pub use options::CONFIG_OPTIONS;
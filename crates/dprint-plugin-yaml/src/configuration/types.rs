@@ -0,0 +1,33 @@
+use serde::{Serialize, Deserialize};
+use dprint_core::configuration::*;
+use dprint_core::generate_str_to_from;
+
+/// How to decide to use single or double quotes for quoted scalars.
+#[derive(Clone, PartialEq, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum QuoteStyle {
+    /// Prefer using double quotes except when the text would need to be escaped.
+    PreferDouble,
+    /// Prefer using single quotes except when the text would need to be escaped.
+    PreferSingle,
+}
+
+generate_str_to_from![
+    QuoteStyle,
+    [PreferDouble, "preferDouble"],
+    [PreferSingle, "preferSingle"]
+];
+
+/// The resolved configuration. Indentation always uses spaces because tabs
+/// are not allowed for indentation in YAML.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Configuration {
+    pub line_width: u32,
+    pub indent_width: u8,
+    pub new_line_kind: NewLineKind,
+    pub unicode_width: bool,
+    pub max_condition_evaluations: u32,
+    pub max_visits_per_node: u32,
+    pub quote_style: QuoteStyle,
+}
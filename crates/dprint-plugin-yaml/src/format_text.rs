@@ -0,0 +1,81 @@
+use dprint_core::{print, print_equals, print_with_long_lines, FormatError, LongLine, PrintBudget, PrintItemsArena, PrintOptions};
+use dprint_core::configuration::resolve_new_line_kind;
+use super::configuration::Configuration;
+use super::parser::parse_items;
+use super::yaml_parser::{parse_text, Stream};
+
+/// Formats the YAML text.
+pub fn format_text(text: &str, config: &Configuration) -> Result<String, FormatError> {
+    let stream = parse_stream(text)?;
+    let arena = PrintItemsArena::new();
+    Ok(print(parse_items(&arena, &stream, config), get_print_options(text, config))?)
+}
+
+/// Formats the YAML text also getting the lines that are still wider than the line width.
+pub fn format_text_with_long_lines(text: &str, config: &Configuration) -> Result<(String, Vec<LongLine>), FormatError> {
+    let stream = parse_stream(text)?;
+    let arena = PrintItemsArena::new();
+    Ok(print_with_long_lines(parse_items(&arena, &stream, config), get_print_options(text, config))?)
+}
+
+/// Gets if formatting the YAML text would leave it unchanged without creating the formatted text.
+pub fn format_text_equals(text: &str, config: &Configuration) -> Result<bool, FormatError> {
+    let stream = parse_stream(text)?;
+    let arena = PrintItemsArena::new();
+    Ok(print_equals(parse_items(&arena, &stream, config), get_print_options(text, config), text)?)
+}
+
+/// Gets the print items of the text as an indented tree for debugging.
+pub fn get_print_ir(text: &str, config: &Configuration) -> Result<String, FormatError> {
+    let stream = parse_stream(text)?;
+    let arena = PrintItemsArena::new();
+    Ok(parse_items(&arena, &stream, config).get_as_text())
+}
+
+fn get_print_options(text: &str, config: &Configuration) -> PrintOptions {
+    PrintOptions {
+        indent_width: config.indent_width,
+        max_width: config.line_width,
+        use_tabs: false,
+        new_line_text: resolve_new_line_kind(text, config.new_line_kind),
+        unicode_width: config.unicode_width,
+        budget: PrintBudget {
+            max_condition_evaluations: config.max_condition_evaluations,
+            max_visits_per_node: config.max_visits_per_node,
+        },
+    }
+}
+
+fn parse_stream(text: &str) -> Result<Stream, FormatError> {
+    match parse_text(text) {
+        Ok(stream) => Ok(stream),
+        Err(err) => Err(FormatError::syntax(err.message, err.range, text)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dprint_core::configuration::*;
+    use std::collections::HashMap;
+    use dprint_core::FormatErrorKind;
+    use super::super::configuration::resolve_config;
+    use super::*;
+
+    #[test]
+    fn should_error_on_syntax_diagnostic() {
+        let global_config = resolve_global_config(HashMap::new()).config;
+        let config = resolve_config(HashMap::new(), &global_config).config;
+        let file_text = "a: b: c";
+        let err = format_text(file_text, &config).err().unwrap();
+        assert_eq!(err.kind, FormatErrorKind::Syntax);
+        assert_eq!(
+            err.get_diagnostic_text(file_text),
+            concat!(
+                "Line 1, column 4: Mappings are not allowed on the same line as a mapping key.\n",
+                "\n",
+                "  1 | a: b: c\n",
+                "    |    ~"
+            )
+        );
+    }
+}
@@ -0,0 +1,399 @@
+use dprint_core::*;
+use dprint_core::parser_helpers::*;
+use super::super::configuration::{Configuration, QuoteStyle};
+use super::super::yaml_parser::*;
+
+struct Context<'a> {
+    arena: &'a PrintItemsArena<'a>,
+    config: &'a Configuration,
+}
+
+pub fn parse_items<'a>(arena: &'a PrintItemsArena<'a>, stream: &Stream, config: &'a Configuration) -> PrintItems<'a> {
+    let context = Context { arena, config };
+    let mut items = PrintItems::new(arena);
+    let mut last_had_end_marker = true;
+
+    for (i, document) in stream.documents.iter().enumerate() {
+        if i > 0 {
+            items.push_signal(Signal::NewLine);
+        }
+        items.extend(parse_document(document, !last_had_end_marker, &context));
+        last_had_end_marker = document.has_end_marker;
+    }
+
+    items.push_condition(conditions::if_true(
+        "endOfFileNewLine",
+        |context| Some(context.writer_info.column_number > 0 || context.writer_info.line_number > 0),
+        arena.items(Signal::NewLine)
+    ));
+
+    items
+}
+
+/// Lines of print items where the blank lines between them are maintained.
+struct Lines<'a> {
+    items: PrintItems<'a>,
+    is_empty: bool,
+}
+
+impl<'a> Lines<'a> {
+    fn new(arena: &'a PrintItemsArena<'a>) -> Lines<'a> {
+        Lines {
+            items: PrintItems::new(arena),
+            is_empty: true,
+        }
+    }
+
+    fn push(&mut self, line: PrintItems<'a>, has_blank_line_before: bool) {
+        if !self.is_empty {
+            self.items.push_signal(Signal::NewLine);
+            if has_blank_line_before {
+                self.items.push_signal(Signal::NewLine);
+            }
+        }
+        self.items.extend(line);
+        self.is_empty = false;
+    }
+
+    fn push_comments(&mut self, comments: &[Comment]) {
+        let arena = self.items.arena();
+        for comment in comments.iter() {
+            self.push(parse_comment(arena, comment), comment.has_blank_line_before);
+        }
+    }
+
+    fn into_items(self) -> PrintItems<'a> {
+        self.items
+    }
+}
+
+fn parse_document<'a>(document: &Document, force_start_marker: bool, context: &Context<'a>) -> PrintItems<'a> {
+    let mut lines = Lines::new(context.arena);
+
+    lines.push_comments(&document.leading_comments);
+    for directive in document.directives.iter() {
+        lines.push(parse_string(context.arena, directive), false);
+    }
+
+    if document.has_start_marker || force_start_marker {
+        let mut items = PrintItems::new(context.arena);
+        items.push_str("---");
+        items.extend(parse_value_after_indicator(&document.value, true, context));
+        lines.push(items, false);
+    } else {
+        let value = &document.value;
+        lines.push_comments(&value.leading_comments);
+        match &value.node {
+            Some(node @ Node::Mapping(_)) | Some(node @ Node::Sequence(_)) => {
+                if value.props.is_some() || value.trailing_comment.is_some() {
+                    lines.push(parse_props_and_trailing_comment(value, context), false);
+                }
+                lines.push(parse_node(node, context), false);
+            },
+            Some(node) => {
+                let mut items = PrintItems::new(context.arena);
+                if let Some(props) = &value.props {
+                    items.extend(parse_string(context.arena, props));
+                    items.push_str(" ");
+                }
+                items.extend(parse_inline_node(node, &value.trailing_comment, true, false, context));
+                lines.push(items, false);
+            },
+            None => {
+                if value.props.is_some() || value.trailing_comment.is_some() {
+                    lines.push(parse_props_and_trailing_comment(value, context), false);
+                }
+            },
+        }
+    }
+
+    lines.push_comments(&document.trailing_comments);
+    if document.has_end_marker {
+        lines.push(context.arena.items("..."), false);
+    }
+
+    lines.into_items()
+}
+
+fn parse_props_and_trailing_comment<'a>(value: &Value, context: &Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
+    if let Some(props) = &value.props {
+        items.extend(parse_string(context.arena, props));
+        items.extend(parse_trailing_comment(&value.trailing_comment, context));
+    } else if let Some(comment) = &value.trailing_comment {
+        items.extend(parse_comment(context.arena, comment));
+    }
+    items
+}
+
+/// Parses the value that goes after an indicator (ex. after `key:`, `-`, or `---`).
+fn parse_value_after_indicator<'a>(value: &Value, is_root: bool, context: &Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
+
+    if let Some(props) = &value.props {
+        items.push_str(" ");
+        items.extend(parse_string(context.arena, props));
+    }
+
+    match &value.node {
+        None => {
+            items.extend(parse_trailing_comment(&value.trailing_comment, context));
+        },
+        Some(node @ Node::Mapping(_)) | Some(node @ Node::Sequence(_)) => {
+            items.extend(parse_trailing_comment(&value.trailing_comment, context));
+            let mut node_items = PrintItems::new(context.arena);
+            node_items.push_signal(Signal::NewLine);
+            node_items.extend(parse_node(node, context));
+            items.extend(if is_root { node_items } else { with_indent(node_items) });
+        },
+        Some(node) => {
+            if value.leading_comments.is_empty() {
+                items.push_str(" ");
+                items.extend(parse_inline_node(node, &value.trailing_comment, is_root, false, context));
+            } else {
+                let mut lines = Lines::new(context.arena);
+                lines.push_comments(&value.leading_comments);
+                lines.push(parse_inline_node(node, &value.trailing_comment, is_root, true, context), false);
+                let mut node_items = PrintItems::new(context.arena);
+                node_items.push_signal(Signal::NewLine);
+                node_items.extend(lines.into_items());
+                items.extend(if is_root { node_items } else { with_indent(node_items) });
+            }
+        },
+    }
+
+    items
+}
+
+/// Parses a node that starts on the current line along with its trailing comment.
+fn parse_inline_node<'a>(node: &Node, trailing_comment: &Option<Comment>, is_root: bool, is_on_own_line: bool, context: &Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
+
+    if let Node::BlockScalar(block_scalar) = node {
+        // the content is indented once from the header's line
+        let parent_indent_levels = if is_on_own_line && !is_root { 2 } else { 1 };
+        let content_indent_width = parent_indent_levels * context.config.indent_width as u32 + if is_root { 1 } else { 0 };
+        items.extend(parse_block_scalar_header(block_scalar, content_indent_width, context));
+        items.extend(parse_trailing_comment(trailing_comment, context));
+        items.extend(parse_block_scalar_content(block_scalar, context));
+    } else {
+        items.extend(parse_node(node, context));
+        items.extend(parse_trailing_comment(trailing_comment, context));
+    }
+
+    items
+}
+
+fn parse_node<'a>(node: &Node, context: &Context<'a>) -> PrintItems<'a> {
+    match node {
+        Node::Mapping(node) => parse_mapping(node, context),
+        Node::Sequence(node) => parse_sequence(node, context),
+        Node::Scalar(node) => parse_scalar(node, context),
+        Node::BlockScalar(node) => {
+            let mut items = parse_block_scalar_header(node, context.config.indent_width as u32, context);
+            items.extend(parse_block_scalar_content(node, context));
+            items
+        },
+        Node::FlowSequence(node) => parse_flow_sequence(node, context),
+        Node::FlowMapping(node) => parse_flow_mapping(node, context),
+    }
+}
+
+fn parse_mapping<'a>(mapping: &Mapping, context: &Context<'a>) -> PrintItems<'a> {
+    let mut lines = Lines::new(context.arena);
+
+    for entry in mapping.entries.iter() {
+        lines.push_comments(&entry.leading_comments);
+
+        let mut items = PrintItems::new(context.arena);
+        items.extend(parse_string(context.arena, &get_quoted_text(&entry.key, context.config.quote_style)));
+        items.push_str(":");
+        items.extend(parse_value_after_indicator(&entry.value, false, context));
+        lines.push(items, entry.has_blank_line_before);
+    }
+
+    lines.push_comments(&mapping.trailing_comments);
+    lines.into_items()
+}
+
+fn parse_sequence<'a>(sequence: &Sequence, context: &Context<'a>) -> PrintItems<'a> {
+    let mut lines = Lines::new(context.arena);
+
+    for item in sequence.items.iter() {
+        lines.push_comments(&item.leading_comments);
+
+        let mut items = PrintItems::new(context.arena);
+        items.push_str("-");
+        items.extend(parse_sequence_item_value(&item.value, context));
+        lines.push(items, item.has_blank_line_before);
+    }
+
+    lines.push_comments(&sequence.trailing_comments);
+    lines.into_items()
+}
+
+fn parse_sequence_item_value<'a>(value: &Value, context: &Context<'a>) -> PrintItems<'a> {
+    let is_collection = matches!(value.node, Some(Node::Mapping(_)) | Some(Node::Sequence(_)));
+    let can_be_compact = is_collection && value.props.is_none() && value.trailing_comment.is_none() && context.config.indent_width >= 2;
+
+    if can_be_compact {
+        // start the collection on the same line as the hyphen (ex. `- key: value`)
+        let mut items = PrintItems::new(context.arena);
+        items.push_str(&" ".repeat(context.config.indent_width as usize - 1));
+        items.extend(with_indent(parse_node(value.node.as_ref().unwrap(), context)));
+        items
+    } else {
+        parse_value_after_indicator(value, false, context)
+    }
+}
+
+fn parse_scalar<'a>(scalar: &Scalar, context: &Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
+
+    if scalar.lines.len() == 1 {
+        items.extend(parse_string(context.arena, &get_quoted_text(&scalar.lines[0], context.config.quote_style)));
+    } else {
+        items.extend(parse_string(context.arena, &scalar.lines[0]));
+        items.extend(with_indent(parse_continuation_lines(&scalar.lines[1..], context)));
+    }
+
+    items
+}
+
+fn parse_block_scalar_header<'a>(block_scalar: &BlockScalar, content_indent_width: u32, context: &Context<'a>) -> PrintItems<'a> {
+    let mut header = block_scalar.indicator.to_string();
+    // the indentation indicator is relative to the parent so it needs to be updated for the new indentation
+    if block_scalar.indentation_indicator.is_some() {
+        header.push_str(&content_indent_width.to_string());
+    }
+    if let Some(chomping_indicator) = block_scalar.chomping_indicator {
+        header.push(chomping_indicator);
+    }
+    context.arena.items(header)
+}
+
+fn parse_block_scalar_content<'a>(block_scalar: &BlockScalar, context: &Context<'a>) -> PrintItems<'a> {
+    with_indent(parse_continuation_lines(&block_scalar.lines, context))
+}
+
+/// Parses lines that each go on a new line. Empty lines are kept empty.
+fn parse_continuation_lines<'a>(lines: &[String], context: &Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
+    for line in lines.iter() {
+        items.push_signal(Signal::NewLine);
+        if !line.is_empty() {
+            items.extend(parse_string(context.arena, line));
+        }
+    }
+    items
+}
+
+fn parse_flow_node<'a>(node: &FlowNode, context: &Context<'a>) -> PrintItems<'a> {
+    match node {
+        FlowNode::Scalar(text) => parse_string(context.arena, &get_quoted_text(text, context.config.quote_style)),
+        FlowNode::Sequence(node) => parse_flow_sequence(node, context),
+        FlowNode::Mapping(node) => parse_flow_mapping(node, context),
+        FlowNode::Pair(node) => {
+            let mut items = PrintItems::new(context.arena);
+            items.extend(parse_flow_node(&node.key, context));
+            items.push_str(":");
+            if let Some(value) = &node.value {
+                items.push_str(" ");
+                items.extend(parse_flow_node(value, context));
+            }
+            items
+        },
+    }
+}
+
+fn parse_flow_sequence<'a>(node: &FlowSequence, context: &Context<'a>) -> PrintItems<'a> {
+    parse_flow_collection(&node.props, &node.items, ("[", "]"), false, context)
+}
+
+fn parse_flow_mapping<'a>(node: &FlowMapping, context: &Context<'a>) -> PrintItems<'a> {
+    parse_flow_collection(&node.props, &node.entries, ("{", "}"), true, context)
+}
+
+/// Parses a flow collection that wraps its items when exceeding the line width.
+fn parse_flow_collection<'a>(
+    props: &Option<String>,
+    nodes: &[FlowNode],
+    (open_token, close_token): (&str, &str),
+    has_inner_spaces: bool,
+    context: &Context<'a>,
+) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
+
+    if let Some(props) = props {
+        items.extend(parse_string(context.arena, props));
+        items.push_str(" ");
+    }
+
+    items.push_str(open_token);
+    if !nodes.is_empty() {
+        let nodes_count = nodes.len();
+        items.extend(parse_separated_values(|_| {
+            nodes.iter().enumerate().map(|(i, node)| {
+                let mut items = parse_flow_node(node, context);
+                if i < nodes_count - 1 {
+                    items.push_str(",");
+                }
+                ParsedValue::from_items(new_line_group(items))
+            }).collect()
+        }, ParseSeparatedValuesOptions {
+            prefer_hanging: true,
+            force_use_new_lines: false,
+            allow_blank_lines: false,
+            single_line_space_at_start: has_inner_spaces,
+            single_line_space_at_end: has_inner_spaces,
+            single_line_separator: context.arena.items(Signal::SpaceOrNewLine),
+            indent_width: context.config.indent_width,
+            multi_line_options: MultiLineOptions::same_line_start_hanging_indent(),
+            force_possible_newline_at_start: false,
+        }).items);
+    }
+    items.push_str(close_token);
+
+    items
+}
+
+/// Gets the text of a quoted scalar using the preferred quote when it won't need to be escaped.
+fn get_quoted_text(text: &str, quote_style: QuoteStyle) -> String {
+    let is_quoted = text.len() >= 2 && (text.starts_with('"') && text.ends_with('"') || text.starts_with('\'') && text.ends_with('\''));
+    if !is_quoted {
+        return String::from(text);
+    }
+
+    let inner_text = &text[1..text.len() - 1];
+    match (text.chars().next().unwrap(), quote_style) {
+        ('\'', QuoteStyle::PreferDouble) => {
+            let inner_text = inner_text.replace("''", "'");
+            if inner_text.contains('"') || inner_text.contains('\\') {
+                String::from(text)
+            } else {
+                format!("\"{}\"", inner_text)
+            }
+        },
+        ('"', QuoteStyle::PreferSingle) => {
+            if inner_text.contains('\'') || inner_text.contains('\\') {
+                String::from(text)
+            } else {
+                format!("'{}'", inner_text)
+            }
+        },
+        _ => String::from(text),
+    }
+}
+
+fn parse_trailing_comment<'a>(comment: &Option<Comment>, context: &Context<'a>) -> PrintItems<'a> {
+    let mut items = PrintItems::new(context.arena);
+    if let Some(comment) = comment {
+        items.push_str(" ");
+        items.extend(parse_comment(context.arena, comment));
+    }
+    items
+}
+
+fn parse_comment<'a>(arena: &'a PrintItemsArena<'a>, comment: &Comment) -> PrintItems<'a> {
+    parse_string(arena, &format!("#{}", comment.text))
+}
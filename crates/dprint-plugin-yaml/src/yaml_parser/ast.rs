@@ -0,0 +1,107 @@
+/// A stream of one or more documents.
+pub struct Stream {
+    pub documents: Vec<Document>,
+}
+
+pub struct Document {
+    /// Comments before the directives and start marker.
+    pub leading_comments: Vec<Comment>,
+    /// Directive lines (ex. `%YAML 1.2`).
+    pub directives: Vec<String>,
+    /// If the document starts with `---`.
+    pub has_start_marker: bool,
+    pub value: Value,
+    pub trailing_comments: Vec<Comment>,
+    /// If the document ends with `...`.
+    pub has_end_marker: bool,
+}
+
+pub struct Comment {
+    /// The text after the `#`.
+    pub text: String,
+    pub has_blank_line_before: bool,
+}
+
+/// A node along with its properties and the comments around it.
+pub struct Value {
+    /// The anchor and tag text (ex. `&anchor !!map`).
+    pub props: Option<String>,
+    /// Comments between the indicator and a scalar on a following line.
+    pub leading_comments: Vec<Comment>,
+    /// The node or `None` when the value is empty.
+    pub node: Option<Node>,
+    /// The comment at the end of the line of the indicator or scalar.
+    pub trailing_comment: Option<Comment>,
+}
+
+pub enum Node {
+    Mapping(Mapping),
+    Sequence(Sequence),
+    Scalar(Scalar),
+    BlockScalar(BlockScalar),
+    FlowSequence(FlowSequence),
+    FlowMapping(FlowMapping),
+}
+
+pub struct Mapping {
+    pub entries: Vec<MappingEntry>,
+    pub trailing_comments: Vec<Comment>,
+}
+
+pub struct MappingEntry {
+    pub leading_comments: Vec<Comment>,
+    pub has_blank_line_before: bool,
+    /// The key as written in the file including any properties.
+    pub key: String,
+    pub value: Value,
+}
+
+pub struct Sequence {
+    pub items: Vec<SequenceItem>,
+    pub trailing_comments: Vec<Comment>,
+}
+
+pub struct SequenceItem {
+    pub leading_comments: Vec<Comment>,
+    pub has_blank_line_before: bool,
+    pub value: Value,
+}
+
+/// A plain, quoted, or alias scalar.
+pub struct Scalar {
+    /// The first line as written followed by any continuation lines without
+    /// their indentation. Blank lines within the scalar are empty strings.
+    pub lines: Vec<String>,
+}
+
+/// A literal (`|`) or folded (`>`) block scalar.
+pub struct BlockScalar {
+    pub indicator: char,
+    pub chomping_indicator: Option<char>,
+    pub indentation_indicator: Option<u32>,
+    /// The content lines without the content indentation.
+    pub lines: Vec<String>,
+}
+
+pub struct FlowSequence {
+    pub props: Option<String>,
+    pub items: Vec<FlowNode>,
+}
+
+pub struct FlowMapping {
+    pub props: Option<String>,
+    pub entries: Vec<FlowNode>,
+}
+
+pub struct FlowPair {
+    pub key: Box<FlowNode>,
+    pub value: Option<Box<FlowNode>>,
+}
+
+pub enum FlowNode {
+    /// A scalar as written including any properties.
+    Scalar(String),
+    Sequence(FlowSequence),
+    Mapping(FlowMapping),
+    Pair(FlowPair),
+}
@@ -0,0 +1,896 @@
+use super::ast::*;
+
+pub struct ParseError {
+    pub range: (usize, usize),
+    pub message: String,
+}
+
+/// Parses the YAML text into a stream of documents that keeps its comments.
+pub fn parse_text(text: &str) -> Result<Stream, ParseError> {
+    let mut parser = Parser {
+        text,
+        lines: get_lines(text),
+        line: 0,
+        col: 0,
+    };
+    parser.parse_stream()
+}
+
+struct Line<'a> {
+    start: usize,
+    text: &'a str,
+}
+
+fn get_lines(text: &str) -> Vec<Line<'_>> {
+    let mut lines = Vec::new();
+    let mut start = 0;
+    for line_text in text.split('\n') {
+        lines.push(Line {
+            start,
+            text: line_text.trim_end_matches('\r'),
+        });
+        start += line_text.len() + 1;
+    }
+    if text.ends_with('\n') {
+        lines.pop();
+    }
+    lines
+}
+
+/// Where a value is being parsed.
+#[derive(Clone, Copy)]
+struct ValueContext {
+    /// The indentation of the parent collection or -1 for the root.
+    parent_indent: isize,
+    /// If a mapping or sequence may start on the same line (ex. `- key: value`).
+    allow_compact_collection: bool,
+    /// If a sequence may be at the same indentation as the parent (ex. `key:\n- item`).
+    allow_same_indent_sequence: bool,
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    lines: Vec<Line<'a>>,
+    line: usize,
+    col: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn parse_stream(&mut self) -> Result<Stream, ParseError> {
+        let mut documents = Vec::new();
+        let mut last_had_end_marker = true;
+
+        loop {
+            let start_line = self.line;
+            let (leading_comments, _) = self.collect_trivia();
+
+            let mut directives = Vec::new();
+            while !self.is_eof() && self.current_line_text().starts_with('%') {
+                directives.push(String::from(self.current_line_text().trim_end()));
+                self.next_line();
+            }
+
+            let has_start_marker = !self.is_eof() && self.is_document_marker(self.line, "---");
+            if !has_start_marker && !directives.is_empty() {
+                return Err(self.create_error("Expected a document start marker after the directives."));
+            }
+            if !has_start_marker && !last_had_end_marker {
+                return Err(self.create_error("Expected a document start marker."));
+            }
+
+            let (leading_comments, value) = if has_start_marker {
+                self.col = 3;
+                (leading_comments, self.parse_value(ValueContext {
+                    parent_indent: -1,
+                    allow_compact_collection: false,
+                    allow_same_indent_sequence: false,
+                })?)
+            } else {
+                // let the value take the comments
+                self.line = start_line;
+                (Vec::new(), self.parse_value_on_following_lines(ValueContext {
+                    parent_indent: -1,
+                    allow_compact_collection: false,
+                    allow_same_indent_sequence: false,
+                })?)
+            };
+
+            let (trailing_comments, _) = self.collect_trivia();
+            let has_end_marker = !self.is_eof() && self.is_document_marker(self.line, "...");
+            if has_end_marker {
+                self.col = 3;
+                self.expect_line_end()?;
+                self.next_line();
+            } else if !self.is_eof() && !self.is_document_marker(self.line, "---") && !self.current_line_text().starts_with('%') {
+                return Err(self.create_error("Unexpected content."));
+            }
+
+            last_had_end_marker = has_end_marker;
+            documents.push(Document {
+                leading_comments,
+                directives,
+                has_start_marker,
+                value,
+                trailing_comments,
+                has_end_marker,
+            });
+
+            if self.is_eof() {
+                break;
+            }
+        }
+
+        Ok(Stream { documents })
+    }
+
+    /// Parses the value after an indicator (ex. after `key:`, `-`, or `---`).
+    fn parse_value(&mut self, context: ValueContext) -> Result<Value, ParseError> {
+        self.skip_spaces();
+
+        if context.allow_compact_collection && !self.is_at_line_end() {
+            let indent = self.col;
+            if is_sequence_start(self.current_text()) {
+                return Ok(create_value(Node::Sequence(self.parse_sequence(indent, true)?)));
+            } else if self.find_mapping_colon(self.current_text())?.is_some() {
+                return Ok(create_value(Node::Mapping(self.parse_mapping(indent, true)?)));
+            }
+        }
+
+        let props = self.parse_props(false);
+        self.skip_spaces();
+
+        if self.is_at_line_end() {
+            let trailing_comment = self.parse_comment();
+            self.next_line();
+            let mut value = self.parse_value_on_following_lines(context)?;
+            if let Some(trailing_comment) = trailing_comment {
+                match &value.node {
+                    Some(Node::Mapping(_)) | Some(Node::Sequence(_)) | None => value.trailing_comment = Some(trailing_comment),
+                    _ => value.leading_comments.insert(0, trailing_comment),
+                }
+            }
+            if props.is_some() {
+                if value.props.is_some() {
+                    return Err(self.create_error("Expected only one set of properties for a node."));
+                }
+                value.props = props;
+            }
+            return Ok(value);
+        }
+
+        let (node, trailing_comment) = if let Some('|') | Some('>') = self.peek_char() {
+            let (block_scalar, header_comment) = self.parse_block_scalar(context)?;
+            (Node::BlockScalar(block_scalar), header_comment)
+        } else {
+            let node = self.parse_inline_node(context)?;
+            let comment = self.parse_comment();
+            self.expect_line_end()?;
+            self.next_line();
+            (node, comment)
+        };
+
+        Ok(Value {
+            props,
+            leading_comments: Vec::new(),
+            node: Some(node),
+            trailing_comment,
+        })
+    }
+
+    /// Parses a value that starts on a line after the indicator.
+    fn parse_value_on_following_lines(&mut self, context: ValueContext) -> Result<Value, ParseError> {
+        let start_line = self.line;
+        let (comments, _) = self.collect_trivia();
+
+        if self.is_eof() {
+            self.line = start_line;
+            return Ok(create_empty_value());
+        }
+
+        let indent = self.get_content_indent(self.line);
+        let text = self.current_line_text();
+        let is_sequence = indent >= 0 && is_sequence_start(&text[indent as usize..]);
+        let is_child = indent > context.parent_indent || context.allow_same_indent_sequence && is_sequence && indent == context.parent_indent;
+
+        if !is_child {
+            self.line = start_line;
+            return Ok(create_empty_value());
+        }
+
+        let indent = indent as usize;
+        if is_sequence {
+            self.line = start_line;
+            Ok(create_value(Node::Sequence(self.parse_sequence(indent, false)?)))
+        } else if self.find_mapping_colon(&text[indent..])?.is_some() {
+            self.line = start_line;
+            Ok(create_value(Node::Mapping(self.parse_mapping(indent, false)?)))
+        } else {
+            self.col = indent;
+            let mut value = self.parse_value(ValueContext {
+                allow_compact_collection: false,
+                ..context
+            })?;
+            for (i, comment) in comments.into_iter().enumerate() {
+                value.leading_comments.insert(i, comment);
+            }
+            Ok(value)
+        }
+    }
+
+    fn parse_mapping(&mut self, indent: usize, is_compact: bool) -> Result<Mapping, ParseError> {
+        let mut entries = Vec::new();
+        let mut is_first = true;
+
+        loop {
+            let start_line = self.line;
+            let (leading_comments, has_blank_line_before) = if is_first && is_compact {
+                (Vec::new(), false)
+            } else {
+                let trivia = self.collect_trivia();
+                if self.is_eof() || self.get_content_indent(self.line) < indent as isize {
+                    self.line = start_line;
+                    break;
+                }
+                if self.get_content_indent(self.line) > indent as isize {
+                    self.col = self.get_content_indent(self.line) as usize;
+                    return Err(self.create_error("Unexpected indentation."));
+                }
+                self.col = indent;
+                trivia
+            };
+
+            if is_sequence_start(self.current_text()) {
+                if is_first {
+                    return Err(self.create_error("Expected a mapping key."));
+                }
+                self.line = start_line;
+                break;
+            }
+
+            let colon_index = match self.find_mapping_colon(self.current_text())? {
+                Some(index) => index,
+                None => return Err(self.create_error("Expected a mapping key.")),
+            };
+            let key = String::from(self.current_text()[..colon_index].trim_end());
+            self.col += colon_index + 1;
+            let value = self.parse_value(ValueContext {
+                parent_indent: indent as isize,
+                allow_compact_collection: false,
+                allow_same_indent_sequence: true,
+            })?;
+
+            entries.push(MappingEntry {
+                leading_comments,
+                has_blank_line_before,
+                key,
+                value,
+            });
+            is_first = false;
+        }
+
+        Ok(Mapping {
+            entries,
+            trailing_comments: self.collect_trailing_comments(indent),
+        })
+    }
+
+    fn parse_sequence(&mut self, indent: usize, is_compact: bool) -> Result<Sequence, ParseError> {
+        let mut items = Vec::new();
+        let mut is_first = true;
+
+        loop {
+            let start_line = self.line;
+            let (leading_comments, has_blank_line_before) = if is_first && is_compact {
+                (Vec::new(), false)
+            } else {
+                let trivia = self.collect_trivia();
+                if self.is_eof() || self.get_content_indent(self.line) != indent as isize {
+                    self.line = start_line;
+                    break;
+                }
+                self.col = indent;
+                if !is_sequence_start(self.current_text()) {
+                    self.line = start_line;
+                    break;
+                }
+                trivia
+            };
+
+            self.col += 1;
+            let value = self.parse_value(ValueContext {
+                parent_indent: indent as isize,
+                allow_compact_collection: true,
+                allow_same_indent_sequence: false,
+            })?;
+
+            items.push(SequenceItem {
+                leading_comments,
+                has_blank_line_before,
+                value,
+            });
+            is_first = false;
+        }
+
+        Ok(Sequence {
+            items,
+            trailing_comments: self.collect_trailing_comments(indent),
+        })
+    }
+
+    /// Takes the comments after a collection that are indented at least as much as it.
+    fn collect_trailing_comments(&mut self, indent: usize) -> Vec<Comment> {
+        let mut comments = Vec::new();
+        let mut has_blank_line_before = false;
+        let mut line_index = self.line;
+        let mut end_line = self.line;
+
+        while line_index < self.lines.len() {
+            if self.is_blank_line(line_index) {
+                has_blank_line_before = true;
+            } else if self.is_comment_line(line_index) && get_indent(self.lines[line_index].text) >= indent {
+                comments.push(self.create_comment_from_line(line_index, has_blank_line_before));
+                has_blank_line_before = false;
+                end_line = line_index + 1;
+            } else {
+                break;
+            }
+            line_index += 1;
+        }
+
+        // leave the blank lines after the last comment for the parent
+        self.line = end_line;
+        self.col = 0;
+
+        comments
+    }
+
+    fn parse_inline_node(&mut self, context: ValueContext) -> Result<Node, ParseError> {
+        match self.peek_char() {
+            Some('[') | Some('{') => {
+                let node = self.parse_flow_node()?;
+                match node {
+                    FlowNode::Sequence(node) => Ok(Node::FlowSequence(node)),
+                    FlowNode::Mapping(node) => Ok(Node::FlowMapping(node)),
+                    _ => unreachable!(),
+                }
+            },
+            Some('"') | Some('\'') => Ok(Node::Scalar(self.parse_quoted_scalar()?)),
+            Some('*') => {
+                let start = self.col;
+                self.skip_while(|c| !c.is_whitespace());
+                Ok(Node::Scalar(Scalar {
+                    lines: vec![String::from(&self.current_line_text()[start..self.col])],
+                }))
+            },
+            Some('?') if self.current_text().len() == 1 || self.current_text()[1..].starts_with(' ') => {
+                Err(self.create_error("Explicit mapping keys are not supported."))
+            },
+            _ => {
+                if self.find_mapping_colon(self.current_text())?.is_some() {
+                    return Err(self.create_error("Mappings are not allowed on the same line as a mapping key."));
+                }
+                Ok(Node::Scalar(self.parse_plain_scalar(context)?))
+            },
+        }
+    }
+
+    /// Parses a block scalar returning it and the comment on its header line.
+    fn parse_block_scalar(&mut self, context: ValueContext) -> Result<(BlockScalar, Option<Comment>), ParseError> {
+        let indicator = self.next_char().unwrap();
+        let mut chomping_indicator = None;
+        let mut indentation_indicator = None;
+
+        for _ in 0..2 {
+            match self.peek_char() {
+                Some(c) if (c == '+' || c == '-') && chomping_indicator.is_none() => {
+                    chomping_indicator = Some(c);
+                    self.next_char();
+                },
+                Some(c) if c.is_ascii_digit() && c != '0' && indentation_indicator.is_none() => {
+                    indentation_indicator = c.to_digit(10);
+                    self.next_char();
+                },
+                _ => break,
+            }
+        }
+
+        let header_comment = self.parse_comment();
+        self.expect_line_end()?;
+        self.next_line();
+
+        let mut content_indent = indentation_indicator.map(|indicator| (context.parent_indent + indicator as isize).max(0) as usize);
+        let mut end_line = self.line;
+        let mut line_index = self.line;
+
+        while line_index < self.lines.len() {
+            let text = self.lines[line_index].text;
+            if text.trim().is_empty() {
+                line_index += 1;
+                continue;
+            }
+            if context.parent_indent == -1 && (self.is_document_marker(line_index, "---") || self.is_document_marker(line_index, "...")) {
+                break;
+            }
+
+            let indent = get_indent(text);
+            let content_indent = *content_indent.get_or_insert(indent);
+            if indent < content_indent || indent as isize <= context.parent_indent {
+                break;
+            }
+            line_index += 1;
+            end_line = line_index;
+        }
+
+        // keep the trailing blank lines when using the keep chomping indicator
+        if chomping_indicator == Some('+') {
+            end_line = line_index;
+        }
+
+        let content_indent = content_indent.unwrap_or(0);
+        let lines = self.lines[self.line..end_line].iter().map(|line| {
+            if line.text.len() > content_indent {
+                String::from(&line.text[content_indent..])
+            } else {
+                String::new()
+            }
+        }).collect();
+        self.line = end_line;
+        self.col = 0;
+
+        Ok((BlockScalar {
+            indicator,
+            chomping_indicator,
+            indentation_indicator,
+            lines,
+        }, header_comment))
+    }
+
+    fn parse_plain_scalar(&mut self, context: ValueContext) -> Result<Scalar, ParseError> {
+        let start = self.col;
+        self.skip_to_comment_or_line_end();
+        let mut lines = vec![String::from(self.current_line_text()[start..self.col].trim_end())];
+        if lines[0].is_empty() {
+            return Err(self.create_error("Expected a value."));
+        }
+        if self.peek_char() == Some('#') {
+            return Ok(Scalar { lines });
+        }
+
+        // continuation lines
+        let mut line_index = self.line + 1;
+        let mut blank_line_count = 0;
+        while line_index < self.lines.len() {
+            let text = self.lines[line_index].text;
+            if text.trim().is_empty() {
+                blank_line_count += 1;
+            } else if self.get_content_indent(line_index) > context.parent_indent && !text.trim_start().starts_with('#') {
+                for _ in 0..blank_line_count {
+                    lines.push(String::new());
+                }
+                blank_line_count = 0;
+                self.line = line_index;
+                self.col = get_indent(text);
+                let start = self.col;
+                self.skip_to_comment_or_line_end();
+                lines.push(String::from(self.current_line_text()[start..self.col].trim_end()));
+                if self.peek_char() == Some('#') {
+                    break;
+                }
+            } else {
+                break;
+            }
+            line_index += 1;
+        }
+
+        Ok(Scalar { lines })
+    }
+
+    fn parse_quoted_scalar(&mut self) -> Result<Scalar, ParseError> {
+        let quote_char = self.next_char().unwrap();
+        let mut lines = Vec::new();
+        let mut start = self.col - 1;
+
+        loop {
+            match self.next_char() {
+                Some('\\') if quote_char == '"' => {
+                    self.next_char();
+                },
+                Some('\'') if quote_char == '\'' && self.peek_char() == Some('\'') => {
+                    self.next_char();
+                },
+                Some(c) if c == quote_char => break,
+                Some(_) => {},
+                None => {
+                    lines.push(String::from(&self.current_line_text()[start..]));
+                    self.next_line();
+                    if self.is_eof() {
+                        return Err(self.create_error("Unterminated string."));
+                    }
+                    self.skip_spaces();
+                    start = self.col;
+                },
+            }
+        }
+
+        lines.push(String::from(&self.current_line_text()[start..self.col]));
+        Ok(Scalar { lines })
+    }
+
+    fn parse_flow_node(&mut self) -> Result<FlowNode, ParseError> {
+        let props = self.parse_props(true);
+        if props.is_some() {
+            self.skip_flow_whitespace()?;
+        }
+
+        match self.peek_char() {
+            Some('[') => {
+                self.next_char();
+                let mut items = Vec::new();
+                loop {
+                    self.skip_flow_whitespace()?;
+                    if self.try_consume_char(']') {
+                        break;
+                    }
+                    let node = self.parse_flow_node()?;
+                    items.push(self.parse_flow_pair_if_exists(node, ']')?);
+                    if !self.parse_flow_separator(']')? {
+                        break;
+                    }
+                }
+                Ok(FlowNode::Sequence(FlowSequence { props, items }))
+            },
+            Some('{') => {
+                self.next_char();
+                let mut entries = Vec::new();
+                loop {
+                    self.skip_flow_whitespace()?;
+                    if self.try_consume_char('}') {
+                        break;
+                    }
+                    let node = self.parse_flow_node()?;
+                    entries.push(self.parse_flow_pair_if_exists(node, '}')?);
+                    if !self.parse_flow_separator('}')? {
+                        break;
+                    }
+                }
+                Ok(FlowNode::Mapping(FlowMapping { props, entries }))
+            },
+            Some('"') | Some('\'') => {
+                let start_line = self.line;
+                let scalar = self.parse_quoted_scalar()?;
+                if self.line != start_line {
+                    return Err(self.create_error("Multi-line strings are not supported in flow collections."));
+                }
+                Ok(FlowNode::Scalar(with_props(props, &scalar.lines[0])))
+            },
+            _ => {
+                let start = self.col;
+                let chars = self.current_text().char_indices().collect::<Vec<_>>();
+                let mut end = None;
+                for (i, (byte_index, c)) in chars.iter().enumerate() {
+                    let next_char = chars.get(i + 1).map(|(_, c)| *c);
+                    let is_end = match c {
+                        ',' | '[' | ']' | '{' | '}' => true,
+                        ':' => next_char.map(|c| c.is_whitespace() || is_flow_indicator(c)).unwrap_or(true),
+                        '#' => i > 0 && chars[i - 1].1.is_whitespace(),
+                        _ => false,
+                    };
+                    if is_end {
+                        end = Some(*byte_index);
+                        break;
+                    }
+                }
+                self.col += end.unwrap_or_else(|| self.current_text().len());
+                let text = self.current_line_text()[start..self.col].trim_end();
+                if text.is_empty() {
+                    return Err(self.create_error("Expected a value."));
+                }
+                Ok(FlowNode::Scalar(with_props(props, text)))
+            },
+        }
+    }
+
+    fn parse_flow_pair_if_exists(&mut self, key: FlowNode, close_char: char) -> Result<FlowNode, ParseError> {
+        self.skip_flow_whitespace()?;
+        if !self.try_consume_char(':') {
+            return Ok(key);
+        }
+
+        self.skip_flow_whitespace()?;
+        let value = match self.peek_char() {
+            Some(c) if c == ',' || c == close_char => None,
+            _ => Some(Box::new(self.parse_flow_node()?)),
+        };
+
+        Ok(FlowNode::Pair(FlowPair {
+            key: Box::new(key),
+            value,
+        }))
+    }
+
+    /// Parses the comma or closing character after a flow item and returns if there could be another item.
+    fn parse_flow_separator(&mut self, close_char: char) -> Result<bool, ParseError> {
+        self.skip_flow_whitespace()?;
+        if self.try_consume_char(',') {
+            Ok(true)
+        } else if self.try_consume_char(close_char) {
+            Ok(false)
+        } else {
+            Err(self.create_error(&format!("Expected a comma or '{}'.", close_char)))
+        }
+    }
+
+    fn skip_flow_whitespace(&mut self) -> Result<(), ParseError> {
+        loop {
+            self.skip_spaces();
+            if self.peek_char() == Some('#') {
+                return Err(self.create_error("Comments are not supported in flow collections."));
+            }
+            if !self.is_at_line_end() {
+                return Ok(());
+            }
+            self.next_line();
+            if self.is_eof() {
+                return Err(self.create_error("Unterminated flow collection."));
+            }
+        }
+    }
+
+    /// Parses the anchor and tag (ex. `&anchor !tag`).
+    fn parse_props(&mut self, is_flow: bool) -> Option<String> {
+        let mut props = Vec::new();
+
+        while let Some('&') | Some('!') = self.peek_char() {
+            let start = self.col;
+            self.skip_while(|c| !(c.is_whitespace() || is_flow && is_flow_indicator(c)));
+            props.push(&self.current_line_text()[start..self.col]);
+            let end = self.col;
+            self.skip_spaces();
+            if !matches!(self.peek_char(), Some('&') | Some('!')) {
+                self.col = end;
+                break;
+            }
+        }
+
+        if props.is_empty() {
+            None
+        } else {
+            Some(props.join(" "))
+        }
+    }
+
+    /// Parses a comment at the current position if it exists.
+    fn parse_comment(&mut self) -> Option<Comment> {
+        self.skip_spaces();
+        if self.peek_char() == Some('#') {
+            let text = String::from(self.current_text()[1..].trim_end());
+            self.col = self.current_line_text().len();
+            Some(Comment {
+                text,
+                has_blank_line_before: false,
+            })
+        } else {
+            None
+        }
+    }
+
+    fn create_comment_from_line(&self, line_index: usize, has_blank_line_before: bool) -> Comment {
+        let text = self.lines[line_index].text.trim();
+        Comment {
+            text: String::from(text[1..].trim_end()),
+            has_blank_line_before,
+        }
+    }
+
+    /// Collects the blank lines and comment lines starting at the current line.
+    /// Returns the comments and if there was a blank line after the last comment.
+    fn collect_trivia(&mut self) -> (Vec<Comment>, bool) {
+        let mut comments = Vec::new();
+        let mut has_blank_line_before = false;
+        self.col = 0;
+
+        while !self.is_eof() {
+            if self.is_blank_line(self.line) {
+                has_blank_line_before = true;
+            } else if self.is_comment_line(self.line) {
+                comments.push(self.create_comment_from_line(self.line, has_blank_line_before));
+                has_blank_line_before = false;
+            } else {
+                break;
+            }
+            self.next_line();
+        }
+
+        (comments, has_blank_line_before)
+    }
+
+    /// Gets the index of the colon when the text starts with a mapping key.
+    fn find_mapping_colon(&self, text: &str) -> Result<Option<usize>, ParseError> {
+        let chars = text.char_indices().collect::<Vec<_>>();
+        let is_colon_at = |i: usize| chars[i].1 == ':' && chars.get(i + 1).map(|(_, c)| c.is_whitespace()).unwrap_or(true);
+        let mut i = 0;
+
+        // skip over a quoted key
+        if let Some((_, quote_char)) = chars.first().filter(|(_, c)| *c == '"' || *c == '\'') {
+            i = 1;
+            while i < chars.len() {
+                let c = chars[i].1;
+                if c == '\\' && *quote_char == '"' {
+                    i += 1;
+                } else if c == *quote_char {
+                    if *quote_char == '\'' && chars.get(i + 1).map(|(_, c)| *c == '\'').unwrap_or(false) {
+                        i += 1;
+                    } else {
+                        break;
+                    }
+                }
+                i += 1;
+            }
+            i += 1;
+            while i < chars.len() && chars[i].1 == ' ' {
+                i += 1;
+            }
+            return Ok(if i < chars.len() && is_colon_at(i) { Some(chars[i].0) } else { None });
+        }
+
+        match chars.first().map(|(_, c)| *c) {
+            Some('[') | Some('{') | Some('#') | Some('|') | Some('>') | None => return Ok(None),
+            Some('?') if chars.len() == 1 || chars[1].1 == ' ' => return Err(self.create_error("Explicit mapping keys are not supported.")),
+            _ => {},
+        }
+
+        while i < chars.len() {
+            let c = chars[i].1;
+            if c == '#' && i > 0 && chars[i - 1].1.is_whitespace() {
+                return Ok(None);
+            }
+            if is_colon_at(i) {
+                return Ok(Some(chars[i].0));
+            }
+            i += 1;
+        }
+
+        Ok(None)
+    }
+
+    fn expect_line_end(&mut self) -> Result<(), ParseError> {
+        self.skip_spaces();
+        if self.is_at_line_end() {
+            Ok(())
+        } else {
+            Err(self.create_error("Expected a new line."))
+        }
+    }
+
+    /// Gets the indentation of the line or -1 for document markers and directives.
+    fn get_content_indent(&self, line_index: usize) -> isize {
+        let text = self.lines[line_index].text;
+        if self.is_document_marker(line_index, "---") || self.is_document_marker(line_index, "...") || text.starts_with('%') {
+            -1
+        } else {
+            get_indent(text) as isize
+        }
+    }
+
+    fn is_document_marker(&self, line_index: usize, marker: &str) -> bool {
+        let text = self.lines[line_index].text;
+        text.starts_with(marker) && text[marker.len()..].chars().next().map(|c| c.is_whitespace()).unwrap_or(true)
+    }
+
+    fn is_blank_line(&self, line_index: usize) -> bool {
+        self.lines[line_index].text.trim().is_empty()
+    }
+
+    fn is_comment_line(&self, line_index: usize) -> bool {
+        self.lines[line_index].text.trim_start().starts_with('#')
+    }
+
+    fn is_at_line_end(&self) -> bool {
+        match self.peek_char() {
+            None => true,
+            Some('#') => self.col == 0 || self.current_line_text()[..self.col].ends_with(char::is_whitespace),
+            _ => false,
+        }
+    }
+
+    fn is_eof(&self) -> bool {
+        self.line >= self.lines.len()
+    }
+
+    fn current_line_text(&self) -> &'a str {
+        self.lines.get(self.line).map(|l| l.text).unwrap_or("")
+    }
+
+    fn current_text(&self) -> &'a str {
+        &self.current_line_text()[self.col..]
+    }
+
+    fn skip_to_comment_or_line_end(&mut self) {
+        while !self.is_at_line_end() {
+            self.next_char();
+        }
+    }
+
+    fn skip_spaces(&mut self) {
+        self.skip_while(|c| c == ' ' || c == '\t');
+    }
+
+    fn skip_while(&mut self, predicate: impl Fn(char) -> bool) {
+        while let Some(c) = self.peek_char() {
+            if !predicate(c) {
+                break;
+            }
+            self.next_char();
+        }
+    }
+
+    fn try_consume_char(&mut self, c: char) -> bool {
+        if self.peek_char() == Some(c) {
+            self.next_char();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn peek_char(&self) -> Option<char> {
+        self.current_text().chars().next()
+    }
+
+    fn next_char(&mut self) -> Option<char> {
+        let c = self.peek_char()?;
+        self.col += c.len_utf8();
+        Some(c)
+    }
+
+    fn next_line(&mut self) {
+        self.line += 1;
+        self.col = 0;
+    }
+
+    fn create_error(&self, message: &str) -> ParseError {
+        let pos = self.lines.get(self.line).map(|l| l.start + self.col).unwrap_or_else(|| self.text.len());
+        let end = pos + self.peek_char().map(|c| c.len_utf8()).unwrap_or(0);
+        ParseError {
+            range: (pos, end),
+            message: String::from(message),
+        }
+    }
+}
+
+fn create_value(node: Node) -> Value {
+    Value {
+        props: None,
+        leading_comments: Vec::new(),
+        node: Some(node),
+        trailing_comment: None,
+    }
+}
+
+fn create_empty_value() -> Value {
+    Value {
+        props: None,
+        leading_comments: Vec::new(),
+        node: None,
+        trailing_comment: None,
+    }
+}
+
+fn with_props(props: Option<String>, text: &str) -> String {
+    match props {
+        Some(props) => format!("{} {}", props, text),
+        None => String::from(text),
+    }
+}
+
+fn is_sequence_start(text: &str) -> bool {
+    text.starts_with('-') && text[1..].chars().next().map(|c| c.is_whitespace()).unwrap_or(true)
+}
+
+fn is_flow_indicator(c: char) -> bool {
+    matches!(c, ',' | '[' | ']' | '{' | '}')
+}
+
+fn get_indent(text: &str) -> usize {
+    text.chars().take_while(|c| *c == ' ').count()
+}
@@ -3,7 +3,11 @@ pub fn get_init_config_file_text() -> &'static str {
   "projectType": "",
   "typescript": {},
   "json": {},
-  "includes": ["**/*.{ts,tsx,js,jsx,json}"],
+  "markdown": {},
+  "toml": {},
+  "yaml": {},
+  "css": {},
+  "includes": ["**/*.{ts,tsx,js,jsx,vue,svelte,json,md,toml,yaml,yml,css,scss,less}"],
   "excludes": []
 }
 "#
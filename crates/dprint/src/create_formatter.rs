@@ -16,7 +16,11 @@ pub fn create_formatter(config_map: ConfigMap, environment: &impl Environment) -
 pub fn get_uninitialized_plugins() -> Vec<Box<dyn Plugin>> {
     vec![
         Box::new(dprint_plugin_typescript::TypeScriptPlugin::new()),
-        Box::new(dprint_plugin_jsonc::JsoncPlugin::new())
+        Box::new(dprint_plugin_jsonc::JsoncPlugin::new()),
+        Box::new(dprint_plugin_markdown::MarkdownPlugin::new()),
+        Box::new(dprint_plugin_toml::TomlPlugin::new()),
+        Box::new(dprint_plugin_yaml::YamlPlugin::new()),
+        Box::new(dprint_plugin_css::CssPlugin::new()),
     ]
 }
 
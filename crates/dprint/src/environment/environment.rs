@@ -7,4 +7,6 @@ pub trait Environment : std::marker::Sync {
     fn path_exists(&self, file_path: &PathBuf) -> bool;
     fn log(&self, text: &str);
     fn log_error(&self, text: &str);
+    /// Gets if stdout is a terminal, which is when the output may be colored.
+    fn is_stdout_tty(&self) -> bool;
 }
@@ -1,5 +1,6 @@
 use std::path::PathBuf;
 use std::fs;
+use std::io::IsTerminal;
 use super::Environment;
 use std::sync::{Arc, Mutex};
 
@@ -58,4 +59,8 @@ impl Environment for RealEnvironment {
         let _g = self.output_lock.lock().unwrap();
         eprintln!("{}", text);
     }
+
+    fn is_stdout_tty(&self) -> bool {
+        std::io::stdout().is_terminal()
+    }
 }
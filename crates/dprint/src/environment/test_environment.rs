@@ -72,6 +72,10 @@ impl Environment for TestEnvironment {
     fn log_error(&self, text: &str) {
         self.logged_errors.lock().unwrap().push(String::from(text));
     }
+
+    fn is_stdout_tty(&self) -> bool {
+        false
+    }
 }
 
 fn file_patterns_to_glob_set(file_patterns: impl Iterator<Item = String>) -> Result<GlobSet, String> {
@@ -6,6 +6,8 @@ mod environment;
 mod run_cli;
 
 fn main() {
+    run_cli::set_panic_hook();
+
     let environment = RealEnvironment::new();
     let args = std::env::args().collect();
 
@@ -1,5 +1,9 @@
 use std::collections::HashMap;
+use dprint_core::{FormatError, LongLine};
 use dprint_core::plugins::Formatter;
+use dprint_core::utils::diagnostics::DiagnosticOptions;
+use dprint_core::utils::string_utils::get_json_string;
+use dprint_core::utils::text_edits::{get_text_edits, TextEdit};
 use clap::{App, Arg, Values, ArgMatches};
 use rayon::prelude::*;
 use std::path::PathBuf;
@@ -37,12 +41,21 @@ pub fn run_cli(environment: &impl Environment, args: Vec<String>) -> Result<(),
 
     let formatter = create_formatter(config_map, environment)?;
 
+    if let Some(file_path) = matches.value_of("print-ir") {
+        output_print_ir(&formatter, &PathBuf::from(file_path), environment)?;
+        return Ok(());
+    }
+
     if matches.is_present("output-resolved-config") {
         output_resolved_config(&formatter, environment);
         return Ok(());
     }
 
-    if matches.is_present("check") {
+    if matches.is_present("output-edits") {
+        output_edits(environment, formatter, file_paths);
+    } else if matches.is_present("report-long-lines") {
+        report_long_lines(environment, formatter, file_paths);
+    } else if matches.is_present("check") {
         check_files(environment, formatter, file_paths)?
     } else {
         format_files(environment, formatter, file_paths);
@@ -51,6 +64,17 @@ pub fn run_cli(environment: &impl Environment, args: Vec<String>) -> Result<(),
     Ok(())
 }
 
+/// Sets a panic hook that doesn't output the panics of plugins because they're
+/// already output as an error for the file.
+pub fn set_panic_hook() {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |panic_info| {
+        if !dprint_core::plugins::is_running_plugin() {
+            default_hook(panic_info);
+        }
+    }));
+}
+
 fn output_version(environment: &impl Environment) {
     environment.log(&format!("{} v{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION")));
     for plugin in get_uninitialized_plugins().iter() {
@@ -70,6 +94,17 @@ fn output_resolved_config(formatter: &Formatter, environment: &impl Environment)
     }
 }
 
+fn output_print_ir(formatter: &Formatter, file_path: &PathBuf, environment: &impl Environment) -> Result<(), String> {
+    let file_text = environment.read_file(file_path)?;
+    match formatter.get_print_ir(file_path, &file_text).map_err(|err| get_diagnostic_text(environment, &err, &file_text))? {
+        Some(print_ir) => {
+            environment.log(print_ir.trim_end());
+            Ok(())
+        },
+        None => Err(format!("Could not find a plugin to format {}", file_path.to_string_lossy())),
+    }
+}
+
 fn init_config_file(environment: &impl Environment) -> Result<(), String> {
     let config_file_path = PathBuf::from("./dprint.config.json");
     if !environment.path_exists(&config_file_path) {
@@ -86,15 +121,13 @@ fn check_files(environment: &impl Environment, formatter: Formatter, file_paths:
         let file_contents = environment.read_file(&file_path);
         match file_contents {
             Ok(file_contents) => {
-                match formatter.format_text(&file_path, &file_contents) {
-                    Ok(Some(formatted_file_text)) => {
-                        if formatted_file_text != file_contents {
-                            not_formatted_files_count.fetch_add(1, Ordering::SeqCst);
-                        }
+                match formatter.format_text_equals(&file_path, &file_contents) {
+                    Ok(Some(false)) => {
+                        not_formatted_files_count.fetch_add(1, Ordering::SeqCst);
                     }
-                    Ok(None) => {}, // do nothing
+                    Ok(Some(true)) | Ok(None) => {}, // do nothing
                     Err(e) => {
-                        output_error(environment, &file_path, "Error checking", &e);
+                        output_error(environment, &file_path, "Error checking", &get_diagnostic_text(environment, &e, &file_contents));
                     },
                 }
             },
@@ -134,7 +167,7 @@ fn format_files(environment: &impl Environment, formatter: Formatter, file_paths
                         }
                     }
                     Ok(None) => {}, // do nothing
-                    Err(e) => output_error(environment, &file_path, "Error formatting", &e),
+                    Err(e) => output_error(environment, &file_path, "Error formatting", &get_diagnostic_text(environment, &e, &file_contents)),
                 }
             },
             Err(e) => output_error(environment, &file_path, "Error reading file", &e),
@@ -148,6 +181,90 @@ fn format_files(environment: &impl Environment, formatter: Formatter, file_paths
     }
 }
 
+/// Outputs a line of JSON with the edits to format each file that isn't formatted without changing the file.
+fn output_edits(environment: &impl Environment, formatter: Formatter, file_paths: Vec<PathBuf>) {
+    file_paths.par_iter().for_each(|file_path| {
+        let file_contents = environment.read_file(&file_path);
+
+        match file_contents {
+            Ok(file_contents) => {
+                match formatter.format_text(&file_path, &file_contents) {
+                    Ok(Some(formatted_text)) => {
+                        if formatted_text != file_contents {
+                            let edits = get_text_edits(&file_contents, &formatted_text);
+                            environment.log(&get_file_edits_json(file_path, &edits));
+                        }
+                    }
+                    Ok(None) => {}, // do nothing
+                    Err(e) => output_error(environment, &file_path, "Error formatting", &get_diagnostic_text(environment, &e, &file_contents)),
+                }
+            },
+            Err(e) => output_error(environment, &file_path, "Error reading file", &e),
+        }
+    });
+
+    fn get_file_edits_json(file_path: &PathBuf, edits: &[TextEdit]) -> String {
+        let edits = edits.iter()
+            .map(|edit| format!(r#"{{"range":[{},{}],"newText":{}}}"#, edit.range.0, edit.range.1, get_json_string(&edit.new_text)))
+            .collect::<Vec<_>>();
+        format!(r#"{{"filePath":{},"edits":[{}]}}"#, get_json_string(&file_path.to_string_lossy()), edits.join(","))
+    }
+}
+
+/// Outputs a warning for each line that's still wider than the line width after formatting without changing the file.
+fn report_long_lines(environment: &impl Environment, formatter: Formatter, file_paths: Vec<PathBuf>) {
+    file_paths.par_iter().for_each(|file_path| {
+        let file_contents = environment.read_file(&file_path);
+
+        match file_contents {
+            Ok(file_contents) => {
+                match formatter.format_text_with_long_lines(&file_path, &file_contents) {
+                    Ok(Some((formatted_text, long_lines))) => {
+                        if !long_lines.is_empty() {
+                            let is_formatted = formatted_text == file_contents;
+                            let warnings = long_lines.iter().map(|long_line| get_long_line_warning(file_path, long_line, is_formatted)).collect::<Vec<_>>();
+                            environment.log(&warnings.join("\n"));
+                        }
+                    }
+                    Ok(None) => {}, // do nothing
+                    Err(e) => output_error(environment, &file_path, "Error formatting", &get_diagnostic_text(environment, &e, &file_contents)),
+                }
+            },
+            Err(e) => output_error(environment, &file_path, "Error reading file", &e),
+        }
+    });
+
+    fn get_long_line_warning(file_path: &PathBuf, long_line: &LongLine, is_formatted: bool) -> String {
+        const MAX_TEXT_CHAR_COUNT: usize = 40;
+        let mut warning = format!(
+            "{}:{}:{}: warning: Line is {} columns wide, which exceeds the line width.",
+            file_path.to_string_lossy(),
+            long_line.line_number,
+            long_line.column_number,
+            long_line.width,
+        );
+        if let Some(text) = &long_line.text {
+            let text = if text.chars().count() > MAX_TEXT_CHAR_COUNT {
+                format!("{}...", text.chars().take(MAX_TEXT_CHAR_COUNT).collect::<String>())
+            } else {
+                text.clone()
+            };
+            warning.push_str(&format!(" Could not break up: {}", text));
+        }
+        if !is_formatted {
+            warning.push_str(" (the position is in the formatted text)");
+        }
+        warning
+    }
+}
+
+fn get_diagnostic_text(environment: &impl Environment, err: &FormatError, file_text: &str) -> String {
+    err.get_diagnostic_text_with_options(file_text, &DiagnosticOptions {
+        use_color: environment.is_stdout_tty(),
+        ..Default::default()
+    })
+}
+
 fn output_error(environment: &impl Environment, file_path: &PathBuf, text: &str, error: &impl std::fmt::Display) {
     environment.log_error(&format!("{}: {}\n    {}", text, &file_path.to_string_lossy(), error));
 }
@@ -156,9 +273,9 @@ fn create_cli_parser<'a, 'b>() -> clap::App<'a, 'b> {
     App::new("dprint")
         .about("Format source files")
         .long_about(
-            r#"Auto-format JavaScript, TypeScript, and JSON source code.
+            r#"Auto-format JavaScript, TypeScript, JSON, Markdown, TOML, YAML, and CSS source code.
 
-  dprint "**/*.{ts,tsx,js,jsx,json}"
+  dprint "**/*.{ts,tsx,js,jsx,vue,svelte,json,md,toml,yaml,yml,css,scss,less}"
 
   dprint --check myfile1.ts myfile2.ts
 
@@ -214,6 +331,25 @@ fn create_cli_parser<'a, 'b>() -> clap::App<'a, 'b> {
                 .help("Outputs the resolved file paths.")
                 .takes_value(false),
         )
+        .arg(
+            Arg::with_name("output-edits")
+                .long("output-edits")
+                .help("Outputs a line of JSON per unformatted file with the edits to format it instead of writing the file. Ranges are byte offsets.")
+                .takes_value(false),
+        )
+        .arg(
+            Arg::with_name("report-long-lines")
+                .long("report-long-lines")
+                .help("Outputs a warning with the file path, line, and column of each line that's still wider than the line width after formatting instead of writing the file.")
+                .takes_value(false),
+        )
+        .arg(
+            Arg::with_name("print-ir")
+                .long("print-ir")
+                .value_name("file")
+                .help("Outputs the print items of a file for debugging the formatter. For Vue and Svelte files, this is the print items of each script block. Not supported for Markdown files because they're formatted without print items.")
+                .takes_value(true),
+        )
 }
 
 fn check_project_type_diagnostic(config_map: &mut ConfigMap, environment: &impl Environment) {
@@ -285,8 +421,12 @@ fn resolve_file_paths(config_map: &mut ConfigMap, args: &ArgMatches, environment
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
     use std::path::PathBuf;
-    use super::run_cli;
+    use dprint_core::FormatError;
+    use dprint_core::configuration::{resolve_global_config, ConfigurationDiagnostic, GlobalConfiguration};
+    use dprint_core::plugins::{Formatter, FormatHost, Plugin};
+    use super::{run_cli, format_files, set_panic_hook};
     use super::super::environment::{Environment, TestEnvironment};
     use super::super::configuration::*;
 
@@ -296,7 +436,7 @@ mod tests {
         run_cli(&environment, vec![String::from(""), String::from("--version")]).unwrap();
         let logged_messages = environment.get_logged_messages();
         assert_eq!(logged_messages[0], format!("dprint v{}", env!("CARGO_PKG_VERSION")));
-        assert_eq!(logged_messages.len(), 3); // good enough
+        assert_eq!(logged_messages.len(), 7); // good enough
     }
 
     #[test]
@@ -306,7 +446,11 @@ mod tests {
         let logged_messages = environment.get_logged_messages();
         assert_eq!(logged_messages[0].starts_with("typescript/javascript: {\n"), true); // good enough
         assert_eq!(logged_messages[1].starts_with("json/jsonc: {\n"), true);
-        assert_eq!(logged_messages.len(), 2);
+        assert_eq!(logged_messages[2].starts_with("markdown: {\n"), true);
+        assert_eq!(logged_messages[3].starts_with("toml: {\n"), true);
+        assert_eq!(logged_messages[4].starts_with("yaml: {\n"), true);
+        assert_eq!(logged_messages[5].starts_with("css: {\n"), true);
+        assert_eq!(logged_messages.len(), 6);
     }
 
     #[test]
@@ -331,6 +475,169 @@ mod tests {
         assert_eq!(environment.read_file(&file_path).unwrap(), "const t = 4;\n");
     }
 
+    struct PanickingPlugin {
+        diagnostics: Vec<ConfigurationDiagnostic>,
+    }
+
+    impl Plugin for PanickingPlugin {
+        fn name(&self) -> &'static str { "panicking" }
+        fn version(&self) -> &'static str { "0.1.0" }
+        fn config_keys(&self) -> Vec<String> { Vec::new() }
+        fn initialize(&mut self, _: HashMap<String, String>, _: &GlobalConfiguration) {}
+        fn should_format_file(&self, file_path: &PathBuf, _: &str) -> bool {
+            file_path.extension().and_then(|e| e.to_str()) == Some("txt")
+        }
+        fn get_resolved_config(&self) -> String { String::new() }
+        fn get_configuration_diagnostics(&self) -> &Vec<ConfigurationDiagnostic> { &self.diagnostics }
+        fn format_text(&self, _: &PathBuf, _: &str, _: &FormatHost) -> Result<String, FormatError> {
+            panic!("Unexpected token.");
+        }
+    }
+
+    #[test]
+    fn it_should_format_other_files_when_a_plugin_panics() {
+        set_panic_hook();
+        let environment = TestEnvironment::new();
+        let file_path1 = PathBuf::from("/file1.ts");
+        let file_path2 = PathBuf::from("/file2.txt");
+        let file_path3 = PathBuf::from("/file3.ts");
+        environment.write_file(&file_path1, "const t=4;").unwrap();
+        environment.write_file(&file_path2, "text").unwrap();
+        environment.write_file(&file_path3, "const u=5;").unwrap();
+        let mut typescript_plugin = dprint_plugin_typescript::TypeScriptPlugin::new();
+        typescript_plugin.initialize(HashMap::new(), &resolve_global_config(HashMap::new()).config);
+        let formatter = Formatter::new(vec![Box::new(PanickingPlugin { diagnostics: Vec::new() }), Box::new(typescript_plugin)]);
+        format_files(&environment, formatter, vec![file_path1.clone(), file_path2.clone(), file_path3.clone()]);
+
+        assert_eq!(environment.get_logged_messages(), vec!["Formatted 2 files."]);
+        assert_eq!(environment.get_logged_errors(), vec![
+            "Error formatting: /file2.txt\n    The panicking plugin panicked formatting /file2.txt: Unexpected token."
+        ]);
+        assert_eq!(environment.read_file(&file_path1).unwrap(), "const t = 4;\n");
+        assert_eq!(environment.read_file(&file_path2).unwrap(), "text");
+        assert_eq!(environment.read_file(&file_path3).unwrap(), "const u = 5;\n");
+    }
+
+    #[test]
+    fn it_should_format_code_blocks_in_markdown_files() {
+        let environment = TestEnvironment::new();
+        let file_path = PathBuf::from("/file.md");
+        environment.write_file(&file_path, "# Title\n```ts\nconst t=4;\n```\n").unwrap();
+        run_cli(&environment, vec![String::from(""), String::from("/file.md")]).unwrap();
+        assert_eq!(environment.get_logged_messages(), vec!["Formatted 1 file."]);
+        assert_eq!(environment.get_logged_errors().len(), 0);
+        assert_eq!(environment.read_file(&file_path).unwrap(), "# Title\n\n```ts\nconst t = 4;\n```\n");
+    }
+
+    #[test]
+    fn it_should_format_toml_files() {
+        let environment = TestEnvironment::new();
+        let file_path = PathBuf::from("/Cargo.toml");
+        environment.write_file(&file_path, "[package]\nname=\"test\"\n").unwrap();
+        run_cli(&environment, vec![String::from(""), String::from("/Cargo.toml")]).unwrap();
+        assert_eq!(environment.get_logged_messages(), vec!["Formatted 1 file."]);
+        assert_eq!(environment.get_logged_errors().len(), 0);
+        assert_eq!(environment.read_file(&file_path).unwrap(), "[package]\nname = \"test\"\n");
+    }
+
+    #[test]
+    fn it_should_format_yaml_files() {
+        let environment = TestEnvironment::new();
+        let file_path = PathBuf::from("/file.yml");
+        environment.write_file(&file_path, "a:\n    - 'b'\n").unwrap();
+        run_cli(&environment, vec![String::from(""), String::from("/file.yml")]).unwrap();
+        assert_eq!(environment.get_logged_messages(), vec!["Formatted 1 file."]);
+        assert_eq!(environment.get_logged_errors().len(), 0);
+        assert_eq!(environment.read_file(&file_path).unwrap(), "a:\n  - \"b\"\n");
+    }
+
+    #[test]
+    fn it_should_output_edits() {
+        let environment = TestEnvironment::new();
+        let file_path = PathBuf::from("/file.ts");
+        environment.write_file(&file_path, "const t=4;").unwrap();
+        environment.write_file(&PathBuf::from("/file2.ts"), "const t = 4;\n").unwrap();
+        run_cli(&environment, vec![String::from(""), String::from("--output-edits"), String::from("/file.ts"), String::from("/file2.ts")]).unwrap();
+        assert_eq!(environment.get_logged_messages(), vec![
+            r#"{"filePath":"/file.ts","edits":[{"range":[7,7],"newText":" "},{"range":[8,8],"newText":" "},{"range":[10,10],"newText":"\n"}]}"#,
+        ]);
+        assert_eq!(environment.get_logged_errors().len(), 0);
+        assert_eq!(environment.read_file(&file_path).unwrap(), "const t=4;");
+    }
+
+    #[test]
+    fn it_should_report_long_lines() {
+        let environment = TestEnvironment::new();
+        let file_path = PathBuf::from("/file.css");
+        let url = format!("url(\"https://example.com/{}.png\")", "a".repeat(100));
+        let file_text = format!("a {{\n    background: {};\n}}\n", url);
+        environment.write_file(&file_path, &file_text).unwrap();
+        environment.write_file(&PathBuf::from("/file2.css"), "a {\n    color: red;\n}\n").unwrap();
+        run_cli(&environment, vec![String::from(""), String::from("--report-long-lines"), String::from("/file.css"), String::from("/file2.css")]).unwrap();
+        assert_eq!(environment.get_logged_messages(), vec![
+            format!("/file.css:2:17: warning: Line is 148 columns wide, which exceeds the line width. Could not break up: {}...", &url[..40]),
+        ]);
+        assert_eq!(environment.get_logged_errors().len(), 0);
+        assert_eq!(environment.read_file(&file_path).unwrap(), file_text);
+    }
+
+    #[test]
+    fn it_should_report_long_lines_of_markdown_and_component_files() {
+        let environment = TestEnvironment::new();
+        let url = format!("https://example.com/{}", "a".repeat(120));
+        environment.write_file(&PathBuf::from("/file.md"), &format!("# Title\n\nSee {}\n", url)).unwrap();
+        environment.write_file(&PathBuf::from("/file.vue"), &format!("<template></template>\n<script>\nconst t =\n    \"{}\";\n</script>\n", url)).unwrap();
+        run_cli(&environment, vec![String::from(""), String::from("--report-long-lines"), String::from("/file.md"), String::from("/file.vue")]).unwrap();
+        let mut logged_messages = environment.get_logged_messages();
+        logged_messages.sort();
+        assert_eq!(logged_messages, vec![
+            format!("/file.md:3:5: warning: Line is 144 columns wide, which exceeds the line width. Could not break up: {}...", &url[..40]),
+            format!("/file.vue:4:5: warning: Line is 147 columns wide, which exceeds the line width. Could not break up: \"{}...", &url[..39]),
+        ]);
+        assert_eq!(environment.get_logged_errors().len(), 0);
+    }
+
+    #[test]
+    fn it_should_output_print_ir() {
+        let environment = TestEnvironment::new();
+        environment.write_file(&PathBuf::from("/file.toml"), "a=1\n").unwrap();
+        run_cli(&environment, vec![String::from(""), String::from("--print-ir"), String::from("/file.toml")]).unwrap();
+        let logged_messages = environment.get_logged_messages();
+        assert_eq!(logged_messages.len(), 1);
+        assert_eq!(logged_messages[0].contains("`a`"), true);
+        assert_eq!(environment.get_logged_errors().len(), 0);
+    }
+
+    #[test]
+    fn it_should_error_printing_ir_when_no_plugin_found() {
+        let environment = TestEnvironment::new();
+        environment.write_file(&PathBuf::from("/file.txt"), "text").unwrap();
+        let error_message = run_cli(&environment, vec![String::from(""), String::from("--print-ir"), String::from("/file.txt")]).err().unwrap();
+        assert_eq!(error_message, "Could not find a plugin to format /file.txt");
+    }
+
+    #[test]
+    fn it_should_format_vue_files() {
+        let environment = TestEnvironment::new();
+        let file_path = PathBuf::from("/file.vue");
+        environment.write_file(&file_path, "<template><div   /></template>\n<script>\nconst  t  =  4\n</script>\n").unwrap();
+        run_cli(&environment, vec![String::from(""), String::from("/file.vue")]).unwrap();
+        assert_eq!(environment.get_logged_messages(), vec!["Formatted 1 file."]);
+        assert_eq!(environment.get_logged_errors().len(), 0);
+        assert_eq!(environment.read_file(&file_path).unwrap(), "<template><div   /></template>\n<script>\nconst t = 4;\n</script>\n");
+    }
+
+    #[test]
+    fn it_should_format_css_files() {
+        let environment = TestEnvironment::new();
+        let file_path = PathBuf::from("/file.scss");
+        environment.write_file(&file_path, "a{color:red;&:hover{color:blue}}").unwrap();
+        run_cli(&environment, vec![String::from(""), String::from("/file.scss")]).unwrap();
+        assert_eq!(environment.get_logged_messages(), vec!["Formatted 1 file."]);
+        assert_eq!(environment.get_logged_errors().len(), 0);
+        assert_eq!(environment.read_file(&file_path).unwrap(), "a {\n    color: red;\n    &:hover {\n        color: blue;\n    }\n}\n");
+    }
+
     #[test]
     fn it_should_ignore_files_in_node_modules_by_default() {
         let environment = TestEnvironment::new();
@@ -47,7 +47,7 @@ impl FormatContext {
                     Some(result)
                 })
             },
-            Err(result) => Err(JsValue::from(result))
+            Err(err) => Err(JsValue::from(err.get_diagnostic_text(file_text)))
         }
     }
 }
@@ -51,9 +51,14 @@ impl FormatContext {
                     Some(result)
                 })
             },
-            Err(result) => Err(JsValue::from(result))
+            Err(err) => Err(JsValue::from(err.get_diagnostic_text(file_text)))
         }
     }
+
+    /// Gets the print items of the file as an indented tree for debugging.
+    pub fn get_print_ir(&self, file_path: &str, file_text: &str) -> Result<String, JsValue> {
+        self.formatter.get_print_ir(&PathBuf::from(file_path), file_text).map_err(|err| JsValue::from(err.get_diagnostic_text(file_text)))
+    }
 }
 
 fn js_map_to_hash_map(map: &js_sys::Map) -> HashMap<String, String> {
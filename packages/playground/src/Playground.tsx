@@ -8,12 +8,14 @@ import "./external/react-splitpane.css";
 
 export interface PlaygroundProps {
     formatText: (text: string, config: TypeScriptConfiguration) => string;
+    getPrintIr: (text: string, config: TypeScriptConfiguration) => string;
     resolveConfig: (config: TypeScriptConfiguration) => ResolvedTypeScriptConfiguration;
 }
 
 export interface PlaygroundState {
     text: string;
     formattedText: string;
+    showPrintIr: boolean;
     scrollTop: number;
     config: TypeScriptConfiguration;
 }
@@ -23,11 +25,13 @@ const urlSaver = new UrlSaver();
 
 export class Playground extends React.Component<PlaygroundProps, PlaygroundState> {
     private readonly formatText: PlaygroundProps["formatText"];
+    private readonly getPrintIr: PlaygroundProps["getPrintIr"];
     private readonly resolveConfig: PlaygroundProps["resolveConfig"];
 
     constructor(props: PlaygroundProps) {
         super(props);
         this.formatText = props.formatText;
+        this.getPrintIr = props.getPrintIr;
         this.resolveConfig = props.resolveConfig;
 
         const { text: initialText, config: initialUnresolvedConfig } = urlSaver.getUrlInfo();
@@ -53,6 +57,7 @@ export class Playground extends React.Component<PlaygroundProps, PlaygroundState
         this.state = {
             text: initialText,
             formattedText: this.formatText(initialText, config),
+            showPrintIr: false,
             scrollTop: 0,
             config,
         };
@@ -60,6 +65,7 @@ export class Playground extends React.Component<PlaygroundProps, PlaygroundState
         this.onConfigUpdate = this.onConfigUpdate.bind(this);
         this.onTextChange = this.onTextChange.bind(this);
         this.onScrollTopChange = this.onScrollTopChange.bind(this);
+        this.onShowPrintIrChange = this.onShowPrintIrChange.bind(this);
     }
 
     render() {
@@ -69,6 +75,10 @@ export class Playground extends React.Component<PlaygroundProps, PlaygroundState
                     <header className="appHeader">
                         <h1 id="title">dprint - Playground</h1>
                         <div id="headerRight">
+                            <label>
+                                <input type="checkbox" checked={this.state.showPrintIr} onChange={this.onShowPrintIrChange} />
+                                Print items
+                            </label>
                             <a href="/">Overview</a>
                             <a href="/playground">Playground</a>
                             <a href="/sponsor">Sponsor</a>
@@ -112,7 +122,7 @@ export class Playground extends React.Component<PlaygroundProps, PlaygroundState
     }
 
     private onConfigUpdate(config: TypeScriptConfiguration) {
-        this.setState({ config, formattedText: this.getFormattedText(config) });
+        this.setState({ config, formattedText: this.getFormattedText(config, this.state.showPrintIr) });
         this.updateUrl({ text: this.state.text, config });
     }
 
@@ -124,7 +134,7 @@ export class Playground extends React.Component<PlaygroundProps, PlaygroundState
         this.setState({ text: newText });
 
         this.lastUpdateTimeout = setTimeout(() => {
-            this.setState({ formattedText: this.getFormattedText() });
+            this.setState({ formattedText: this.getFormattedText(this.state.config, this.state.showPrintIr) });
             this.updateUrl({ text: newText, config: this.state.config });
         }, 250);
     }
@@ -133,8 +143,13 @@ export class Playground extends React.Component<PlaygroundProps, PlaygroundState
         urlSaver.updateUrl(urlInfo);
     }
 
-    private getFormattedText(config?: TypeScriptConfiguration) {
-        return this.formatText(this.state.text, config || this.state.config);
+    private getFormattedText(config: TypeScriptConfiguration, showPrintIr: boolean) {
+        return showPrintIr ? this.getPrintIr(this.state.text, config) : this.formatText(this.state.text, config);
+    }
+
+    private onShowPrintIrChange(e: React.ChangeEvent<HTMLInputElement>) {
+        const showPrintIr = e.target.checked;
+        this.setState({ showPrintIr, formattedText: this.getFormattedText(this.state.config, showPrintIr) });
     }
 
     private onScrollTopChange(scrollTop: number) {
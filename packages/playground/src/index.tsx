@@ -8,6 +8,7 @@ import { TypeScriptConfiguration, ResolvedTypeScriptConfiguration } from "dprint
 
 interface LoaderState {
     formatText: ((text: string, configuration: TypeScriptConfiguration) => string) | undefined;
+    getPrintIr: ((text: string, configuration: TypeScriptConfiguration) => string) | undefined;
     resolveConfig: ((configuration: TypeScriptConfiguration) => ResolvedTypeScriptConfiguration) | undefined;
 }
 
@@ -17,6 +18,7 @@ class Loader extends React.Component<{}, LoaderState> {
 
         this.state = {
             formatText: undefined,
+            getPrintIr: undefined,
             resolveConfig: undefined,
         };
 
@@ -29,6 +31,13 @@ class Loader extends React.Component<{}, LoaderState> {
                         return "Panic formatting file. Check console for details and report this bug.";
                     }
                 },
+                getPrintIr: (text, config) => {
+                    try {
+                        return wasmPkg.get_print_ir(text, getConfigAsMap(config));
+                    } catch (err) {
+                        return "Panic getting the print items. Check console for details and report this bug.";
+                    }
+                },
                 resolveConfig: config => {
                     return JSON.parse(wasmPkg.resolve_config(getConfigAsMap(config))) as ResolvedTypeScriptConfiguration;
                 },
@@ -37,10 +46,17 @@ class Loader extends React.Component<{}, LoaderState> {
     }
 
     render() {
-        if (this.state.formatText == null || this.state.resolveConfig == null)
+        if (this.state.formatText == null || this.state.getPrintIr == null || this.state.resolveConfig == null)
             return <Spinner />;
-        else
-            return <Playground formatText={this.state.formatText} resolveConfig={this.state.resolveConfig} />;
+        else {
+            return (
+                <Playground
+                    formatText={this.state.formatText}
+                    getPrintIr={this.state.getPrintIr}
+                    resolveConfig={this.state.resolveConfig}
+                />
+            );
+        }
     }
 }
 
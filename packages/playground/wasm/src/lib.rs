@@ -25,7 +25,19 @@ pub fn format_text(file_text: &str, configuration: &js_sys::Map) -> String {
     let formatter = dprint_plugin_typescript::Formatter::new(configuration);
     match formatter.format_text(&PathBuf::from("./file.tsx"), file_text) {
         Ok(result) => result,
-        Err(error) => String::from(error),
+        Err(error) => error.get_diagnostic_text(file_text),
+    }
+}
+
+#[wasm_bindgen]
+pub fn get_print_ir(file_text: &str, configuration: &js_sys::Map) -> String {
+    console_error_panic_hook::set_once();
+
+    let configuration = resolve_to_typescript_config(&configuration);
+    let formatter = dprint_plugin_typescript::Formatter::new(configuration);
+    match formatter.get_print_ir(&PathBuf::from("./file.tsx"), file_text) {
+        Ok(result) => result,
+        Err(error) => error.get_diagnostic_text(file_text),
     }
 }
 